@@ -1,6 +1,7 @@
 use clap::ValueEnum;
 use moon_affected::Affected;
 use moon_common::path::WorkspaceRelativePathBuf;
+use moon_common::SyncCheck;
 use moon_target::Target;
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
@@ -59,6 +60,14 @@ pub struct ActionContext {
     /// The type of profiler to run tasks with.
     pub profile: Option<ProfileType>,
 
+    /// Targets that completed in a previous run, and will be
+    /// skipped when resuming (via `--resume`).
+    pub resumed_targets: FxHashSet<Target>,
+
+    /// Check for drift instead of mutating files while syncing (via `--check`).
+    #[serde(skip)]
+    pub sync_check: Option<Arc<SyncCheck>>,
+
     /// The current state of running tasks (via their target).
     /// @mutable
     pub target_states: scc::HashMap<Target, TargetState>,
@@ -103,6 +112,10 @@ impl ActionContext {
         self.primary_targets.contains(target.as_ref())
     }
 
+    pub fn is_resumed_target<T: AsRef<Target>>(&self, target: T) -> bool {
+        self.resumed_targets.contains(target.as_ref())
+    }
+
     pub fn set_target_state<T: AsRef<Target>>(&self, target: T, state: TargetState) {
         let _ = self.target_states.insert(target.as_ref().to_owned(), state);
     }
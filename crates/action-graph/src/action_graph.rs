@@ -2,6 +2,7 @@ use crate::action_graph_error::ActionGraphError;
 use graph_cycles::Cycles;
 use moon_action::ActionNode;
 use moon_common::{color, is_test_env};
+use moon_graph_utils::{format_cytoscape, format_graphml, format_mermaid, GraphNodeAttributes};
 use petgraph::dot::{Config, Dot};
 use petgraph::prelude::*;
 use petgraph::visit::{IntoEdgeReferences, IntoNodeReferences};
@@ -136,6 +137,55 @@ impl ActionGraph {
 
         format!("{dot:?}")
     }
+
+    pub fn to_mermaid(&self) -> String {
+        format_mermaid(&self.labeled_graph())
+    }
+
+    pub fn to_graphml(&self) -> String {
+        format_graphml(&self.labeled_graph(), |index, _| {
+            self.get_node_attributes(index)
+        })
+    }
+
+    pub fn to_cytoscape(&self) -> miette::Result<String> {
+        format_cytoscape(&self.labeled_graph(), |index, _| {
+            self.get_node_attributes(index)
+        })
+    }
+
+    fn get_node_attributes(&self, index: NodeIndex) -> GraphNodeAttributes {
+        let Some(node) = self.graph.node_weight(index) else {
+            return vec![];
+        };
+
+        let type_of = match node {
+            ActionNode::None => "none",
+            ActionNode::InstallProjectDeps(_) => "install-project-deps",
+            ActionNode::InstallWorkspaceDeps(_) => "install-workspace-deps",
+            ActionNode::RunTask(_) => "run-task",
+            ActionNode::SetupToolchain(_) => "setup-toolchain",
+            ActionNode::SyncProject(_) => "sync-project",
+            ActionNode::SyncWorkspace => "sync-workspace",
+        };
+        let mut attributes = vec![("type", type_of.to_owned())];
+
+        match node {
+            ActionNode::RunTask(inner) => {
+                if let Some(project_id) = inner.target.get_project_id() {
+                    attributes.push(("project", project_id.to_string()));
+                }
+
+                attributes.push(("target", inner.target.to_string()));
+            }
+            ActionNode::InstallProjectDeps(inner) | ActionNode::SyncProject(inner) => {
+                attributes.push(("project", inner.project.to_string()));
+            }
+            _ => {}
+        };
+
+        attributes
+    }
 }
 
 pub struct ActionGraphIter<'graph> {
@@ -17,6 +17,7 @@ use moon_workspace_graph::{tasks::TaskGraphError, GraphConnections, WorkspaceGra
 use petgraph::prelude::*;
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::mem;
+use std::sync::Arc;
 use tracing::{debug, instrument, trace};
 
 #[derive(Default)]
@@ -40,6 +41,7 @@ pub struct ActionGraphBuilder<'app> {
     graph: DiGraph<ActionNode, ()>,
     indices: FxHashMap<ActionNode, NodeIndex>,
     platform_manager: &'app PlatformManager,
+    sync_check: bool,
     workspace_graph: &'app WorkspaceGraph,
 
     // Affected states
@@ -47,6 +49,7 @@ pub struct ActionGraphBuilder<'app> {
     touched_files: Option<FxHashSet<WorkspaceRelativePathBuf>>,
 
     // Target tracking
+    excluded_targets: FxHashSet<Target>,
     initial_targets: FxHashSet<Target>,
     passthrough_targets: FxHashSet<Target>,
     primary_targets: FxHashSet<Target>,
@@ -66,12 +69,14 @@ impl<'app> ActionGraphBuilder<'app> {
         Ok(ActionGraphBuilder {
             all_query: None,
             affected: None,
+            excluded_targets: FxHashSet::default(),
             graph: DiGraph::new(),
             indices: FxHashMap::default(),
             initial_targets: FxHashSet::default(),
             passthrough_targets: FxHashSet::default(),
             platform_manager,
             primary_targets: FxHashSet::default(),
+            sync_check: false,
             workspace_graph,
             touched_files: None,
         })
@@ -137,6 +142,25 @@ impl<'app> ActionGraphBuilder<'app> {
         }
     }
 
+    pub fn set_base_tasks(&mut self, tasks: FxHashMap<Target, Arc<Task>>) {
+        self.set_affected();
+
+        if let Some(affected) = self.affected.as_mut() {
+            affected.with_base_tasks(tasks);
+        }
+    }
+
+    pub fn set_lockfile_changes(
+        &mut self,
+        changes: FxHashMap<WorkspaceRelativePathBuf, FxHashSet<Id>>,
+    ) {
+        self.set_affected();
+
+        if let Some(affected) = self.affected.as_mut() {
+            affected.with_lockfile_changes(changes);
+        }
+    }
+
     pub fn set_affected_scopes(
         &mut self,
         upstream: UpstreamScope,
@@ -166,6 +190,12 @@ impl<'app> ActionGraphBuilder<'app> {
         Ok(())
     }
 
+    /// When checking for drift, syncing must not mutate the environment,
+    /// so do not setup toolchains, which may install tools and dependencies.
+    pub fn set_sync_check(&mut self, check: bool) {
+        self.sync_check = check;
+    }
+
     pub fn set_touched_files(
         &mut self,
         touched_files: FxHashSet<WorkspaceRelativePathBuf>,
@@ -311,6 +341,18 @@ impl<'app> ActionGraphBuilder<'app> {
             return Ok(None);
         }
 
+        // Exclusions only apply to the requested targets, so avoid filtering
+        // dependencies and dependents, which are ran without locators
+        if !reqs.target_locators.is_empty() && self.excluded_targets.contains(&task.target) {
+            debug!(
+                task_target = task.target.as_str(),
+                "Not running task {} because it has been excluded",
+                color::label(&task.target.id),
+            );
+
+            return Ok(None);
+        }
+
         // These tasks shouldn't actually run, so filter them out
         if self.passthrough_targets.contains(&task.target) {
             trace!(
@@ -494,6 +536,29 @@ impl<'app> ActionGraphBuilder<'app> {
             TargetScope::Deps => {
                 return Err(TargetError::NoDepsInRunContext.into());
             }
+            // +project:task
+            TargetScope::Dependents(project_locator) => {
+                for project_id in self.get_dependent_project_ids(project_locator)? {
+                    let project = self.workspace_graph.get_project(&project_id)?;
+
+                    // Don't error if the task does not exist
+                    if let Ok(task) = self
+                        .workspace_graph
+                        .get_task_from_project(&project.id, &target.task_id)
+                    {
+                        if task.is_internal() {
+                            continue;
+                        }
+
+                        if let Some(index) =
+                            self.run_task_with_config(&project, &task, reqs, config)?
+                        {
+                            inserted_targets.insert(task.target.clone());
+                            inserted_indices.insert(index);
+                        }
+                    }
+                }
+            }
             // project:task
             TargetScope::Project(project_locator) => {
                 let project = self.workspace_graph.get_project(project_locator)?;
@@ -560,64 +625,22 @@ impl<'app> ActionGraphBuilder<'app> {
         // Track the qualified as an initial target
         for locator in reqs.target_locators.clone() {
             match locator {
+                TargetLocator::Exclude { locator, .. } => {
+                    let excluded_targets = self.resolve_excluded_targets(&locator)?;
+
+                    self.excluded_targets.extend(excluded_targets);
+                }
                 TargetLocator::GlobMatch {
                     project_glob,
                     task_glob,
                     scope,
                     ..
                 } => {
-                    let mut is_all = false;
-                    let mut do_query = false;
-                    let mut projects = vec![];
-
-                    // Query for all applicable projects first since we can't
-                    // query projects + tasks at the same time
-                    if let Some(glob) = project_glob {
-                        let query = if let Some(tag_glob) = glob.strip_prefix('#') {
-                            format!("tag~{tag_glob}")
-                        } else {
-                            format!("project~{glob}")
-                        };
-
-                        projects = self.workspace_graph.query_projects(build_query(&query)?)?;
-                        do_query = !projects.is_empty();
-                    } else {
-                        match scope {
-                            Some(TargetScope::All) => {
-                                is_all = true;
-                                do_query = true;
-                            }
-                            _ => {
-                                // Don't query for the other scopes,
-                                // since they're not valid from the run context
-                            }
-                        };
-                    }
-
-                    // Then query for all tasks within the queried projects
-                    if do_query {
-                        let mut query = format!("task~{task_glob}");
-
-                        if !is_all {
-                            query = format!(
-                                "project=[{}] && {query}",
-                                projects
-                                    .into_iter()
-                                    .map(|project| project.id.to_string())
-                                    .collect::<Vec<_>>()
-                                    .join(",")
-                            );
-                        }
-
-                        let tasks = self.workspace_graph.query_tasks(build_query(&query)?)?;
-
-                        initial_targets.extend(
-                            tasks
-                                .into_iter()
-                                .map(|task| task.target.clone())
-                                .collect::<Vec<_>>(),
-                        );
-                    }
+                    initial_targets.extend(self.query_targets_by_glob(
+                        project_glob.as_deref(),
+                        &task_glob,
+                        scope.as_ref(),
+                    )?);
                 }
                 TargetLocator::Qualified(target) => {
                     initial_targets.push(target);
@@ -695,10 +718,15 @@ impl<'app> ActionGraphBuilder<'app> {
             }
         }
 
-        // Syncing requires the language's tool to be installed
-        let setup_tool_index = self.setup_toolchain(node.get_runtime());
+        // Syncing requires the language's tool to be installed,
+        // unless checking, which only requires the workspace to be synced
+        let requirement_index = if self.sync_check {
+            self.sync_workspace()
+        } else {
+            self.setup_toolchain(node.get_runtime())
+        };
         let index = self.insert_node(node);
-        let mut edges = vec![setup_tool_index];
+        let mut edges = vec![requirement_index];
 
         // And we should also depend on other projects
         for dep_project_id in self.workspace_graph.projects.dependencies_of(project) {
@@ -731,6 +759,144 @@ impl<'app> ActionGraphBuilder<'app> {
 
     // PRIVATE
 
+    fn query_targets_by_glob(
+        &self,
+        project_glob: Option<&str>,
+        task_glob: &str,
+        scope: Option<&TargetScope>,
+    ) -> miette::Result<Vec<Target>> {
+        let mut is_all = false;
+        let mut do_query = false;
+        let mut projects = vec![];
+
+        // Query for all applicable projects first since we can't
+        // query projects + tasks at the same time
+        if let Some(glob) = project_glob {
+            let query = if let Some(tag_glob) = glob.strip_prefix('#') {
+                format!("tag~{tag_glob}")
+            } else {
+                format!("project~{glob}")
+            };
+
+            projects = self.workspace_graph.query_projects(build_query(&query)?)?;
+            do_query = !projects.is_empty();
+        } else {
+            match scope {
+                Some(TargetScope::All) => {
+                    is_all = true;
+                    do_query = true;
+                }
+                _ => {
+                    // Don't query for the other scopes,
+                    // since they're not valid from the run context
+                }
+            };
+        }
+
+        // Then query for all tasks within the queried projects
+        if !do_query {
+            return Ok(vec![]);
+        }
+
+        let mut query = format!("task~{task_glob}");
+
+        if !is_all {
+            query = format!(
+                "project=[{}] && {query}",
+                projects
+                    .into_iter()
+                    .map(|project| project.id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            );
+        }
+
+        Ok(self
+            .workspace_graph
+            .query_tasks(build_query(&query)?)?
+            .into_iter()
+            .map(|task| task.target.clone())
+            .collect())
+    }
+
+    fn resolve_excluded_targets(&self, locator: &TargetLocator) -> miette::Result<Vec<Target>> {
+        let target = match locator {
+            TargetLocator::Exclude { original, .. } => {
+                return Err(TargetError::InvalidExclusion(format!("!{original}")).into());
+            }
+            TargetLocator::GlobMatch {
+                project_glob,
+                task_glob,
+                scope,
+                ..
+            } => {
+                return self.query_targets_by_glob(
+                    project_glob.as_deref(),
+                    task_glob,
+                    scope.as_ref(),
+                );
+            }
+            TargetLocator::Qualified(target) => target,
+            TargetLocator::TaskFromWorkingDir(task_id) => {
+                return Ok(vec![Target::new(
+                    &self.workspace_graph.get_project_from_path(None)?.id,
+                    task_id,
+                )?]);
+            }
+        };
+
+        let project_ids = match &target.scope {
+            // :task
+            TargetScope::All => self
+                .workspace_graph
+                .get_projects()?
+                .into_iter()
+                .map(|project| project.id.clone())
+                .collect(),
+            // ^:task
+            TargetScope::Deps => {
+                return Err(TargetError::NoDepsInRunContext.into());
+            }
+            // +project:task
+            TargetScope::Dependents(project_locator) => {
+                self.get_dependent_project_ids(project_locator)?
+            }
+            // ~:task
+            TargetScope::OwnSelf => {
+                return Err(TargetError::NoSelfInRunContext.into());
+            }
+            // project:task
+            TargetScope::Project(project_locator) => {
+                vec![self
+                    .workspace_graph
+                    .get_project(project_locator)?
+                    .id
+                    .clone()]
+            }
+            // #tag:task
+            TargetScope::Tag(tag) => self
+                .workspace_graph
+                .query_projects(build_query(format!("tag={}", tag).as_str())?)?
+                .into_iter()
+                .map(|project| project.id.clone())
+                .collect(),
+        };
+
+        project_ids
+            .into_iter()
+            .map(|project_id| Target::new(project_id, &target.task_id))
+            .collect()
+    }
+
+    fn get_dependent_project_ids(&self, project_locator: &Id) -> miette::Result<Vec<Id>> {
+        let project = self.workspace_graph.get_project(project_locator)?;
+
+        // Dependents are only known once all projects have been loaded
+        self.workspace_graph.get_projects()?;
+
+        Ok(self.workspace_graph.projects.dependents_of(&project))
+    }
+
     fn link_requirements(&mut self, index: NodeIndex, edges: Vec<NodeIndex>) {
         trace!(
             index = index.index(),
@@ -1841,6 +1841,132 @@ mod action_graph {
             assert!(graph.is_empty());
         }
 
+        #[tokio::test]
+        async fn runs_by_dependents_scope() {
+            let sandbox = create_sandbox("tasks");
+            let container = ActionGraphContainer::new(sandbox.path()).await;
+            let mut builder = container.create_builder();
+
+            builder
+                .run_from_requirements(RunRequirements {
+                    target_locators: FxHashSet::from_iter([
+                        TargetLocator::parse("+common:build").unwrap()
+                    ]),
+                    ..Default::default()
+                })
+                .unwrap();
+
+            let context = builder.build_context();
+
+            assert_eq!(
+                context.primary_targets,
+                FxHashSet::from_iter([Target::parse("client:build").unwrap()])
+            );
+        }
+
+        #[tokio::test]
+        async fn excludes_targets() {
+            let sandbox = create_sandbox("tasks");
+            let container = ActionGraphContainer::new(sandbox.path()).await;
+            let mut builder = container.create_builder();
+
+            builder
+                .run_from_requirements(RunRequirements {
+                    target_locators: FxHashSet::from_iter([
+                        TargetLocator::parse(":build").unwrap(),
+                        TargetLocator::parse("!server:build").unwrap(),
+                    ]),
+                    ..Default::default()
+                })
+                .unwrap();
+
+            let context = builder.build_context();
+
+            assert_eq!(
+                context.primary_targets,
+                FxHashSet::from_iter([
+                    Target::parse("client:build").unwrap(),
+                    Target::parse("common:build").unwrap(),
+                    Target::parse("base:build").unwrap(),
+                ])
+            );
+        }
+
+        #[tokio::test]
+        async fn excludes_by_project_glob() {
+            let sandbox = create_sandbox("tasks");
+            let container = ActionGraphContainer::new(sandbox.path()).await;
+            let mut builder = container.create_builder();
+
+            builder
+                .run_from_requirements(RunRequirements {
+                    target_locators: FxHashSet::from_iter([
+                        TargetLocator::parse(":build").unwrap(),
+                        TargetLocator::parse("!c*:build").unwrap(),
+                    ]),
+                    ..Default::default()
+                })
+                .unwrap();
+
+            let context = builder.build_context();
+
+            assert_eq!(
+                context.primary_targets,
+                FxHashSet::from_iter([
+                    Target::parse("server:build").unwrap(),
+                    Target::parse("base:build").unwrap(),
+                ])
+            );
+        }
+
+        #[tokio::test]
+        async fn excludes_by_tag() {
+            let sandbox = create_sandbox("tasks");
+            let container = ActionGraphContainer::new(sandbox.path()).await;
+            let mut builder = container.create_builder();
+
+            builder
+                .run_from_requirements(RunRequirements {
+                    target_locators: FxHashSet::from_iter([
+                        TargetLocator::parse(":lint").unwrap(),
+                        TargetLocator::parse("!#frontend").unwrap(),
+                    ]),
+                    ..Default::default()
+                })
+                .unwrap();
+
+            let context = builder.build_context();
+
+            assert_eq!(
+                context.primary_targets,
+                FxHashSet::from_iter([Target::parse("server:lint").unwrap()])
+            );
+        }
+
+        #[tokio::test]
+        async fn doesnt_exclude_dependencies() {
+            let sandbox = create_sandbox("tasks");
+            let container = ActionGraphContainer::new(sandbox.path()).await;
+            let mut builder = container.create_builder();
+
+            builder
+                .run_from_requirements(RunRequirements {
+                    target_locators: FxHashSet::from_iter([
+                        TargetLocator::parse("client:build").unwrap(),
+                        TargetLocator::parse("!server:build").unwrap(),
+                    ]),
+                    ..Default::default()
+                })
+                .unwrap();
+
+            let graph = builder.build();
+
+            assert!(graph.get_nodes().into_iter().any(|node| match node {
+                ActionNode::RunTask(inner) => inner.target.as_str() == "server:build",
+                _ => false,
+            }));
+        }
+
         #[tokio::test]
         async fn computes_context() {
             let sandbox = create_sandbox("tasks");
@@ -2014,6 +2140,33 @@ mod action_graph {
             );
         }
 
+        #[tokio::test]
+        async fn doesnt_setup_toolchain_when_checking() {
+            let wg = create_project_graph().await;
+            let mut builder = ActionGraphBuilder::new(&wg).unwrap();
+            builder.set_sync_check(true);
+
+            let foo = wg.get_project("foo").unwrap();
+            builder.sync_project(&foo).unwrap();
+
+            let graph = builder.build();
+
+            assert_eq!(
+                topo(graph),
+                vec![
+                    ActionNode::sync_workspace(),
+                    ActionNode::sync_project(SyncProjectNode {
+                        project: Id::raw("bar"),
+                        runtime: Runtime::system()
+                    }),
+                    ActionNode::sync_project(SyncProjectNode {
+                        project: Id::raw("foo"),
+                        runtime: Runtime::system()
+                    })
+                ]
+            );
+        }
+
         #[tokio::test]
         async fn graphs_single_with_dep() {
             let wg = create_project_graph().await;
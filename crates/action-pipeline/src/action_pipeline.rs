@@ -4,9 +4,11 @@ use crate::job_context::JobContext;
 use crate::job_dispatcher::JobDispatcher;
 use crate::subscribers::cleanup_subscriber::CleanupSubscriber;
 use crate::subscribers::console_subscriber::ConsoleSubscriber;
+use crate::subscribers::history_subscriber::HistorySubscriber;
 use crate::subscribers::moonbase_subscriber::MoonbaseSubscriber;
 use crate::subscribers::remote_subscriber::RemoteSubscriber;
 use crate::subscribers::reports_subscriber::ReportsSubscriber;
+use crate::subscribers::resume_subscriber::ResumeSubscriber;
 use crate::subscribers::webhooks_subscriber::WebhooksSubscriber;
 use miette::IntoDiagnostic;
 use moon_action::{Action, ActionNode, ActionPipelineStatus};
@@ -15,6 +17,7 @@ use moon_action_graph::ActionGraph;
 use moon_api::Moonbase;
 use moon_app_context::AppContext;
 use moon_common::{color, is_ci, is_test_env};
+use moon_config::NotifierWebhookConfig;
 use moon_process::{ProcessRegistry, SignalType};
 use moon_toolchain_plugin::ToolchainRegistry;
 use moon_workspace_graph::WorkspaceGraph;
@@ -31,6 +34,7 @@ pub struct ActionPipeline {
     pub bail: bool,
     pub concurrency: usize,
     pub report_name: String,
+    pub resumable: bool,
     pub summarize: bool,
 
     // State
@@ -63,6 +67,7 @@ impl ActionPipeline {
             duration: None,
             emitter: Arc::new(EventEmitter::default()),
             report_name: "runReport.json".into(),
+            resumable: false,
             status: ActionPipelineStatus::Pending,
             summarize: false,
             toolchain_registry,
@@ -403,6 +408,23 @@ impl ActionPipeline {
             ))
             .await;
 
+        self.emitter
+            .subscribe(HistorySubscriber::new(Arc::clone(
+                &self.app_context.cache_engine,
+            )))
+            .await;
+
+        if self.resumable {
+            debug!("Subscribing resume state");
+
+            self.emitter
+                .subscribe(ResumeSubscriber::new(
+                    Arc::clone(&self.app_context.cache_engine),
+                    Arc::clone(&self.action_context),
+                ))
+                .await;
+        }
+
         if let Some(session) = Moonbase::session() {
             debug!("Subscribing moonbase");
 
@@ -413,15 +435,36 @@ impl ActionPipeline {
 
         // For security and privacy purposes, only send webhooks from a CI environment
         if is_ci() || is_test_env() {
-            if let Some(webhook_url) = &self.app_context.workspace_config.notifier.webhook_url {
+            let notifier_config = &self.app_context.workspace_config.notifier;
+            let mut webhooks = notifier_config.webhooks.clone();
+
+            // The legacy setting sends all events, and does not retry,
+            // as the first failed request disables the endpoint
+            if let Some(webhook_url) = &notifier_config.webhook_url {
                 debug!(
                     url = webhook_url,
                     "Subscribing webhook events ({} enabled)",
                     color::property("notifier.webhookUrl"),
                 );
 
+                webhooks.push(NotifierWebhookConfig {
+                    retries: 0,
+                    url: webhook_url.to_owned(),
+                    ..Default::default()
+                });
+            }
+
+            if !notifier_config.webhooks.is_empty() {
+                debug!(
+                    "Subscribing webhook events to {} endpoints ({} enabled)",
+                    notifier_config.webhooks.len(),
+                    color::property("notifier.webhooks"),
+                );
+            }
+
+            if !webhooks.is_empty() {
                 self.emitter
-                    .subscribe(WebhooksSubscriber::new(webhook_url))
+                    .subscribe(WebhooksSubscriber::new(webhooks))
                     .await;
             }
         }
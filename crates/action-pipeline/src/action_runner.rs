@@ -167,6 +167,18 @@ pub async fn run_action(
             result
         }
 
+        // Task completed in the run that is being resumed,
+        // so avoid running it again (even if not cacheable)
+        ActionNode::RunTask(inner) if action_context.is_resumed_target(&inner.target) => {
+            trace!(
+                index = action.node_index,
+                target = inner.target.as_str(),
+                "Skipping task as it completed in the previous run"
+            );
+
+            Ok(ActionStatus::Skipped)
+        }
+
         ActionNode::RunTask(inner) => {
             emitter
                 .emit(Event::TaskRunning {
@@ -111,6 +111,28 @@ impl Event<'_> {
             Event::WorkspaceSynced { .. } => "workspace.synced",
         }
     }
+
+    pub fn is_failure(&self) -> bool {
+        match self {
+            Event::ActionCompleted { action, error, .. } => error.is_some() || action.has_failed(),
+            Event::PipelineCompleted {
+                actions,
+                error,
+                status,
+                ..
+            } => {
+                error.is_some()
+                    || !matches!(status, ActionPipelineStatus::Completed)
+                    || actions.iter().any(|action| action.has_failed())
+            }
+            Event::DependenciesInstalled { error, .. }
+            | Event::ProjectSynced { error, .. }
+            | Event::TaskRan { error, .. }
+            | Event::ToolInstalled { error, .. }
+            | Event::WorkspaceSynced { error } => error.is_some(),
+            _ => false,
+        }
+    }
 }
 
 #[async_trait]
@@ -5,6 +5,7 @@ mod job;
 mod job_context;
 mod job_dispatcher;
 pub mod reports;
+pub mod resume_state;
 mod subscribers;
 
 pub use action_pipeline::*;
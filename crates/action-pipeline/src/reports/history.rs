@@ -0,0 +1,182 @@
+use miette::IntoDiagnostic;
+use moon_action::{Action, ActionNode, ActionPipelineStatus, ActionStatus};
+use moon_task::Target;
+use moon_time::now_millis;
+use serde::{Deserialize, Serialize};
+use starbase_utils::fs;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// When the history file grows larger than this size (in bytes),
+/// the oldest half of all records will be removed.
+const MAX_HISTORY_SIZE: u64 = 10 * 1024 * 1024;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskHistoryRecord {
+    /// How long the task took to run, in milliseconds.
+    pub duration: u64,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+
+    pub status: ActionStatus,
+
+    pub target: Target,
+}
+
+impl TaskHistoryRecord {
+    pub fn get_duration(&self) -> Duration {
+        Duration::from_millis(self.duration)
+    }
+
+    pub fn has_failed(&self) -> bool {
+        matches!(
+            self.status,
+            ActionStatus::Aborted | ActionStatus::Failed | ActionStatus::TimedOut
+        )
+    }
+
+    pub fn was_cached(&self) -> bool {
+        matches!(
+            self.status,
+            ActionStatus::Cached | ActionStatus::CachedFromRemote
+        )
+    }
+
+    pub fn was_executed(&self) -> bool {
+        matches!(self.status, ActionStatus::Passed) || self.has_failed()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunHistoryRecord {
+    /// How long the pipeline took to run, in milliseconds.
+    pub duration: u64,
+
+    /// When the pipeline completed, in milliseconds since the epoch.
+    pub finished_at: u64,
+
+    pub status: ActionPipelineStatus,
+
+    pub tasks: Vec<TaskHistoryRecord>,
+}
+
+impl RunHistoryRecord {
+    pub fn from_actions(
+        actions: &[Action],
+        duration: &Duration,
+        status: &ActionPipelineStatus,
+    ) -> Self {
+        let tasks = actions
+            .iter()
+            .filter_map(|action| {
+                let ActionNode::RunTask(node) = &*action.node else {
+                    return None;
+                };
+
+                Some(TaskHistoryRecord {
+                    duration: action.duration.unwrap_or_default().as_millis() as u64,
+                    hash: action.operations.get_hash().map(|hash| hash.to_owned()),
+                    status: action.status,
+                    target: node.target.clone(),
+                })
+            })
+            .collect();
+
+        Self {
+            duration: duration.as_millis() as u64,
+            finished_at: now_millis() as u64,
+            status: *status,
+            tasks,
+        }
+    }
+}
+
+/// An append-only store of compact run records, persisted as
+/// JSON lines in the `.moon/cache/history.jsonl` file.
+pub struct RunHistory {
+    pub path: PathBuf,
+}
+
+impl RunHistory {
+    pub fn new(cache_dir: &Path) -> Self {
+        Self {
+            path: cache_dir.join("history.jsonl"),
+        }
+    }
+
+    pub fn append(&self, record: &RunHistoryRecord) -> miette::Result<()> {
+        debug!(history = ?self.path, "Appending run to history");
+
+        let mut line = serde_json::to_string(record).into_diagnostic()?;
+        line.push('\n');
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .into_diagnostic()?;
+
+        file.write_all(line.as_bytes()).into_diagnostic()?;
+
+        drop(file);
+
+        if fs::metadata(&self.path)?.len() > MAX_HISTORY_SIZE {
+            self.prune()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn load(&self) -> miette::Result<Vec<RunHistoryRecord>> {
+        if !self.path.exists() {
+            return Ok(vec![]);
+        }
+
+        let content = fs::read_file(&self.path)?;
+        let mut records = vec![];
+
+        for line in content.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            // Ignore records from older versions or partial writes
+            match serde_json::from_str::<RunHistoryRecord>(line) {
+                Ok(record) => records.push(record),
+                Err(error) => {
+                    warn!(history = ?self.path, "Skipping invalid run history record: {error}");
+                }
+            };
+        }
+
+        Ok(records)
+    }
+
+    pub fn prune(&self) -> miette::Result<()> {
+        let records = self.load()?;
+        let remove_count = records.len() / 2;
+
+        debug!(
+            history = ?self.path,
+            "Run history is too large, removing the {} oldest records",
+            remove_count
+        );
+
+        let mut content = String::new();
+
+        for record in records.into_iter().skip(remove_count) {
+            content.push_str(&serde_json::to_string(&record).into_diagnostic()?);
+            content.push('\n');
+        }
+
+        fs::write_file(&self.path, content)?;
+
+        Ok(())
+    }
+}
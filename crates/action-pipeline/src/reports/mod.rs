@@ -1 +1,3 @@
 pub mod estimate;
+pub mod history;
+pub mod stats;
@@ -0,0 +1,272 @@
+use crate::reports::history::RunHistoryRecord;
+use moon_task::Target;
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use std::mem;
+use std::time::Duration;
+use tracing::{debug, instrument};
+
+/// Minimum amount of executions required before a task
+/// can be considered for regression detection.
+const MIN_REGRESSION_SAMPLES: usize = 4;
+
+/// Percentage that a task's recent average must be slower
+/// than its previous average to be considered a regression.
+const REGRESSION_THRESHOLD: f32 = 25.0;
+
+/// Ignore regressions that are smaller than this, as they're
+/// typically noise from the machine or environment.
+const REGRESSION_MIN_DIFF: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskStats {
+    pub target: Target,
+    pub runs: usize,
+    pub cached: usize,
+    pub executed: usize,
+    pub failed: usize,
+
+    /// Average duration of executions (excludes cache hits).
+    pub average_duration: Duration,
+
+    /// Longest duration of an execution.
+    pub max_duration: Duration,
+
+    #[serde(skip)]
+    durations: Vec<Duration>,
+}
+
+impl TaskStats {
+    pub fn cache_hit_rate(&self) -> f32 {
+        percent(self.cached, self.runs)
+    }
+
+    pub fn failure_rate(&self) -> f32 {
+        percent(self.failed, self.executed)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectStats {
+    pub project: String,
+    pub runs: usize,
+    pub cached: usize,
+    pub cache_hit_rate: f32,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskRegression {
+    pub target: Target,
+    pub previous_duration: Duration,
+    pub recent_duration: Duration,
+    pub percent: f32,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunStats {
+    /// Total amount of pipeline runs in the history.
+    pub total_runs: usize,
+
+    /// Total amount of task runs in the history.
+    pub total_tasks: usize,
+
+    /// Percentage of task runs that were a cache hit.
+    pub cache_hit_rate: f32,
+
+    /// Tasks with the highest average execution duration.
+    pub slowest_tasks: Vec<TaskStats>,
+
+    /// Tasks that failed the most often.
+    pub failing_tasks: Vec<TaskStats>,
+
+    /// Cache hit rate per project, lowest first.
+    pub projects: Vec<ProjectStats>,
+
+    /// Tasks whose recent executions are noticeably slower
+    /// than their previous executions.
+    pub regressions: Vec<TaskRegression>,
+}
+
+impl RunStats {
+    #[instrument(name = "calculate_stats", skip_all)]
+    pub fn calculate(records: &[RunHistoryRecord], limit: usize) -> Self {
+        debug!(
+            records = records.len(),
+            "Calculating stats from run history"
+        );
+
+        let mut tasks: FxHashMap<&Target, TaskStats> = FxHashMap::default();
+        let mut projects: FxHashMap<String, ProjectStats> = FxHashMap::default();
+        let mut total_tasks = 0;
+        let mut total_cached = 0;
+
+        // Records are in chronological order, which
+        // is required for detecting regressions
+        for record in records {
+            for task in &record.tasks {
+                total_tasks += 1;
+
+                let stats = tasks.entry(&task.target).or_insert_with(|| TaskStats {
+                    target: task.target.clone(),
+                    ..TaskStats::default()
+                });
+
+                stats.runs += 1;
+
+                if task.was_cached() {
+                    stats.cached += 1;
+                    total_cached += 1;
+                }
+
+                if task.was_executed() {
+                    let duration = task.get_duration();
+
+                    stats.executed += 1;
+                    stats.max_duration = stats.max_duration.max(duration);
+                    stats.durations.push(duration);
+                }
+
+                if task.has_failed() {
+                    stats.failed += 1;
+                }
+
+                if let Some(project_id) = task.target.get_project_id() {
+                    let project =
+                        projects
+                            .entry(project_id.to_string())
+                            .or_insert_with(|| ProjectStats {
+                                project: project_id.to_string(),
+                                ..ProjectStats::default()
+                            });
+
+                    project.runs += 1;
+
+                    if task.was_cached() {
+                        project.cached += 1;
+                    }
+                }
+            }
+        }
+
+        let mut regressions = vec![];
+
+        for stats in tasks.values_mut() {
+            let durations = mem::take(&mut stats.durations);
+
+            stats.average_duration = average(&durations);
+
+            if let Some(regression) = detect_regression(&stats.target, &durations) {
+                regressions.push(regression);
+            }
+        }
+
+        let mut slowest_tasks = tasks
+            .values()
+            .filter(|stats| stats.executed > 0)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        slowest_tasks.sort_by(|a, b| {
+            b.average_duration
+                .cmp(&a.average_duration)
+                .then_with(|| a.target.cmp(&b.target))
+        });
+        slowest_tasks.truncate(limit);
+
+        let mut failing_tasks = tasks
+            .values()
+            .filter(|stats| stats.failed > 0)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        failing_tasks.sort_by(|a, b| {
+            b.failed
+                .cmp(&a.failed)
+                .then_with(|| a.target.cmp(&b.target))
+        });
+        failing_tasks.truncate(limit);
+
+        let mut projects = projects
+            .into_values()
+            .map(|mut project| {
+                project.cache_hit_rate = percent(project.cached, project.runs);
+                project
+            })
+            .collect::<Vec<_>>();
+
+        projects.sort_by(|a, b| {
+            a.cache_hit_rate
+                .total_cmp(&b.cache_hit_rate)
+                .then_with(|| a.project.cmp(&b.project))
+        });
+        projects.truncate(limit);
+
+        regressions.sort_by(|a, b| {
+            b.percent
+                .total_cmp(&a.percent)
+                .then_with(|| a.target.cmp(&b.target))
+        });
+        regressions.truncate(limit);
+
+        Self {
+            total_runs: records.len(),
+            total_tasks,
+            cache_hit_rate: percent(total_cached, total_tasks),
+            slowest_tasks,
+            failing_tasks,
+            projects,
+            regressions,
+        }
+    }
+}
+
+fn detect_regression(target: &Target, durations: &[Duration]) -> Option<TaskRegression> {
+    if durations.len() < MIN_REGRESSION_SAMPLES {
+        return None;
+    }
+
+    let (previous, recent) = durations.split_at(durations.len() / 2);
+    let previous_duration = average(previous);
+    let recent_duration = average(recent);
+
+    if recent_duration <= previous_duration
+        || recent_duration - previous_duration < REGRESSION_MIN_DIFF
+    {
+        return None;
+    }
+
+    let percent = ((recent_duration.as_secs_f32() - previous_duration.as_secs_f32())
+        / previous_duration.as_secs_f32().max(f32::EPSILON))
+        * 100.0;
+
+    if percent < REGRESSION_THRESHOLD {
+        return None;
+    }
+
+    Some(TaskRegression {
+        target: target.clone(),
+        previous_duration,
+        recent_duration,
+        percent,
+    })
+}
+
+fn average(durations: &[Duration]) -> Duration {
+    if durations.is_empty() {
+        return Duration::ZERO;
+    }
+
+    durations.iter().sum::<Duration>() / durations.len() as u32
+}
+
+fn percent(count: usize, total: usize) -> f32 {
+    if total == 0 {
+        return 0.0;
+    }
+
+    (count as f32 / total as f32) * 100.0
+}
@@ -0,0 +1,127 @@
+use moon_action::{Action, ActionNode, ActionPipelineStatus, ActionStatus};
+use moon_action_context::{ActionContext, TargetState};
+use moon_task::Target;
+use serde::{Deserialize, Serialize};
+use starbase_utils::{fs, json};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+/// State of a pipeline that failed or was aborted, which
+/// is used by `moon run --resume` to only run tasks
+/// that failed or were never started.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ResumeState {
+    /// Targets that completed successfully, and their resulting state.
+    pub completed_targets: BTreeMap<Target, TargetState>,
+
+    /// Targets that failed to run.
+    pub failed_targets: Vec<Target>,
+
+    /// Initial targets that were requested to run.
+    pub initial_targets: Vec<Target>,
+
+    /// Additional arguments passed after `--`.
+    pub passthrough_args: Vec<String>,
+
+    pub status: ActionPipelineStatus,
+}
+
+impl ResumeState {
+    pub fn get_path(states_dir: &Path) -> PathBuf {
+        states_dir.join("resumeState.json")
+    }
+
+    pub fn from_pipeline(
+        actions: &[Action],
+        action_context: &ActionContext,
+        status: &ActionPipelineStatus,
+    ) -> Self {
+        let target_states = action_context.get_target_states();
+        let mut state = Self {
+            passthrough_args: action_context.passthrough_args.clone(),
+            status: *status,
+            ..Default::default()
+        };
+
+        for action in actions {
+            let ActionNode::RunTask(node) = &*action.node else {
+                continue;
+            };
+
+            let completed = match action.status {
+                ActionStatus::Passed | ActionStatus::Cached | ActionStatus::CachedFromRemote => {
+                    true
+                }
+                ActionStatus::Skipped => action_context.is_resumed_target(&node.target),
+                _ => false,
+            };
+
+            if completed {
+                if let Some(target_state) = target_states.get(&node.target) {
+                    if target_state.is_complete() {
+                        state
+                            .completed_targets
+                            .insert(node.target.clone(), target_state.to_owned());
+                    }
+                }
+            } else if action.has_failed() {
+                state.failed_targets.push(node.target.clone());
+            }
+        }
+
+        state.failed_targets.sort();
+        state.failed_targets.dedup();
+
+        state.initial_targets = action_context.initial_targets.iter().cloned().collect();
+        state.initial_targets.sort();
+
+        state
+    }
+
+    /// Return true if the pipeline completed and all tasks were successful,
+    /// in which case there is nothing to resume.
+    pub fn is_successful(&self) -> bool {
+        matches!(self.status, ActionPipelineStatus::Completed) && self.failed_targets.is_empty()
+    }
+
+    pub fn load(states_dir: &Path) -> miette::Result<Option<Self>> {
+        let path = Self::get_path(states_dir);
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        debug!(state = ?path, "Loading resume state from previous run");
+
+        Ok(Some(json::read_file(path)?))
+    }
+
+    pub fn save(&self, states_dir: &Path) -> miette::Result<()> {
+        let path = Self::get_path(states_dir);
+
+        debug!(state = ?path, "Saving resume state for subsequent runs");
+
+        // This purposefully ignores the cache mode and always writes,
+        // as resuming is also used for non-cacheable tasks
+        json::write_file(path, self, false)?;
+
+        Ok(())
+    }
+
+    pub fn clear(states_dir: &Path) -> miette::Result<()> {
+        fs::remove_file(Self::get_path(states_dir))?;
+
+        Ok(())
+    }
+
+    /// Inherit the completed targets into the provided context,
+    /// so that they are skipped when the pipeline runs.
+    pub fn apply_to_context(&self, action_context: &mut ActionContext) {
+        for (target, target_state) in &self.completed_targets {
+            action_context.set_target_state(target, target_state.to_owned());
+            action_context.resumed_targets.insert(target.to_owned());
+        }
+    }
+}
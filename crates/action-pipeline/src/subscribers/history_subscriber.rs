@@ -0,0 +1,45 @@
+use crate::event_emitter::{Event, Subscriber};
+use crate::reports::history::{RunHistory, RunHistoryRecord};
+use async_trait::async_trait;
+use moon_cache::CacheEngine;
+use std::sync::Arc;
+use tracing::debug;
+
+pub struct HistorySubscriber {
+    cache_engine: Arc<CacheEngine>,
+}
+
+impl HistorySubscriber {
+    pub fn new(cache_engine: Arc<CacheEngine>) -> Self {
+        HistorySubscriber { cache_engine }
+    }
+}
+
+#[async_trait]
+impl Subscriber for HistorySubscriber {
+    async fn on_emit<'data>(&mut self, event: &Event<'data>) -> miette::Result<()> {
+        if let Event::PipelineCompleted {
+            actions,
+            duration: Some(duration),
+            status,
+            ..
+        } = event
+        {
+            let record = RunHistoryRecord::from_actions(actions, duration, status);
+
+            // Nothing worth tracking
+            if record.tasks.is_empty() || !self.cache_engine.is_writable() {
+                return Ok(());
+            }
+
+            debug!("Recording run in history");
+
+            // Multiple processes may be running at the same time
+            let _lock = self.cache_engine.create_lock("history")?;
+
+            RunHistory::new(&self.cache_engine.cache_dir).append(&record)?;
+        }
+
+        Ok(())
+    }
+}
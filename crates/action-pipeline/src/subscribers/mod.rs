@@ -1,6 +1,8 @@
 pub mod cleanup_subscriber;
 pub mod console_subscriber;
+pub mod history_subscriber;
 pub mod moonbase_subscriber;
 pub mod remote_subscriber;
 pub mod reports_subscriber;
+pub mod resume_subscriber;
 pub mod webhooks_subscriber;
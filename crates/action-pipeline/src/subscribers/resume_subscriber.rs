@@ -0,0 +1,65 @@
+use crate::event_emitter::{Event, Subscriber};
+use crate::resume_state::ResumeState;
+use async_trait::async_trait;
+use moon_action::ActionNode;
+use moon_action_context::ActionContext;
+use moon_cache::CacheEngine;
+use std::sync::Arc;
+use tracing::debug;
+
+pub struct ResumeSubscriber {
+    cache_engine: Arc<CacheEngine>,
+    action_context: Arc<ActionContext>,
+}
+
+impl ResumeSubscriber {
+    pub fn new(cache_engine: Arc<CacheEngine>, action_context: Arc<ActionContext>) -> Self {
+        ResumeSubscriber {
+            cache_engine,
+            action_context,
+        }
+    }
+}
+
+#[async_trait]
+impl Subscriber for ResumeSubscriber {
+    async fn on_emit<'data>(&mut self, event: &Event<'data>) -> miette::Result<()> {
+        if let Event::PipelineCompleted {
+            actions, status, ..
+        } = event
+        {
+            // Nothing to resume
+            if !actions
+                .iter()
+                .any(|action| matches!(&*action.node, ActionNode::RunTask(_)))
+            {
+                return Ok(());
+            }
+
+            let states_dir = &self.cache_engine.state.states_dir;
+            let state = ResumeState::from_pipeline(actions, &self.action_context, status);
+
+            if state.is_successful() {
+                // Only clear the previous state if it was for the same targets,
+                // otherwise an unrelated run would make it impossible to resume
+                if ResumeState::load(states_dir)?
+                    .is_some_and(|prev| prev.initial_targets == state.initial_targets)
+                {
+                    debug!("Pipeline completed successfully, clearing previous resume state");
+
+                    ResumeState::clear(states_dir)?;
+                }
+            } else {
+                debug!(
+                    completed = state.completed_targets.len(),
+                    failed = state.failed_targets.len(),
+                    "Pipeline did not complete successfully, persisting state so that it may be resumed"
+                );
+
+                state.save(states_dir)?;
+            }
+        }
+
+        Ok(())
+    }
+}
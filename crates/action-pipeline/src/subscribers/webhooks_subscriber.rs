@@ -1,5 +1,6 @@
 use crate::event_emitter::{Event, Subscriber};
 use async_trait::async_trait;
+use moon_config::NotifierWebhookConfig;
 use moon_notifier::WebhooksNotifier;
 use tracing::debug;
 
@@ -8,9 +9,9 @@ pub struct WebhooksSubscriber {
 }
 
 impl WebhooksSubscriber {
-    pub fn new(url: &str) -> Self {
+    pub fn new(webhooks: Vec<NotifierWebhookConfig>) -> Self {
         WebhooksSubscriber {
-            notifier: WebhooksNotifier::new(url.to_owned()),
+            notifier: WebhooksNotifier::new(webhooks),
         }
     }
 }
@@ -18,9 +19,19 @@ impl WebhooksSubscriber {
 #[async_trait]
 impl Subscriber for WebhooksSubscriber {
     async fn on_emit<'data>(&mut self, event: &Event<'data>) -> miette::Result<()> {
-        self.notifier.notify(event.get_type(), event).await?;
+        let completed = matches!(event, Event::PipelineCompleted { .. });
 
-        if matches!(event, Event::PipelineCompleted { .. }) {
+        // Send any batched events before the completed event,
+        // so that consumers receive events in a consistent order
+        if completed {
+            self.notifier.flush().await?;
+        }
+
+        self.notifier
+            .notify(event.get_type(), event, event.is_failure())
+            .await?;
+
+        if completed {
             debug!("Waiting for webhook requests to finish");
 
             self.notifier.wait_for_requests().await;
@@ -0,0 +1,237 @@
+use moon_action::*;
+use moon_action_pipeline::reports::history::*;
+use moon_action_pipeline::reports::stats::*;
+use moon_task::Target;
+use moon_toolchain::Runtime;
+use starbase_sandbox::create_empty_sandbox;
+use std::time::Duration;
+
+fn create_task(target: &str, status: ActionStatus, duration: u64) -> TaskHistoryRecord {
+    TaskHistoryRecord {
+        duration,
+        hash: None,
+        status,
+        target: Target::parse(target).unwrap(),
+    }
+}
+
+fn create_record(tasks: Vec<TaskHistoryRecord>) -> RunHistoryRecord {
+    RunHistoryRecord {
+        duration: tasks.iter().map(|task| task.duration).sum(),
+        finished_at: 0,
+        status: ActionPipelineStatus::Completed,
+        tasks,
+    }
+}
+
+mod history {
+    use super::*;
+
+    #[test]
+    fn only_records_tasks() {
+        let mut task = Action::new(ActionNode::run_task(RunTaskNode::new(
+            Target::parse("app:build").unwrap(),
+            Runtime::system(),
+        )));
+        task.duration = Some(Duration::from_millis(1500));
+        task.status = ActionStatus::Passed;
+
+        let mut sync = Action::new(ActionNode::sync_workspace());
+        sync.status = ActionStatus::Passed;
+
+        let record = RunHistoryRecord::from_actions(
+            &[sync, task],
+            &Duration::from_secs(2),
+            &ActionPipelineStatus::Completed,
+        );
+
+        assert_eq!(record.duration, 2000);
+        assert_eq!(
+            record.tasks,
+            [create_task("app:build", ActionStatus::Passed, 1500)]
+        );
+    }
+
+    #[test]
+    fn appends_and_loads_records() {
+        let sandbox = create_empty_sandbox();
+        let history = RunHistory::new(sandbox.path());
+
+        assert!(history.load().unwrap().is_empty());
+
+        let a = create_record(vec![create_task("a:build", ActionStatus::Passed, 10)]);
+        let b = create_record(vec![create_task("b:build", ActionStatus::Cached, 0)]);
+
+        history.append(&a).unwrap();
+        history.append(&b).unwrap();
+
+        assert_eq!(history.load().unwrap(), [a, b]);
+    }
+
+    #[test]
+    fn skips_invalid_records() {
+        let sandbox = create_empty_sandbox();
+        let history = RunHistory::new(sandbox.path());
+        let record = create_record(vec![create_task("a:build", ActionStatus::Failed, 10)]);
+
+        sandbox.create_file("history.jsonl", "{\"invalid\n");
+        history.append(&record).unwrap();
+
+        assert_eq!(history.load().unwrap(), [record]);
+    }
+
+    #[test]
+    fn prunes_oldest_records() {
+        let sandbox = create_empty_sandbox();
+        let history = RunHistory::new(sandbox.path());
+
+        for i in 0..4 {
+            history
+                .append(&create_record(vec![create_task(
+                    "a:build",
+                    ActionStatus::Passed,
+                    i,
+                )]))
+                .unwrap();
+        }
+
+        history.prune().unwrap();
+
+        let records = history.load().unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].tasks[0].duration, 2);
+        assert_eq!(records[1].tasks[0].duration, 3);
+    }
+}
+
+mod stats {
+    use super::*;
+
+    #[test]
+    fn returns_empty_stats() {
+        assert_eq!(RunStats::calculate(&[], 10), RunStats::default());
+    }
+
+    #[test]
+    fn calculates_cache_hit_rates() {
+        let stats = RunStats::calculate(
+            &[
+                create_record(vec![
+                    create_task("a:build", ActionStatus::Passed, 100),
+                    create_task("b:build", ActionStatus::Passed, 100),
+                ]),
+                create_record(vec![
+                    create_task("a:build", ActionStatus::Cached, 0),
+                    create_task("b:build", ActionStatus::Passed, 100),
+                ]),
+            ],
+            10,
+        );
+
+        assert_eq!(stats.total_runs, 2);
+        assert_eq!(stats.total_tasks, 4);
+        assert_eq!(stats.cache_hit_rate, 25.0);
+        assert_eq!(
+            stats
+                .projects
+                .iter()
+                .map(|project| (project.project.as_str(), project.cache_hit_rate))
+                .collect::<Vec<_>>(),
+            [("b", 0.0), ("a", 50.0)]
+        );
+    }
+
+    #[test]
+    fn orders_slowest_tasks_by_average_execution() {
+        let stats = RunStats::calculate(
+            &[
+                create_record(vec![
+                    create_task("a:build", ActionStatus::Passed, 300),
+                    create_task("b:build", ActionStatus::Passed, 200),
+                    create_task("c:build", ActionStatus::Cached, 0),
+                ]),
+                create_record(vec![
+                    create_task("a:build", ActionStatus::Cached, 0),
+                    create_task("b:build", ActionStatus::Passed, 600),
+                ]),
+            ],
+            10,
+        );
+
+        assert_eq!(
+            stats
+                .slowest_tasks
+                .iter()
+                .map(|task| (task.target.as_str(), task.average_duration))
+                .collect::<Vec<_>>(),
+            [
+                ("b:build", Duration::from_millis(400)),
+                ("a:build", Duration::from_millis(300)),
+            ]
+        );
+    }
+
+    #[test]
+    fn orders_failing_tasks_by_count() {
+        let stats = RunStats::calculate(
+            &[
+                create_record(vec![
+                    create_task("a:test", ActionStatus::Failed, 10),
+                    create_task("b:test", ActionStatus::Failed, 10),
+                ]),
+                create_record(vec![
+                    create_task("a:test", ActionStatus::Passed, 10),
+                    create_task("b:test", ActionStatus::TimedOut, 10),
+                ]),
+            ],
+            1,
+        );
+
+        assert_eq!(stats.failing_tasks.len(), 1);
+        assert_eq!(stats.failing_tasks[0].target.as_str(), "b:test");
+        assert_eq!(stats.failing_tasks[0].failure_rate(), 100.0);
+    }
+
+    #[test]
+    fn detects_regressions() {
+        let records = [1000, 1100, 3000, 3200]
+            .into_iter()
+            .map(|duration| {
+                create_record(vec![
+                    create_task("slow:build", ActionStatus::Passed, duration),
+                    create_task("stable:build", ActionStatus::Passed, 1000),
+                ])
+            })
+            .collect::<Vec<_>>();
+
+        let stats = RunStats::calculate(&records, 10);
+
+        assert_eq!(stats.regressions.len(), 1);
+        assert_eq!(stats.regressions[0].target.as_str(), "slow:build");
+        assert_eq!(
+            stats.regressions[0].previous_duration,
+            Duration::from_millis(1050)
+        );
+        assert_eq!(
+            stats.regressions[0].recent_duration,
+            Duration::from_millis(3100)
+        );
+    }
+
+    #[test]
+    fn ignores_small_regressions() {
+        let records = [100, 100, 300, 300]
+            .into_iter()
+            .map(|duration| {
+                create_record(vec![create_task(
+                    "fast:build",
+                    ActionStatus::Passed,
+                    duration,
+                )])
+            })
+            .collect::<Vec<_>>();
+
+        assert!(RunStats::calculate(&records, 10).regressions.is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+use moon_action::*;
+use moon_action_context::{ActionContext, TargetState};
+use moon_action_pipeline::resume_state::ResumeState;
+use moon_task::Target;
+use moon_toolchain::Runtime;
+use starbase_sandbox::create_empty_sandbox;
+
+fn create_task_action(target: &str, status: ActionStatus) -> Action {
+    let mut action = Action::new(ActionNode::run_task(RunTaskNode::new(
+        Target::parse(target).unwrap(),
+        Runtime::system(),
+    )));
+    action.status = status;
+    action
+}
+
+fn create_context() -> ActionContext {
+    let context = ActionContext {
+        initial_targets: [
+            Target::parse("b:deploy").unwrap(),
+            Target::parse("a:deploy").unwrap(),
+        ]
+        .into_iter()
+        .collect(),
+        passthrough_args: vec!["--env".into(), "prod".into()],
+        ..ActionContext::default()
+    };
+
+    context.set_target_state(
+        Target::parse("a:build").unwrap(),
+        TargetState::Passed("hash".into()),
+    );
+    context.set_target_state(
+        Target::parse("a:migrate").unwrap(),
+        TargetState::Passthrough,
+    );
+    context.set_target_state(Target::parse("a:deploy").unwrap(), TargetState::Failed);
+    context
+}
+
+mod resume_state {
+    use super::*;
+
+    #[test]
+    fn tracks_completed_and_failed_targets() {
+        let state = ResumeState::from_pipeline(
+            &[
+                Action::new(ActionNode::sync_workspace()),
+                create_task_action("a:build", ActionStatus::Cached),
+                create_task_action("a:migrate", ActionStatus::Passed),
+                create_task_action("a:deploy", ActionStatus::Failed),
+                create_task_action("b:deploy", ActionStatus::Skipped),
+            ],
+            &create_context(),
+            &ActionPipelineStatus::Aborted,
+        );
+
+        assert_eq!(
+            state.completed_targets.into_iter().collect::<Vec<_>>(),
+            [
+                (
+                    Target::parse("a:build").unwrap(),
+                    TargetState::Passed("hash".into())
+                ),
+                (
+                    Target::parse("a:migrate").unwrap(),
+                    TargetState::Passthrough
+                ),
+            ]
+        );
+        assert_eq!(state.failed_targets, [Target::parse("a:deploy").unwrap()]);
+        assert_eq!(
+            state.initial_targets,
+            [
+                Target::parse("a:deploy").unwrap(),
+                Target::parse("b:deploy").unwrap()
+            ]
+        );
+        assert_eq!(state.passthrough_args, ["--env", "prod"]);
+        assert_eq!(state.status, ActionPipelineStatus::Aborted);
+    }
+
+    #[test]
+    fn keeps_resumed_targets_as_completed() {
+        let mut context = create_context();
+        context
+            .resumed_targets
+            .insert(Target::parse("a:migrate").unwrap());
+
+        let state = ResumeState::from_pipeline(
+            &[create_task_action("a:migrate", ActionStatus::Skipped)],
+            &context,
+            &ActionPipelineStatus::Completed,
+        );
+
+        assert!(state
+            .completed_targets
+            .contains_key(&Target::parse("a:migrate").unwrap()));
+        assert!(state.is_successful());
+    }
+
+    #[test]
+    fn is_not_successful_if_failed_or_aborted() {
+        let failed = ResumeState::from_pipeline(
+            &[create_task_action("a:deploy", ActionStatus::Failed)],
+            &create_context(),
+            &ActionPipelineStatus::Completed,
+        );
+
+        assert!(!failed.is_successful());
+
+        let aborted = ResumeState::from_pipeline(
+            &[create_task_action("a:build", ActionStatus::Passed)],
+            &create_context(),
+            &ActionPipelineStatus::Interrupted,
+        );
+
+        assert!(!aborted.is_successful());
+    }
+
+    #[test]
+    fn saves_loads_and_clears() {
+        let sandbox = create_empty_sandbox();
+        let state = ResumeState::from_pipeline(
+            &[
+                create_task_action("a:build", ActionStatus::Passed),
+                create_task_action("a:deploy", ActionStatus::Failed),
+            ],
+            &create_context(),
+            &ActionPipelineStatus::Aborted,
+        );
+
+        assert_eq!(ResumeState::load(sandbox.path()).unwrap(), None);
+
+        state.save(sandbox.path()).unwrap();
+
+        assert_eq!(ResumeState::load(sandbox.path()).unwrap(), Some(state));
+
+        ResumeState::clear(sandbox.path()).unwrap();
+
+        assert_eq!(ResumeState::load(sandbox.path()).unwrap(), None);
+    }
+
+    #[test]
+    fn applies_completed_targets_to_context() {
+        let state = ResumeState::from_pipeline(
+            &[
+                create_task_action("a:build", ActionStatus::Passed),
+                create_task_action("a:deploy", ActionStatus::Failed),
+            ],
+            &create_context(),
+            &ActionPipelineStatus::Aborted,
+        );
+
+        let mut context = ActionContext::default();
+
+        state.apply_to_context(&mut context);
+
+        assert!(context.is_resumed_target(Target::parse("a:build").unwrap()));
+        assert!(!context.is_resumed_target(Target::parse("a:deploy").unwrap()));
+        assert_eq!(
+            context.get_target_states(),
+            [(
+                Target::parse("a:build").unwrap(),
+                TargetState::Passed("hash".into())
+            )]
+            .into_iter()
+            .collect()
+        );
+    }
+}
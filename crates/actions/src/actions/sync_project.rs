@@ -62,7 +62,7 @@ pub async fn sync_project(
 
     // If files have been modified in CI, we should update the status to warning,
     // as these modifications should be committed to the repo!
-    if mutated_files && is_ci() {
+    if mutated_files && is_ci() && action_context.sync_check.is_none() {
         warn!(
             project_id = project.id.as_str(),
             "Files were modified during project sync that should be committed to the repository"
@@ -7,9 +7,11 @@ use moon_action::{Action, ActionStatus, Operation};
 use moon_action_context::ActionContext;
 use moon_app_context::AppContext;
 use moon_common::color;
+use moon_pdk_api::Operation as PluginOperation;
 use moon_remote::RemoteService;
 use moon_toolchain_plugin::ToolchainRegistry;
 use moon_workspace_graph::WorkspaceGraph;
+use starbase_utils::fs;
 use std::sync::Arc;
 use tokio::task;
 use tracing::{debug, instrument};
@@ -105,19 +107,53 @@ pub async fn sync_workspace(
 
         let mut sync_results = vec![];
         let sync_context = toolchain_registry.create_context();
+        let check = app_context.sync_check.as_deref();
 
         for plugin_id in toolchain_registry.get_plugin_ids() {
             if let Some(result) = toolchain_registry
                 .load(plugin_id)
                 .await?
-                .sync_workspace(sync_context.clone())
+                .sync_workspace(sync_context.clone(), check.is_some())
                 .await?
             {
-                sync_results.push(result);
+                sync_results.push((plugin_id, result));
             }
         }
 
-        for result in sync_results {
+        for (plugin_id, result) in sync_results {
+            // When checking, files are compared against their expected contents,
+            // and operations are not executed, but recorded as drift
+            if let Some(check) = check {
+                for file in result.changed_files {
+                    let Some(path) = file.path.real_path() else {
+                        continue;
+                    };
+
+                    match file.content {
+                        Some(content) => check.write_file(&path, |path| {
+                            fs::write_file(path, content)?;
+
+                            Ok(())
+                        })?,
+                        None => check.remove_file(&path),
+                    };
+                }
+
+                for op in result.operations {
+                    match op {
+                        PluginOperation::ProcessExecution(process) => {
+                            check.record_operation(format!(
+                                "{plugin_id}: {} {}",
+                                process.command,
+                                process.args.join(" ")
+                            ));
+                        }
+                    };
+                }
+
+                continue;
+            }
+
             operation_futures.push(task::spawn(async move {
                 let mut ops = vec![];
 
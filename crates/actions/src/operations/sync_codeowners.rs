@@ -14,7 +14,8 @@ pub async fn sync_codeowners(
     let mut generator = CodeownersGenerator::new(
         &app_context.workspace_root,
         app_context.workspace_config.vcs.provider,
-    )?;
+    )?
+    .with_check(app_context.sync_check.clone());
 
     // Sort the projects based on config
     let mut projects = workspace_graph.projects.get_all_unexpanded();
@@ -52,8 +53,9 @@ pub async fn sync_codeowners(
 
     let file_path = generator.file_path.clone();
 
-    // Force run the generator and bypass cache
-    if force {
+    // Force run the generator and bypass cache,
+    // as the cache must not be updated while checking
+    if force || app_context.sync_check.is_some() {
         generator.generate()?;
 
         return Ok(Some(file_path));
@@ -78,7 +80,8 @@ pub async fn unsync_codeowners(app_context: &AppContext) -> miette::Result<PathB
     let codeowners = CodeownersGenerator::new(
         &app_context.workspace_root,
         app_context.workspace_config.vcs.provider,
-    )?;
+    )?
+    .with_check(app_context.sync_check.clone());
 
     let file_path = codeowners.file_path.clone();
 
@@ -1,8 +1,10 @@
 use moon_app_context::AppContext;
-use moon_common::color;
+use moon_common::{color, SyncCheck};
 use moon_config::Version;
 use moon_config_schema::json_schemas::generate_json_schemas;
 use moon_hash::hash_content;
+use starbase_utils::fs;
+use std::path::Path;
 use tracing::{instrument, warn};
 
 hash_content!(
@@ -15,7 +17,9 @@ hash_content!(
 pub async fn sync_config_schemas(app_context: &AppContext, force: bool) -> miette::Result<bool> {
     let out_dir = app_context.cache_engine.cache_dir.join("schemas");
 
-    if let Err(error) = if force {
+    if let Err(error) = if let Some(check) = &app_context.sync_check {
+        check_json_schemas(app_context, check, &out_dir)
+    } else if force {
         generate_json_schemas(out_dir).map(|_| true)
     } else {
         app_context
@@ -39,3 +43,29 @@ pub async fn sync_config_schemas(app_context: &AppContext, force: bool) -> miett
 
     Ok(true)
 }
+
+// Generate into a temporary directory, and then sync each schema,
+// so that they can be compared against the existing schemas.
+fn check_json_schemas(
+    app_context: &AppContext,
+    check: &SyncCheck,
+    out_dir: &Path,
+) -> miette::Result<bool> {
+    let temp_dir = app_context.cache_engine.temp_dir.join("schemas");
+
+    generate_json_schemas(&temp_dir)?;
+
+    for file in fs::read_dir(&temp_dir)? {
+        let temp_path = file.path();
+
+        check.write_file(&out_dir.join(file.file_name()), |path| {
+            fs::copy_file(&temp_path, path)?;
+
+            Ok(())
+        })?;
+    }
+
+    fs::remove_dir_all(&temp_dir)?;
+
+    Ok(true)
+}
@@ -5,10 +5,12 @@ use tracing::instrument;
 #[instrument(skip_all)]
 pub async fn sync_vcs_hooks(app_context: &AppContext, force: bool) -> miette::Result<bool> {
     let vcs_config = &app_context.workspace_config.vcs;
-    let generator = HooksGenerator::new(&app_context.vcs, vcs_config, &app_context.workspace_root);
+    let generator = HooksGenerator::new(&app_context.vcs, vcs_config, &app_context.workspace_root)
+        .with_check(app_context.sync_check.clone());
 
-    // Force run the generator and bypass cache
-    if force {
+    // Force run the generator and bypass cache,
+    // as the cache must not be updated while checking
+    if force || app_context.sync_check.is_some() {
         generator.generate().await?;
 
         return Ok(true);
@@ -37,6 +39,7 @@ pub async fn unsync_vcs_hooks(app_context: &AppContext) -> miette::Result<()> {
         &app_context.workspace_config.vcs,
         &app_context.workspace_root,
     )
+    .with_check(app_context.sync_check.clone())
     .cleanup()
     .await?;
 
@@ -10,6 +10,7 @@ use std::fmt;
 pub enum AffectedBy {
     AlreadyMarked,
     AlwaysAffected,
+    ConfigChanged,
     DownstreamProject(Id),
     DownstreamTask(Target),
     EnvironmentVariable(String),
@@ -114,6 +115,8 @@ impl AffectedProjectState {
 #[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
 #[serde(default)]
 pub struct AffectedTaskState {
+    pub config: bool,
+
     #[serde(skip_serializing_if = "FxHashSet::is_empty")]
     pub env: FxHashSet<String>,
 
@@ -135,6 +138,9 @@ impl AffectedTaskState {
 
         for by in list {
             match by {
+                AffectedBy::ConfigChanged => {
+                    state.config = true;
+                }
                 AffectedBy::DownstreamTask(target) => {
                     state.downstream.insert(target);
                 }
@@ -1,4 +1,5 @@
 use crate::affected::*;
+use moon_common::consts::CONFIG_DIRNAME;
 use moon_common::path::WorkspaceRelativePathBuf;
 use moon_common::{color, Id};
 use moon_project::Project;
@@ -7,10 +8,13 @@ use moon_workspace_graph::{GraphConnections, WorkspaceGraph};
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::env;
 use std::fmt;
+use std::sync::Arc;
 use tracing::{debug, trace};
 
 pub struct AffectedTracker<'app> {
+    base_tasks: Option<FxHashMap<Target, Arc<Task>>>,
     ci: bool,
+    lockfile_changes: FxHashMap<WorkspaceRelativePathBuf, FxHashSet<Id>>,
 
     workspace_graph: &'app WorkspaceGraph,
     touched_files: FxHashSet<WorkspaceRelativePathBuf>,
@@ -32,6 +36,8 @@ impl<'app> AffectedTracker<'app> {
         debug!("Creating affected tracker");
 
         Self {
+            base_tasks: None,
+            lockfile_changes: FxHashMap::default(),
             workspace_graph,
             touched_files,
             projects: FxHashMap::default(),
@@ -70,6 +76,7 @@ impl<'app> AffectedTracker<'app> {
             let state = AffectedTaskState::from(list);
 
             debug!(
+                config = state.config,
                 env = ?state.env.iter().collect::<Vec<_>>(),
                 files = ?state.files.iter().collect::<Vec<_>>(),
                 upstream = ?state.upstream.iter().map(|target| target.as_str()).collect::<Vec<_>>(),
@@ -85,6 +92,39 @@ impl<'app> AffectedTracker<'app> {
         affected
     }
 
+    /// Provide the resolved tasks from the base revision. When provided,
+    /// tasks are compared against their base definition to determine whether
+    /// their configuration changed, and touched moon config files are no longer
+    /// matched against task inputs.
+    pub fn with_base_tasks(&mut self, tasks: FxHashMap<Target, Arc<Task>>) -> &mut Self {
+        debug!(
+            "Comparing against {} tasks from the base revision",
+            tasks.len()
+        );
+
+        self.base_tasks = Some(tasks);
+        self
+    }
+
+    /// Provide the projects that depend on changed packages, for each touched
+    /// lockfile that was diffed against the base revision. When provided, a
+    /// touched lockfile will only affect tasks that belong to these projects.
+    pub fn with_lockfile_changes(
+        &mut self,
+        changes: FxHashMap<WorkspaceRelativePathBuf, FxHashSet<Id>>,
+    ) -> &mut Self {
+        for (file, project_ids) in &changes {
+            debug!(
+                lockfile = file.as_str(),
+                projects = ?project_ids.iter().map(|id| id.as_str()).collect::<Vec<_>>(),
+                "Lockfile has changed packages, only marking dependent projects as affected"
+            );
+        }
+
+        self.lockfile_changes = changes;
+        self
+    }
+
     pub fn set_ci_check(&mut self, ci: bool) -> &mut Self {
         self.ci = ci;
         self
@@ -353,9 +393,36 @@ impl<'app> AffectedTracker<'app> {
             }
         }
 
+        if let Some(base_tasks) = &self.base_tasks {
+            let changed = match base_tasks.get(&task.target) {
+                Some(base_task) => has_task_config_changed(base_task, task),
+                None => true,
+            };
+
+            if changed {
+                return Ok(Some(AffectedBy::ConfigChanged));
+            }
+        }
+
         let globset = task.create_globset()?;
 
         for file in self.touched_files.iter() {
+            // Task config changes are determined by the base task comparison above
+            if self.base_tasks.is_some() && is_task_config_file(file) {
+                continue;
+            }
+
+            // Lockfile changes are determined by the packages that changed
+            if let Some(project_ids) = self.lockfile_changes.get(file) {
+                if !task
+                    .target
+                    .get_project_id()
+                    .is_some_and(|id| project_ids.contains(id))
+                {
+                    continue;
+                }
+            }
+
             if task.input_files.contains(file) || globset.matches(file.as_str()) {
                 return Ok(Some(AffectedBy::TouchedFile(file.to_owned())));
             }
@@ -503,6 +570,55 @@ impl<'app> AffectedTracker<'app> {
     }
 }
 
+/// Return true if the file is a workspace or project moon configuration file.
+pub fn is_config_file(file: &WorkspaceRelativePathBuf) -> bool {
+    if !matches!(file.extension(), Some("pkl" | "yml")) {
+        return false;
+    }
+
+    file.starts_with(CONFIG_DIRNAME) || matches!(file.file_stem(), Some("moon"))
+}
+
+/// Return true if the file is a moon configuration file that only affects how
+/// tasks are defined, which are the inherited `.moon/tasks` configs and project
+/// configs. Other workspace configs (toolchain, workspace, etc) are not included.
+pub fn is_task_config_file(file: &WorkspaceRelativePathBuf) -> bool {
+    if !is_config_file(file) {
+        return false;
+    }
+
+    if file.starts_with(CONFIG_DIRNAME) {
+        return file.starts_with(format!("{CONFIG_DIRNAME}/tasks"))
+            || file
+                .parent()
+                .is_some_and(|dir| dir.as_str() == CONFIG_DIRNAME)
+                && matches!(file.file_stem(), Some("tasks"));
+    }
+
+    true
+}
+
+// Only compare settings that influence how a task runs,
+// and ignore runtime state and metadata like descriptions
+fn has_task_config_changed(base: &Task, head: &Task) -> bool {
+    base.args != head.args
+        || base.command != head.command
+        || base.deps != head.deps
+        || base.env != head.env
+        || base.inputs != head.inputs
+        || base.input_env != head.input_env
+        || base.input_files != head.input_files
+        || base.input_globs != head.input_globs
+        || base.options != head.options
+        || base.outputs != head.outputs
+        || base.output_files != head.output_files
+        || base.output_globs != head.output_globs
+        || base.preset != head.preset
+        || base.script != head.script
+        || base.toolchains != head.toolchains
+        || base.type_of != head.type_of
+}
+
 impl fmt::Debug for AffectedTracker<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("AffectedTracker")
@@ -1,4 +1,5 @@
 use moon_affected::*;
+use moon_common::path::WorkspaceRelativePathBuf;
 use moon_common::Id;
 use moon_task::Target;
 use moon_test_utils2::generate_workspace_graph;
@@ -1063,3 +1064,277 @@ mod affected_tasks {
         }
     }
 }
+
+mod affected_by_config {
+    use super::*;
+    use moon_task::Task;
+    use moon_workspace_graph::WorkspaceGraph;
+    use std::sync::Arc;
+
+    fn create_base_tasks(workspace_graph: &WorkspaceGraph) -> FxHashMap<Target, Arc<Task>> {
+        workspace_graph
+            .get_tasks_with_internal()
+            .unwrap()
+            .into_iter()
+            .map(|task| (task.target.clone(), task))
+            .collect()
+    }
+
+    // Avoid tracking `by-env`, as the env var is set by other tests
+    fn get_targets() -> Vec<Target> {
+        ["base:a", "base:b", "base:c", "base:by-file", "base:by-glob"]
+            .into_iter()
+            .map(|target| Target::parse(target).unwrap())
+            .collect()
+    }
+
+    fn create_state_from_config() -> AffectedTaskState {
+        AffectedTaskState {
+            config: true,
+            ..AffectedTaskState::default()
+        }
+    }
+
+    #[test]
+    fn detects_config_files() {
+        assert!(is_config_file(&".moon/workspace.yml".into()));
+        assert!(is_config_file(&".moon/tasks/node.yml".into()));
+        assert!(is_config_file(&".moon/toolchain.pkl".into()));
+        assert!(is_config_file(&"moon.yml".into()));
+        assert!(is_config_file(&"app/moon.yml".into()));
+        assert!(is_config_file(&"app/moon.pkl".into()));
+
+        assert!(!is_config_file(&".moon/cache/file.json".into()));
+        assert!(!is_config_file(&"app/moon.json".into()));
+        assert!(!is_config_file(&"app/config.yml".into()));
+    }
+
+    #[test]
+    fn detects_task_config_files() {
+        assert!(is_task_config_file(&".moon/tasks.yml".into()));
+        assert!(is_task_config_file(&".moon/tasks/node.yml".into()));
+        assert!(is_task_config_file(&".moon/tasks/node/build.pkl".into()));
+        assert!(is_task_config_file(&"moon.yml".into()));
+        assert!(is_task_config_file(&"app/moon.pkl".into()));
+
+        assert!(!is_task_config_file(&".moon/workspace.yml".into()));
+        assert!(!is_task_config_file(&".moon/toolchain.yml".into()));
+        assert!(!is_task_config_file(&".moon/moon.yml".into()));
+        assert!(!is_task_config_file(&"app/tasks.yml".into()));
+    }
+
+    #[tokio::test]
+    async fn not_affected_if_config_unchanged() {
+        let workspace_graph = generate_workspace_graph("tasks").await;
+        let touched_files = FxHashSet::from_iter(["base/moon.yml".into()]);
+        let base_tasks = create_base_tasks(&workspace_graph);
+
+        let mut tracker = AffectedTracker::new(&workspace_graph, touched_files);
+        tracker.with_base_tasks(base_tasks);
+        tracker.track_tasks_by_target(&get_targets()).unwrap();
+        let affected = tracker.build();
+
+        assert!(affected.tasks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn affected_if_definition_changed() {
+        let workspace_graph = generate_workspace_graph("tasks").await;
+        let touched_files = FxHashSet::from_iter(["base/moon.yml".into()]);
+        let mut base_tasks = create_base_tasks(&workspace_graph);
+
+        let target = Target::parse("base:a").unwrap();
+        let mut task = (*base_tasks[&target]).clone();
+        task.args.push("--old".into());
+        base_tasks.insert(target.clone(), Arc::new(task));
+
+        let mut tracker = AffectedTracker::new(&workspace_graph, touched_files);
+        tracker.with_base_tasks(base_tasks);
+        tracker.track_tasks_by_target(&get_targets()).unwrap();
+        let affected = tracker.build();
+
+        assert_eq!(
+            affected.tasks,
+            FxHashMap::from_iter([(target, create_state_from_config())])
+        );
+    }
+
+    #[tokio::test]
+    async fn affected_if_new_task() {
+        let workspace_graph = generate_workspace_graph("tasks").await;
+        let touched_files = FxHashSet::from_iter([".moon/tasks.yml".into()]);
+        let mut base_tasks = create_base_tasks(&workspace_graph);
+
+        let target = Target::parse("base:b").unwrap();
+        base_tasks.remove(&target);
+
+        let mut tracker = AffectedTracker::new(&workspace_graph, touched_files);
+        tracker.with_base_tasks(base_tasks);
+        tracker.track_tasks_by_target(&get_targets()).unwrap();
+        let affected = tracker.build();
+
+        assert_eq!(
+            affected.tasks,
+            FxHashMap::from_iter([(target, create_state_from_config())])
+        );
+    }
+
+    #[tokio::test]
+    async fn ignores_description_changes() {
+        let workspace_graph = generate_workspace_graph("tasks").await;
+        let touched_files = FxHashSet::from_iter(["base/moon.yml".into()]);
+        let mut base_tasks = create_base_tasks(&workspace_graph);
+
+        let target = Target::parse("base:a").unwrap();
+        let mut task = (*base_tasks[&target]).clone();
+        task.description = Some("Old description".into());
+        base_tasks.insert(target, Arc::new(task));
+
+        let mut tracker = AffectedTracker::new(&workspace_graph, touched_files);
+        tracker.with_base_tasks(base_tasks);
+        tracker.track_tasks_by_target(&get_targets()).unwrap();
+        let affected = tracker.build();
+
+        assert!(affected.tasks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn affected_by_non_task_config_files() {
+        let workspace_graph = generate_workspace_graph("tasks").await;
+        let touched_files = FxHashSet::from_iter([".moon/toolchain.yml".into()]);
+        let base_tasks = create_base_tasks(&workspace_graph);
+
+        let mut tracker = AffectedTracker::new(&workspace_graph, touched_files);
+        tracker.with_base_tasks(base_tasks);
+        tracker.track_tasks_by_target(&get_targets()).unwrap();
+        let affected = tracker.build();
+
+        assert_eq!(
+            affected.tasks,
+            FxHashMap::from_iter(get_targets().into_iter().map(|target| {
+                let mut state = AffectedTaskState::default();
+                state.files.insert(".moon/toolchain.yml".into());
+
+                (target, state)
+            }))
+        );
+    }
+
+    #[tokio::test]
+    async fn still_affected_by_other_files() {
+        let workspace_graph = generate_workspace_graph("tasks").await;
+        let touched_files = FxHashSet::from_iter(["base/moon.yml".into(), "base/file.txt".into()]);
+        let base_tasks = create_base_tasks(&workspace_graph);
+
+        let mut tracker = AffectedTracker::new(&workspace_graph, touched_files);
+        tracker.with_base_tasks(base_tasks);
+        tracker
+            .track_tasks_by_target(&[Target::parse("base:by-file").unwrap()])
+            .unwrap();
+        let affected = tracker.build();
+
+        let mut state = AffectedTaskState::default();
+        state.files.insert("base/file.txt".into());
+
+        assert_eq!(
+            affected.tasks,
+            FxHashMap::from_iter([(Target::parse("base:by-file").unwrap(), state)])
+        );
+    }
+}
+
+mod affected_by_lockfile {
+    use super::*;
+
+    fn create_changes(ids: &[&str]) -> FxHashMap<WorkspaceRelativePathBuf, FxHashSet<Id>> {
+        FxHashMap::from_iter([(
+            "base/Cargo.lock".into(),
+            FxHashSet::from_iter(ids.iter().map(Id::raw)),
+        )])
+    }
+
+    fn create_state_from_file(file: &str) -> AffectedTaskState {
+        let mut state = AffectedTaskState::default();
+        state.files.insert(file.into());
+        state
+    }
+
+    #[tokio::test]
+    async fn not_affected_if_project_not_dependent() {
+        let workspace_graph = generate_workspace_graph("tasks").await;
+        let touched_files = FxHashSet::from_iter(["base/Cargo.lock".into()]);
+
+        let mut tracker = AffectedTracker::new(&workspace_graph, touched_files);
+        tracker.with_lockfile_changes(create_changes(&["upstream"]));
+        tracker
+            .track_tasks_by_target(&[Target::parse("base:a").unwrap()])
+            .unwrap();
+        let affected = tracker.build();
+
+        assert!(affected.tasks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn affected_if_project_dependent() {
+        let workspace_graph = generate_workspace_graph("tasks").await;
+        let touched_files = FxHashSet::from_iter(["base/Cargo.lock".into()]);
+
+        let mut tracker = AffectedTracker::new(&workspace_graph, touched_files);
+        tracker.with_lockfile_changes(create_changes(&["base"]));
+        tracker
+            .track_tasks_by_target(&[Target::parse("base:a").unwrap()])
+            .unwrap();
+        let affected = tracker.build();
+
+        assert_eq!(
+            affected.tasks,
+            FxHashMap::from_iter([(
+                Target::parse("base:a").unwrap(),
+                create_state_from_file("base/Cargo.lock")
+            )])
+        );
+    }
+
+    #[tokio::test]
+    async fn affected_if_lockfile_not_diffed() {
+        let workspace_graph = generate_workspace_graph("tasks").await;
+        let touched_files = FxHashSet::from_iter(["base/Cargo.lock".into()]);
+
+        let mut tracker = AffectedTracker::new(&workspace_graph, touched_files);
+        tracker.with_lockfile_changes(FxHashMap::default());
+        tracker
+            .track_tasks_by_target(&[Target::parse("base:a").unwrap()])
+            .unwrap();
+        let affected = tracker.build();
+
+        assert_eq!(
+            affected.tasks,
+            FxHashMap::from_iter([(
+                Target::parse("base:a").unwrap(),
+                create_state_from_file("base/Cargo.lock")
+            )])
+        );
+    }
+
+    #[tokio::test]
+    async fn still_affected_by_other_files() {
+        let workspace_graph = generate_workspace_graph("tasks").await;
+        let touched_files =
+            FxHashSet::from_iter(["base/Cargo.lock".into(), "base/file.txt".into()]);
+
+        let mut tracker = AffectedTracker::new(&workspace_graph, touched_files);
+        tracker.with_lockfile_changes(create_changes(&[]));
+        tracker
+            .track_tasks_by_target(&[Target::parse("base:by-file").unwrap()])
+            .unwrap();
+        let affected = tracker.build();
+
+        assert_eq!(
+            affected.tasks,
+            FxHashMap::from_iter([(
+                Target::parse("base:by-file").unwrap(),
+                create_state_from_file("base/file.txt")
+            )])
+        );
+    }
+}
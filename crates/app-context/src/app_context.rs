@@ -1,4 +1,5 @@
 use moon_cache::CacheEngine;
+use moon_common::SyncCheck;
 use moon_config::{ToolchainConfig, Version, WorkspaceConfig};
 use moon_console::Console;
 use moon_vcs::BoxedVcs;
@@ -12,6 +13,7 @@ pub struct AppContext {
     // Components
     pub cache_engine: Arc<CacheEngine>,
     pub console: Arc<Console>,
+    pub sync_check: Option<Arc<SyncCheck>>, // Only when `--check`
     pub vcs: Arc<BoxedVcs>,
 
     // Configs
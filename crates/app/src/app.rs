@@ -9,20 +9,25 @@ use crate::commands::generate::GenerateArgs;
 use crate::commands::graph::action::ActionGraphArgs;
 use crate::commands::graph::project::ProjectGraphArgs;
 use crate::commands::graph::task::TaskGraphArgs;
+use crate::commands::imports::ImportsArgs;
 use crate::commands::init::InitArgs;
 use crate::commands::migrate::MigrateCommands;
 use crate::commands::node::NodeCommands;
+use crate::commands::owners::OwnersArgs;
 use crate::commands::project::ProjectArgs;
 use crate::commands::query::QueryCommands;
 use crate::commands::run::RunArgs;
+use crate::commands::stats::StatsArgs;
 use crate::commands::sync::SyncCommands;
 use crate::commands::task::TaskArgs;
+use crate::commands::templates::TemplatesCommands;
 use crate::systems::bootstrap;
 use clap::builder::styling::{Color, Style, Styles};
 use clap::{Parser, Subcommand};
 use moon_cache::CacheMode;
 use moon_codegen::TemplatesArgs;
 use moon_common::consts::BIN_NAME;
+use moon_console_reporter::ReporterType;
 use starbase::tracing::LogLevel;
 use starbase_styles::color::Color as ColorType;
 use std::env;
@@ -86,6 +91,20 @@ pub enum Commands {
     )]
     ActionGraph(ActionGraphArgs),
 
+    // moon imports [...ids]
+    #[command(
+        name = "imports",
+        about = "Verify source imports against declared project dependencies."
+    )]
+    Imports(ImportsArgs),
+
+    // moon owners [...paths]
+    #[command(
+        name = "owners",
+        about = "Display the owners of files, and the reviewers they require."
+    )]
+    Owners(OwnersArgs),
+
     // moon project <id>
     #[command(
         name = "project",
@@ -114,6 +133,13 @@ pub enum Commands {
     Sync {
         #[command(subcommand)]
         command: Option<SyncCommands>,
+
+        #[arg(
+            long,
+            global = true,
+            help = "Check for drift without writing files, and exit with a non-zero code if out of sync"
+        )]
+        check: bool,
     },
 
     // moon task <target>
@@ -137,9 +163,16 @@ pub enum Commands {
     // moon templates
     #[command(
         name = "templates",
-        about = "List all templates that are available for code generation."
+        about = "List all templates that are available for code generation.",
+        args_conflicts_with_subcommands = true
     )]
-    Templates(TemplatesArgs),
+    Templates {
+        #[command(subcommand)]
+        command: Option<TemplatesCommands>,
+
+        #[command(flatten)]
+        args: TemplatesArgs,
+    },
 
     // RUNNER
 
@@ -220,6 +253,13 @@ pub enum Commands {
         command: QueryCommands,
     },
 
+    // moon stats
+    #[command(
+        name = "stats",
+        about = "Display statistics about previous runs, derived from the run history."
+    )]
+    Stats(StatsArgs),
+
     // moon upgrade
     #[command(
         alias = "up",
@@ -312,6 +352,15 @@ pub struct Cli {
     )]
     pub quiet: bool,
 
+    #[arg(
+        value_enum,
+        long,
+        global = true,
+        env = "MOON_REPORTER",
+        help = "Reporter to render pipeline output with [default: ci when in CI, otherwise default]"
+    )]
+    pub reporter: Option<ReporterType>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -2,6 +2,8 @@
 
 use miette::Diagnostic;
 use moon_common::{consts, Id, Style, Stylize};
+use std::fmt;
+use std::path::PathBuf;
 use thiserror::Error;
 
 #[derive(Error, Debug, Diagnostic)]
@@ -57,6 +59,59 @@ pub enum AppError {
     )]
     UnknownExtension { id: Id },
 
+    #[diagnostic(
+        code(app::run::no_resume_state),
+        help = "Only runs that failed or were aborted can be resumed."
+    )]
+    #[error("There is no previous run to resume.")]
+    NoRunToResume,
+
+    #[diagnostic(
+        code(app::run::unstaged_not_restored),
+        help = "Apply the patch with git apply, or delete it, and try again."
+    )]
+    #[error(
+        "Unstaged changes from a previous staged run were not restored, and are saved to {}.",
+        .0.style(Style::Path)
+    )]
+    UnstagedChangesNotRestored(PathBuf),
+
+    #[diagnostic(
+        code(app::run::unstaged_restore_failed),
+        help = "Apply the patch with git apply to recover unstaged changes."
+    )]
+    #[error(
+        "Failed to restore unstaged changes after running. Unstaged changes have been kept at {}.",
+        .patch_file.style(Style::Path)
+    )]
+    UnstagedChangesRestoreFailed {
+        patch_file: PathBuf,
+        #[diagnostic_source]
+        error: miette::Report,
+        #[related]
+        related: Vec<RelatedError>,
+    },
+
+    #[diagnostic(code(app::run::staged_restage_failed))]
+    #[error("Failed to re-stage files that were modified while running.")]
+    StagedFilesRestageFailed {
+        #[diagnostic_source]
+        error: miette::Report,
+        #[related]
+        related: Vec<RelatedError>,
+    },
+
+    #[diagnostic(
+        code(app::generate::post_generate_failed),
+        help = "Files were generated successfully, but the remaining post-generation steps were not ran."
+    )]
+    #[error("Post-generation step {} failed.", .step.style(Style::Label))]
+    PostGenerateFailed {
+        step: String,
+        #[diagnostic_source]
+        error: miette::Report,
+    },
+
     #[diagnostic(code(app::upgrade::requires_internet))]
     #[error("Upgrading moon requires an internet connection!")]
     UpgradeRequiresInternet,
@@ -73,3 +128,27 @@ pub enum AppError {
     )]
     InvalidMoonVersion { actual: String, expected: String },
 }
+
+/// Wraps a report so that it can be rendered as a related diagnostic,
+/// as a [`miette::Report`] itself does not implement [`Diagnostic`].
+#[derive(Debug, Error)]
+#[error("{0}")]
+pub struct RelatedError(pub miette::Report);
+
+impl Diagnostic for RelatedError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.0.code()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.0.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.0.url()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.0.diagnostic_source()
+    }
+}
@@ -1,17 +1,20 @@
 use crate::app_error::AppError;
 use crate::components::run_action_pipeline;
-use crate::queries::touched_files::{query_touched_files, QueryTouchedFilesOptions};
+use crate::queries::base_tasks::query_base_tasks;
+use crate::queries::lockfile_changes::query_lockfile_changes;
+use crate::queries::touched_files::{
+    query_touched_files, QueryTouchedFilesOptions, QueryTouchedFilesResult,
+};
 use crate::session::CliSession;
 use ci_env::CiOutput;
 use clap::Args;
 use moon_action_context::ActionContext;
 use moon_action_graph::{ActionGraph, RunRequirements};
 use moon_affected::{DownstreamScope, UpstreamScope};
-use moon_common::path::WorkspaceRelativePathBuf;
 use moon_console::Console;
 use moon_task::TargetLocator;
 use moon_workspace_graph::WorkspaceGraph;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 use starbase::AppResult;
 use starbase_styles::color;
 use std::sync::Arc;
@@ -86,7 +89,7 @@ async fn gather_touched_files(
     console: &mut CiConsole,
     session: &CliSession,
     args: &CiArgs,
-) -> miette::Result<FxHashSet<WorkspaceRelativePathBuf>> {
+) -> miette::Result<QueryTouchedFilesResult> {
     console.print_header("Gathering touched files")?;
 
     let mut base = args.base.clone();
@@ -134,7 +137,7 @@ async fn gather_touched_files(
     console.write_line(files.join("\n"))?;
     console.print_footer()?;
 
-    Ok(result.files)
+    Ok(result)
 }
 
 /// Gather potential runnable targets.
@@ -147,14 +150,15 @@ async fn gather_potential_targets(
 
     let mut targets = vec![];
 
-    if args.targets.is_empty() {
+    // When only exclusions are provided, exclude from all tasks
+    if args.targets.iter().all(|target| target.is_exclusion()) {
         for task in workspace_graph.get_tasks()? {
             targets.push(TargetLocator::Qualified(task.target.clone()));
         }
-    } else {
-        targets.extend(args.targets.clone());
     }
 
+    targets.extend(args.targets.clone());
+
     console.print_targets(&targets)?;
     console.print_footer()?;
 
@@ -171,10 +175,15 @@ fn distribute_targets_across_jobs(
         return Ok(targets);
     }
 
+    // Exclusions must apply to every job, so don't distribute them
+    let (exclusions, targets): (TargetList, TargetList) = targets
+        .into_iter()
+        .partition(|target| target.is_exclusion());
+
     let job_index = args.job.unwrap_or_default();
     let job_total = args.job_total.unwrap_or_default();
     let batch_size = targets.len().div_ceil(job_total);
-    let batched_targets;
+    let mut batched_targets;
 
     console.print_header("Distributing targets across jobs")?;
     console.write_line(format!("Job index: {job_index}"))?;
@@ -191,6 +200,8 @@ fn distribute_targets_across_jobs(
             targets[(batch_size * job_index)..(batch_size * (job_index + 1))].to_vec();
     }
 
+    batched_targets.extend(exclusions);
+
     console.print_targets(&batched_targets)?;
     console.print_footer()?;
 
@@ -203,14 +214,31 @@ async fn generate_action_graph(
     session: &CliSession,
     workspace_graph: &WorkspaceGraph,
     targets: &TargetList,
-    touched_files: FxHashSet<WorkspaceRelativePathBuf>,
+    touched_files: QueryTouchedFilesResult,
 ) -> miette::Result<(ActionGraph, ActionContext)> {
     console.print_header("Generating action graph")?;
 
+    let (base_tasks, lockfile_changes) = match &touched_files.base_revision {
+        Some(revision) => (
+            query_base_tasks(session, revision, &touched_files.files).await?,
+            query_lockfile_changes(session, workspace_graph, revision, &touched_files.files)
+                .await?,
+        ),
+        None => (None, FxHashMap::default()),
+    };
+
     let mut action_graph_builder = session.build_action_graph(workspace_graph).await?;
-    action_graph_builder.set_touched_files(touched_files)?;
+    action_graph_builder.set_touched_files(touched_files.files)?;
     action_graph_builder.set_affected_scopes(UpstreamScope::Deep, DownstreamScope::Deep)?;
 
+    if let Some(base_tasks) = base_tasks {
+        action_graph_builder.set_base_tasks(base_tasks);
+    }
+
+    if !lockfile_changes.is_empty() {
+        action_graph_builder.set_lockfile_changes(lockfile_changes);
+    }
+
     // Run dependents to ensure consumers still work correctly
     action_graph_builder.run_from_requirements(RunRequirements {
         ci: true,
@@ -12,4 +12,12 @@ pub enum AppDockerError {
         "moon docker scaffold".style(Style::Shell)
     )]
     MissingManifest,
+
+    #[diagnostic(code(app::docker::unscaffolded_project))]
+    #[error(
+        "Unable to prune project {}, as it was not included when running {}.",
+        .0.style(Style::Id),
+        "moon docker scaffold".style(Style::Shell)
+    )]
+    UnscaffoldedProject(String),
 }
@@ -1,19 +1,35 @@
+use crate::queries::lockfile_changes::query_lockfile_packages;
 use crate::session::CliSession;
-use clap::Args;
+use clap::{ArgAction, Args};
+use moon_common::path::{RelativePathBuf, WorkspaceRelativePathBuf};
 use moon_common::{color, Id};
 use moon_console::prompts::{Select, Text};
 use moon_docker::*;
 use moon_project::Project;
+use moon_workspace_graph::WorkspaceGraph;
+use rustc_hash::FxHashSet;
 use starbase::AppResult;
 use starbase_utils::fs;
+use std::collections::BTreeSet;
+use std::sync::Arc;
 use tracing::{debug, instrument};
 
 #[derive(Args, Clone, Debug)]
 pub struct DockerFileArgs {
-    #[arg(help = "ID of project to create a Dockerfile for")]
-    id: Id,
+    // A single value that is split by commas, so that the destination
+    // can still be passed as the next positional argument
+    #[arg(
+        required = true,
+        num_args = 1,
+        action = ArgAction::Set,
+        value_delimiter = ',',
+        help = "ID of project to create a Dockerfile for, or a comma separated list of IDs"
+    )]
+    ids: Vec<Id>,
 
-    #[arg(help = "Destination path, relative from the project root")]
+    #[arg(
+        help = "Destination path, relative from the project root, or the workspace root if multiple projects"
+    )]
     dest: Option<String>,
 
     #[arg(long, help = "Use default options instead of prompting")]
@@ -34,6 +50,12 @@ pub struct DockerFileArgs {
     )]
     no_toolchain: bool,
 
+    #[arg(
+        long = "runtimeImage",
+        help = "Base Docker image for a runtime-only final stage"
+    )]
+    runtime_image: Option<String>,
+
     #[arg(long = "startTask", help = "ID of a task to run the project")]
     start_task: Option<Id>,
 }
@@ -43,138 +65,382 @@ pub async fn file(session: CliSession, args: DockerFileArgs) -> AppResult {
     let console = &session.console;
     let workspace_graph = session.get_workspace_graph().await?;
 
-    // Ensure the project exists
-    let project = workspace_graph.get_project(&args.id)?;
-    let tasks = workspace_graph.get_tasks_from_project(&project.id)?;
+    // Ensure the projects exist
+    let mut projects = vec![];
+
+    for id in &args.ids {
+        let project = workspace_graph.get_project(id)?;
+
+        if !projects
+            .iter()
+            .any(|existing: &Arc<Project>| existing.id == project.id)
+        {
+            projects.push(project);
+        }
+    }
+
+    let multiple = projects.len() > 1;
 
     // Build the options
     let mut options = GenerateDockerfileOptions {
         disable_toolchain: args.no_toolchain,
-        project: args.id,
         prune: !args.no_prune,
         ..GenerateDockerfileOptions::default()
     };
 
     debug!("Gathering Dockerfile options");
 
-    if let Some(image) = args.image {
-        options.image = image;
+    // The image is shared, so use the first project for defaults
+    let first_project = &projects[0];
+
+    if let Some(image) = &args.image {
+        options.image = image.to_owned();
     } else if args.defaults {
-        options.image = project
+        options.image = first_project
             .config
             .docker
             .file
             .image
             .clone()
-            .unwrap_or_else(|| get_base_image(&project).into());
+            .unwrap_or_else(|| get_base_image(first_project).into());
     } else {
         options.image = console.prompt_text(
             Text::new("Docker image?").with_default(
-                project
+                first_project
                     .config
                     .docker
                     .file
                     .image
                     .as_deref()
-                    .unwrap_or_else(|| get_base_image(&project)),
+                    .unwrap_or_else(|| get_base_image(first_project)),
             ),
         )?;
     }
 
     debug!(image = &options.image, "Using Docker image");
 
-    let build_task_id = if let Some(id) = &args.build_task {
-        Some(id)
-    } else if args.defaults {
-        project.config.docker.file.build_task.as_ref()
-    } else {
+    options.runtime_image = args
+        .runtime_image
+        .clone()
+        .or_else(|| first_project.config.docker.file.runtime_image.clone());
+
+    if let Some(runtime_image) = &options.runtime_image {
+        debug!(
+            runtime_image,
+            "Using runtime image for a runtime-only final stage"
+        );
+    }
+
+    let mut sboms = vec![];
+
+    for project in &projects {
+        let tasks = workspace_graph.get_tasks_from_project(&project.id)?;
         let mut ids = tasks.iter().map(|task| &task.id).collect::<Vec<_>>();
         ids.sort();
 
-        let starting_cursor = project
-            .config
-            .docker
-            .file
-            .build_task
-            .as_ref()
-            .and_then(|id| ids.iter().position(|cursor_id| cursor_id == &id));
-
-        console.prompt_select_skippable(
-            Select::new("Build task?", ids)
-                .with_help_message("Skip build with ESC")
-                .with_starting_cursor(starting_cursor.unwrap_or(0)),
-        )?
-    };
+        let label = |kind: &str| {
+            if multiple {
+                format!("{kind} task for {}?", project.id)
+            } else {
+                format!("{kind} task?")
+            }
+        };
 
-    if let Some(task_id) = build_task_id {
-        let target = workspace_graph
-            .get_task_from_project(&project.id, task_id)?
-            .target
-            .to_owned();
+        let build_task_id = if let Some(id) = &args.build_task {
+            Some(id)
+        } else if args.defaults {
+            project.config.docker.file.build_task.as_ref()
+        } else {
+            let starting_cursor = project
+                .config
+                .docker
+                .file
+                .build_task
+                .as_ref()
+                .and_then(|id| ids.iter().position(|cursor_id| cursor_id == &id));
+
+            console.prompt_select_skippable(
+                Select::new(&label("Build"), ids.clone())
+                    .with_help_message("Skip build with ESC")
+                    .with_starting_cursor(starting_cursor.unwrap_or(0)),
+            )?
+        };
 
-        debug!(task = target.as_str(), "Using build task");
+        let build_task = if let Some(task_id) = build_task_id {
+            let target = workspace_graph
+                .get_task_from_project(&project.id, task_id)?
+                .target
+                .to_owned();
 
-        options.build_task = Some(target);
-    } else {
-        debug!("Not using a build task");
-    }
+            debug!(task = target.as_str(), "Using build task");
 
-    let start_task_id = if let Some(id) = &args.start_task {
-        Some(id)
-    } else if args.defaults {
-        project.config.docker.file.start_task.as_ref()
-    } else {
-        let mut ids = tasks.iter().map(|task| &task.id).collect::<Vec<_>>();
-        ids.sort();
+            Some(target)
+        } else {
+            debug!(project_id = project.id.as_str(), "Not using a build task");
 
-        let starting_cursor = project
-            .config
-            .docker
-            .file
-            .start_task
-            .as_ref()
-            .and_then(|id| ids.iter().position(|cursor_id| cursor_id == &id));
-
-        console.prompt_select_skippable(
-            Select::new("Start task?", ids)
-                .with_help_message("Skip start with ESC")
-                .with_starting_cursor(starting_cursor.unwrap_or(0)),
-        )?
-    };
+            None
+        };
+
+        let start_task_id = if let Some(id) = &args.start_task {
+            Some(id)
+        } else if args.defaults {
+            project.config.docker.file.start_task.as_ref()
+        } else {
+            let starting_cursor = project
+                .config
+                .docker
+                .file
+                .start_task
+                .as_ref()
+                .and_then(|id| ids.iter().position(|cursor_id| cursor_id == &id));
+
+            console.prompt_select_skippable(
+                Select::new(&label("Start"), ids.clone())
+                    .with_help_message("Skip start with ESC")
+                    .with_starting_cursor(starting_cursor.unwrap_or(0)),
+            )?
+        };
 
-    if let Some(task_id) = start_task_id {
-        let target = workspace_graph
-            .get_task_from_project(&project.id, task_id)?
-            .target
-            .to_owned();
+        let start_task = if let Some(task_id) = start_task_id {
+            let target = workspace_graph
+                .get_task_from_project(&project.id, task_id)?
+                .target
+                .to_owned();
 
-        debug!(task = target.as_str(), "Using start task");
+            debug!(task = target.as_str(), "Using start task");
 
-        options.start_task = Some(target);
-    } else {
-        debug!("Not using a start task");
+            Some(target)
+        } else {
+            debug!(project_id = project.id.as_str(), "Not using a start task");
+
+            None
+        };
+
+        let mut dockerfile_project = DockerfileProject {
+            build_task,
+            id: project.id.clone(),
+            start_task,
+            ..Default::default()
+        };
+
+        if options.runtime_image.is_some() {
+            let runtime_projects = get_runtime_projects(&workspace_graph, project)?;
+            let sbom_source = project.source.join("sbom.cdx.json").normalize();
+
+            apply_runtime(&workspace_graph, &runtime_projects, &mut dockerfile_project)?;
+
+            sboms.push((
+                sbom_source.clone(),
+                generate_sbom(GenerateSbomOptions {
+                    components: get_sbom_components(&session, &runtime_projects)?,
+                    project_id: project.id.to_string(),
+                })?,
+            ));
+
+            dockerfile_project.sbom_file = Some(sbom_source.to_string());
+        }
+
+        options.projects.push(dockerfile_project);
     }
 
     // Generate the file
     let out = args.dest.unwrap_or("Dockerfile".into());
-    let out_file = project.root.join(&out);
+    let (out_file, out_source) = if multiple {
+        (
+            session.workspace_root.join(&out),
+            RelativePathBuf::from(&out),
+        )
+    } else {
+        (
+            first_project.root.join(&out),
+            first_project.source.join(&out),
+        )
+    };
 
     debug!(
         dockerfile = ?out_file,
-        project = options.project.as_str(),
-        "Generating Dockerfile in project",
+        projects = ?options.projects.iter().map(|project| project.id.as_str()).collect::<Vec<_>>(),
+        "Generating Dockerfile",
     );
 
     fs::write_file(out_file, generate_dockerfile(options)?)?;
 
-    console.out.write_line(format!(
-        "Generated {}",
-        color::rel_path(project.source.join(out))
-    ))?;
+    console
+        .out
+        .write_line(format!("Generated {}", color::rel_path(out_source)))?;
+
+    for (sbom_source, sbom) in sboms {
+        fs::write_file(sbom_source.to_logical_path(&session.workspace_root), sbom)?;
+
+        console
+            .out
+            .write_line(format!("Generated {}", color::rel_path(sbom_source)))?;
+    }
 
     Ok(None)
 }
 
+/// Return the project, and all projects it depends on at runtime (non-root scopes).
+fn get_runtime_projects(
+    workspace_graph: &WorkspaceGraph,
+    project: &Arc<Project>,
+) -> miette::Result<Vec<Arc<Project>>> {
+    let mut projects = vec![];
+    let mut seen = FxHashSet::default();
+    let mut queue = vec![project.id.clone()];
+
+    while let Some(id) = queue.pop() {
+        if !seen.insert(id.clone()) {
+            continue;
+        }
+
+        let dep_project = workspace_graph.get_project(&id)?;
+
+        for dep_cfg in &dep_project.dependencies {
+            if !dep_cfg.is_root_scope() {
+                queue.push(dep_cfg.id.clone());
+            }
+        }
+
+        projects.push(dep_project);
+    }
+
+    Ok(projects)
+}
+
+/// Determine the files to copy into the runtime image, and the command to
+/// run it with. Files are the outputs of the build task (and its dependencies),
+/// and the manifests and installed dependencies of each runtime project.
+fn apply_runtime(
+    workspace_graph: &WorkspaceGraph,
+    runtime_projects: &[Arc<Project>],
+    dockerfile_project: &mut DockerfileProject,
+) -> miette::Result<()> {
+    let project = &runtime_projects[0];
+    let mut files = BTreeSet::new();
+
+    if let Some(build_target) = &dockerfile_project.build_task {
+        let mut seen = FxHashSet::default();
+        let mut queue = vec![build_target.to_owned()];
+
+        while let Some(target) = queue.pop() {
+            if !seen.insert(target.clone()) {
+                continue;
+            }
+
+            let task = workspace_graph.get_task(&target)?;
+
+            files.extend(task.output_files.iter().map(|file| file.to_string()));
+            files.extend(task.output_globs.iter().filter_map(get_glob_prefix));
+            queue.extend(task.deps.iter().map(|dep| dep.target.clone()));
+        }
+    }
+
+    for runtime_project in runtime_projects {
+        for toolchain in &runtime_project.toolchains {
+            let deps_dir = match toolchain.as_str() {
+                "bun" | "node" => {
+                    files.insert("package.json".into());
+                    files.insert(
+                        runtime_project
+                            .source
+                            .join("package.json")
+                            .normalize()
+                            .to_string(),
+                    );
+
+                    "node_modules"
+                }
+                "python" => ".venv",
+                _ => continue,
+            };
+
+            files.insert(deps_dir.into());
+            files.insert(
+                runtime_project
+                    .source
+                    .join(deps_dir)
+                    .normalize()
+                    .to_string(),
+            );
+        }
+    }
+
+    dockerfile_project.runtime_files = files.into_iter().collect();
+    dockerfile_project.runtime_dir = project.source.normalize().to_string();
+
+    if let Some(start_target) = &dockerfile_project.start_task {
+        let task = workspace_graph.get_task(start_target)?;
+
+        dockerfile_project.runtime_command = match &task.script {
+            Some(script) => vec!["sh".into(), "-c".into(), script.to_owned()],
+            None => [task.command.clone()]
+                .into_iter()
+                .chain(task.args.clone())
+                .collect(),
+        };
+
+        if task.options.run_from_workspace_root {
+            dockerfile_project.runtime_dir = String::new();
+        }
+    }
+
+    Ok(())
+}
+
+/// Return the leading path of a glob that doesn't contain wildcards,
+/// as the matched files can't be resolved until the build has ran.
+fn get_glob_prefix(glob: &WorkspaceRelativePathBuf) -> Option<String> {
+    if glob.as_str().starts_with('!') {
+        return None;
+    }
+
+    let prefix = glob
+        .components()
+        .map(|component| component.as_str())
+        .take_while(|part| !part.contains(['*', '?', '[', '{']))
+        .collect::<Vec<_>>()
+        .join("/");
+
+    if prefix.is_empty() {
+        None
+    } else {
+        Some(prefix)
+    }
+}
+
+/// Return the workspace projects (excluding the project itself) and third-party
+/// packages that are included at runtime, for use within an SBOM.
+fn get_sbom_components(
+    session: &CliSession,
+    runtime_projects: &[Arc<Project>],
+) -> miette::Result<Vec<SbomComponent>> {
+    let mut components = runtime_projects
+        .iter()
+        .skip(1)
+        .map(|project| SbomComponent::project(project.id.as_str()))
+        .collect::<Vec<_>>();
+
+    for package in query_lockfile_packages(&session.workspace_root, runtime_projects)? {
+        let ecosystem = match package.lockfile {
+            "Cargo.lock" => "cargo",
+            "package-lock.json" | "pnpm-lock.yaml" | "yarn.lock" | "bun.lock" | "bun.lockb" => {
+                "npm"
+            }
+            "uv.lock" => "pypi",
+            _ => continue,
+        };
+
+        components.push(SbomComponent::package(
+            ecosystem,
+            &package.name,
+            &package.version,
+        ));
+    }
+
+    Ok(components)
+}
+
 fn get_base_image(project: &Project) -> &str {
     if let Some(tc) = project.toolchains.first() {
         return match tc.as_str() {
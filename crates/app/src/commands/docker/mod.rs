@@ -23,7 +23,7 @@ pub enum DockerCommands {
         name = "prune",
         about = "Remove extraneous files and folders within a Dockerfile."
     )]
-    Prune,
+    Prune(DockerPruneArgs),
 
     #[command(
         name = "scaffold",
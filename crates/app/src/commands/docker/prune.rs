@@ -1,19 +1,32 @@
 use super::{docker_error::AppDockerError, DockerManifest, MANIFEST_NAME};
 use crate::session::CliSession;
+use clap::Args;
 use moon_bun_tool::BunTool;
 use moon_common::Id;
-use moon_config::PlatformType;
+use moon_config::{PlatformType, PythonPackageManager};
+use moon_deno_lang::DenoJson;
 use moon_deno_tool::DenoTool;
+use moon_docker::prune_cargo_target_dir;
 use moon_node_lang::PackageJsonCache;
 use moon_node_tool::NodeTool;
 use moon_platform::PlatformManager;
+use moon_python_lang::uv::PyProjectTomlCache;
+use moon_python_tool::{find_requirements_txt, PythonTool};
+use moon_rust_lang::cargo_toml::CargoTomlCache;
 use moon_rust_tool::RustTool;
 use moon_tool::DependencyManager;
+use moon_workspace_graph::WorkspaceGraph;
 use rustc_hash::FxHashSet;
 use starbase::AppResult;
 use starbase_utils::{fs, json};
 use tracing::{debug, instrument};
 
+#[derive(Args, Clone, Debug, Default)]
+pub struct DockerPruneArgs {
+    #[arg(help = "List of project IDs to prune for, otherwise all scaffolded projects")]
+    ids: Vec<Id>,
+}
+
 #[instrument(skip_all)]
 pub async fn prune_bun(
     bun: &BunTool,
@@ -70,11 +83,54 @@ pub async fn prune_bun(
 pub async fn prune_deno(
     deno: &DenoTool,
     session: &CliSession,
-    _manifest: &DockerManifest,
+    manifest: &DockerManifest,
 ) -> AppResult {
-    // noop
+    let project_graph = session.get_project_graph().await?;
+
+    if session
+        .workspace_config
+        .docker
+        .prune
+        .delete_vendor_directories
+    {
+        debug!("Removing Deno vendor directories (node_modules, vendor)");
+
+        fs::remove_dir_all(session.workspace_root.join("node_modules"))?;
+
+        for source in project_graph.sources().values() {
+            fs::remove_dir_all(source.join("node_modules").to_path(&session.workspace_root))?;
+        }
+
+        // Only remove when enabled, as it may be a legitimate sources directory
+        if DenoJson::read(&session.workspace_root)?
+            .is_some_and(|deno_json| deno_json.vendor.unwrap_or_default())
+        {
+            fs::remove_dir_all(session.workspace_root.join("vendor"))?;
+        }
+    }
+
+    // Install only dependencies imported by focused projects
     if session.workspace_config.docker.prune.install_toolchain_deps {
-        deno.install_focused_dependencies(&(), &[], true).await?;
+        let mut entry_files = vec![];
+
+        for project_id in &manifest.focused_projects {
+            if let Some(source) = project_graph.sources().get(project_id) {
+                let deps_path = source
+                    .join(&deno.config.deps_file)
+                    .to_path(&session.workspace_root);
+
+                if deps_path.exists() {
+                    entry_files.push(deps_path.to_string_lossy().to_string());
+                }
+            }
+        }
+
+        debug!(
+            files = ?entry_files,
+            "Pruning Deno dependencies"
+        );
+
+        deno.install_entry_dependencies(&entry_files).await?;
     }
 
     Ok(None)
@@ -133,9 +189,103 @@ pub async fn prune_node(
     Ok(None)
 }
 
-// This assumes that the project was built in --release mode. Is this correct?
 #[instrument(skip_all)]
-pub async fn prune_rust(_rust: &RustTool, session: &CliSession) -> AppResult {
+pub async fn prune_python(
+    python: &PythonTool,
+    session: &CliSession,
+    manifest: &DockerManifest,
+) -> AppResult {
+    let project_graph = session.get_project_graph().await?;
+    let venv_name = &python.config.venv_name;
+
+    // Packages can't be reliably uninstalled, so start from a fresh environment
+    if session
+        .workspace_config
+        .docker
+        .prune
+        .delete_vendor_directories
+    {
+        debug!("Removing Python virtual environments ({venv_name})");
+
+        fs::remove_dir_all(session.workspace_root.join(venv_name))?;
+
+        for source in project_graph.sources().values() {
+            fs::remove_dir_all(source.join(venv_name).to_path(&session.workspace_root))?;
+        }
+    }
+
+    // Install production only dependencies for focused projects
+    if session.workspace_config.docker.prune.install_toolchain_deps {
+        let mut package_names = vec![];
+        let mut requirements_files = vec![];
+
+        for project_id in &manifest.focused_projects {
+            if let Some(source) = project_graph.sources().get(project_id) {
+                let project_root = source.to_path(&session.workspace_root);
+
+                match python.config.package_manager {
+                    PythonPackageManager::Pip => {
+                        if let Some(reqs_path) =
+                            find_requirements_txt(&project_root, &session.workspace_root)
+                        {
+                            let reqs_path = reqs_path.to_string_lossy().to_string();
+
+                            if !requirements_files.contains(&reqs_path) {
+                                requirements_files.push(reqs_path);
+                            }
+                        }
+                    }
+                    PythonPackageManager::Uv => {
+                        if let Some(project) =
+                            PyProjectTomlCache::read(&project_root)?.and_then(|data| data.project)
+                        {
+                            package_names.push(project.name);
+                        }
+                    }
+                };
+            }
+        }
+
+        // uv creates the environment while syncing, but pip requires it to exist
+        let venv_root = session.workspace_root.join(venv_name);
+
+        if python.config.package_manager == PythonPackageManager::Pip && !venv_root.exists() {
+            python
+                .exec_venv(&venv_root, &session.workspace_root, &session.workspace_root)
+                .await?;
+        }
+
+        debug!(
+            packages = ?package_names,
+            files = ?requirements_files,
+            "Pruning Python dependencies"
+        );
+
+        match python.config.package_manager {
+            PythonPackageManager::Pip => {
+                python
+                    .get_pip()?
+                    .install_requirements_files(python, &requirements_files)
+                    .await?;
+            }
+            PythonPackageManager::Uv => {
+                python
+                    .get_uv()?
+                    .install_focused_dependencies(python, &package_names, true)
+                    .await?;
+            }
+        };
+    }
+
+    Ok(None)
+}
+
+#[instrument(skip_all)]
+pub async fn prune_rust(
+    _rust: &RustTool,
+    session: &CliSession,
+    manifest: &DockerManifest,
+) -> AppResult {
     if session
         .workspace_config
         .docker
@@ -145,14 +295,33 @@ pub async fn prune_rust(_rust: &RustTool, session: &CliSession) -> AppResult {
         let target_dir = &session.workspace_root.join("target");
         let lockfile_path = &session.workspace_root.join("Cargo.lock");
 
-        // Only delete target if relative to `Cargo.lock`
+        // Only prune target if relative to `Cargo.lock`
         if target_dir.exists() && lockfile_path.exists() {
+            let project_graph = session.get_project_graph().await?;
+            let mut crate_names = vec![];
+
+            for project_id in &manifest.focused_projects {
+                if let Some(source) = project_graph.sources().get(project_id) {
+                    if let Some(cargo_toml) =
+                        CargoTomlCache::read(source.to_path(&session.workspace_root))?
+                    {
+                        if let Some(package) = cargo_toml.package {
+                            crate_names.push(package.name);
+                        }
+
+                        crate_names.extend(cargo_toml.lib.and_then(|lib| lib.name));
+                        crate_names.extend(cargo_toml.bin.into_iter().filter_map(|bin| bin.name));
+                    }
+                }
+            }
+
             debug!(
                 target_dir = ?target_dir,
-                "Deleting Rust target directory"
+                crates = ?crate_names,
+                "Pruning Rust target directory for focused crates"
             );
 
-            fs::remove_dir_all(target_dir)?;
+            prune_cargo_target_dir(target_dir, &crate_names)?;
         }
     }
 
@@ -160,7 +329,7 @@ pub async fn prune_rust(_rust: &RustTool, session: &CliSession) -> AppResult {
 }
 
 #[instrument(skip_all)]
-pub async fn prune(session: CliSession) -> AppResult {
+pub async fn prune(session: CliSession, args: DockerPruneArgs) -> AppResult {
     let manifest_path = session.workspace_root.join(MANIFEST_NAME);
 
     if !manifest_path.exists() {
@@ -168,9 +337,15 @@ pub async fn prune(session: CliSession) -> AppResult {
     }
 
     let workspace_graph = session.get_workspace_graph().await?;
-    let manifest: DockerManifest = json::read_file(manifest_path)?;
+    let mut manifest: DockerManifest = json::read_file(manifest_path)?;
     let mut toolchains = FxHashSet::<Id>::default();
 
+    // When building multiple projects from the same scaffold,
+    // only keep dependencies for the requested projects
+    if !args.ids.is_empty() {
+        narrow_focused_projects(&workspace_graph, &mut manifest, &args.ids)?;
+    }
+
     debug!(
         projects = ?manifest.focused_projects.iter().map(|id| id.as_str()).collect::<Vec<_>>(),
         "Pruning dependencies for focused projects"
@@ -226,6 +401,18 @@ pub async fn prune(session: CliSession) -> AppResult {
                 )
                 .await?;
             }
+            PlatformType::Python => {
+                prune_python(
+                    platform
+                        .get_tool()?
+                        .as_any()
+                        .downcast_ref::<PythonTool>()
+                        .unwrap(),
+                    &session,
+                    &manifest,
+                )
+                .await?;
+            }
             PlatformType::Rust => {
                 prune_rust(
                     platform
@@ -234,6 +421,7 @@ pub async fn prune(session: CliSession) -> AppResult {
                         .downcast_ref::<RustTool>()
                         .unwrap(),
                     &session,
+                    &manifest,
                 )
                 .await?;
             }
@@ -243,3 +431,42 @@ pub async fn prune(session: CliSession) -> AppResult {
 
     Ok(None)
 }
+
+fn narrow_focused_projects(
+    workspace_graph: &WorkspaceGraph,
+    manifest: &mut DockerManifest,
+    ids: &[Id],
+) -> miette::Result<()> {
+    let mut focused_projects = FxHashSet::default();
+    let mut queue = ids.to_vec();
+
+    while let Some(id) = queue.pop() {
+        let project = workspace_graph.get_project(&id)?;
+
+        if !manifest.focused_projects.contains(&project.id) {
+            return Err(AppDockerError::UnscaffoldedProject(project.id.to_string()).into());
+        }
+
+        if !focused_projects.insert(project.id.clone()) {
+            continue;
+        }
+
+        // Match the dependencies that were scaffolded
+        for dep_cfg in &project.dependencies {
+            if !dep_cfg.is_root_scope() {
+                queue.push(dep_cfg.id.clone());
+            }
+        }
+    }
+
+    manifest.unfocused_projects.extend(
+        manifest
+            .focused_projects
+            .difference(&focused_projects)
+            .cloned(),
+    );
+
+    manifest.focused_projects = focused_projects;
+
+    Ok(())
+}
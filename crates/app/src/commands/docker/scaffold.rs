@@ -283,7 +283,10 @@ async fn scaffold_sources_project(
     let project = project_graph.get(project_id)?;
     let mut include_globs = vec!["!node_modules/**", "!target/**/*", "!vendor/**"];
 
-    manifest.focused_projects.insert(project_id.to_owned());
+    // Projects may be shared between focused projects, so only copy once
+    if !manifest.focused_projects.insert(project.id.clone()) {
+        return Ok(None);
+    }
 
     if project.config.docker.scaffold.include.is_empty() {
         include_globs.push("**/*");
@@ -1,11 +1,23 @@
-use crate::helpers::create_theme;
+use crate::app_error::AppError;
+use crate::commands::run::{run_target, RunArgs};
+use crate::helpers::{add_workspace_project_globs, add_workspace_project_sources, create_theme};
 use crate::session::CliSession;
+use crate::systems::startup;
 use dialoguer::{Confirm, Input, Select};
 use miette::IntoDiagnostic;
-use moon_codegen::{gather_variables, CodeGenerator, FileState};
+use moon_codegen::{
+    gather_variables, CodeGenerator, CodegenError, FileState, Template, TemplateContext,
+    TemplateLock,
+};
+use moon_common::path::to_virtual_string;
+use moon_common::{consts::CONFIG_DIRNAME, Id};
+use moon_config::WorkspaceProjects;
+use moon_process::Command;
+use moon_task::TargetLocator;
 use starbase::AppResult;
 use starbase_styles::color;
-use std::path::PathBuf;
+use starbase_utils::{fs, glob, yaml};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tracing::{debug, instrument};
 
@@ -57,8 +69,45 @@ pub async fn generate(session: CliSession, args: GenerateArgs) -> AppResult {
     console.write_newline()?;
     console.flush()?;
 
+    // When updating, load the lock from the destination, which is required
+    let lock = if args.update {
+        let dest = args.dest.as_deref().unwrap_or_default();
+        let dest = template
+            .interpolate_path(&PathBuf::from(dest), &TemplateContext::default())?
+            .to_logical_path(if dest.starts_with('/') {
+                &session.workspace_root
+            } else {
+                &session.working_dir
+            });
+        let Some(lock) = TemplateLock::load(&dest)? else {
+            return Err(CodegenError::MissingTemplateLock(TemplateLock::get_path(&dest)).into());
+        };
+
+        if lock.template != template.id {
+            return Err(CodegenError::TemplateLockMismatch {
+                id: template.id.clone(),
+                locked_id: lock.template,
+            }
+            .into());
+        }
+
+        if lock.version != template.config.version {
+            console.write_line(format!(
+                "Updating from version {} to {}",
+                color::symbol(lock.version.as_deref().unwrap_or("unknown")),
+                color::symbol(template.config.version.as_deref().unwrap_or("unknown")),
+            ))?;
+            console.write_newline()?;
+            console.flush()?;
+        }
+
+        Some(lock)
+    } else {
+        None
+    };
+
     // Gather variables
-    let mut context = gather_variables(&args, &template, &session.console)?;
+    let mut context = gather_variables(&args, &template, &session.console, lock.as_ref())?;
     context.insert("working_dir", &session.working_dir);
     context.insert("workspace_root", &session.workspace_root);
 
@@ -98,18 +147,45 @@ pub async fn generate(session: CliSession, args: GenerateArgs) -> AppResult {
     // Load template files and determine when to overwrite
     template.load_files(&dest, &context)?;
 
+    // Create the lock before merging, as it requires the newly rendered content
+    let next_lock = TemplateLock::new(&template, &dest, &context);
+
     for file in template.files.values_mut() {
         if file.is_skipped() {
             file.state = FileState::Skip;
             continue;
         }
 
+        // Merge the new content with the current file, using the previous
+        // content as the base, and avoid re-creating files that were removed
+        if let Some(lock) = &lock {
+            let base = lock.get_file_content(&dest, &file.dest_path);
+
+            if file.dest_path.exists() {
+                file.set_update_content(base.map(|content| content.as_str()))?;
+            } else if base.is_some() {
+                file.state = FileState::Skip;
+            }
+
+            continue;
+        }
+
         if file.dest_path.exists() {
             if args.force || file.is_forced() {
                 file.state = FileState::Replace;
                 continue;
             }
 
+            // Don't prompt when previewing a diff, and preview the merge when applicable
+            if args.diff {
+                file.state = if file.is_mergeable().is_some() {
+                    FileState::Merge
+                } else {
+                    FileState::Replace
+                };
+                continue;
+            }
+
             // Merge files when applicable
             if file.is_mergeable().is_some() {
                 let operations = [
@@ -151,9 +227,34 @@ pub async fn generate(session: CliSession, args: GenerateArgs) -> AppResult {
         }
     }
 
+    // Label files that would not change when previewing a diff
+    if args.diff {
+        let mut unchanged = vec![];
+
+        for (key, file) in &template.files {
+            if matches!(file.state, FileState::Merge | FileState::Replace)
+                && fs::read_file(&file.dest_path)? == template.get_file_output(file)?
+            {
+                unchanged.push(key.to_owned());
+            }
+        }
+
+        for key in unchanged {
+            if let Some(file) = template.files.get_mut(&key) {
+                file.state = FileState::Unchanged;
+            }
+        }
+    }
+
+    // Only copy assets that don't exist, as binary files can't be merged
+    if args.update {
+        template.assets.retain(|_, asset| !asset.dest_path.exists());
+    }
+
     // Generate the files in the destination and print the results
     if !args.dry_run {
         generator.generate(&template)?;
+        next_lock.save(&dest)?;
     }
 
     console.write_newline()?;
@@ -162,27 +263,274 @@ pub async fn generate(session: CliSession, args: GenerateArgs) -> AppResult {
         console.write_line(format!(
             "{} {} {}",
             match &file.state {
+                FileState::Conflict => color::failure("conflict"),
                 FileState::Create => color::success("created"),
                 FileState::Merge => color::success("merged"),
                 FileState::Replace => color::failure("replaced"),
                 FileState::Skip => color::invalid("skipped"),
+                FileState::Unchanged => color::muted_light("unchanged"),
+                FileState::Update => color::success("updated"),
             },
             match &file.state {
-                FileState::Merge => color::muted("--->"),
-                FileState::Replace => color::muted("->"),
+                FileState::Merge | FileState::Update => color::muted("--->"),
+                FileState::Conflict | FileState::Replace => color::muted("->"),
                 _ => color::muted("-->"),
             },
-            color::muted_light(
-                file.dest_path
-                    .strip_prefix(&session.working_dir)
-                    .unwrap_or(&file.dest_path)
-                    .to_string_lossy()
-            )
+            color::muted_light(get_relative_path(&session, &file.dest_path))
         ))?;
     }
 
     console.write_newline()?;
+
+    // Preview all changes, and fail when something would change
+    if args.diff {
+        let mut changes = 0;
+
+        for asset in template.assets.values() {
+            if template.has_asset_changed(asset)? {
+                changes += 1;
+
+                console.write_line(format!(
+                    "{} {} {}",
+                    if asset.dest_path.exists() {
+                        color::failure("replaced")
+                    } else {
+                        color::success("created")
+                    },
+                    color::muted("-->"),
+                    color::muted_light(get_relative_path(&session, &asset.dest_path))
+                ))?;
+            }
+        }
+
+        if changes > 0 {
+            console.write_newline()?;
+        }
+
+        for file in template.files.values() {
+            if !file.should_write() {
+                continue;
+            }
+
+            if !file.dest_path.exists() {
+                changes += 1;
+                continue;
+            }
+
+            if let Some(patch) =
+                template.diff_file(file, &get_relative_path(&session, &file.dest_path))?
+            {
+                changes += 1;
+
+                for line in patch.lines() {
+                    console.write_line(if line.starts_with("+++") || line.starts_with("---") {
+                        color::muted_light(line)
+                    } else if line.starts_with('+') {
+                        color::success(line)
+                    } else if line.starts_with('-') {
+                        color::failure(line)
+                    } else if line.starts_with("@@") {
+                        color::muted(line)
+                    } else {
+                        line.to_owned()
+                    })?;
+                }
+
+                console.write_newline()?;
+            }
+        }
+
+        if changes > 0 {
+            console.write_line(format!(
+                "{} would be changed",
+                if changes == 1 {
+                    "1 file".into()
+                } else {
+                    format!("{changes} files")
+                }
+            ))?;
+            console.write_newline()?;
+            console.flush()?;
+
+            return Ok(Some(1));
+        }
+    }
+
+    let conflicts = template
+        .files
+        .values()
+        .filter(|file| matches!(file.state, FileState::Conflict))
+        .count();
+
+    if conflicts > 0 {
+        console.write_line(format!(
+            "Resolve the conflict markers in {} before committing",
+            if conflicts == 1 {
+                "1 file".into()
+            } else {
+                format!("{conflicts} files")
+            }
+        ))?;
+        console.write_newline()?;
+    }
+
     console.flush()?;
 
+    // Run post-generation steps once all files have been written
+    if args.dry_run {
+        debug!("Skipping post-generation steps in dry run");
+
+        return Ok(None);
+    }
+
+    run_post_generate(session, &mut template, &context, &dest).await
+}
+
+#[instrument(skip_all)]
+async fn run_post_generate(
+    mut session: CliSession,
+    template: &mut Template,
+    context: &TemplateContext,
+    dest: &Path,
+) -> AppResult {
+    let config = template.config.post_generate.clone();
+    let console = session.console.stdout();
+
+    if config.add_to_workspace {
+        let added = add_project_to_workspace(&session, dest).map_err(|error| {
+            AppError::PostGenerateFailed {
+                step: "add to workspace".into(),
+                error,
+            }
+        })?;
+
+        if let Some(id) = added {
+            console.write_line(format!(
+                "Added project {} to {}",
+                color::id(id),
+                color::file(format!("{CONFIG_DIRNAME}/workspace.yml"))
+            ))?;
+            console.flush()?;
+
+            // Reload the config so that the new project exists in the graph
+            session.workspace_config = startup::load_workspace_config(
+                session.config_loader.clone(),
+                &session.workspace_root,
+            )
+            .await?;
+        }
+    }
+
+    for command in &config.commands {
+        let command = template.interpolate_value(command, context)?;
+
+        console.write_line(format!("Running {}", color::shell(&command)))?;
+        console.flush()?;
+
+        Command::new(&command)
+            .cwd(dest)
+            .exec_stream_output()
+            .await
+            .map_err(|error| AppError::PostGenerateFailed {
+                step: command.clone(),
+                error,
+            })?;
+    }
+
+    if !config.targets.is_empty() {
+        let mut locators = vec![];
+
+        for target in &config.targets {
+            locators.push(TargetLocator::parse(
+                &template.interpolate_value(target, context)?,
+            )?);
+        }
+
+        let step = locators
+            .iter()
+            .map(|locator| locator.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        // Pass through the exit code of the run, as tasks may fail without erroring
+        return run_target(&session, &RunArgs::default(), &locators)
+            .await
+            .map_err(|error| AppError::PostGenerateFailed { step, error }.into());
+    }
+
     Ok(None)
 }
+
+/// Add the destination as a project to `.moon/workspace.yml`, unless it's
+/// already matched by a glob or source. Returns the ID of the new project.
+fn add_project_to_workspace(session: &CliSession, dest: &Path) -> miette::Result<Option<Id>> {
+    let source = to_virtual_string(
+        dest.strip_prefix(&session.workspace_root)
+            .into_diagnostic()?,
+    )?;
+    let id = Id::clean(fs::file_name(dest))?;
+
+    let (globs, sources) = match &session.workspace_config.projects {
+        WorkspaceProjects::Both(cfg) => (cfg.globs.clone(), cfg.sources.clone()),
+        WorkspaceProjects::Globs(globs) => (globs.clone(), Default::default()),
+        WorkspaceProjects::Sources(sources) => (vec![], sources.clone()),
+    };
+
+    if sources
+        .values()
+        .any(|value| value.trim_start_matches("./").trim_end_matches('/') == source)
+    {
+        debug!(source, "Project already exists in workspace sources");
+
+        return Ok(None);
+    }
+
+    if !globs.is_empty() {
+        let globset = glob::GlobSet::new(&globs)?;
+
+        if globset.matches(&source)
+            || globset.matches(format!("{source}/moon.yml"))
+            || globset.matches(format!("{source}/moon.pkl"))
+        {
+            debug!(source, "Project already matched by workspace globs");
+
+            return Ok(None);
+        }
+    }
+
+    let config_path = session
+        .workspace_root
+        .join(CONFIG_DIRNAME)
+        .join("workspace.yml");
+
+    if !config_path.exists() {
+        return Err(miette::miette!(
+            "Projects can only be added to {}.",
+            color::file(format!("{CONFIG_DIRNAME}/workspace.yml"))
+        ));
+    }
+
+    debug!(id = id.as_str(), source, "Adding project to workspace");
+
+    // Edit in place to preserve comments and formatting
+    let content = fs::read_file(&config_path)?;
+    let config: yaml::YamlValue = yaml::parse(&content)?;
+
+    let content = match config.get("projects") {
+        Some(yaml::YamlValue::Mapping(_)) => {
+            add_workspace_project_sources(&content, [(id.as_str(), source.as_str())])?
+        }
+        _ => add_workspace_project_globs(&content, &[source])?,
+    };
+
+    fs::write_file(&config_path, content)?;
+
+    Ok(Some(id))
+}
+
+fn get_relative_path(session: &CliSession, path: &Path) -> String {
+    path.strip_prefix(&session.working_dir)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .to_string()
+}
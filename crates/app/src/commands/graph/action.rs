@@ -1,4 +1,5 @@
-use crate::commands::graph::utils::{action_graph_repr, respond_to_request, setup_server};
+use crate::commands::graph::explorer::GraphExplorer;
+use crate::commands::graph::utils::{action_graph_repr, setup_server};
 use crate::session::CliSession;
 use clap::Args;
 use moon_action_graph::RunRequirements;
@@ -16,11 +17,20 @@ pub struct ActionGraphArgs {
     #[arg(long, help = "Include dependents of the focused target(s)")]
     dependents: bool,
 
+    #[arg(long, help = "Print the graph in Cytoscape JSON format")]
+    cytoscape: bool,
+
     #[arg(long, help = "Print the graph in DOT format")]
     dot: bool,
 
+    #[arg(long, help = "Print the graph in GraphML format")]
+    graphml: bool,
+
     #[arg(long, help = "Print the graph in JSON format")]
     json: bool,
+
+    #[arg(long, help = "Print the graph as a Mermaid flowchart")]
+    mermaid: bool,
 }
 
 #[instrument]
@@ -50,13 +60,31 @@ pub async fn action_graph(session: CliSession, args: ActionGraphArgs) -> AppResu
 
     let action_graph = action_graph_builder.build();
 
+    if args.cytoscape {
+        println!("{}", action_graph.to_cytoscape()?);
+
+        return Ok(None);
+    }
+
     if args.dot {
         println!("{}", action_graph.to_dot());
 
         return Ok(None);
     }
 
-    let graph_info = action_graph_repr(&action_graph).await;
+    if args.graphml {
+        println!("{}", action_graph.to_graphml());
+
+        return Ok(None);
+    }
+
+    if args.mermaid {
+        println!("{}", action_graph.to_mermaid());
+
+        return Ok(None);
+    }
+
+    let graph_info = action_graph_repr(&action_graph, &workspace_graph).await?;
 
     if args.json {
         println!("{}", json::format(&graph_info, true)?);
@@ -64,6 +92,7 @@ pub async fn action_graph(session: CliSession, args: ActionGraphArgs) -> AppResu
         return Ok(None);
     }
 
+    let explorer = GraphExplorer::new("Action graph", graph_info, workspace_graph);
     let (server, mut tera) = setup_server().await?;
     let url = format!("http://{}", server.server_addr());
     let _ = open::that(&url);
@@ -71,7 +100,7 @@ pub async fn action_graph(session: CliSession, args: ActionGraphArgs) -> AppResu
     println!("Started server on {}", color::url(url));
 
     for req in server.incoming_requests() {
-        respond_to_request(req, &mut tera, &graph_info, "Action graph".to_owned())?;
+        explorer.respond(req, &mut tera)?;
     }
 
     Ok(None)
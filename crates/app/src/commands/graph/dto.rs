@@ -1,21 +1,72 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Hash, Eq, PartialEq, Serialize, Deserialize)]
 pub struct GraphNodeDto {
     pub id: usize,
     pub label: String,
+
+    // Metadata used for filtering and collapsing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layer: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stack: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    // Node IDs that were collapsed into this node
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<usize>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GraphEdgeReasonDto {
+    pub scope: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub via: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GraphEdgeDto {
     pub id: String,
     pub label: String,
     pub source: usize,
     pub target: usize,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<GraphEdgeReasonDto>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct GraphInfoDto {
     pub nodes: Vec<GraphNodeDto>,
     pub edges: Vec<GraphEdgeDto>,
 }
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GraphPathsDto {
+    pub paths: Vec<Vec<usize>>,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct GraphQueryDto {
+    pub nodes: Vec<usize>,
+}
@@ -0,0 +1,630 @@
+use super::dto::{GraphEdgeDto, GraphInfoDto, GraphNodeDto, GraphPathsDto, GraphQueryDto};
+use super::utils::{get_js_url, RenderContext, INDEX_HTML};
+use miette::IntoDiagnostic;
+use moon_query::{build_query, Condition, Criteria, Field};
+use moon_workspace_graph::WorkspaceGraph;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
+use starbase::AppResult;
+use starbase_utils::json;
+use std::collections::{BTreeMap, VecDeque};
+use std::io::Cursor;
+use tera::{Context, Tera};
+use tiny_http::{Header, Request, Response};
+
+// Finding all paths is exponential, so cap the results,
+// and the number of nodes visited while searching for them
+const MAX_PATHS: usize = 100;
+const MAX_VISITS: usize = 100_000;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CollapseBy {
+    Stack,
+    Tag,
+}
+
+impl CollapseBy {
+    fn from_param(value: &str) -> Option<Self> {
+        match value {
+            "stack" => Some(Self::Stack),
+            "tag" => Some(Self::Tag),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ErrorDto {
+    error: String,
+}
+
+/// Serves the visualizer, and an API for exploring the graph, which
+/// is backed by the in-memory workspace graph.
+pub struct GraphExplorer {
+    adjacency: FxHashMap<usize, Vec<usize>>,
+    graph: GraphInfoDto,
+    page_title: String,
+    workspace_graph: WorkspaceGraph,
+}
+
+impl GraphExplorer {
+    pub fn new(
+        page_title: impl AsRef<str>,
+        graph: GraphInfoDto,
+        workspace_graph: WorkspaceGraph,
+    ) -> Self {
+        Self {
+            adjacency: create_adjacency(&graph),
+            graph,
+            page_title: page_title.as_ref().to_owned(),
+            workspace_graph,
+        }
+    }
+
+    /// Collapse nodes that share the same stack, or the same first tag, into
+    /// a single node. Nodes without a value to group by are left as-is.
+    pub fn collapse(&self, by: CollapseBy) -> GraphInfoDto {
+        let mut groups: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+        let mut nodes = vec![];
+
+        for node in &self.graph.nodes {
+            let key = match by {
+                CollapseBy::Stack => node.stack.as_ref(),
+                CollapseBy::Tag => node.tags.first(),
+            };
+
+            match key {
+                Some(key) => groups.entry(key.to_owned()).or_default().push(node.id),
+                None => nodes.push(node.clone()),
+            };
+        }
+
+        let mut next_id = self
+            .graph
+            .nodes
+            .iter()
+            .map(|node| node.id + 1)
+            .max()
+            .unwrap_or_default();
+        let mut remapped = FxHashMap::default();
+        let prefix = match by {
+            CollapseBy::Stack => "stack",
+            CollapseBy::Tag => "tag",
+        };
+
+        for (key, members) in groups {
+            for member in &members {
+                remapped.insert(*member, next_id);
+            }
+
+            nodes.push(GraphNodeDto {
+                id: next_id,
+                label: format!("{prefix}:{key} ({})", members.len()),
+                stack: (by == CollapseBy::Stack).then(|| key.clone()),
+                tags: if by == CollapseBy::Tag {
+                    vec![key]
+                } else {
+                    vec![]
+                },
+                members,
+                ..Default::default()
+            });
+
+            next_id += 1;
+        }
+
+        let mut edges = vec![];
+        let mut seen = FxHashSet::default();
+
+        for edge in &self.graph.edges {
+            let source = remapped.get(&edge.source).copied().unwrap_or(edge.source);
+            let target = remapped.get(&edge.target).copied().unwrap_or(edge.target);
+
+            // Edges within a group are hidden
+            if source == target || !seen.insert((source, target)) {
+                continue;
+            }
+
+            let collapsed = source != edge.source || target != edge.target;
+
+            edges.push(GraphEdgeDto {
+                id: format!("{source} -> {target}"),
+                label: if collapsed {
+                    String::new()
+                } else {
+                    edge.label.clone()
+                },
+                source,
+                target,
+                reason: if collapsed { None } else { edge.reason.clone() },
+            });
+        }
+
+        GraphInfoDto { nodes, edges }
+    }
+
+    /// Find the shortest path, or all paths, between 2 nodes. Since edges point
+    /// from a dependent to its dependency, when no path exists from the first
+    /// node to the second, the reverse direction is attempted.
+    pub fn find_paths(
+        &self,
+        from: usize,
+        to: usize,
+        all: bool,
+        collapse: Option<CollapseBy>,
+    ) -> GraphPathsDto {
+        let collapsed_adjacency = collapse.map(|by| create_adjacency(&self.collapse(by)));
+        let adjacency = collapsed_adjacency.as_ref().unwrap_or(&self.adjacency);
+
+        let find = |start: usize, end: usize| {
+            if all {
+                find_all_paths(adjacency, start, end)
+            } else {
+                GraphPathsDto {
+                    paths: find_shortest_path(adjacency, start, end)
+                        .into_iter()
+                        .collect(),
+                    truncated: false,
+                }
+            }
+        };
+
+        let result = find(from, to);
+
+        if result.paths.is_empty() && !result.truncated && from != to {
+            return find(to, from);
+        }
+
+        result
+    }
+
+    /// Return the IDs of nodes whose project or task matches the MQL query.
+    /// For task based nodes, project fields match all tasks of the project,
+    /// unless the query also contains task fields.
+    pub fn query(&self, input: &str) -> miette::Result<GraphQueryDto> {
+        let query = build_query(input)?;
+        let has_task_nodes = self.graph.nodes.iter().any(|node| node.target.is_some());
+
+        let projects = self
+            .workspace_graph
+            .query_projects(&query)?
+            .into_iter()
+            .map(|project| project.id.to_string())
+            .collect::<FxHashSet<_>>();
+
+        let (tasks, by_task) = if has_task_nodes {
+            (
+                self.workspace_graph
+                    .query_tasks(&query)?
+                    .into_iter()
+                    .map(|task| task.target.to_string())
+                    .collect::<FxHashSet<_>>(),
+                has_task_fields(&query),
+            )
+        } else {
+            (FxHashSet::default(), false)
+        };
+
+        let mut nodes = self
+            .graph
+            .nodes
+            .iter()
+            .filter(|node| match (&node.target, &node.project) {
+                (Some(target), _) if by_task => tasks.contains(target),
+                (_, Some(project)) => projects.contains(project),
+                _ => false,
+            })
+            .map(|node| node.id)
+            .collect::<Vec<_>>();
+
+        nodes.sort();
+
+        Ok(GraphQueryDto { nodes })
+    }
+
+    pub fn respond(&self, req: Request, tera: &mut Tera) -> AppResult {
+        let (path, query) = req.url().split_once('?').unwrap_or((req.url(), ""));
+        let params = form_urlencoded::parse(query.as_bytes())
+            .into_owned()
+            .collect::<FxHashMap<_, _>>();
+        let get_index = |key: &str| params.get(key).and_then(|value| value.parse().ok());
+        let collapse = params
+            .get("collapse")
+            .and_then(|by| CollapseBy::from_param(by));
+
+        let response = match path {
+            "/graph-data" | "/api/graph" => match collapse {
+                Some(by) => create_json_response(&self.collapse(by), 200)?,
+                None => create_json_response(&self.graph, 200)?,
+            },
+            "/api/query" => match self.query(params.get("q").map(|q| q.as_str()).unwrap_or("")) {
+                Ok(result) => create_json_response(&result, 200)?,
+                Err(error) => create_json_response(
+                    &ErrorDto {
+                        error: error.to_string(),
+                    },
+                    400,
+                )?,
+            },
+            "/api/paths" => match (get_index("from"), get_index("to")) {
+                (Some(from), Some(to)) => create_json_response(
+                    &self.find_paths(
+                        from,
+                        to,
+                        params.get("all").is_some_and(|all| all == "true"),
+                        collapse,
+                    ),
+                    200,
+                )?,
+                _ => create_json_response(
+                    &ErrorDto {
+                        error: "Both `from` and `to` node IDs are required.".into(),
+                    },
+                    400,
+                )?,
+            },
+            _ => {
+                let context = RenderContext {
+                    page_title: self.page_title.clone(),
+                    graph_data: json::format(&self.graph, false)?,
+                    js_url: get_js_url(),
+                };
+                let info = tera
+                    .render_str(
+                        INDEX_HTML,
+                        &Context::from_serialize(context).into_diagnostic()?,
+                    )
+                    .unwrap();
+                let mut response = Response::from_data(info);
+                response.add_header(
+                    Header::from_bytes(&b"Content-Type"[..], &b"text/html"[..]).unwrap(),
+                );
+                response
+            }
+        };
+
+        req.respond(response).unwrap_or_default();
+
+        Ok(None)
+    }
+}
+
+fn create_adjacency(graph: &GraphInfoDto) -> FxHashMap<usize, Vec<usize>> {
+    let mut adjacency: FxHashMap<usize, Vec<usize>> = FxHashMap::default();
+
+    for edge in &graph.edges {
+        adjacency.entry(edge.source).or_default().push(edge.target);
+    }
+
+    adjacency
+}
+
+fn find_shortest_path(
+    adjacency: &FxHashMap<usize, Vec<usize>>,
+    from: usize,
+    to: usize,
+) -> Option<Vec<usize>> {
+    let mut parents = FxHashMap::default();
+    let mut queue = VecDeque::from([from]);
+    let mut visited = FxHashSet::from_iter([from]);
+
+    while let Some(current) = queue.pop_front() {
+        if current == to {
+            let mut path = vec![to];
+            let mut node = to;
+
+            while let Some(parent) = parents.get(&node) {
+                path.push(*parent);
+                node = *parent;
+            }
+
+            path.reverse();
+
+            return Some(path);
+        }
+
+        for next in adjacency.get(&current).into_iter().flatten() {
+            if visited.insert(*next) {
+                parents.insert(*next, current);
+                queue.push_back(*next);
+            }
+        }
+    }
+
+    None
+}
+
+// Nodes that can reach the target, found by walking the edges in reverse
+fn find_reachable_nodes(adjacency: &FxHashMap<usize, Vec<usize>>, to: usize) -> FxHashSet<usize> {
+    let mut reverse: FxHashMap<usize, Vec<usize>> = FxHashMap::default();
+
+    for (source, targets) in adjacency {
+        for target in targets {
+            reverse.entry(*target).or_default().push(*source);
+        }
+    }
+
+    let mut queue = VecDeque::from([to]);
+    let mut reachable = FxHashSet::from_iter([to]);
+
+    while let Some(current) = queue.pop_front() {
+        for prev in reverse.get(&current).into_iter().flatten() {
+            if reachable.insert(*prev) {
+                queue.push_back(*prev);
+            }
+        }
+    }
+
+    reachable
+}
+
+fn find_all_paths(
+    adjacency: &FxHashMap<usize, Vec<usize>>,
+    from: usize,
+    to: usize,
+) -> GraphPathsDto {
+    let mut result = GraphPathsDto::default();
+    let reachable = find_reachable_nodes(adjacency, to);
+
+    if !reachable.contains(&from) {
+        return result;
+    }
+
+    let mut path = vec![from];
+    let mut on_path = FxHashSet::from_iter([from]);
+    let mut stack = vec![adjacency.get(&from).map(|n| n.iter())];
+    let mut visits = 0;
+
+    if from == to {
+        result.paths.push(path);
+
+        return result;
+    }
+
+    while let Some(neighbors) = stack.last_mut() {
+        match neighbors.as_mut().and_then(|n| n.next()) {
+            Some(next) if *next == to => {
+                if result.paths.len() == MAX_PATHS {
+                    result.truncated = true;
+                    break;
+                }
+
+                let mut found = path.clone();
+                found.push(to);
+                result.paths.push(found);
+            }
+            Some(next) => {
+                // Only descend into nodes that can lead to the target
+                if reachable.contains(next) && on_path.insert(*next) {
+                    visits += 1;
+
+                    if visits > MAX_VISITS {
+                        result.truncated = true;
+                        break;
+                    }
+
+                    path.push(*next);
+                    stack.push(adjacency.get(next).map(|n| n.iter()));
+                }
+            }
+            None => {
+                stack.pop();
+
+                if let Some(last) = path.pop() {
+                    on_path.remove(&last);
+                }
+            }
+        };
+    }
+
+    result.paths.sort_by_key(|path| path.len());
+    result
+}
+
+fn create_json_response<T: Serialize>(
+    data: &T,
+    status: u16,
+) -> miette::Result<Response<Cursor<Vec<u8>>>> {
+    let mut response = Response::from_data(json::format(data, false)?).with_status_code(status);
+    response
+        .add_header(Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap());
+
+    Ok(response)
+}
+
+fn has_task_fields(criteria: &Criteria) -> bool {
+    criteria.conditions.iter().any(|condition| match condition {
+        Condition::Field { field, .. } => matches!(
+            field,
+            Field::Task(_) | Field::TaskPlatform(_) | Field::TaskToolchain(_) | Field::TaskType(_)
+        ),
+        Condition::Criteria { criteria } => has_task_fields(criteria),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_node(id: usize, stack: &str, tags: &[&str]) -> GraphNodeDto {
+        GraphNodeDto {
+            id,
+            label: format!("node{id}"),
+            project: Some(format!("node{id}")),
+            stack: Some(stack.into()),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn create_edge(source: usize, target: usize) -> GraphEdgeDto {
+        GraphEdgeDto {
+            id: format!("{source} -> {target}"),
+            label: "production".into(),
+            source,
+            target,
+            reason: None,
+        }
+    }
+
+    // 0 -> 1 -> 3
+    // 0 -> 2 -> 3
+    // 1 -> 2
+    fn create_explorer() -> GraphExplorer {
+        GraphExplorer::new(
+            "Test",
+            GraphInfoDto {
+                nodes: vec![
+                    create_node(0, "frontend", &["app"]),
+                    create_node(1, "frontend", &["lib"]),
+                    create_node(2, "backend", &["lib", "api"]),
+                    create_node(3, "backend", &[]),
+                ],
+                edges: vec![
+                    create_edge(0, 1),
+                    create_edge(0, 2),
+                    create_edge(1, 2),
+                    create_edge(1, 3),
+                    create_edge(2, 3),
+                ],
+            },
+            WorkspaceGraph::default(),
+        )
+    }
+
+    #[test]
+    fn finds_shortest_path() {
+        let explorer = create_explorer();
+
+        assert_eq!(
+            explorer.find_paths(0, 3, false, None).paths,
+            vec![vec![0, 1, 3]]
+        );
+    }
+
+    #[test]
+    fn finds_shortest_path_in_reverse() {
+        let explorer = create_explorer();
+
+        assert_eq!(
+            explorer.find_paths(3, 1, false, None).paths,
+            vec![vec![1, 3]]
+        );
+    }
+
+    #[test]
+    fn finds_all_paths() {
+        let explorer = create_explorer();
+        let result = explorer.find_paths(0, 3, true, None);
+
+        assert_eq!(
+            result.paths,
+            vec![vec![0, 1, 3], vec![0, 2, 3], vec![0, 1, 2, 3]]
+        );
+        assert!(!result.truncated);
+    }
+
+    #[test]
+    fn truncates_all_paths_in_large_graphs() {
+        // A chain of diamonds has 2^n paths between the ends
+        let mut nodes = vec![create_node(0, "frontend", &[])];
+        let mut edges = vec![];
+
+        for i in 0..20 {
+            let start = i * 3;
+
+            nodes.push(create_node(start + 1, "frontend", &[]));
+            nodes.push(create_node(start + 2, "frontend", &[]));
+            nodes.push(create_node(start + 3, "frontend", &[]));
+            edges.push(create_edge(start, start + 1));
+            edges.push(create_edge(start, start + 2));
+            edges.push(create_edge(start + 1, start + 3));
+            edges.push(create_edge(start + 2, start + 3));
+        }
+
+        // A dead end branch that can never reach the target
+        nodes.push(create_node(61, "frontend", &[]));
+        edges.push(create_edge(0, 61));
+
+        let explorer = GraphExplorer::new(
+            "Test",
+            GraphInfoDto { nodes, edges },
+            WorkspaceGraph::default(),
+        );
+        let result = explorer.find_paths(0, 60, true, None);
+
+        assert_eq!(result.paths.len(), MAX_PATHS);
+        assert!(result.truncated);
+        assert!(result.paths.iter().all(|path| !path.contains(&61)));
+    }
+
+    #[test]
+    fn returns_no_paths_when_disconnected() {
+        let mut explorer = create_explorer();
+        explorer.graph.nodes.push(create_node(4, "systems", &[]));
+
+        assert!(explorer.find_paths(0, 4, true, None).paths.is_empty());
+    }
+
+    #[test]
+    fn finds_paths_between_collapsed_nodes() {
+        let explorer = create_explorer();
+
+        // frontend (5) -> backend (4)
+        assert_eq!(
+            explorer
+                .find_paths(5, 4, false, Some(CollapseBy::Stack))
+                .paths,
+            vec![vec![5, 4]]
+        );
+    }
+
+    #[test]
+    fn collapses_by_stack() {
+        let explorer = create_explorer();
+        let graph = explorer.collapse(CollapseBy::Stack);
+
+        assert_eq!(
+            graph
+                .nodes
+                .iter()
+                .map(|node| (node.label.as_str(), node.members.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("stack:backend (2)", vec![2, 3]),
+                ("stack:frontend (2)", vec![0, 1])
+            ]
+        );
+        assert_eq!(
+            graph
+                .edges
+                .iter()
+                .map(|edge| (edge.source, edge.target))
+                .collect::<Vec<_>>(),
+            vec![(5, 4)]
+        );
+    }
+
+    #[test]
+    fn collapses_by_first_tag() {
+        let explorer = create_explorer();
+        let graph = explorer.collapse(CollapseBy::Tag);
+
+        assert_eq!(
+            graph
+                .nodes
+                .iter()
+                .map(|node| node.label.as_str())
+                .collect::<Vec<_>>(),
+            vec!["node3", "tag:app (1)", "tag:lib (2)"]
+        );
+        assert_eq!(
+            graph
+                .edges
+                .iter()
+                .map(|edge| (edge.source, edge.target))
+                .collect::<Vec<_>>(),
+            vec![(4, 5), (5, 3)]
+        );
+    }
+}
@@ -1,8 +1,11 @@
-use super::utils::{project_graph_repr, respond_to_request, setup_server};
+use super::explorer::GraphExplorer;
+use super::utils::{project_graph_repr, setup_server};
 use crate::session::CliSession;
 use clap::Args;
 use moon_common::Id;
-use moon_project_graph::{GraphToDot, GraphToJson};
+use moon_project_graph::{
+    GraphToCytoscape, GraphToDot, GraphToGraphMl, GraphToJson, GraphToMermaid,
+};
 use starbase::AppResult;
 use starbase_styles::color;
 use std::sync::Arc;
@@ -16,11 +19,20 @@ pub struct ProjectGraphArgs {
     #[arg(long, help = "Include direct dependents of the focused project")]
     dependents: bool,
 
+    #[arg(long, help = "Print the graph in Cytoscape JSON format")]
+    cytoscape: bool,
+
     #[arg(long, help = "Print the graph in DOT format")]
     dot: bool,
 
+    #[arg(long, help = "Print the graph in GraphML format")]
+    graphml: bool,
+
     #[arg(long, help = "Print the graph in JSON format")]
     json: bool,
+
+    #[arg(long, help = "Print the graph as a Mermaid flowchart")]
+    mermaid: bool,
 }
 
 #[instrument(skip_all)]
@@ -34,12 +46,30 @@ pub async fn project_graph(session: CliSession, args: ProjectGraphArgs) -> AppRe
     // Force expand all projects
     project_graph.get_all()?;
 
+    if args.cytoscape {
+        println!("{}", project_graph.to_cytoscape()?);
+
+        return Ok(None);
+    }
+
     if args.dot {
         println!("{}", project_graph.to_dot());
 
         return Ok(None);
     }
 
+    if args.graphml {
+        println!("{}", project_graph.to_graphml());
+
+        return Ok(None);
+    }
+
+    if args.mermaid {
+        println!("{}", project_graph.to_mermaid());
+
+        return Ok(None);
+    }
+
     if args.json {
         println!("{}", project_graph.to_json()?);
 
@@ -47,6 +77,11 @@ pub async fn project_graph(session: CliSession, args: ProjectGraphArgs) -> AppRe
     }
 
     let graph_info = project_graph_repr(&project_graph).await;
+    let explorer = GraphExplorer::new(
+        "Project graph",
+        graph_info,
+        session.get_workspace_graph().await?,
+    );
     let (server, mut tera) = setup_server().await?;
     let url = format!("http://{}", server.server_addr());
     let _ = open::that(&url);
@@ -54,7 +89,7 @@ pub async fn project_graph(session: CliSession, args: ProjectGraphArgs) -> AppRe
     println!("Started server on {}", color::url(url));
 
     for req in server.incoming_requests() {
-        respond_to_request(req, &mut tera, &graph_info, "Project graph".to_owned())?;
+        explorer.respond(req, &mut tera)?;
     }
 
     Ok(None)
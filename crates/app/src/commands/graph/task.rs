@@ -1,8 +1,9 @@
-use super::utils::{respond_to_request, setup_server, task_graph_repr};
+use super::explorer::GraphExplorer;
+use super::utils::{setup_server, task_graph_repr};
 use crate::session::CliSession;
 use clap::Args;
 use moon_task::Target;
-use moon_task_graph::{GraphToDot, GraphToJson};
+use moon_task_graph::{GraphToCytoscape, GraphToDot, GraphToGraphMl, GraphToJson, GraphToMermaid};
 use starbase::AppResult;
 use starbase_styles::color;
 use std::sync::Arc;
@@ -16,11 +17,20 @@ pub struct TaskGraphArgs {
     #[arg(long, help = "Include direct dependents of the focused target")]
     dependents: bool,
 
+    #[arg(long, help = "Print the graph in Cytoscape JSON format")]
+    cytoscape: bool,
+
     #[arg(long, help = "Print the graph in DOT format")]
     dot: bool,
 
+    #[arg(long, help = "Print the graph in GraphML format")]
+    graphml: bool,
+
     #[arg(long, help = "Print the graph in JSON format")]
     json: bool,
+
+    #[arg(long, help = "Print the graph as a Mermaid flowchart")]
+    mermaid: bool,
 }
 
 #[instrument(skip_all)]
@@ -34,19 +44,39 @@ pub async fn task_graph(session: CliSession, args: TaskGraphArgs) -> AppResult {
     // Force expand all tasks
     task_graph.get_all()?;
 
+    if args.cytoscape {
+        println!("{}", task_graph.to_cytoscape()?);
+
+        return Ok(None);
+    }
+
     if args.dot {
         println!("{}", task_graph.to_dot());
 
         return Ok(None);
     }
 
+    if args.graphml {
+        println!("{}", task_graph.to_graphml());
+
+        return Ok(None);
+    }
+
+    if args.mermaid {
+        println!("{}", task_graph.to_mermaid());
+
+        return Ok(None);
+    }
+
     if args.json {
         println!("{}", task_graph.to_json()?);
 
         return Ok(None);
     }
 
-    let graph_info = task_graph_repr(&task_graph).await;
+    let workspace_graph = session.get_workspace_graph().await?;
+    let graph_info = task_graph_repr(&task_graph, &workspace_graph).await?;
+    let explorer = GraphExplorer::new("Task graph", graph_info, workspace_graph);
     let (server, mut tera) = setup_server().await?;
     let url = format!("http://{}", server.server_addr());
     let _ = open::that(&url);
@@ -54,7 +84,7 @@ pub async fn task_graph(session: CliSession, args: TaskGraphArgs) -> AppResult {
     println!("Started server on {}", color::url(url));
 
     for req in server.incoming_requests() {
-        respond_to_request(req, &mut tera, &graph_info, "Task graph".to_owned())?;
+        explorer.respond(req, &mut tera)?;
     }
 
     Ok(None)
@@ -1,19 +1,18 @@
-use super::dto::{GraphEdgeDto, GraphInfoDto, GraphNodeDto};
-use miette::IntoDiagnostic;
+use super::dto::{GraphEdgeDto, GraphEdgeReasonDto, GraphInfoDto, GraphNodeDto};
+use moon_action::ActionNode;
 use moon_action_graph::ActionGraph;
-use moon_project_graph::{GraphConversions, ProjectGraph};
+use moon_project_graph::{GraphConversions, GraphData, Project, ProjectGraph};
 use moon_task_graph::TaskGraph;
+use moon_workspace_graph::WorkspaceGraph;
 use petgraph::{graph::NodeIndex, Graph};
 use rustc_hash::FxHashMap;
 use serde::Serialize;
-use starbase::AppResult;
-use starbase_utils::json;
 use std::env;
 use std::fmt::Display;
-use tera::{Context, Tera};
-use tiny_http::{Header, Request, Response, Server};
+use tera::Tera;
+use tiny_http::Server;
 
-const INDEX_HTML: &str = include_str!("graph.html.tera");
+pub const INDEX_HTML: &str = include_str!("graph.html.tera");
 
 #[derive(Debug, Serialize)]
 pub struct RenderContext {
@@ -51,6 +50,7 @@ pub fn extract_nodes_and_edges_from_graph<T: Display>(
             source: e.source().index(),
             target: e.target().index(),
             id: format!("{} -> {}", e.source().index(), e.target().index()),
+            reason: None,
         })
         .collect::<Vec<_>>();
 
@@ -60,6 +60,7 @@ pub fn extract_nodes_and_edges_from_graph<T: Display>(
             .node_weight(ni)
             .expect("Unable to get node weight")
             .clone(),
+        ..Default::default()
     };
 
     for edge in graph.raw_edges().iter() {
@@ -77,62 +78,128 @@ pub fn extract_nodes_and_edges_from_graph<T: Display>(
     GraphInfoDto { edges, nodes }
 }
 
+fn apply_project_metadata(node: &mut GraphNodeDto, project: &Project) {
+    node.project = Some(project.id.to_string());
+    node.language = Some(project.language.to_string());
+    node.layer = Some(project.type_of.to_string());
+    node.stack = Some(project.stack.to_string());
+    node.owner = project.config.owners.default_owner.clone();
+    node.tags = project
+        .config
+        .tags
+        .iter()
+        .map(|tag| tag.to_string())
+        .collect();
+}
+
 /// Get a serialized representation of the project graph.
 pub async fn project_graph_repr(project_graph: &ProjectGraph) -> GraphInfoDto {
     let labeled_graph = project_graph.to_labeled_graph();
-    extract_nodes_and_edges_from_graph(&labeled_graph, true)
+    let mut graph_info = extract_nodes_and_edges_from_graph(&labeled_graph, true);
+    let graph = project_graph.get_graph();
+    let get_project = |index: usize| {
+        graph
+            .node_weight(NodeIndex::new(index))
+            .expect("Unable to get node weight")
+    };
+
+    for node in &mut graph_info.nodes {
+        apply_project_metadata(node, get_project(node.id));
+    }
+
+    // Explain why the edge exists, based on the dependency config
+    for edge in &mut graph_info.edges {
+        let dep_id = &get_project(edge.target).id;
+
+        edge.reason = get_project(edge.source)
+            .dependencies
+            .iter()
+            .find(|dep| &dep.id == dep_id)
+            .map(|dep| GraphEdgeReasonDto {
+                scope: dep.scope.to_string(),
+                source: Some(dep.source.to_string()),
+                via: dep.via.clone(),
+            });
+    }
+
+    graph_info
 }
 
 /// Get a serialized representation of the task graph.
-pub async fn task_graph_repr(task_graph: &TaskGraph) -> GraphInfoDto {
+pub async fn task_graph_repr(
+    task_graph: &TaskGraph,
+    workspace_graph: &WorkspaceGraph,
+) -> miette::Result<GraphInfoDto> {
     let labeled_graph = task_graph.to_labeled_graph();
-    extract_nodes_and_edges_from_graph(&labeled_graph, true)
+    let mut graph_info = extract_nodes_and_edges_from_graph(&labeled_graph, true);
+    let graph = task_graph.get_graph();
+    let get_task = |index: usize| {
+        graph
+            .node_weight(NodeIndex::new(index))
+            .expect("Unable to get node weight")
+    };
+
+    for node in &mut graph_info.nodes {
+        let task = get_task(node.id);
+
+        if let Some(project_id) = task.target.get_project_id() {
+            let project = workspace_graph.get_project(project_id)?;
+
+            apply_project_metadata(node, &project);
+        }
+
+        node.target = Some(task.target.to_string());
+    }
+
+    // Explain why the edge exists, based on the configured task deps,
+    // which may have been declared with a scope like `^:build`
+    for edge in &mut graph_info.edges {
+        let dep_target = &get_task(edge.target).target;
+
+        edge.reason = Some(GraphEdgeReasonDto {
+            scope: edge.label.clone(),
+            source: None,
+            via: get_task(edge.source)
+                .deps
+                .iter()
+                .find(|dep| &dep.target == dep_target || dep.target.task_id == dep_target.task_id)
+                .map(|dep| dep.target.to_string()),
+        });
+    }
+
+    Ok(graph_info)
 }
 
 /// Get a serialized representation of the dependency graph.
-pub async fn action_graph_repr(action_graph: &ActionGraph) -> GraphInfoDto {
+pub async fn action_graph_repr(
+    action_graph: &ActionGraph,
+    workspace_graph: &WorkspaceGraph,
+) -> miette::Result<GraphInfoDto> {
     let labeled_graph = action_graph.labeled_graph();
-    extract_nodes_and_edges_from_graph(&labeled_graph, false)
-}
-
-pub fn respond_to_request(
-    req: Request,
-    tera: &mut Tera,
-    graph: &GraphInfoDto,
-    page_title: String,
-) -> AppResult {
-    let response = match req.url() {
-        "/graph-data" => {
-            let mut response = Response::from_data(json::format(graph, false)?);
-            response.add_header(
-                Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..]).unwrap(),
-            );
-            response
+    let mut graph_info = extract_nodes_and_edges_from_graph(&labeled_graph, false);
+
+    for node in &mut graph_info.nodes {
+        let (project_id, target) = match action_graph.get_node_from_index(&NodeIndex::new(node.id))
+        {
+            Some(ActionNode::RunTask(inner)) => {
+                (inner.target.get_project_id(), Some(&inner.target))
+            }
+            Some(ActionNode::InstallProjectDeps(inner) | ActionNode::SyncProject(inner)) => {
+                (Some(&inner.project), None)
+            }
+            _ => (None, None),
+        };
+
+        if let Some(project_id) = project_id {
+            let project = workspace_graph.get_project(project_id)?;
+
+            apply_project_metadata(node, &project);
         }
-        _ => {
-            let graph_data = json::format(graph, false)?;
-            let js_url = get_js_url();
-            let context = RenderContext {
-                page_title,
-                graph_data,
-                js_url,
-            };
-            let info = tera
-                .render_str(
-                    INDEX_HTML,
-                    &Context::from_serialize(context).into_diagnostic()?,
-                )
-                .unwrap();
-            let mut response = Response::from_data(info);
-            response
-                .add_header(Header::from_bytes(&b"Content-Type"[..], &b"text/html"[..]).unwrap());
-            response
-        }
-    };
 
-    req.respond(response).unwrap_or_default();
+        node.target = target.map(|target| target.to_string());
+    }
 
-    Ok(None)
+    Ok(graph_info)
 }
 
 // Use the local version of the JS file when in development mode otherwise the CDN URL.
@@ -0,0 +1,112 @@
+use crate::session::CliSession;
+use clap::Args;
+use moon_common::Id;
+use moon_project_imports::ImportsVerifier;
+use starbase::AppResult;
+use starbase_styles::color;
+use tracing::{instrument, warn};
+
+#[derive(Args, Clone, Debug)]
+pub struct ImportsArgs {
+    #[arg(help = "List of project IDs to verify, otherwise verifies all projects")]
+    ids: Vec<Id>,
+
+    #[arg(
+        long,
+        help = "Update dependsOn to add undeclared and remove unused dependencies"
+    )]
+    fix: bool,
+}
+
+#[instrument(skip_all)]
+pub async fn imports(session: CliSession, args: ImportsArgs) -> AppResult {
+    let workspace_graph = session.get_workspace_graph().await?;
+    let all_projects = workspace_graph.get_projects()?;
+    let verifier = ImportsVerifier::new(&all_projects).await?;
+
+    let projects = if args.ids.is_empty() {
+        all_projects
+    } else {
+        args.ids
+            .iter()
+            .map(|id| workspace_graph.get_project(id))
+            .collect::<miette::Result<Vec<_>>>()?
+    };
+
+    let config_names = session.config_loader.get_project_file_names();
+    let out = session.console.stdout();
+    let mut failed = 0;
+
+    for project in projects {
+        let report = verifier.verify(&project)?;
+
+        if report.is_valid() {
+            continue;
+        }
+
+        out.print_entry_header(&project.id)?;
+
+        for (dep_id, files) in &report.undeclared {
+            out.write_line(format!(
+                "{} {} is imported but not declared as a dependency",
+                color::failure("✕"),
+                color::id(dep_id)
+            ))?;
+
+            for file in files {
+                out.write_line(format!("  {} {}", color::muted("-"), color::file(file)))?;
+            }
+        }
+
+        for dep_id in &report.unused {
+            out.write_line(format!(
+                "{} {} is declared as a dependency but never imported",
+                color::failure("✕"),
+                color::id(dep_id)
+            ))?;
+        }
+
+        if !args.fix {
+            failed += 1;
+            continue;
+        }
+
+        // Only YAML configs can be updated automatically
+        let config_path = project.root.join(&config_names[0]);
+
+        if !config_path.exists()
+            && config_names
+                .iter()
+                .skip(1)
+                .any(|name| project.root.join(name).exists())
+        {
+            warn!(
+                project_id = project.id.as_str(),
+                "Unable to fix dependencies, as the project is not configured with {}",
+                color::file(&config_names[0])
+            );
+
+            failed += 1;
+            continue;
+        }
+
+        verifier.fix(&config_path, &report)?;
+
+        out.write_line(format!(
+            "{} Updated dependsOn in {}",
+            color::success("✓"),
+            color::rel_path(project.source.join(&config_names[0]))
+        ))?;
+    }
+
+    if failed == 0 {
+        out.write_line(format!(
+            "{} Source imports match declared project dependencies",
+            color::success("✓")
+        ))?;
+    }
+
+    out.flush()?;
+
+    Ok(if failed > 0 { Some(1) } else { None })
+}
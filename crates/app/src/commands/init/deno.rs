@@ -0,0 +1,147 @@
+use super::prompts::{fully_qualify_version, prompt_version};
+use super::InitOptions;
+use dialoguer::theme::ColorfulTheme;
+use miette::IntoDiagnostic;
+use moon_config::load_toolchain_deno_config_template;
+use moon_console::Console;
+use moon_deno_lang::{DenoJson, DenoJsonLock};
+use moon_lang::{is_using_dependency_manager, is_using_version_manager};
+use starbase_styles::color;
+use starbase_utils::fs;
+use std::path::Path;
+use tera::{Context, Tera};
+use tracing::instrument;
+
+pub fn render_template(context: Context) -> miette::Result<String> {
+    Tera::one_off(load_toolchain_deno_config_template(), &context, false).into_diagnostic()
+}
+
+/// Detect the Deno version from local configuration files,
+/// otherwise fallback to the configuration default.
+fn detect_deno_version(dest_dir: &Path) -> miette::Result<String> {
+    Ok(if is_using_version_manager(dest_dir, ".dvmrc") {
+        fully_qualify_version(
+            fs::read_file(dest_dir.join(".dvmrc"))?
+                .trim()
+                .trim_start_matches('v'),
+        )
+    } else {
+        String::new()
+    })
+}
+
+/// Detect whether a lockfile is used, either by the file existing,
+/// or the `lock` field in `deno.json` being enabled.
+fn detect_lockfile(dest_dir: &Path, deno_json: Option<&DenoJson>) -> bool {
+    if is_using_dependency_manager(dest_dir, "deno.lock") {
+        return true;
+    }
+
+    deno_json
+        .and_then(|json| json.lock.as_ref())
+        .is_some_and(|lock| !matches!(lock, DenoJsonLock::Enabled(false)))
+}
+
+/// Detect the file that dependencies are managed in. Deno 2 manages
+/// dependencies in `deno.json`, while Deno 1 used a `deps.ts` file.
+fn detect_deps_file(dest_dir: &Path, deno_json: Option<&DenoJson>) -> String {
+    if deno_json.is_some() && !dest_dir.join("deps.ts").exists() {
+        "deno.json".into()
+    } else {
+        String::new()
+    }
+}
+
+/// Detect project globs from the `workspace` members in `deno.json`.
+pub fn detect_workspace_members(dest_dir: &Path) -> miette::Result<Vec<String>> {
+    Ok(DenoJson::read(dest_dir)?
+        .and_then(|json| json.workspace)
+        .map(|workspace| workspace.get_members().to_vec())
+        .unwrap_or_default())
+}
+
+#[instrument(skip_all)]
+pub async fn init_deno(
+    dest_dir: &Path,
+    options: &InitOptions,
+    theme: &ColorfulTheme,
+    console: &Console,
+) -> miette::Result<String> {
+    if !options.yes {
+        console.out.print_header("Deno")?;
+
+        console.out.write_raw(|buffer| {
+            buffer.extend_from_slice(
+                format!(
+                    "Toolchain: {}\n",
+                    color::url("https://moonrepo.dev/docs/concepts/toolchain")
+                )
+                .as_bytes(),
+            );
+            buffer.extend_from_slice(
+                format!(
+                    "Handbook: {}\n",
+                    color::url("https://moonrepo.dev/docs/guides/javascript/deno-handbook")
+                )
+                .as_bytes(),
+            );
+            buffer.extend_from_slice(
+                format!(
+                    "Config: {}\n\n",
+                    color::url("https://moonrepo.dev/docs/config/toolchain#deno")
+                )
+                .as_bytes(),
+            );
+        })?;
+
+        console.out.flush()?;
+    }
+
+    let deno_json = DenoJson::read(dest_dir)?;
+    let deno_version = prompt_version("Deno", options, theme, || detect_deno_version(dest_dir))?;
+
+    let mut context = Context::new();
+    context.insert("deno_version", &deno_version);
+    context.insert("deps_file", &detect_deps_file(dest_dir, deno_json.as_ref()));
+    context.insert("lockfile", &detect_lockfile(dest_dir, deno_json.as_ref()));
+    context.insert("minimal", &options.minimal);
+
+    render_template(context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starbase_sandbox::assert_snapshot;
+
+    fn create_context() -> Context {
+        let mut context = Context::new();
+        context.insert("deno_version", &"2.1.0");
+        context.insert("deps_file", &"");
+        context.insert("lockfile", &false);
+        context.insert("minimal", &false);
+        context
+    }
+
+    #[test]
+    fn renders_default() {
+        assert_snapshot!(render_template(create_context()).unwrap());
+    }
+
+    #[test]
+    fn renders_minimal() {
+        let mut context = create_context();
+        context.insert("minimal", &true);
+
+        assert_snapshot!(render_template(context).unwrap());
+    }
+
+    #[test]
+    fn renders_deps_file_and_lockfile() {
+        let mut context = create_context();
+        context.insert("deps_file", &"deno.json");
+        context.insert("lockfile", &true);
+
+        assert_snapshot!(render_template(context).unwrap());
+    }
+}
@@ -1,43 +1,72 @@
 mod bun;
+mod deno;
 mod node;
+mod plugin;
 mod prompts;
+mod python;
 mod rust;
 mod typescript;
 
-use crate::helpers::create_theme;
+use crate::helpers::{add_workspace_project_globs, create_theme};
 use crate::session::CliSession;
 use bun::init_bun;
-use clap::{Args, ValueEnum};
+use clap::Args;
+use deno::init_deno;
 use dialoguer::theme::ColorfulTheme;
 use dialoguer::Confirm;
 use miette::IntoDiagnostic;
 use moon_common::consts::CONFIG_DIRNAME;
-use moon_common::is_test_env;
-use moon_config::{load_toolchain_config_template, load_workspace_config_template};
+use moon_common::{is_test_env, Id, IdError};
+use moon_config::{
+    load_toolchain_config_template, load_workspace_config_template, PartialWorkspaceConfig,
+    PartialWorkspaceProjects,
+};
 use moon_vcs::{Git, Vcs};
 use node::init_node;
+use plugin::init_plugin;
+use python::init_python;
 use rust::init_rust;
 use starbase::AppResult;
 use starbase_styles::color;
-use starbase_utils::fs;
+use starbase_utils::{fs, yaml};
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use tera::{Context, Tera};
-use tracing::instrument;
+use tracing::{debug, instrument};
 use typescript::init_typescript;
 
-#[derive(ValueEnum, Clone, Debug)]
-#[value(rename_all = "lowercase")]
+#[derive(Clone, Debug)]
 pub enum InitTool {
     Bun,
+    Deno,
     Node,
+    Python,
     Rust,
     TypeScript,
+    /// A plugin based toolchain, configured in `.moon/toolchain.yml`.
+    Plugin(Id),
+}
+
+impl FromStr for InitTool {
+    type Err = IdError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value.to_lowercase().as_str() {
+            "bun" => Self::Bun,
+            "deno" => Self::Deno,
+            "node" => Self::Node,
+            "python" => Self::Python,
+            "rust" => Self::Rust,
+            "typescript" => Self::TypeScript,
+            _ => Self::Plugin(Id::new(value)?),
+        })
+    }
 }
 
 #[derive(Args, Clone, Debug)]
 pub struct InitArgs {
-    #[arg(value_enum, help = "Specific tool to initialize")]
+    #[arg(help = "Specific toolchain to initialize, either built-in or a configured plugin ID")]
     tool: Option<InitTool>,
 
     #[arg(
@@ -85,6 +114,71 @@ fn detect_vcs_provider(repo_root: PathBuf) -> String {
     }
 }
 
+fn normalize_workspace_members(members: Vec<String>) -> Vec<String> {
+    let mut globs = vec![];
+
+    for member in members {
+        let glob = member
+            .trim_start_matches("./")
+            .trim_end_matches('/')
+            .to_owned();
+
+        if !glob.is_empty() && !glob.starts_with("..") && !globs.contains(&glob) {
+            globs.push(glob);
+        }
+    }
+
+    globs
+}
+
+/// Infer project globs from an existing workspace layout,
+/// like the members of a uv or Deno workspace.
+fn infer_project_globs(dest_dir: &Path, tool: Option<&InitTool>) -> miette::Result<Vec<String>> {
+    let mut members = vec![];
+
+    if matches!(tool, None | Some(InitTool::Python)) {
+        members.extend(python::detect_workspace_members(dest_dir)?);
+    }
+
+    if matches!(tool, None | Some(InitTool::Deno)) {
+        members.extend(deno::detect_workspace_members(dest_dir)?);
+    }
+
+    Ok(normalize_workspace_members(members))
+}
+
+/// Add project globs to an existing `.moon/workspace.yml`,
+/// and return the globs that were missing.
+fn add_project_globs(config_path: &Path, globs: Vec<String>) -> miette::Result<Vec<String>> {
+    if globs.is_empty() || !config_path.exists() {
+        return Ok(vec![]);
+    }
+
+    let config: PartialWorkspaceConfig = yaml::read_file(config_path)?;
+    let existing_globs = match config.projects {
+        Some(PartialWorkspaceProjects::Both(both)) => both.globs.unwrap_or_default(),
+        Some(PartialWorkspaceProjects::Globs(globs)) => globs,
+        _ => vec![],
+    };
+
+    let missing_globs = globs
+        .into_iter()
+        .filter(|glob| !existing_globs.contains(glob))
+        .collect::<Vec<_>>();
+
+    if missing_globs.is_empty() {
+        return Ok(missing_globs);
+    }
+
+    // Edit in place to preserve comments and formatting
+    fs::write_file(
+        config_path,
+        add_workspace_project_globs(&fs::read_file(config_path)?, &missing_globs)?,
+    )?;
+
+    Ok(missing_globs)
+}
+
 pub struct InitOptions {
     pub force: bool,
     pub minimal: bool,
@@ -144,21 +238,33 @@ pub async fn init_tool(
 
     let tool_config = match tool {
         InitTool::Bun => init_bun(dest_dir, options, theme, console).await?,
+        InitTool::Deno => init_deno(dest_dir, options, theme, console).await?,
         InitTool::Node => init_node(dest_dir, options, theme, console).await?,
+        InitTool::Python => init_python(dest_dir, options, theme, console).await?,
         InitTool::Rust => init_rust(dest_dir, options, theme, console).await?,
         InitTool::TypeScript => init_typescript(dest_dir, options, theme, console).await?,
+        InitTool::Plugin(id) => {
+            // Plugins update their existing config block instead of appending
+            if !init_plugin(dest_dir, id, options, theme, session).await? {
+                return Ok(None);
+            }
+
+            String::new()
+        }
     };
 
     let toolchain_config_path = &session.config_loader.get_toolchain_files(dest_dir)[0];
 
-    if !toolchain_config_path.exists() {
-        fs::write_file(
-            toolchain_config_path,
-            render_toolchain_template(&Context::new())?.trim(),
-        )?;
-    }
+    if !tool_config.is_empty() {
+        if !toolchain_config_path.exists() {
+            fs::write_file(
+                toolchain_config_path,
+                render_toolchain_template(&Context::new())?.trim(),
+            )?;
+        }
 
-    fs::append_file(toolchain_config_path, format!("\n\n{}", tool_config.trim()))?;
+        fs::append_file(toolchain_config_path, format!("\n\n{}", tool_config.trim()))?;
+    }
 
     console.out.write_newline()?;
 
@@ -166,6 +272,25 @@ pub async fn init_tool(
         .out
         .write_line("Toolchain config has successfully been updated")?;
 
+    // Register projects from an existing workspace layout
+    let added_globs = add_project_globs(
+        &session.config_loader.get_workspace_files(dest_dir)[0],
+        infer_project_globs(dest_dir, Some(tool))?,
+    )?;
+
+    if !added_globs.is_empty() {
+        debug!(globs = ?added_globs, "Adding inferred project globs");
+
+        console.out.write_line(format!(
+            "Workspace config has been updated with project globs: {}",
+            added_globs
+                .iter()
+                .map(color::file)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))?;
+    }
+
     Ok(None)
 }
 
@@ -202,6 +327,13 @@ pub async fn init(session: CliSession, args: InitArgs) -> AppResult {
     let git = Git::load(&dest_dir, "master", &[])?;
 
     let mut context = create_default_context();
+
+    let project_globs = infer_project_globs(&dest_dir, None)?;
+
+    if !project_globs.is_empty() {
+        context.insert("project_globs", &project_globs);
+    }
+
     context.insert("vcs_manager", "git");
     context.insert(
         "vcs_provider",
@@ -289,6 +421,19 @@ mod tests {
         assert_snapshot!(render_workspace_template(&context).unwrap());
     }
 
+    #[test]
+    fn normalizes_workspace_members() {
+        assert_eq!(
+            normalize_workspace_members(vec![
+                "./packages/*".into(),
+                "apps/web/".into(),
+                "packages/*".into(),
+                "../outside".into(),
+            ]),
+            vec!["packages/*", "apps/web"]
+        );
+    }
+
     #[test]
     fn renders_git_vcs() {
         let mut context = create_default_context();
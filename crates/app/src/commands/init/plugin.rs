@@ -0,0 +1,346 @@
+use super::InitOptions;
+use crate::session::CliSession;
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::{Confirm, Input, Select};
+use miette::{miette, IntoDiagnostic};
+use moon_common::Id;
+use moon_pdk_api::{PromptType, SettingPrompt};
+use moon_plugin::{PluginHostData, PluginId};
+use moon_toolchain_plugin::ToolchainRegistry;
+use moon_workspace_graph::WorkspaceGraph;
+use moon_yaml_editor::YamlEditor;
+use serde::Deserialize;
+use starbase_styles::color;
+use starbase_utils::fs;
+use starbase_utils::json::JsonValue;
+use starbase_utils::yaml::YamlValue;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+use tracing::instrument;
+
+fn get_default_answer(prompt: &SettingPrompt) -> JsonValue {
+    match &prompt.type_of {
+        PromptType::Confirm { default } => JsonValue::Bool(*default),
+        PromptType::Input { default } => JsonValue::String(default.to_owned()),
+        PromptType::Select {
+            default_index,
+            options,
+        } => options
+            .get(*default_index)
+            .map(|option| JsonValue::String(option.to_owned()))
+            .unwrap_or(JsonValue::Null),
+    }
+}
+
+fn render_prompt(prompt: &SettingPrompt, theme: &ColorfulTheme) -> miette::Result<JsonValue> {
+    let question = match &prompt.description {
+        Some(description) => format!("{} {}", prompt.question, color::muted(description)),
+        None => prompt.question.clone(),
+    };
+
+    Ok(match &prompt.type_of {
+        PromptType::Confirm { default } => JsonValue::Bool(
+            Confirm::with_theme(theme)
+                .with_prompt(question)
+                .default(*default)
+                .interact()
+                .into_diagnostic()?,
+        ),
+        PromptType::Input { default } => JsonValue::String(
+            Input::<String>::with_theme(theme)
+                .with_prompt(question)
+                .default(default.to_owned())
+                .interact_text()
+                .into_diagnostic()?,
+        ),
+        PromptType::Select {
+            default_index,
+            options,
+        } => {
+            let index = Select::with_theme(theme)
+                .with_prompt(question)
+                .items(options)
+                .default(*default_index)
+                .interact_opt()
+                .into_diagnostic()?
+                .unwrap_or(*default_index);
+
+            options
+                .get(index)
+                .map(|option| JsonValue::String(option.to_owned()))
+                .unwrap_or(JsonValue::Null)
+        }
+    })
+}
+
+/// Write the settings into the toolchain's block within `.moon/toolchain.yml`.
+/// The block may not exist when the toolchain was inherited through `extends`.
+/// The file is edited line by line, so that formatting and comments are preserved.
+fn write_settings(
+    config_path: &Path,
+    id: &Id,
+    settings: BTreeMap<String, JsonValue>,
+) -> miette::Result<()> {
+    let content = fs::read_file(config_path)?;
+
+    let Some(content) = update_settings(&content, id, settings)? else {
+        return Err(miette!(
+            code = "moon::init",
+            "Unable to write settings for toolchain {} into {}, expected a mapping.",
+            color::id(id),
+            color::path(config_path)
+        ));
+    };
+
+    fs::write_file(config_path, content)?;
+
+    Ok(())
+}
+
+/// Update the settings within the toolchain's block of the provided content,
+/// or return none if the block is not a mapping.
+fn update_settings(
+    content: &str,
+    id: &Id,
+    settings: BTreeMap<String, JsonValue>,
+) -> miette::Result<Option<String>> {
+    let mut editor = YamlEditor::new(content);
+
+    let index = match editor.find_key(None, id.as_str()) {
+        Some(index) => {
+            if !matches!(
+                editor.parse_value(index)?,
+                YamlValue::Mapping(_) | YamlValue::Null
+            ) {
+                return Ok(None);
+            }
+
+            // Inline values (flow mappings) are converted to a block
+            editor.expand_value(index)?;
+            index
+        }
+        None => editor.insert_key(None, id.as_str()),
+    };
+
+    for (key, value) in settings {
+        editor.set_entry(
+            Some(index),
+            &key,
+            YamlValue::deserialize(value).into_diagnostic()?,
+        )?;
+    }
+
+    Ok(Some(editor.into_content()))
+}
+
+/// Validate prompts provided by the plugin before rendering them.
+fn validate_prompts(id: &Id, prompts: &[SettingPrompt]) -> miette::Result<()> {
+    for prompt in prompts {
+        if let PromptType::Select {
+            default_index,
+            options,
+        } = &prompt.type_of
+        {
+            if *default_index >= options.len() {
+                return Err(miette!(
+                    code = "moon::init",
+                    "Toolchain {} provided an invalid prompt for setting {}, the default index {} is out of bounds for {} options.",
+                    color::id(id),
+                    color::property(&prompt.setting),
+                    default_index,
+                    options.len(),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn init_plugin(
+    dest_dir: &Path,
+    id: &Id,
+    options: &InitOptions,
+    theme: &ColorfulTheme,
+    session: &CliSession,
+) -> miette::Result<bool> {
+    let console = &session.console;
+    let toolchain_config = session.config_loader.load_toolchain_config(
+        dest_dir,
+        session
+            .proto_env
+            .load_config_manager()?
+            .get_local_config(dest_dir)?,
+    )?;
+
+    let Some(plugin_config) = toolchain_config.toolchains.get(id) else {
+        return Err(miette!(
+            code = "moon::init",
+            "Unknown toolchain {}. Plugin based toolchains must be configured in {} before they can be initialized.",
+            color::id(id),
+            color::file(".moon/toolchain.yml"),
+        ));
+    };
+
+    // Plugins are loaded outside of a workspace, as the workspace
+    // may not be fully configured yet
+    let plugin_id = PluginId::raw(id);
+    let mut registry = ToolchainRegistry::new(PluginHostData {
+        moon_env: Arc::clone(&session.moon_env),
+        proto_env: Arc::clone(&session.proto_env),
+        workspace_graph: WorkspaceGraph::default(),
+    });
+
+    registry
+        .configs
+        .insert(plugin_id.clone(), plugin_config.to_owned());
+    registry.load_all().await?;
+
+    let plugin = registry.load(&plugin_id).await?;
+
+    let Some(output) = plugin
+        .initialize_toolchain(registry.create_context())
+        .await?
+    else {
+        console.out.write_line(format!(
+            "Toolchain {} does not support initialization, skipping",
+            color::id(id)
+        ))?;
+
+        return Ok(false);
+    };
+
+    validate_prompts(id, &output.prompts)?;
+
+    if !options.yes {
+        console.out.print_header(id)?;
+
+        console.out.write_raw(|buffer| {
+            if let Some(url) = &output.docs_url {
+                buffer.extend_from_slice(format!("Docs: {}\n", color::url(url)).as_bytes());
+            }
+
+            if let Some(url) = &output.config_url {
+                buffer.extend_from_slice(format!("Config: {}\n", color::url(url)).as_bytes());
+            }
+
+            buffer.extend_from_slice(b"\n");
+        })?;
+
+        console.out.flush()?;
+    }
+
+    let mut settings = BTreeMap::from_iter(output.default_settings);
+
+    for prompt in &output.prompts {
+        if options.minimal && !prompt.minimal {
+            continue;
+        }
+
+        let answer = if options.yes || options.minimal {
+            get_default_answer(prompt)
+        } else {
+            render_prompt(prompt, theme)?
+        };
+
+        if !answer.is_null() {
+            settings.insert(prompt.setting.clone(), answer);
+        }
+    }
+
+    write_settings(
+        &session.config_loader.get_toolchain_files(dest_dir)[0],
+        id,
+        settings,
+    )?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starbase_utils::yaml;
+
+    fn create_settings() -> BTreeMap<String, JsonValue> {
+        BTreeMap::from_iter([
+            ("syncConfig".into(), JsonValue::Bool(true)),
+            ("version".into(), JsonValue::String("stable".into())),
+        ])
+    }
+
+    #[test]
+    fn preserves_comments_when_updating_block() {
+        let content = "# Toolchains\nnode:\n  version: '20.0.0'\n\nexample:\n  # The version\n  version: '1.0.0'\n  plugin: 'file://plugin.wasm'\n\n# Other\nrust: {}\n";
+
+        assert_eq!(
+            update_settings(content, &Id::raw("example"), create_settings())
+                .unwrap()
+                .unwrap(),
+            "# Toolchains\nnode:\n  version: '20.0.0'\n\nexample:\n  # The version\n  version: stable\n  plugin: 'file://plugin.wasm'\n  syncConfig: true\n\n# Other\nrust: {}\n"
+        );
+    }
+
+    #[test]
+    fn appends_missing_block() {
+        assert_eq!(
+            update_settings(
+                "# Toolchains\nnode: {}\n",
+                &Id::raw("example"),
+                create_settings()
+            )
+            .unwrap()
+            .unwrap(),
+            "# Toolchains\nnode: {}\n\nexample:\n  syncConfig: true\n  version: stable\n"
+        );
+    }
+
+    #[test]
+    fn converts_flow_mappings() {
+        let content = update_settings(
+            "example: { plugin: 'file://plugin.wasm' }\n",
+            &Id::raw("example"),
+            create_settings(),
+        )
+        .unwrap()
+        .unwrap();
+
+        assert!(!content.contains('{'));
+        assert_eq!(
+            yaml::parse::<_, YamlValue>(&content).unwrap(),
+            yaml::parse::<_, YamlValue>(
+                "example:\n  plugin: 'file://plugin.wasm'\n  syncConfig: true\n  version: stable\n"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn returns_none_if_not_a_mapping() {
+        assert!(
+            update_settings("example: true\n", &Id::raw("example"), create_settings())
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn errors_for_invalid_default_index() {
+        let result = validate_prompts(
+            &Id::raw("example"),
+            &[SettingPrompt {
+                question: "Which?".into(),
+                setting: "which".into(),
+                type_of: PromptType::Select {
+                    default_index: 2,
+                    options: vec!["a".into()],
+                },
+                ..SettingPrompt::default()
+            }],
+        );
+
+        assert!(result.is_err());
+    }
+}
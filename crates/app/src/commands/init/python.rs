@@ -0,0 +1,213 @@
+use super::prompts::{fully_qualify_version, prompt_version};
+use super::InitOptions;
+use dialoguer::theme::ColorfulTheme;
+use dialoguer::Select;
+use miette::IntoDiagnostic;
+use moon_config::load_toolchain_python_config_template;
+use moon_console::Console;
+use moon_lang::is_using_dependency_manager;
+use moon_python_lang::pyproject::PyProject;
+use starbase_styles::color;
+use starbase_utils::fs;
+use std::path::Path;
+use tera::{Context, Tera};
+use tracing::instrument;
+
+pub fn render_template(context: Context) -> miette::Result<String> {
+    Tera::one_off(load_toolchain_python_config_template(), &context, false).into_diagnostic()
+}
+
+/// Extract a version from a version requirement, like `>=3.11` or `==3.12.*`.
+/// Requirements without a lower bound can't be used.
+fn extract_version_from_requirement(requirement: &str) -> String {
+    let requirement = requirement.split(',').next().unwrap_or_default().trim();
+
+    for op in ["==", ">=", "~="] {
+        if let Some(version) = requirement.strip_prefix(op) {
+            return fully_qualify_version(version.trim().trim_end_matches(".*"));
+        }
+    }
+
+    String::new()
+}
+
+/// Detect the Python version from `.python-version` or the `requires-python`
+/// field in `pyproject.toml`, otherwise fallback to the configuration default.
+fn detect_python_version(dest_dir: &Path) -> miette::Result<String> {
+    let version_file = dest_dir.join(".python-version");
+
+    if version_file.exists() {
+        let content = fs::read_file(version_file)?;
+        let version = content.lines().next().unwrap_or_default().trim();
+
+        // Ignore non-CPython values, like `pypy3.10` or `system`
+        if version.starts_with(|ch: char| ch.is_ascii_digit()) {
+            return Ok(fully_qualify_version(version));
+        }
+    }
+
+    if let Some(pyproject) = PyProject::read(dest_dir)? {
+        if let Some(requirement) = pyproject
+            .project
+            .and_then(|project| project.requires_python)
+        {
+            return Ok(extract_version_from_requirement(&requirement));
+        }
+    }
+
+    Ok(String::new())
+}
+
+/// Detect the package manager to use, based on lockfiles and
+/// `pyproject.toml` settings, otherwise ask for explicit input.
+fn detect_package_manager(
+    dest_dir: &Path,
+    options: &InitOptions,
+    theme: &ColorfulTheme,
+) -> miette::Result<(String, String)> {
+    let mut pm_type = String::new();
+    let mut pm_version = String::new();
+    let pyproject = PyProject::read(dest_dir)?;
+
+    if let Some(uv) = pyproject
+        .as_ref()
+        .and_then(|pyproject| pyproject.get_uv_tool())
+    {
+        pm_type = "uv".to_owned();
+
+        if let Some(requirement) = &uv.required_version {
+            pm_version = extract_version_from_requirement(requirement);
+        }
+    } else if is_using_dependency_manager(dest_dir, "uv.lock") {
+        pm_type = "uv".to_owned();
+    } else if is_using_dependency_manager(dest_dir, "requirements.txt") {
+        pm_type = "pip".to_owned();
+    }
+
+    if pm_type.is_empty() {
+        let items = vec!["pip", "uv"];
+        let default_index = 0;
+
+        let index = if options.yes || options.minimal {
+            default_index
+        } else {
+            Select::with_theme(theme)
+                .with_prompt("Package manager?")
+                .items(&items)
+                .default(default_index)
+                .interact_opt()
+                .into_diagnostic()?
+                .unwrap_or(default_index)
+        };
+
+        pm_type = items[index].to_owned();
+    }
+
+    // pip is bundled with Python and is not versioned separately
+    if pm_type == "uv" {
+        pm_version = prompt_version("uv", options, theme, || Ok(pm_version))?;
+    }
+
+    Ok((pm_type, fully_qualify_version(&pm_version)))
+}
+
+/// Detect project globs from the `members` of a uv workspace.
+pub fn detect_workspace_members(dest_dir: &Path) -> miette::Result<Vec<String>> {
+    Ok(PyProject::read(dest_dir)?
+        .map(|pyproject| pyproject.get_uv_workspace_members())
+        .unwrap_or_default())
+}
+
+#[instrument(skip_all)]
+pub async fn init_python(
+    dest_dir: &Path,
+    options: &InitOptions,
+    theme: &ColorfulTheme,
+    console: &Console,
+) -> miette::Result<String> {
+    if !options.yes {
+        console.out.print_header("Python")?;
+
+        console.out.write_raw(|buffer| {
+            buffer.extend_from_slice(
+                format!(
+                    "Toolchain: {}\n",
+                    color::url("https://moonrepo.dev/docs/concepts/toolchain")
+                )
+                .as_bytes(),
+            );
+            buffer.extend_from_slice(
+                format!(
+                    "Config: {}\n\n",
+                    color::url("https://moonrepo.dev/docs/config/toolchain#python")
+                )
+                .as_bytes(),
+            );
+        })?;
+
+        console.out.flush()?;
+    }
+
+    let python_version =
+        prompt_version("Python", options, theme, || detect_python_version(dest_dir))?;
+    let package_manager = detect_package_manager(dest_dir, options, theme)?;
+
+    // uv workspaces share a single virtual environment in the root
+    let root_venv_only = !detect_workspace_members(dest_dir)?.is_empty();
+
+    let mut context = Context::new();
+    context.insert("python_version", &python_version);
+    context.insert("package_manager", &package_manager.0);
+    context.insert("package_manager_version", &package_manager.1);
+    context.insert("root_venv_only", &root_venv_only);
+    context.insert("minimal", &options.minimal);
+
+    render_template(context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starbase_sandbox::assert_snapshot;
+
+    fn create_context() -> Context {
+        let mut context = Context::new();
+        context.insert("python_version", &"3.12.0");
+        context.insert("package_manager", &"pip");
+        context.insert("package_manager_version", &"");
+        context.insert("root_venv_only", &false);
+        context.insert("minimal", &false);
+        context
+    }
+
+    #[test]
+    fn renders_default() {
+        assert_snapshot!(render_template(create_context()).unwrap());
+    }
+
+    #[test]
+    fn renders_minimal() {
+        let mut context = create_context();
+        context.insert("minimal", &true);
+
+        assert_snapshot!(render_template(context).unwrap());
+    }
+
+    #[test]
+    fn renders_uv() {
+        let mut context = create_context();
+        context.insert("package_manager", &"uv");
+        context.insert("package_manager_version", &"0.5.0");
+        context.insert("root_venv_only", &true);
+
+        assert_snapshot!(render_template(context).unwrap());
+    }
+
+    #[test]
+    fn extracts_version_from_requirements() {
+        assert_eq!(extract_version_from_requirement(">=3.11"), "3.11.0");
+        assert_eq!(extract_version_from_requirement("==3.12.*"), "3.12.0");
+        assert_eq!(extract_version_from_requirement(">=3.10,<4"), "3.10.0");
+        assert_eq!(extract_version_from_requirement("<3.13"), "");
+    }
+}
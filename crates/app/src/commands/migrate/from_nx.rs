@@ -0,0 +1,1013 @@
+use super::check_dirty_repo;
+use crate::helpers::add_workspace_project_sources;
+use crate::session::CliSession;
+use clap::Args;
+use miette::miette;
+use moon_common::consts::CONFIG_DIRNAME;
+use moon_common::path::to_virtual_string;
+use moon_common::Id;
+use moon_config::{
+    InputPath, NodePackageManager, OutputPath, PartialInheritedTasksConfig, PartialProjectConfig,
+    PartialProjectDependsOn, PartialTaskArgs, PartialTaskConfig, PartialTaskDependency,
+    PartialTaskOptionsConfig, ProjectType, TaskPreset, WorkspaceProjects,
+};
+use moon_target::Target;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Deserialize;
+use starbase::AppResult;
+use starbase_styles::color;
+use starbase_utils::json::{self, JsonMap, JsonValue};
+use starbase_utils::{fs, glob, yaml};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::str::FromStr;
+use tracing::{debug, info, instrument};
+
+#[derive(Args, Clone, Debug)]
+pub struct FromNxArgs {
+    #[arg(long, hide = true)]
+    pub skip_touched_files_check: bool,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum NxOneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl NxOneOrMany {
+    fn to_list(&self) -> Vec<String> {
+        match self {
+            Self::One(value) => vec![value.to_owned()],
+            Self::Many(values) => values.to_owned(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct NxInputObject {
+    dependencies: Option<bool>,
+    dependent_tasks_output_files: Option<String>,
+    env: Option<String>,
+    external_dependencies: Option<Vec<String>>,
+    fileset: Option<String>,
+    input: Option<String>,
+    projects: Option<NxOneOrMany>,
+    runtime: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum NxInput {
+    String(String),
+    Object(NxInputObject),
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct NxDependsOnObject {
+    dependencies: Option<bool>,
+    params: Option<String>,
+    projects: Option<NxOneOrMany>,
+    target: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum NxDependsOn {
+    String(String),
+    Object(NxDependsOnObject),
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct NxTarget {
+    cache: Option<bool>,
+    command: Option<String>,
+    configurations: Option<BTreeMap<String, JsonValue>>,
+    continuous: Option<bool>,
+    depends_on: Option<Vec<NxDependsOn>>,
+    executor: Option<String>,
+    inputs: Option<Vec<NxInput>>,
+    options: Option<JsonMap<String, JsonValue>>,
+    outputs: Option<Vec<String>>,
+}
+
+impl NxTarget {
+    // Fields on the target take precedence, while options are shallow merged
+    fn merge_with_defaults(&self, defaults: &NxTarget) -> NxTarget {
+        let mut options = defaults.options.clone().unwrap_or_default();
+
+        if let Some(target_options) = &self.options {
+            options.extend(target_options.clone());
+        }
+
+        NxTarget {
+            cache: self.cache.or(defaults.cache),
+            command: self.command.clone().or_else(|| defaults.command.clone()),
+            configurations: self
+                .configurations
+                .clone()
+                .or_else(|| defaults.configurations.clone()),
+            continuous: self.continuous.or(defaults.continuous),
+            depends_on: self
+                .depends_on
+                .clone()
+                .or_else(|| defaults.depends_on.clone()),
+            executor: self.executor.clone().or_else(|| defaults.executor.clone()),
+            inputs: self.inputs.clone().or_else(|| defaults.inputs.clone()),
+            options: if options.is_empty() {
+                None
+            } else {
+                Some(options)
+            },
+            outputs: self.outputs.clone().or_else(|| defaults.outputs.clone()),
+        }
+    }
+
+    fn get_option(&self, key: &str) -> Option<&JsonValue> {
+        self.options.as_ref().and_then(|options| options.get(key))
+    }
+
+    fn get_option_string(&self, key: &str) -> Option<String> {
+        self.get_option(key)
+            .and_then(|value| value.as_str())
+            .map(|value| value.to_owned())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum NxPlugin {
+    String(String),
+    Object { plugin: String },
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct NxTasksRunnerOptions {
+    cacheable_operations: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+struct NxTasksRunner {
+    options: NxTasksRunnerOptions,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct NxJson {
+    named_inputs: BTreeMap<String, Vec<NxInput>>,
+    plugins: Vec<NxPlugin>,
+    target_defaults: BTreeMap<String, NxTarget>,
+    tasks_runner_options: BTreeMap<String, NxTasksRunner>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct NxProjectJson {
+    implicit_dependencies: Vec<String>,
+    name: Option<String>,
+    named_inputs: BTreeMap<String, Vec<NxInput>>,
+    project_type: Option<String>,
+    tags: Vec<String>,
+    targets: BTreeMap<String, NxTarget>,
+}
+
+struct NxProject {
+    id: Id,
+    name: String,
+    root: String,
+}
+
+struct NxMigrator<'nx> {
+    nx_json: &'nx NxJson,
+    package_manager: NodePackageManager,
+    unmapped: Vec<String>,
+}
+
+impl<'nx> NxMigrator<'nx> {
+    fn report(&mut self, label: &str, message: String) {
+        self.unmapped.push(format!("{label}: {message}"));
+    }
+
+    fn is_cacheable(&self, target_name: &str, target: &NxTarget) -> bool {
+        if let Some(cache) = target.cache {
+            return cache;
+        }
+
+        // Older workspaces declare cacheable targets on the task runner
+        self.nx_json.tasks_runner_options.values().any(|runner| {
+            runner
+                .options
+                .cacheable_operations
+                .iter()
+                .any(|op| op == target_name)
+        })
+    }
+
+    fn get_target_defaults(&self, target_name: &str, target: &NxTarget) -> Option<&'nx NxTarget> {
+        self.nx_json.target_defaults.get(target_name).or_else(|| {
+            target
+                .executor
+                .as_ref()
+                .and_then(|executor| self.nx_json.target_defaults.get(executor))
+        })
+    }
+
+    fn convert_path(&self, project: &NxProject, value: &str) -> Option<String> {
+        let (negated, value) = match value.strip_prefix('!') {
+            Some(inner) => (true, inner),
+            None => (false, value),
+        };
+
+        let value = value.replace("{projectName}", &project.name);
+
+        let path = if value == "{projectRoot}" {
+            "**/*".to_owned()
+        } else if let Some(inner) = value.strip_prefix("{projectRoot}/") {
+            inner.to_owned()
+        } else if value == "{workspaceRoot}" {
+            "/**/*".to_owned()
+        } else if let Some(inner) = value.strip_prefix("{workspaceRoot}/") {
+            format!("/{inner}")
+        } else if let Some(inner) = value.strip_prefix(&format!("{}/", project.root)) {
+            inner.to_owned()
+        } else {
+            // Paths without a token are relative to the workspace root
+            format!("/{}", value.trim_start_matches("./"))
+        };
+
+        if path.contains('{') {
+            return None;
+        }
+
+        Some(if negated { format!("!{path}") } else { path })
+    }
+
+    fn convert_input_path(
+        &mut self,
+        label: &str,
+        project: &NxProject,
+        value: &str,
+    ) -> Option<InputPath> {
+        let input = self
+            .convert_path(project, value)
+            .and_then(|path| InputPath::from_str(&path).ok());
+
+        if input.is_none() {
+            self.report(label, format!("input `{value}` could not be resolved"));
+        }
+
+        input
+    }
+
+    fn convert_input(
+        &mut self,
+        label: &str,
+        project: &NxProject,
+        input: &NxInput,
+        named_inputs: &BTreeMap<String, Vec<NxInput>>,
+    ) -> Vec<InputPath> {
+        let mut inputs = vec![];
+
+        match input {
+            NxInput::String(value) => {
+                if value.starts_with('^') {
+                    self.report(
+                        label,
+                        format!("input `{value}` from dependencies is not supported"),
+                    );
+                } else if named_inputs.contains_key(value) {
+                    inputs.push(InputPath::TokenFunc(format!("@group({value})")));
+                } else {
+                    inputs.extend(self.convert_input_path(label, project, value));
+                }
+            }
+            NxInput::Object(object) => {
+                if let Some(fileset) = &object.fileset {
+                    inputs.extend(self.convert_input_path(label, project, fileset));
+                } else if let Some(env) = &object.env {
+                    inputs.push(InputPath::EnvVar(env.to_owned()));
+                } else if let Some(name) = &object.input {
+                    let is_self = object.dependencies != Some(true)
+                        && object
+                            .projects
+                            .as_ref()
+                            .is_none_or(|projects| projects.to_list() == ["self"]);
+
+                    if is_self && named_inputs.contains_key(name) {
+                        inputs.push(InputPath::TokenFunc(format!("@group({name})")));
+                    } else {
+                        self.report(
+                            label,
+                            format!("input `{name}` from other projects is not supported"),
+                        );
+                    }
+                } else if let Some(runtime) = &object.runtime {
+                    self.report(label, format!("runtime input `{runtime}` is not supported"));
+                } else if let Some(deps) = &object.external_dependencies {
+                    self.report(
+                        label,
+                        format!(
+                            "external dependency inputs ({}) are not supported, as lockfiles are hashed automatically",
+                            deps.join(", ")
+                        ),
+                    );
+                } else if let Some(files) = &object.dependent_tasks_output_files {
+                    self.report(
+                        label,
+                        format!("dependent task output inputs (`{files}`) are not supported"),
+                    );
+                }
+            }
+        };
+
+        inputs
+    }
+
+    // Named inputs may reference other named inputs, which we flatten,
+    // as moon file groups can't reference other file groups
+    fn expand_named_input(
+        &mut self,
+        label: &str,
+        project: &NxProject,
+        name: &str,
+        named_inputs: &BTreeMap<String, Vec<NxInput>>,
+        seen: &mut BTreeSet<String>,
+    ) -> Vec<InputPath> {
+        let mut inputs = vec![];
+
+        if !seen.insert(name.to_owned()) {
+            return inputs;
+        }
+
+        for input in named_inputs.get(name).into_iter().flatten() {
+            match input {
+                NxInput::String(value) if named_inputs.contains_key(value) => {
+                    inputs.extend(self.expand_named_input(
+                        label,
+                        project,
+                        value,
+                        named_inputs,
+                        seen,
+                    ));
+                }
+                _ => {
+                    inputs.extend(self.convert_input(label, project, input, named_inputs));
+                }
+            };
+        }
+
+        inputs
+    }
+
+    fn convert_file_groups<'names>(
+        &mut self,
+        label: &str,
+        project: &NxProject,
+        names: impl IntoIterator<Item = &'names String>,
+        named_inputs: &BTreeMap<String, Vec<NxInput>>,
+    ) -> miette::Result<FxHashMap<Id, Vec<InputPath>>> {
+        let mut file_groups = FxHashMap::default();
+
+        for name in names {
+            let mut inputs =
+                self.expand_named_input(label, project, name, named_inputs, &mut BTreeSet::new());
+            inputs.dedup();
+
+            file_groups.insert(Id::new(name)?, inputs);
+        }
+
+        Ok(file_groups)
+    }
+
+    fn convert_depends_on(
+        &mut self,
+        label: &str,
+        depends_on: &NxDependsOn,
+    ) -> miette::Result<Vec<PartialTaskDependency>> {
+        let mut targets = vec![];
+
+        match depends_on {
+            NxDependsOn::String(value) => {
+                if value.contains('*') {
+                    self.report(
+                        label,
+                        format!("dependency `{value}` uses a wildcard, which is not supported"),
+                    );
+                } else if let Some(task) = value.strip_prefix('^') {
+                    targets.push(format!("^:{task}"));
+                } else if value.contains(':') {
+                    targets.push(value.to_owned());
+                } else {
+                    targets.push(format!("~:{value}"));
+                }
+            }
+            NxDependsOn::Object(object) => {
+                if object.target.contains('*') {
+                    self.report(
+                        label,
+                        format!(
+                            "dependency `{}` uses a wildcard, which is not supported",
+                            object.target
+                        ),
+                    );
+
+                    return Ok(vec![]);
+                }
+
+                if object.params.as_deref() == Some("forward") {
+                    self.report(
+                        label,
+                        format!(
+                            "arguments are not forwarded to dependency `{}`",
+                            object.target
+                        ),
+                    );
+                }
+
+                if object.dependencies == Some(true) {
+                    targets.push(format!("^:{}", object.target));
+                } else {
+                    let projects = object
+                        .projects
+                        .as_ref()
+                        .map(|projects| projects.to_list())
+                        .unwrap_or_else(|| vec!["self".into()]);
+
+                    for project in projects {
+                        match project.as_str() {
+                            "self" => targets.push(format!("~:{}", object.target)),
+                            "dependencies" => targets.push(format!("^:{}", object.target)),
+                            name if name.contains('*') || name.starts_with("tag:") => {
+                                self.report(
+                                    label,
+                                    format!(
+                                        "dependency on `{}` for projects `{name}` is not supported",
+                                        object.target
+                                    ),
+                                );
+                            }
+                            name => targets.push(format!("{}:{}", Id::clean(name)?, object.target)),
+                        };
+                    }
+                }
+            }
+        };
+
+        targets
+            .into_iter()
+            .map(|target| Target::parse(&target).map(PartialTaskDependency::Target))
+            .collect()
+    }
+
+    fn convert_command(
+        &mut self,
+        label: &str,
+        project: &NxProject,
+        target_name: &str,
+        target: &NxTarget,
+        task: &mut PartialTaskConfig,
+    ) {
+        let executor = target
+            .executor
+            .as_deref()
+            .unwrap_or(if target.command.is_some() {
+                "nx:run-commands"
+            } else {
+                ""
+            });
+
+        match executor {
+            "nx:run-commands" => {
+                self.convert_run_commands(label, project, target, task);
+            }
+            "nx:run-script" => match target.get_option_string("script") {
+                Some(script) => {
+                    task.command = Some(PartialTaskArgs::String(format!(
+                        "{} run {script}",
+                        self.package_manager
+                    )));
+                }
+                None => {
+                    self.report(label, "`nx:run-script` is missing a script".into());
+                    task.command = Some(PartialTaskArgs::String("noop".into()));
+                }
+            },
+            "nx:noop" => {
+                task.command = Some(PartialTaskArgs::String("noop".into()));
+            }
+            "" => {
+                self.report(
+                    label,
+                    "target has no executor, it may be inferred by a plugin".into(),
+                );
+                task.command = Some(PartialTaskArgs::String("noop".into()));
+            }
+            other => {
+                self.report(
+                    label,
+                    format!("executor `{other}` is not supported, falling back to `nx run`"),
+                );
+                task.command = Some(PartialTaskArgs::String(format!(
+                    "nx run {}:{target_name}",
+                    project.name
+                )));
+                task.options
+                    .get_or_insert_with(PartialTaskOptionsConfig::default)
+                    .run_from_workspace_root = Some(true);
+            }
+        };
+    }
+
+    fn convert_run_commands(
+        &mut self,
+        label: &str,
+        project: &NxProject,
+        target: &NxTarget,
+        task: &mut PartialTaskConfig,
+    ) {
+        let mut commands = vec![];
+
+        if let Some(command) = target
+            .command
+            .clone()
+            .or_else(|| target.get_option_string("command"))
+        {
+            commands.push(command);
+        } else if let Some(JsonValue::Array(list)) = target.get_option("commands") {
+            for item in list {
+                if let Some(command) = item
+                    .as_str()
+                    .or_else(|| item.get("command").and_then(|command| command.as_str()))
+                {
+                    commands.push(command.to_owned());
+                }
+            }
+        }
+
+        if commands.is_empty() {
+            self.report(label, "`nx:run-commands` is missing a command".into());
+            task.command = Some(PartialTaskArgs::String("noop".into()));
+
+            return;
+        }
+
+        if commands.len() > 1
+            && target
+                .get_option("parallel")
+                .and_then(|parallel| parallel.as_bool())
+                != Some(false)
+        {
+            self.report(
+                label,
+                "multiple commands were configured to run in parallel, but will now run serially"
+                    .into(),
+            );
+        }
+
+        if let Some(args) = target.get_option_string("args") {
+            if let Some(last) = commands.last_mut() {
+                last.push(' ');
+                last.push_str(&args);
+            }
+        }
+
+        if let Some(JsonValue::Object(env)) = target.get_option("env") {
+            let task_env = task.env.get_or_insert_with(FxHashMap::default);
+
+            for (key, value) in env {
+                if let Some(value) = value.as_str() {
+                    task_env.insert(key.to_owned(), value.to_owned());
+                }
+            }
+        }
+
+        if let Some(env_file) = target.get_option_string("envFile") {
+            self.report(label, format!("env file `{env_file}` is not supported"));
+        }
+
+        // Commands run from the workspace root by default in Nx
+        let mut run_from_workspace_root = true;
+
+        if let Some(cwd) = target.get_option_string("cwd") {
+            let cwd = cwd
+                .replace("{projectRoot}", &project.root)
+                .replace("{workspaceRoot}", "");
+            let cwd = cwd.trim_matches('/').trim_start_matches("./");
+
+            if cwd == project.root {
+                run_from_workspace_root = false;
+            } else if !cwd.is_empty() && cwd != "." {
+                commands.insert(0, format!("cd {cwd}"));
+            }
+        }
+
+        if commands.len() == 1 && !is_shell_command(&commands[0]) {
+            task.command = Some(PartialTaskArgs::String(commands.remove(0)));
+        } else {
+            task.script = Some(commands.join(" && "));
+        }
+
+        if run_from_workspace_root {
+            task.options
+                .get_or_insert_with(PartialTaskOptionsConfig::default)
+                .run_from_workspace_root = Some(true);
+        }
+    }
+
+    fn convert_target(
+        &mut self,
+        project: &NxProject,
+        target_name: &str,
+        target: &NxTarget,
+        named_inputs: &BTreeMap<String, Vec<NxInput>>,
+    ) -> miette::Result<PartialTaskConfig> {
+        let label = format!("{}:{target_name}", project.name);
+        let target = match self.get_target_defaults(target_name, target) {
+            Some(defaults) => target.merge_with_defaults(defaults),
+            None => target.to_owned(),
+        };
+        let mut task = PartialTaskConfig::default();
+
+        self.convert_command(&label, project, target_name, &target, &mut task);
+
+        if let Some(depends_on) = &target.depends_on {
+            let mut deps = vec![];
+
+            for dep in depends_on {
+                deps.extend(self.convert_depends_on(&label, dep)?);
+            }
+
+            if !deps.is_empty() {
+                task.deps = Some(deps);
+            }
+        }
+
+        if let Some(inputs) = &target.inputs {
+            let mut task_inputs = vec![];
+
+            for input in inputs {
+                task_inputs.extend(self.convert_input(&label, project, input, named_inputs));
+            }
+
+            task_inputs.dedup();
+            task.inputs = Some(task_inputs);
+        }
+
+        if let Some(outputs) = &target.outputs {
+            let mut task_outputs = vec![];
+
+            for output in outputs {
+                let mut value = output.to_owned();
+
+                // Resolve `{options.*}` tokens from the target's options
+                if let Some(options) = &target.options {
+                    for (key, option) in options {
+                        if let Some(option) = option.as_str() {
+                            value = value.replace(&format!("{{options.{key}}}"), option);
+                        }
+                    }
+                }
+
+                match self
+                    .convert_path(project, &value)
+                    .and_then(|path| OutputPath::from_str(&path).ok())
+                {
+                    Some(output) => task_outputs.push(output),
+                    None => self.report(&label, format!("output `{output}` could not be resolved")),
+                };
+            }
+
+            task.outputs = Some(task_outputs);
+        }
+
+        if target.continuous == Some(true) {
+            task.preset = Some(TaskPreset::Server);
+        } else if !self.is_cacheable(target_name, &target) {
+            task.options
+                .get_or_insert_with(PartialTaskOptionsConfig::default)
+                .cache = Some(false);
+        }
+
+        if let Some(configurations) = &target.configurations {
+            if !configurations.is_empty() {
+                self.report(
+                    &label,
+                    format!(
+                        "configurations ({}) are not supported, create a task for each instead",
+                        configurations
+                            .keys()
+                            .cloned()
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                );
+            }
+        }
+
+        Ok(task)
+    }
+
+    fn convert_project(
+        &mut self,
+        project: &NxProject,
+        project_json: &NxProjectJson,
+        config: &mut PartialProjectConfig,
+    ) -> miette::Result<()> {
+        let label = project.name.clone();
+
+        match project_json.project_type.as_deref() {
+            Some("application") => config.type_of = Some(ProjectType::Application),
+            Some("library") => config.type_of = Some(ProjectType::Library),
+            _ => {}
+        };
+
+        for tag in &project_json.tags {
+            config
+                .tags
+                .get_or_insert_with(Vec::new)
+                .push(Id::clean(tag)?);
+        }
+
+        for dep in &project_json.implicit_dependencies {
+            if dep.starts_with('!') || dep.contains('*') {
+                self.report(
+                    &label,
+                    format!("implicit dependency `{dep}` is not supported"),
+                );
+            } else {
+                config
+                    .depends_on
+                    .get_or_insert_with(Vec::new)
+                    .push(PartialProjectDependsOn::String(Id::clean(dep)?));
+            }
+        }
+
+        // Project named inputs may reference workspace named inputs
+        let mut named_inputs = self.nx_json.named_inputs.clone();
+        named_inputs.extend(project_json.named_inputs.clone());
+
+        if !project_json.named_inputs.is_empty() {
+            config
+                .file_groups
+                .get_or_insert_with(FxHashMap::default)
+                .extend(self.convert_file_groups(
+                    &label,
+                    project,
+                    project_json.named_inputs.keys(),
+                    &named_inputs,
+                )?);
+        }
+
+        for (target_name, target) in &project_json.targets {
+            let task = self.convert_target(project, target_name, target, &named_inputs)?;
+
+            config
+                .tasks
+                .get_or_insert_with(BTreeMap::new)
+                .insert(Id::clean(target_name)?, task);
+        }
+
+        Ok(())
+    }
+}
+
+fn is_shell_command(command: &str) -> bool {
+    ["&&", "||", "|", ";", ">", "<", "$("]
+        .iter()
+        .any(|op| command.contains(op))
+}
+
+fn is_project_registered(projects: &WorkspaceProjects, id: &Id, source: &str) -> bool {
+    let (globs, sources) = match projects {
+        WorkspaceProjects::Both(config) => (config.globs.clone(), Some(&config.sources)),
+        WorkspaceProjects::Globs(globs) => (globs.clone(), None),
+        WorkspaceProjects::Sources(sources) => (vec![], Some(sources)),
+    };
+
+    if sources.is_some_and(|sources| {
+        sources.contains_key(id) || sources.values().any(|value| value == source)
+    }) {
+        return true;
+    }
+
+    glob::GlobSet::new(&globs).is_ok_and(|globset| {
+        globset.matches(source) || globset.matches(format!("{source}/moon.yml"))
+    })
+}
+
+fn register_projects(
+    workspace_root: &Path,
+    projects: &WorkspaceProjects,
+    nx_projects: &[NxProject],
+) -> miette::Result<()> {
+    let mut sources = BTreeMap::default();
+
+    for project in nx_projects {
+        if !is_project_registered(projects, &project.id, &project.root) {
+            sources.insert(project.id.as_str(), project.root.as_str());
+        }
+    }
+
+    if sources.is_empty() {
+        return Ok(());
+    }
+
+    let config_path = workspace_root.join(CONFIG_DIRNAME).join("workspace.yml");
+    let content = if config_path.exists() {
+        fs::read_file(&config_path)?
+    } else {
+        String::new()
+    };
+
+    // Edit in place to preserve comments and formatting
+    fs::write_file(
+        &config_path,
+        add_workspace_project_sources(&content, sources)?,
+    )?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn from_nx(session: CliSession, args: FromNxArgs) -> AppResult {
+    if args.skip_touched_files_check {
+        info!("Skipping touched files check.");
+    } else {
+        check_dirty_repo(&session).await?;
+    };
+
+    let workspace_root = &session.workspace_root;
+    let nx_json_path = workspace_root.join("nx.json");
+
+    if !nx_json_path.exists() {
+        return Err(miette!(
+            code = "moon::migrate",
+            "No {} found in the workspace root, unable to migrate.",
+            color::file("nx.json"),
+        ));
+    }
+
+    let nx_json: NxJson = json::read_file(&nx_json_path)?;
+    let mut migrator = NxMigrator {
+        nx_json: &nx_json,
+        package_manager: session
+            .toolchain_config
+            .node
+            .as_ref()
+            .map(|cfg| cfg.package_manager)
+            .unwrap_or(NodePackageManager::Npm),
+        unmapped: vec![],
+    };
+
+    for plugin in &nx_json.plugins {
+        let name = match plugin {
+            NxPlugin::String(name) => name,
+            NxPlugin::Object { plugin } => plugin,
+        };
+
+        migrator.report(
+            "nx.json",
+            format!("plugin `{name}` infers targets, which must be migrated manually"),
+        );
+    }
+
+    // Convert workspace named inputs to inherited file groups
+    if !nx_json.named_inputs.is_empty() {
+        let workspace_project = NxProject {
+            id: Id::raw("workspace"),
+            name: String::new(),
+            root: String::new(),
+        };
+        let tasks_path = workspace_root.join(CONFIG_DIRNAME).join("tasks.yml");
+        let mut tasks_config: PartialInheritedTasksConfig = session
+            .config_loader
+            .load_tasks_partial_config_from_path(workspace_root, &tasks_path)?;
+
+        tasks_config
+            .file_groups
+            .get_or_insert_with(FxHashMap::default)
+            .extend(migrator.convert_file_groups(
+                "nx.json",
+                &workspace_project,
+                nx_json.named_inputs.keys(),
+                &nx_json.named_inputs,
+            )?);
+
+        yaml::write_file_with_config(&tasks_path, &tasks_config)?;
+    }
+
+    // Convert each `project.json` to a `moon.*`
+    let config_names = session.config_loader.get_project_file_names();
+    let vcs = session.get_vcs_adapter()?;
+    let mut project_files = glob::walk_files(
+        workspace_root,
+        [
+            "**/project.json",
+            "!**/node_modules/**",
+            "!**/dist/**",
+            "!.git/**",
+            "!.moon/**",
+            "!.nx/**",
+        ],
+    )?;
+    let mut nx_projects = vec![];
+
+    // Skip build output and caches that are ignored by the VCS, by only
+    // keeping files that are tracked, or untracked but not ignored
+    if vcs.is_enabled() {
+        let tree = vcs
+            .get_file_tree(".")
+            .await?
+            .into_iter()
+            .map(|file| file.to_string())
+            .collect::<FxHashSet<_>>();
+
+        project_files.retain(|project_file| {
+            let tracked = project_file
+                .strip_prefix(workspace_root)
+                .ok()
+                .and_then(|file| to_virtual_string(file).ok())
+                .is_some_and(|file| tree.contains(&file));
+
+            if !tracked {
+                debug!(file = ?project_file, "Skipping project.json that is ignored by the VCS");
+            }
+
+            tracked
+        });
+    }
+
+    project_files.sort();
+
+    for project_file in project_files {
+        let project_root = project_file.parent().unwrap();
+        let project_json: NxProjectJson = json::read_file(&project_file)?;
+        let source = to_virtual_string(project_root.strip_prefix(workspace_root).unwrap())?;
+        let name = project_json.name.clone().unwrap_or_else(|| {
+            project_root
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default()
+        });
+        let project = NxProject {
+            id: Id::clean(&name)?,
+            name,
+            root: source,
+        };
+
+        debug!(
+            source = &project.root,
+            "Migrating Nx project {}",
+            color::id(&project.id)
+        );
+
+        let mut partial_config = session
+            .config_loader
+            .load_project_partial_config(project_root)?;
+
+        // Preserve the Nx name, as targets and dependencies reference it
+        if project_root
+            .file_name()
+            .is_none_or(|dir| dir.to_string_lossy() != project.id.as_str())
+        {
+            partial_config.id = Some(project.id.clone());
+        }
+
+        migrator.convert_project(&project, &project_json, &mut partial_config)?;
+
+        yaml::write_file_with_config(project_root.join(&config_names[0]), &partial_config)?;
+
+        nx_projects.push(project);
+    }
+
+    register_projects(
+        workspace_root,
+        &session.workspace_config.projects,
+        &nx_projects,
+    )?;
+
+    let console = session.console.stdout();
+
+    console.print_entry_header("Migrated projects")?;
+    console.print_list(
+        nx_projects
+            .iter()
+            .map(|project| format!("{} ({})", project.id, project.root))
+            .collect::<Vec<_>>(),
+    )?;
+
+    if !migrator.unmapped.is_empty() {
+        console.print_entry_header("Unable to migrate")?;
+        console.print_list(&migrator.unmapped)?;
+    }
+
+    console.write_newline()?;
+
+    Ok(None)
+}
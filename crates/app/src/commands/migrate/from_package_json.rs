@@ -67,7 +67,7 @@ pub async fn from_package_json(session: CliSession, args: FromPackageJsonArgs) -
         }
     };
 
-    PackageJsonCache::sync(&project.root, |package_json| {
+    PackageJsonCache::sync(&project.root, None, |package_json| {
         // Create tasks from `package.json` scripts
         for (task_id, task_config) in create_tasks_from_scripts(
             &project.id,
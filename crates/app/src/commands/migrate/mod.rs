@@ -1,6 +1,8 @@
+mod from_nx;
 mod from_package_json;
 mod from_turborepo;
 
+pub use from_nx::{from_nx, FromNxArgs};
 pub use from_package_json::{from_package_json, FromPackageJsonArgs};
 pub use from_turborepo::*;
 
@@ -12,6 +14,12 @@ use starbase_styles::color;
 
 #[derive(Clone, Debug, Subcommand)]
 pub enum MigrateCommands {
+    #[command(
+        name = "from-nx",
+        about = "Migrate `nx.json` and `project.json` files to moon configuration files."
+    )]
+    FromNx(FromNxArgs),
+
     #[command(
         name = "from-package-json",
         about = "Migrate `package.json` scripts and dependencies to `moon.*`."
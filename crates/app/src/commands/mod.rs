@@ -7,13 +7,16 @@ pub mod docker;
 pub mod ext;
 pub mod generate;
 pub mod graph;
+pub mod imports;
 pub mod init;
 pub mod migrate;
 pub mod node;
+pub mod owners;
 pub mod project;
 pub mod query;
 pub mod run;
 pub mod setup;
+pub mod stats;
 pub mod sync;
 pub mod syncs;
 pub mod task;
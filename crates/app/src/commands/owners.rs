@@ -0,0 +1,151 @@
+use crate::queries::touched_files::{query_touched_files, QueryTouchedFilesOptions};
+use crate::session::CliSession;
+use clap::Args;
+use moon_codeowners::CodeownersResolver;
+use moon_common::path::to_virtual_string;
+use moon_config::CodeownersOrderBy;
+use starbase::AppResult;
+use starbase_styles::color;
+use starbase_utils::json;
+use tracing::{instrument, warn};
+
+#[derive(Args, Clone, Debug)]
+pub struct OwnersArgs {
+    #[arg(help = "List of file paths to resolve owners for, otherwise uses touched files")]
+    paths: Vec<String>,
+
+    #[arg(long, help = "Base branch, commit, or revision to compare against")]
+    base: Option<String>,
+
+    #[arg(long, help = "Current branch, commit, or revision to compare with")]
+    head: Option<String>,
+
+    #[arg(long, help = "Print in JSON format")]
+    json: bool,
+}
+
+#[instrument(skip_all)]
+pub async fn owners(session: CliSession, args: OwnersArgs) -> AppResult {
+    let workspace_graph = session.get_workspace_graph().await?;
+    let codeowners_config = &session.workspace_config.codeowners;
+
+    // Load files relative to the workspace root
+    let mut files = vec![];
+
+    if args.paths.is_empty() {
+        let vcs = session.get_vcs_adapter()?;
+        let result = query_touched_files(
+            &vcs,
+            &QueryTouchedFilesOptions {
+                base: args.base.clone(),
+                head: args.head.clone(),
+                ..QueryTouchedFilesOptions::default()
+            },
+        )
+        .await?;
+
+        for file in result.files {
+            files.push(file.to_string());
+        }
+    } else {
+        for path in &args.paths {
+            let abs_path = session.working_dir.join(path);
+
+            match abs_path.strip_prefix(&session.workspace_root) {
+                Ok(rel_path) => files.push(to_virtual_string(rel_path)?),
+                Err(_) => {
+                    warn!(
+                        "Path {} is outside of the workspace, skipping",
+                        color::path(&abs_path)
+                    );
+                }
+            };
+        }
+    }
+
+    // Add entries in the same order as the generated CODEOWNERS file
+    let mut resolver = CodeownersResolver::new(session.workspace_config.vcs.provider);
+    let mut projects = workspace_graph.projects.get_all_unexpanded();
+
+    projects.sort_by(|a, d| match codeowners_config.order_by {
+        CodeownersOrderBy::FileSource => a.source.cmp(&d.source),
+        CodeownersOrderBy::ProjectName => a.id.cmp(&d.id),
+    });
+
+    resolver.add_workspace_entries(codeowners_config);
+
+    for project in projects {
+        resolver.add_project_entry(
+            &project.id,
+            project.source.as_str(),
+            &project.config.owners,
+            codeowners_config,
+        );
+    }
+
+    let result = resolver.resolve(&files)?;
+    let console = session.console.stdout();
+
+    if args.json {
+        console.write_line(json::format(&result, true)?)?;
+        console.flush()?;
+
+        return Ok(None);
+    }
+
+    if result.files.is_empty() {
+        console.write_line(color::muted_light("No files to resolve owners for"))?;
+        console.flush()?;
+
+        return Ok(None);
+    }
+
+    console.print_header("Files")?;
+
+    for (file, owners) in &result.files {
+        console.print_entry(
+            color::file(file),
+            if owners.is_empty() {
+                color::muted_light("(unowned)")
+            } else {
+                owners.iter().cloned().collect::<Vec<_>>().join(" ")
+            },
+        )?;
+    }
+
+    for section in &result.sections {
+        console.print_entry_header(section.project_id.as_deref().unwrap_or("(workspace)"))?;
+
+        console.print_entry(
+            "Owners",
+            section.owners.iter().cloned().collect::<Vec<_>>().join(" "),
+        )?;
+
+        if section.required_approvals > 0 {
+            console.print_entry("Required approvals", section.required_approvals.to_string())?;
+        }
+
+        if section.optional {
+            console.print_entry_bool("Optional", true)?;
+        }
+
+        console.print_entry_list(
+            "Files",
+            section.files.iter().map(color::file).collect::<Vec<_>>(),
+        )?;
+    }
+
+    console.write_newline()?;
+    console.print_entry_list(
+        "Required reviewers",
+        result
+            .required_reviewers
+            .iter()
+            .cloned()
+            .collect::<Vec<_>>(),
+    )?;
+    console.write_newline()?;
+    console.flush()?;
+
+    Ok(None)
+}
@@ -1,26 +1,33 @@
+use crate::app_error::{AppError, RelatedError};
 use crate::components::run_action_pipeline;
+use crate::queries::base_tasks::query_base_tasks;
+use crate::queries::lockfile_changes::query_lockfile_changes;
 use crate::queries::touched_files::{query_touched_files, QueryTouchedFilesOptions};
 use crate::session::CliSession;
 use clap::Args;
 use moon_action_context::{ActionContext, ProfileType};
 use moon_action_graph::RunRequirements;
+use moon_action_pipeline::resume_state::ResumeState;
 use moon_affected::{DownstreamScope, UpstreamScope};
 use moon_cache::CacheMode;
+use moon_common::path::WorkspaceRelativePathBuf;
 use moon_common::{is_ci, is_test_env};
 use moon_task::TargetLocator;
-use moon_vcs::TouchedStatus;
-use rustc_hash::FxHashSet;
+use moon_vcs::{BoxedVcs, TouchedStatus};
+use rustc_hash::{FxHashMap, FxHashSet};
 use starbase::AppResult;
 use starbase_styles::color;
+use std::path::{Path, PathBuf};
 use std::string::ToString;
-use tracing::instrument;
+use std::time::SystemTime;
+use tracing::{debug, instrument};
 
 const HEADING_AFFECTED: &str = "Affected by";
 const HEADING_DEBUGGING: &str = "Debugging";
 
 #[derive(Args, Clone, Debug, Default)]
 pub struct RunArgs {
-    #[arg(required = true, help = "List of targets to run")]
+    #[arg(required_unless_present = "resume", help = "List of targets to run")]
     pub targets: Vec<TargetLocator>,
 
     #[arg(long, help = "Run dependents of the primary targets")]
@@ -39,6 +46,12 @@ pub struct RunArgs {
     #[arg(long, help = "Focus target(s) based on the result of a query")]
     pub query: Option<String>,
 
+    #[arg(
+        long,
+        help = "Resume the previous failed or aborted run, by only running tasks that failed or did not start"
+    )]
+    pub resume: bool,
+
     #[arg(
         long,
         short = 's',
@@ -86,6 +99,14 @@ pub struct RunArgs {
     )]
     pub remote: bool,
 
+    #[arg(
+        long,
+        help = "Only run target if affected by staged files, and run against staged content only",
+        help_heading = HEADING_AFFECTED,
+        conflicts_with_all = ["remote", "status"],
+    )]
+    pub staged: bool,
+
     #[arg(
         long,
         help = "Filter affected files based on a touched status",
@@ -103,7 +124,9 @@ pub struct RunArgs {
 }
 
 pub fn is_local(args: &RunArgs) -> bool {
-    if args.affected {
+    if args.staged {
+        true
+    } else if args.affected {
         !args.remote
     } else {
         !is_ci()
@@ -114,6 +137,71 @@ pub async fn run_target(
     session: &CliSession,
     args: &RunArgs,
     target_locators: &[TargetLocator],
+) -> AppResult {
+    let vcs = session.get_vcs_adapter()?;
+
+    if args.staged && vcs.is_enabled() {
+        return run_target_with_staged(session, args, target_locators, &vcs).await;
+    }
+
+    run_target_with_workspace(session, args, target_locators).await
+}
+
+/// Stash unstaged changes before anything reads from the workspace, so that
+/// configs and tasks only see staged content, and always restore afterwards.
+async fn run_target_with_staged(
+    session: &CliSession,
+    args: &RunArgs,
+    target_locators: &[TargetLocator],
+    vcs: &BoxedVcs,
+) -> AppResult {
+    let cache_engine = session.get_cache_engine()?;
+
+    let staged_files = query_touched_files(
+        vcs,
+        &QueryTouchedFilesOptions {
+            local: true,
+            status: vec![TouchedStatus::Staged],
+            ..QueryTouchedFilesOptions::default()
+        },
+    )
+    .await?
+    .files;
+
+    let stash = StagedStash::create(
+        vcs,
+        &session.workspace_root,
+        cache_engine.state.states_dir.join("unstaged.patch"),
+        &staged_files,
+    )
+    .await?;
+
+    let result = run_target_with_workspace(session, args, target_locators).await;
+
+    // Always restore, even if the run failed
+    let patch_file = stash.patch_file.clone();
+
+    if let Err(error) = stash.restore(vcs, &session.workspace_root).await {
+        let related = result.err().into_iter().map(RelatedError).collect();
+
+        return Err(match patch_file.filter(|file| file.exists()) {
+            Some(patch_file) => AppError::UnstagedChangesRestoreFailed {
+                patch_file,
+                error,
+                related,
+            },
+            None => AppError::StagedFilesRestageFailed { error, related },
+        }
+        .into());
+    }
+
+    result
+}
+
+async fn run_target_with_workspace(
+    session: &CliSession,
+    args: &RunArgs,
+    target_locators: &[TargetLocator],
 ) -> AppResult {
     let console = &session.console;
     let cache_engine = session.get_cache_engine()?;
@@ -125,7 +213,29 @@ pub async fn run_target(
         cache_engine.force_mode(CacheMode::Write);
     }
 
-    let mut should_run_affected = !args.force && args.affected;
+    // Load the state of the previous run, so that completed tasks are skipped
+    let resume_state = if args.resume {
+        Some(ResumeState::load(&cache_engine.state.states_dir)?.ok_or(AppError::NoRunToResume)?)
+    } else {
+        None
+    };
+
+    let resumed_locators;
+    let target_locators = match &resume_state {
+        Some(state) if target_locators.is_empty() => {
+            resumed_locators = state
+                .initial_targets
+                .iter()
+                .map(|target| TargetLocator::Qualified(target.to_owned()))
+                .collect::<Vec<_>>();
+
+            &resumed_locators
+        }
+        _ => target_locators,
+    };
+
+    let mut should_run_affected = !args.force && (args.affected || args.staged);
+    let mut base_revision = None;
 
     // Always query for a touched files list as it'll be used by many actions
     let touched_files = if vcs.is_enabled() {
@@ -135,7 +245,11 @@ pub async fn run_target(
             &QueryTouchedFilesOptions {
                 default_branch: !local && !is_test_env(),
                 local,
-                status: args.status.clone(),
+                status: if args.staged {
+                    vec![TouchedStatus::Staged]
+                } else {
+                    args.status.clone()
+                },
                 ..QueryTouchedFilesOptions::default()
             },
         )
@@ -145,11 +259,21 @@ pub async fn run_target(
             should_run_affected = false;
         }
 
+        base_revision = result.base_revision;
+
         result.files
     } else {
         FxHashSet::default()
     };
 
+    let (base_tasks, lockfile_changes) = match &base_revision {
+        Some(revision) if should_run_affected => (
+            query_base_tasks(session, revision, &touched_files).await?,
+            query_lockfile_changes(session, &workspace_graph, revision, &touched_files).await?,
+        ),
+        _ => (None, FxHashMap::default()),
+    };
+
     // Generate a dependency graph for all the targets that need to be ran
     let mut action_graph_builder = session.build_action_graph(&workspace_graph).await?;
     action_graph_builder.set_touched_files(touched_files)?;
@@ -160,6 +284,14 @@ pub async fn run_target(
 
     if should_run_affected {
         action_graph_builder.set_affected_scopes(UpstreamScope::Deep, DownstreamScope::Deep)?;
+
+        if let Some(base_tasks) = base_tasks {
+            action_graph_builder.set_base_tasks(base_tasks);
+        }
+
+        if !lockfile_changes.is_empty() {
+            action_graph_builder.set_lockfile_changes(lockfile_changes);
+        }
     }
 
     // Run targets, optionally based on affected files
@@ -179,7 +311,9 @@ pub async fn run_target(
             .join(", ");
 
         if should_run_affected {
-            let status_list = if args.status.is_empty() {
+            let status_list = if args.staged {
+                color::symbol(TouchedStatus::Staged.to_string())
+            } else if args.status.is_empty() {
                 color::symbol(TouchedStatus::All.to_string())
             } else {
                 args.status
@@ -207,17 +341,28 @@ pub async fn run_target(
         return Ok(None);
     }
 
+    let mut action_context = ActionContext {
+        passthrough_args: args.passthrough.to_owned(),
+        profile: args.profile.to_owned(),
+        ..action_graph_builder.build_context()
+    };
+
+    if let Some(state) = &resume_state {
+        if action_context.passthrough_args.is_empty() {
+            action_context.passthrough_args = state.passthrough_args.clone();
+        }
+
+        console.out.write_line(format!(
+            "Resuming previous run, skipping {} completed tasks",
+            state.completed_targets.len()
+        ))?;
+
+        state.apply_to_context(&mut action_context);
+    }
+
     // Process all tasks in the graph
-    let results = run_action_pipeline(
-        session,
-        ActionContext {
-            passthrough_args: args.passthrough.to_owned(),
-            profile: args.profile.to_owned(),
-            ..action_graph_builder.build_context()
-        },
-        action_graph_builder.build(),
-    )
-    .await?;
+    let results =
+        run_action_pipeline(session, action_context, action_graph_builder.build()).await?;
 
     if args.no_bail {
         let failed = results.iter().any(|result| {
@@ -236,6 +381,87 @@ pub async fn run_target(
     Ok(None)
 }
 
+struct StagedStash {
+    files: Vec<(String, Option<SystemTime>)>,
+    patch_file: Option<PathBuf>,
+}
+
+impl StagedStash {
+    /// Revert unstaged changes from the working tree, and record the state
+    /// of staged files, so that files modified by tasks can be re-staged.
+    pub async fn create(
+        vcs: &BoxedVcs,
+        workspace_root: &Path,
+        patch_file: PathBuf,
+        staged_files: &FxHashSet<WorkspaceRelativePathBuf>,
+    ) -> miette::Result<Self> {
+        // Don't overwrite changes that failed to be restored previously
+        if patch_file.exists() {
+            return Err(AppError::UnstagedChangesNotRestored(patch_file).into());
+        }
+
+        let stashed = vcs.stash_unstaged_changes(&patch_file).await?;
+
+        let files = staged_files
+            .iter()
+            .map(|file| {
+                (
+                    file.to_string(),
+                    get_modified_time(&file.to_logical_path(workspace_root)),
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            files,
+            patch_file: if stashed { Some(patch_file) } else { None },
+        })
+    }
+
+    /// Re-stage files that were modified by tasks (formatters, etc), and then
+    /// restore the unstaged changes to the working tree. Unstaged changes are
+    /// restored even if re-staging failed, so that they aren't left behind.
+    pub async fn restore(self, vcs: &BoxedVcs, workspace_root: &Path) -> miette::Result<()> {
+        let modified_files = self
+            .files
+            .into_iter()
+            .filter_map(|(file, prev_time)| {
+                let time = get_modified_time(&workspace_root.join(&file));
+
+                if time.is_some() && time != prev_time {
+                    Some(file)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut result = Ok(());
+
+        if !modified_files.is_empty() {
+            debug!(
+                files = ?modified_files,
+                "Re-staging {} files that were modified while running",
+                modified_files.len()
+            );
+
+            result = vcs.stage_files(&modified_files).await;
+        }
+
+        if let Some(patch_file) = &self.patch_file {
+            vcs.restore_unstaged_changes(patch_file).await?;
+        }
+
+        result
+    }
+}
+
+fn get_modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+}
+
 #[instrument(skip_all)]
 pub async fn run(session: CliSession, args: RunArgs) -> AppResult {
     return run_target(&session, &args, &args.targets).await;
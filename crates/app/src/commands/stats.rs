@@ -0,0 +1,117 @@
+use crate::session::CliSession;
+use clap::Args;
+use moon_action_pipeline::reports::history::RunHistory;
+use moon_action_pipeline::reports::stats::RunStats;
+use moon_time::elapsed;
+use starbase::AppResult;
+use starbase_styles::color;
+use starbase_utils::json;
+use tracing::instrument;
+
+#[derive(Args, Clone, Debug)]
+pub struct StatsArgs {
+    #[arg(long, help = "Print in JSON format")]
+    json: bool,
+
+    #[arg(
+        long,
+        default_value_t = 10,
+        help = "Maximum amount of entries per section"
+    )]
+    limit: usize,
+}
+
+#[instrument(skip_all)]
+pub async fn stats(session: CliSession, args: StatsArgs) -> AppResult {
+    let history = RunHistory::new(&session.get_cache_engine()?.cache_dir);
+    let stats = RunStats::calculate(&history.load()?, args.limit);
+
+    let console = session.console.stdout();
+
+    if args.json {
+        console.write_line(json::format(&stats, true)?)?;
+
+        return Ok(None);
+    }
+
+    if stats.total_runs == 0 {
+        console.write_line("No run history has been recorded yet. Run some tasks first!")?;
+
+        return Ok(None);
+    }
+
+    console.print_header("Stats")?;
+    console.print_entry("Runs", stats.total_runs.to_string())?;
+    console.print_entry("Tasks", stats.total_tasks.to_string())?;
+    console.print_entry("Cache hit rate", format!("{:.1}%", stats.cache_hit_rate))?;
+
+    if !stats.slowest_tasks.is_empty() {
+        console.print_entry_header("Slowest tasks")?;
+
+        for task in &stats.slowest_tasks {
+            console.print_entry(
+                color::label(&task.target),
+                format!(
+                    "{} avg {}",
+                    elapsed(task.average_duration),
+                    color::muted_light(format!(
+                        "(max {}, {} runs)",
+                        elapsed(task.max_duration),
+                        task.executed
+                    ))
+                ),
+            )?;
+        }
+    }
+
+    if !stats.failing_tasks.is_empty() {
+        console.print_entry_header("Most failing tasks")?;
+
+        for task in &stats.failing_tasks {
+            console.print_entry(
+                color::label(&task.target),
+                format!(
+                    "{} failures {}",
+                    task.failed,
+                    color::muted_light(format!("({:.1}% of runs)", task.failure_rate()))
+                ),
+            )?;
+        }
+    }
+
+    if !stats.projects.is_empty() {
+        console.print_entry_header("Cache hit rate by project")?;
+
+        for project in &stats.projects {
+            console.print_entry(
+                color::id(&project.project),
+                format!(
+                    "{:.1}% {}",
+                    project.cache_hit_rate,
+                    color::muted_light(format!("({} of {} runs)", project.cached, project.runs))
+                ),
+            )?;
+        }
+    }
+
+    if !stats.regressions.is_empty() {
+        console.print_entry_header("Regressions")?;
+
+        for regression in &stats.regressions {
+            console.print_entry(
+                color::label(&regression.target),
+                format!(
+                    "{} → {} {}",
+                    elapsed(regression.previous_duration),
+                    elapsed(regression.recent_duration),
+                    color::failure(format!("(+{:.0}%)", regression.percent))
+                ),
+            )?;
+        }
+    }
+
+    console.write_newline()?;
+    console.flush()?;
+
+    Ok(None)
+}
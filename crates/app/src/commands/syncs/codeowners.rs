@@ -1,3 +1,4 @@
+use super::report_check;
 use crate::helpers::create_progress_bar;
 use crate::session::CliSession;
 use clap::Args;
@@ -13,10 +14,28 @@ pub struct SyncCodeownersArgs {
 
     #[arg(long, help = "Bypass cache and force create file")]
     force: bool,
+
+    #[arg(skip)]
+    pub check: bool,
 }
 
 #[instrument(skip_all)]
 pub async fn sync(session: CliSession, args: SyncCodeownersArgs) -> AppResult {
+    if args.check {
+        let check = session.enable_sync_check()?;
+        let context = session.get_app_context()?;
+
+        if args.clean {
+            unsync_codeowners(&context).await?;
+        } else {
+            let workspace_graph = session.get_workspace_graph().await?;
+
+            sync_codeowners(&context, &workspace_graph, true).await?;
+        }
+
+        return report_check(&session, &check);
+    }
+
     let done = create_progress_bar("Syncing code owners...");
     let context = session.get_app_context()?;
 
@@ -1,3 +1,4 @@
+use super::report_check;
 use crate::helpers::create_progress_bar;
 use crate::session::CliSession;
 use clap::Args;
@@ -9,10 +10,22 @@ use tracing::instrument;
 pub struct SyncConfigSchemasArgs {
     #[arg(long, help = "Bypass cache and force create schemas")]
     force: bool,
+
+    #[arg(skip)]
+    pub check: bool,
 }
 
 #[instrument(skip_all)]
 pub async fn sync(session: CliSession, args: SyncConfigSchemasArgs) -> AppResult {
+    if args.check {
+        let check = session.enable_sync_check()?;
+        let context = session.get_app_context()?;
+
+        sync_config_schemas(&context, true).await?;
+
+        return report_check(&session, &check);
+    }
+
     let done = create_progress_bar("Generating configuration schemas...");
 
     let context = session.get_app_context()?;
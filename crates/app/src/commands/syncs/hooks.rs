@@ -1,3 +1,4 @@
+use super::report_check;
 use crate::helpers::create_progress_bar;
 use crate::session::CliSession;
 use clap::Args;
@@ -13,6 +14,9 @@ pub struct SyncHooksArgs {
 
     #[arg(long, help = "Bypass cache and force create hooks")]
     force: bool,
+
+    #[arg(skip)]
+    pub check: bool,
 }
 
 #[instrument(skip_all)]
@@ -30,6 +34,19 @@ pub async fn sync(session: CliSession, args: SyncHooksArgs) -> AppResult {
         return Ok(None);
     }
 
+    if args.check {
+        let check = session.enable_sync_check()?;
+        let context = session.get_app_context()?;
+
+        if args.clean {
+            unsync_vcs_hooks(&context).await?;
+        } else {
+            sync_vcs_hooks(&context, true).await?;
+        }
+
+        return report_check(&session, &check);
+    }
+
     let done = create_progress_bar(format!(
         "Syncing {} hooks...",
         session.workspace_config.vcs.manager
@@ -2,3 +2,64 @@ pub mod codeowners;
 pub mod config_schemas;
 pub mod hooks;
 pub mod projects;
+
+use crate::session::CliSession;
+use diffy::{DiffOptions, PatchFormatter};
+use moon_common::path::to_virtual_string;
+use moon_common::SyncCheck;
+use starbase::AppResult;
+use starbase_styles::color;
+
+/// Print a diff for all files that have drifted from their synced state,
+/// and exit with a non-zero code if any drift was detected.
+pub fn report_check(session: &CliSession, check: &SyncCheck) -> AppResult {
+    let result = check.finish()?;
+    let console = session.console.stdout();
+
+    if result.is_empty() {
+        console.write_line("Everything is in sync")?;
+        console.flush()?;
+
+        return Ok(None);
+    }
+
+    let formatter = if color::no_color() {
+        PatchFormatter::new()
+    } else {
+        PatchFormatter::new().with_color()
+    };
+
+    for drift in &result.files {
+        let file = to_virtual_string(
+            drift
+                .path
+                .strip_prefix(&session.workspace_root)
+                .unwrap_or(&drift.path),
+        )?;
+
+        let patch = DiffOptions::new()
+            .set_original_filename(format!("a/{file}"))
+            .set_modified_filename(format!("b/{file}"))
+            .create_patch(
+                drift.current.as_deref().unwrap_or_default(),
+                drift.expected.as_deref().unwrap_or_default(),
+            );
+
+        console.write_line(formatter.fmt_patch(&patch).to_string())?;
+    }
+
+    if !result.operations.is_empty() {
+        console.print_entry_list("Pending operations", &result.operations)?;
+        console.write_newline()?;
+    }
+
+    console.write_line(format!(
+        "Workspace is out of sync ({} files, {} operations), run {} to fix",
+        result.files.len(),
+        result.operations.len(),
+        color::shell("moon sync")
+    ))?;
+    console.flush()?;
+
+    Ok(Some(1))
+}
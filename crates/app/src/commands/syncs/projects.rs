@@ -1,16 +1,35 @@
+use super::report_check;
 use crate::components::run_action_pipeline;
 use crate::helpers::create_progress_bar;
 use crate::session::CliSession;
+use clap::Args;
 use starbase::AppResult;
 use tracing::instrument;
 
+#[derive(Args, Clone, Debug, Default)]
+pub struct SyncProjectsArgs {
+    #[arg(skip)]
+    pub check: bool,
+}
+
 #[instrument(skip_all)]
-pub async fn sync(session: CliSession) -> AppResult {
-    let done = create_progress_bar("Syncing projects...");
+pub async fn sync(session: CliSession, args: SyncProjectsArgs) -> AppResult {
+    let check = if args.check {
+        Some(session.enable_sync_check()?)
+    } else {
+        None
+    };
+
+    let done = create_progress_bar(if check.is_some() {
+        "Checking projects..."
+    } else {
+        "Syncing projects..."
+    });
 
     let workspace_graph = session.get_workspace_graph().await?;
     let mut project_count = 0;
     let mut action_graph_builder = session.build_action_graph(&workspace_graph).await?;
+    action_graph_builder.set_sync_check(check.is_some());
 
     for project in workspace_graph.projects.get_all_unexpanded() {
         action_graph_builder.sync_project(project)?;
@@ -24,6 +43,12 @@ pub async fn sync(session: CliSession) -> AppResult {
     )
     .await?;
 
+    if let Some(check) = check {
+        done(format!("Checked {project_count} projects"), true);
+
+        return report_check(&session, &check);
+    }
+
     done(
         format!("Successfully synced {project_count} projects"),
         true,
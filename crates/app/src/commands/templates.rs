@@ -1,9 +1,21 @@
 use crate::session::CliSession;
-use moon_codegen::{templates_command, CodeGenerator, TemplatesArgs};
+use clap::Subcommand;
+use moon_codegen::{
+    templates_command, templates_test_command, CodeGenerator, TemplatesArgs, TemplatesTestArgs,
+};
 use starbase::AppResult;
 use std::sync::Arc;
 use tracing::instrument;
 
+#[derive(Clone, Debug, Subcommand)]
+pub enum TemplatesCommands {
+    #[command(
+        name = "test",
+        about = "Render templates with their fixtures and compare against snapshots."
+    )]
+    Test(TemplatesTestArgs),
+}
+
 #[instrument(skip_all)]
 pub async fn templates(session: CliSession, args: TemplatesArgs) -> AppResult {
     let generator = CodeGenerator::new(
@@ -14,3 +26,22 @@ pub async fn templates(session: CliSession, args: TemplatesArgs) -> AppResult {
 
     templates_command(generator, &session.console, &args).await
 }
+
+#[instrument(skip_all)]
+pub async fn test(session: CliSession, args: TemplatesTestArgs) -> AppResult {
+    let generator = CodeGenerator::new(
+        &session.workspace_root,
+        &session.workspace_config.generator,
+        Arc::clone(&session.moon_env),
+    );
+    let temp_dir = session.get_cache_engine()?.temp_dir.join("templates");
+
+    templates_test_command(
+        generator,
+        &session.console,
+        &args,
+        &session.workspace_root,
+        &temp_dir,
+    )
+    .await
+}
@@ -15,16 +15,16 @@ use tokio::sync::RwLock;
 
 pub async fn run_action_pipeline(
     session: &CliSession,
-    action_context: ActionContext,
+    mut action_context: ActionContext,
     action_graph: ActionGraph,
 ) -> miette::Result<Vec<Action>> {
     let workspace_graph = session.get_workspace_graph().await?;
     let toolchain_registry = session.get_toolchain_registry().await?;
-    let mut pipeline = ActionPipeline::new(
-        session.get_app_context()?,
-        toolchain_registry,
-        workspace_graph,
-    );
+    let app_context = session.get_app_context()?;
+
+    action_context.sync_check = app_context.sync_check.clone();
+
+    let mut pipeline = ActionPipeline::new(app_context, toolchain_registry, workspace_graph);
 
     if let Some(concurrency) = &session.cli.concurrency {
         pipeline.concurrency = *concurrency;
@@ -35,12 +35,16 @@ pub async fn run_action_pipeline(
             pipeline.bail = true;
             pipeline.summarize = cmd.summary;
         }
+        Commands::Generate(_) => {
+            pipeline.bail = true;
+        }
         Commands::Ci(_) => {
             pipeline.report_name = "ciReport.json".into();
             pipeline.summarize = true;
         }
         Commands::Run(cmd) => {
             pipeline.bail = !cmd.no_bail;
+            pipeline.resumable = true;
             pipeline.summarize = cmd.summary;
         }
         _ => {}
@@ -69,6 +73,12 @@ pub async fn create_workspace_graph_context(
         workspace_root: &session.workspace_root,
     };
 
+    register_workspace_graph_events(&context).await;
+
+    Ok(context)
+}
+
+pub async fn register_workspace_graph_events(context: &WorkspaceBuilderContext<'_>) {
     context
         .extend_project
         .on(
@@ -106,6 +116,4 @@ pub async fn create_workspace_graph_context(
             Ok(EventState::Continue)
         })
         .await;
-
-    Ok(context)
 }
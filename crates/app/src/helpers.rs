@@ -1,7 +1,9 @@
 use console::{style, Style};
 use dialoguer::theme::ColorfulTheme;
 use indicatif::{ProgressBar, ProgressStyle};
+use moon_yaml_editor::{format_item, format_mapping, YamlEditor};
 use starbase_styles::color::Color;
+use starbase_utils::yaml::YamlValue;
 use std::time::Duration;
 
 pub fn create_progress_bar<S: AsRef<str>, F: AsRef<str>>(start: S) -> impl FnOnce(F, bool) {
@@ -68,3 +70,96 @@ pub fn create_theme() -> ColorfulTheme {
         unpicked_item_prefix: style(" ".to_string()).for_stderr(),
     }
 }
+
+/// Add globs to the `projects` setting of `.moon/workspace.yml` content. If the
+/// setting only contains sources, they are moved to `sources`, and the globs to `globs`.
+/// The content is edited in place, so that formatting and comments are preserved.
+pub fn add_workspace_project_globs(content: &str, globs: &[String]) -> miette::Result<String> {
+    let mut editor = YamlEditor::new(content);
+    let mut lines = vec![];
+
+    for glob in globs {
+        lines.extend(format_item(&YamlValue::String(glob.to_owned()))?);
+    }
+
+    let Some(index) = editor.find_key(None, "projects") else {
+        let index = editor.insert_key(None, "projects");
+        editor.append_to_block(index, lines);
+
+        return Ok(editor.into_content());
+    };
+
+    let value = editor.parse_value(index)?;
+
+    editor.expand_value(index)?;
+
+    if let YamlValue::Mapping(map) = value {
+        if !map.contains_key("globs") && !map.contains_key("sources") {
+            editor.nest_block(index, "sources");
+        }
+
+        let globs_index = match editor.find_key(Some(index), "globs") {
+            Some(globs_index) => {
+                editor.expand_value(globs_index)?;
+                globs_index
+            }
+            None => editor.insert_key(Some(index), "globs"),
+        };
+
+        editor.append_to_block(globs_index, lines);
+    } else {
+        editor.append_to_block(index, lines);
+    }
+
+    Ok(editor.into_content())
+}
+
+/// Add sources to the `projects` setting of `.moon/workspace.yml` content. If the
+/// setting only contains globs, they are moved to `globs`, and the sources to `sources`.
+/// The content is edited in place, so that formatting and comments are preserved.
+pub fn add_workspace_project_sources<I, K, V>(content: &str, sources: I) -> miette::Result<String>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    let mut editor = YamlEditor::new(content);
+    let lines = format_mapping(sources.into_iter().map(|(id, source)| {
+        (
+            YamlValue::String(id.as_ref().to_owned()),
+            YamlValue::String(source.as_ref().to_owned()),
+        )
+    }))?;
+
+    let Some(index) = editor.find_key(None, "projects") else {
+        let index = editor.insert_key(None, "projects");
+        editor.append_to_block(index, lines);
+
+        return Ok(editor.into_content());
+    };
+
+    let value = editor.parse_value(index)?;
+
+    editor.expand_value(index)?;
+
+    let sources_index = match value {
+        YamlValue::Sequence(list) if !list.is_empty() => {
+            editor.nest_block(index, "globs");
+            editor.insert_key(Some(index), "sources")
+        }
+        YamlValue::Mapping(map) if map.contains_key("globs") || map.contains_key("sources") => {
+            match editor.find_key(Some(index), "sources") {
+                Some(sources_index) => {
+                    editor.expand_value(sources_index)?;
+                    sources_index
+                }
+                None => editor.insert_key(Some(index), "sources"),
+            }
+        }
+        _ => index,
+    };
+
+    editor.append_to_block(sources_index, lines);
+
+    Ok(editor.into_content())
+}
@@ -0,0 +1,131 @@
+use crate::components::register_workspace_graph_events;
+use crate::session::CliSession;
+use crate::systems::startup;
+use moon_affected::is_task_config_file;
+use moon_common::path::WorkspaceRelativePathBuf;
+use moon_task::{Target, Task};
+use moon_workspace::{
+    ExtendProjectEvent, ExtendProjectGraphEvent, WorkspaceBuilder, WorkspaceBuilderContext,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use starbase_events::Emitter;
+use starbase_styles::color;
+use std::path::Path;
+use std::process;
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+/// Query the resolved tasks at the base revision, so that they can be compared
+/// against the current tasks, but only when task configuration files have been
+/// touched. If the tasks could not be resolved, a warning is logged and
+/// `None` is returned, so that affected detection can fall back to touched files.
+pub async fn query_base_tasks(
+    session: &CliSession,
+    revision: &str,
+    touched_files: &FxHashSet<WorkspaceRelativePathBuf>,
+) -> miette::Result<Option<FxHashMap<Target, Arc<Task>>>> {
+    if !touched_files.iter().any(is_task_config_file) {
+        return Ok(None);
+    }
+
+    debug!(
+        revision,
+        "Configuration files have been touched, resolving tasks from the base revision to compare against"
+    );
+
+    let vcs = session.get_vcs_adapter()?;
+    let dir = session
+        .moon_env
+        .temp_dir
+        .join("checkouts")
+        .join(format!("base-{}", process::id()));
+
+    let result = match vcs.checkout_revision(revision, &dir).await {
+        Ok(root) => load_tasks_from_root(session, &root).await,
+        Err(error) => Err(error),
+    };
+
+    // Cleanup is best effort, and shouldn't fail the run
+    if dir.exists() {
+        if let Err(error) = vcs.remove_checkout(&dir).await {
+            warn!(
+                checkout = ?dir,
+                "Failed to remove checkout of base revision {}: {error}",
+                color::hash(revision),
+            );
+        }
+    }
+
+    match result {
+        Ok(tasks) => Ok(Some(tasks)),
+        Err(error) => {
+            warn!(
+                "Failed to resolve tasks from base revision {}, falling back to touched files: {error}",
+                color::hash(revision),
+            );
+
+            Ok(None)
+        }
+    }
+}
+
+async fn load_tasks_from_root(
+    session: &CliSession,
+    root: &Path,
+) -> miette::Result<FxHashMap<Target, Arc<Task>>> {
+    let (workspace_config, tasks_config, toolchain_config) = tokio::try_join!(
+        startup::load_workspace_config(session.config_loader.clone(), root),
+        startup::load_tasks_configs(session.config_loader.clone(), root),
+        startup::load_toolchain_config(
+            session.config_loader.clone(),
+            session.proto_env.clone(),
+            root,
+            root,
+        ),
+    )?;
+
+    let context = WorkspaceBuilderContext {
+        config_loader: &session.config_loader,
+        enabled_toolchains: toolchain_config.get_enabled(),
+        extend_project: Emitter::<ExtendProjectEvent>::new(),
+        extend_project_graph: Emitter::<ExtendProjectGraphEvent>::new(),
+        inherited_tasks: &tasks_config,
+        toolchain_config: &toolchain_config,
+        vcs: None,
+        working_dir: root,
+        workspace_config: &workspace_config,
+        workspace_root: root,
+    };
+
+    register_workspace_graph_events(&context).await;
+
+    let workspace_graph = WorkspaceBuilder::new(context).await?.build().await?;
+    let base_root = root.to_string_lossy();
+    let head_root = session.workspace_root.to_string_lossy();
+    let mut tasks = FxHashMap::default();
+
+    for task in workspace_graph.get_tasks_with_internal()? {
+        let mut task = (*task).clone();
+
+        // Tokens like `$workspaceRoot` expand to absolute paths,
+        // so rewrite them to avoid false positives when comparing
+        task.command = task.command.replace(base_root.as_ref(), &head_root);
+        task.script = task
+            .script
+            .map(|script| script.replace(base_root.as_ref(), &head_root));
+
+        for arg in task.args.iter_mut() {
+            *arg = arg.replace(base_root.as_ref(), &head_root);
+        }
+
+        for value in task.env.values_mut() {
+            *value = value.replace(base_root.as_ref(), &head_root);
+        }
+
+        tasks.insert(task.target.clone(), Arc::new(task));
+    }
+
+    debug!("Resolved {} tasks from the base revision", tasks.len());
+
+    Ok(tasks)
+}
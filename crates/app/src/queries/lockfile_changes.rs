@@ -0,0 +1,391 @@
+use crate::session::CliSession;
+use moon_common::path::WorkspaceRelativePathBuf;
+use moon_common::{color, Id};
+use moon_lang::{
+    collect_lockfile_dependencies, collect_lockfile_dependents, diff_lockfile_dependencies,
+    LockfileDependencyGraph, LockfileDependencyVersions,
+};
+use moon_node_lang::{bun, npm, pnpm, yarn, PackageJsonCache};
+use moon_project::Project;
+use moon_python_lang::uv;
+use moon_rust_lang::{cargo_lock, cargo_toml::CargoTomlCache};
+use moon_workspace_graph::WorkspaceGraph;
+use rustc_hash::{FxHashMap, FxHashSet};
+use starbase_utils::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+const LOCKFILES: [&str; 7] = [
+    "Cargo.lock",
+    "package-lock.json",
+    "pnpm-lock.yaml",
+    "yarn.lock",
+    "bun.lock",
+    "bun.lockb",
+    "uv.lock",
+];
+
+// Node.js lockfiles, where projects are located by the dependencies in their `package.json`
+const NODE_LOCKFILES: [&str; 5] = [
+    "package-lock.json",
+    "pnpm-lock.yaml",
+    "yarn.lock",
+    "bun.lock",
+    "bun.lockb",
+];
+
+type LoadedLockfile = (LockfileDependencyVersions, LockfileDependencyGraph);
+
+/// A third-party package, and its resolved version, from a lockfile.
+#[derive(Debug)]
+pub struct LockfilePackage {
+    pub lockfile: &'static str,
+    pub name: String,
+    pub version: String,
+}
+
+/// Return true if the file is a lockfile that can be diffed between revisions.
+pub fn is_supported_lockfile(file: &WorkspaceRelativePathBuf) -> bool {
+    file.file_name()
+        .is_some_and(|name| LOCKFILES.contains(&name))
+}
+
+/// Diff each touched lockfile against the base revision to determine which
+/// packages have changed, and return the projects that depend on those packages,
+/// either directly or transitively. Lockfiles that could not be diffed are not
+/// returned, so that affected detection can fall back to touched files.
+pub async fn query_lockfile_changes(
+    session: &CliSession,
+    workspace_graph: &WorkspaceGraph,
+    revision: &str,
+    touched_files: &FxHashSet<WorkspaceRelativePathBuf>,
+) -> miette::Result<FxHashMap<WorkspaceRelativePathBuf, FxHashSet<Id>>> {
+    let lockfiles = touched_files
+        .iter()
+        .filter(|file| is_supported_lockfile(file))
+        .collect::<Vec<_>>();
+
+    let mut changes = FxHashMap::default();
+
+    if lockfiles.is_empty() {
+        return Ok(changes);
+    }
+
+    let projects = workspace_graph.get_projects()?;
+
+    for (index, lockfile) in lockfiles.into_iter().enumerate() {
+        let head_path = lockfile.to_logical_path(&session.workspace_root);
+
+        if !head_path.exists() {
+            continue;
+        }
+
+        debug!(
+            revision,
+            lockfile = lockfile.as_str(),
+            "Lockfile has been touched, diffing against the base revision to determine changed packages"
+        );
+
+        let base_dir = session
+            .moon_env
+            .temp_dir
+            .join("lockfiles")
+            .join(format!("base-{}-{index}", process::id()));
+
+        let result = match load_base_lockfile(session, revision, lockfile, &base_dir).await {
+            Ok(base_path) => diff_lockfile(lockfile, &base_path, &head_path).and_then(|packages| {
+                get_dependent_projects(session, lockfile, &packages, &projects)
+            }),
+            Err(error) => Err(error),
+        };
+
+        if base_dir.exists() {
+            fs::remove_dir_all(&base_dir)?;
+        }
+
+        match result {
+            Ok(project_ids) => {
+                changes.insert(lockfile.to_owned(), project_ids);
+            }
+            Err(error) => {
+                warn!(
+                    "Failed to diff lockfile {} against base revision {}, falling back to touched files: {error}",
+                    color::file(lockfile),
+                    color::hash(revision),
+                );
+            }
+        };
+    }
+
+    Ok(changes)
+}
+
+/// Return the third-party packages that the provided projects depend on, either
+/// directly or transitively, from the closest supported lockfiles between the first
+/// project's root and the workspace root. Development dependencies are excluded
+/// for Node.js and uv, but not for Cargo, as `Cargo.lock` does not record the kind
+/// of each dependency. Binary `bun.lockb` lockfiles are not supported and will error.
+pub fn query_lockfile_packages(
+    workspace_root: &Path,
+    projects: &[Arc<Project>],
+) -> miette::Result<Vec<LockfilePackage>> {
+    let mut packages = vec![];
+
+    let Some(first_project) = projects.first() else {
+        return Ok(packages);
+    };
+
+    for lockfile_name in LOCKFILES {
+        let Some(path) = fs::find_upwards_until(lockfile_name, &first_project.root, workspace_root)
+        else {
+            continue;
+        };
+
+        let lockfile =
+            WorkspaceRelativePathBuf::from_path(path.strip_prefix(workspace_root).unwrap())
+                .unwrap();
+
+        debug!(
+            lockfile = lockfile.as_str(),
+            "Resolving third-party packages from lockfile"
+        );
+
+        let (versions, graph) = load_lockfile_for_packages(&lockfile, &path)?;
+        let mut project_names = FxHashSet::default();
+
+        for project in projects {
+            if let Some(names) = get_project_package_names(&lockfile, project, true)? {
+                project_names.extend(names);
+            }
+        }
+
+        // Workspace members are represented by name in these lockfiles,
+        // so they must be excluded from the third-party packages
+        let members = if NODE_LOCKFILES.contains(&lockfile_name) {
+            FxHashSet::default()
+        } else {
+            project_names.clone()
+        };
+
+        for name in collect_lockfile_dependencies(&graph, project_names) {
+            if members.contains(&name) {
+                continue;
+            }
+
+            for version in versions.get(&name).into_iter().flatten() {
+                packages.push(LockfilePackage {
+                    lockfile: lockfile_name,
+                    name: name.clone(),
+                    version: version.to_owned(),
+                });
+            }
+        }
+    }
+
+    Ok(packages)
+}
+
+async fn load_base_lockfile(
+    session: &CliSession,
+    revision: &str,
+    lockfile: &WorkspaceRelativePathBuf,
+    base_dir: &Path,
+) -> miette::Result<PathBuf> {
+    let vcs = session.get_vcs_adapter()?;
+    let contents = vcs
+        .get_file_contents_at_revision(revision, lockfile.as_str())
+        .await?;
+
+    // Keep the original file name, as the parsers rely on it
+    let base_path = base_dir.join(lockfile.file_name().unwrap_or_default());
+
+    fs::write_file(&base_path, contents.as_bytes())?;
+
+    Ok(base_path)
+}
+
+fn load_lockfile(
+    lockfile: &WorkspaceRelativePathBuf,
+    path: &Path,
+) -> miette::Result<LoadedLockfile> {
+    let path = path.to_path_buf();
+
+    Ok(match lockfile.file_name() {
+        Some("Cargo.lock") => (
+            cargo_lock::load_lockfile_dependencies(path.clone())?,
+            cargo_lock::load_lockfile_dependency_graph(path)?,
+        ),
+        Some("package-lock.json") => (
+            npm::load_lockfile_dependencies(path.clone())?,
+            npm::load_lockfile_dependency_graph(path)?,
+        ),
+        Some("pnpm-lock.yaml") => (
+            pnpm::load_lockfile_dependencies(path.clone())?,
+            pnpm::load_lockfile_dependency_graph(path)?,
+        ),
+        Some("yarn.lock") => (
+            yarn::load_lockfile_dependencies(path.clone())?,
+            yarn::load_lockfile_dependency_graph(path)?,
+        ),
+        Some("bun.lock" | "bun.lockb") => {
+            let text = Arc::new(fs::read_file(&path)?);
+
+            (
+                bun::load_lockfile_dependencies(text.clone(), path.clone())?,
+                bun::load_lockfile_dependency_graph(text, path)?,
+            )
+        }
+        Some("uv.lock") => (
+            uv::load_lockfile_dependencies(path.clone())?,
+            uv::load_lockfile_dependency_graph(path)?,
+        ),
+        _ => unreachable!(),
+    })
+}
+
+/// Like [`load_lockfile`], but load resolved versions instead of checksums,
+/// and exclude development and optional dependencies where recorded.
+fn load_lockfile_for_packages(
+    lockfile: &WorkspaceRelativePathBuf,
+    path: &Path,
+) -> miette::Result<LoadedLockfile> {
+    let path = path.to_path_buf();
+
+    Ok(match lockfile.file_name() {
+        Some("Cargo.lock") => (
+            cargo_lock::load_lockfile_package_versions(path.clone())?,
+            cargo_lock::load_lockfile_dependency_graph(path)?,
+        ),
+        Some("package-lock.json") => (
+            npm::load_lockfile_package_versions(path.clone())?,
+            npm::load_lockfile_dependency_graph(path)?,
+        ),
+        Some("pnpm-lock.yaml") => (
+            pnpm::load_lockfile_package_versions(path.clone())?,
+            pnpm::load_lockfile_dependency_graph(path)?,
+        ),
+        Some("yarn.lock") => (
+            yarn::load_lockfile_package_versions(path.clone())?,
+            yarn::load_lockfile_dependency_graph(path)?,
+        ),
+        Some("bun.lock" | "bun.lockb") => {
+            let text = Arc::new(fs::read_file(&path)?);
+
+            (
+                bun::load_lockfile_package_versions(text.clone(), path.clone())?,
+                bun::load_lockfile_dependency_graph(text, path)?,
+            )
+        }
+        Some("uv.lock") => (
+            uv::load_lockfile_package_versions(path.clone())?,
+            uv::load_lockfile_production_dependency_graph(path)?,
+        ),
+        _ => unreachable!(),
+    })
+}
+
+/// Return the packages that have changed, and all packages that depend on them.
+fn diff_lockfile(
+    lockfile: &WorkspaceRelativePathBuf,
+    base_path: &Path,
+    head_path: &Path,
+) -> miette::Result<FxHashSet<String>> {
+    let (base_versions, base_graph) = load_lockfile(lockfile, base_path)?;
+    let (head_versions, mut graph) = load_lockfile(lockfile, head_path)?;
+
+    let changed = diff_lockfile_dependencies(&base_versions, &head_versions);
+
+    debug!(
+        lockfile = lockfile.as_str(),
+        packages = ?changed.iter().collect::<Vec<_>>(),
+        "Found {} changed packages in lockfile",
+        changed.len()
+    );
+
+    // Removed packages only exist in the base graph
+    for (name, deps) in base_graph {
+        graph.entry(name).or_default().extend(deps);
+    }
+
+    Ok(collect_lockfile_dependents(&graph, changed))
+}
+
+fn get_dependent_projects(
+    session: &CliSession,
+    lockfile: &WorkspaceRelativePathBuf,
+    packages: &FxHashSet<String>,
+    projects: &[Arc<Project>],
+) -> miette::Result<FxHashSet<Id>> {
+    let lockfile_dir = lockfile
+        .parent()
+        .map(|dir| dir.to_logical_path(&session.workspace_root))
+        .unwrap_or_else(|| session.workspace_root.clone());
+    let mut project_ids = FxHashSet::default();
+
+    for project in projects {
+        // Projects outside of the lockfile's directory, or without a manifest,
+        // can't be located in the lockfile, so assume they are affected
+        let is_affected = if project.root.starts_with(&lockfile_dir) {
+            match get_project_package_names(lockfile, project, false)? {
+                Some(names) => names.iter().any(|name| packages.contains(name)),
+                None => true,
+            }
+        } else {
+            true
+        };
+
+        if is_affected {
+            project_ids.insert(project.id.clone());
+        }
+    }
+
+    Ok(project_ids)
+}
+
+/// Return the names of packages that the project is represented by, or depends on,
+/// within the lockfile. For Node.js, workspace members aren't reliably named in the
+/// lockfile, so use the direct dependencies from the project's `package.json`,
+/// optionally excluding development dependencies.
+fn get_project_package_names(
+    lockfile: &WorkspaceRelativePathBuf,
+    project: &Project,
+    production: bool,
+) -> miette::Result<Option<Vec<String>>> {
+    let names = match lockfile.file_name() {
+        Some("Cargo.lock") => CargoTomlCache::read(&project.root)?
+            .and_then(|manifest| manifest.package)
+            .map(|package| vec![package.name]),
+        Some(name) if NODE_LOCKFILES.contains(&name) => {
+            PackageJsonCache::read(&project.root)?.map(|package| {
+                [
+                    package.data.dependencies,
+                    if production {
+                        None
+                    } else {
+                        package.data.dev_dependencies
+                    },
+                    package.data.optional_dependencies,
+                    package.data.peer_dependencies,
+                ]
+                .into_iter()
+                .flatten()
+                .flat_map(|deps| deps.into_keys())
+                .collect()
+            })
+        }
+        Some("uv.lock") => uv::PyProjectTomlCache::read(&project.root)?
+            .and_then(|data| data.project)
+            .map(|project| vec![normalize_python_name(&project.name)]),
+        _ => None,
+    };
+
+    Ok(names)
+}
+
+// Package names are normalized in the lockfile
+// https://packaging.python.org/en/latest/specifications/name-normalization/
+fn normalize_python_name(name: &str) -> String {
+    name.to_lowercase().replace(['_', '.'], "-")
+}
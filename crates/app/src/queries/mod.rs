@@ -1,5 +1,7 @@
+pub mod base_tasks;
 pub mod hash;
 pub mod hash_diff;
+pub mod lockfile_changes;
 pub mod projects;
 pub mod tasks;
 pub mod touched_files;
@@ -23,6 +23,9 @@ pub struct QueryTouchedFilesOptions {
 
 #[derive(Default, Deserialize, Serialize)]
 pub struct QueryTouchedFilesResult {
+    /// The revision that files were compared against, if known.
+    #[serde(skip)]
+    pub base_revision: Option<String>,
     pub files: FxHashSet<WorkspaceRelativePathBuf>,
     pub options: QueryTouchedFilesOptions,
     pub shallow: bool,
@@ -75,10 +78,14 @@ pub async fn query_touched_files(
         check_shallow!(vcs);
     }
 
+    let base_revision;
+
     // Check locally touched files
     let touched_files_map = if options.local {
         trace!("Against local");
 
+        base_revision = "HEAD".to_owned();
+
         vcs.get_touched_files().await?
     }
     // Otherwise compare against previous commit
@@ -88,6 +95,8 @@ pub async fn query_touched_files(
             current_branch
         );
 
+        base_revision = "HEAD~1".to_owned();
+
         vcs.get_touched_files_against_previous_revision(&default_branch)
             .await?
     }
@@ -99,6 +108,8 @@ pub async fn query_touched_files(
             head,
         );
 
+        base_revision = base.to_owned();
+
         vcs.get_touched_files_between_revisions(base, head).await?
     };
 
@@ -146,6 +157,7 @@ pub async fn query_touched_files(
     );
 
     Ok(QueryTouchedFilesResult {
+        base_revision: Some(base_revision),
         files: touched_files,
         options: options.to_owned(),
         shallow: false,
@@ -7,10 +7,10 @@ use async_trait::async_trait;
 use moon_action_graph::ActionGraphBuilder;
 use moon_app_context::AppContext;
 use moon_cache::CacheEngine;
-use moon_common::{is_ci, is_test_env};
+use moon_common::{is_ci, is_test_env, SyncCheck};
 use moon_config::{ConfigLoader, InheritedTasksManager, ToolchainConfig, WorkspaceConfig};
 use moon_console::Console;
-use moon_console_reporter::DefaultReporter;
+use moon_console_reporter::{CiReporter, DefaultReporter, ReporterType, TuiReporter};
 use moon_env::MoonEnvironment;
 use moon_extension_plugin::*;
 use moon_plugin::{PluginHostData, PluginId};
@@ -47,6 +47,7 @@ pub struct CliSession {
     cache_engine: OnceCell<Arc<CacheEngine>>,
     extension_registry: OnceCell<Arc<ExtensionRegistry>>,
     project_graph: OnceCell<Arc<ProjectGraph>>,
+    sync_check: OnceCell<Arc<SyncCheck>>,
     task_graph: OnceCell<Arc<TaskGraph>>,
     toolchain_registry: OnceCell<Arc<ToolchainRegistry>>,
     vcs_adapter: OnceCell<Arc<BoxedVcs>>,
@@ -74,6 +75,7 @@ impl CliSession {
             moon_env: Arc::new(MoonEnvironment::default()),
             project_graph: OnceCell::new(),
             proto_env: Arc::new(ProtoEnvironment::default()),
+            sync_check: OnceCell::new(),
             task_graph: OnceCell::new(),
             tasks_config: Arc::new(InheritedTasksManager::default()),
             toolchain_config: Arc::new(ToolchainConfig::default()),
@@ -98,6 +100,7 @@ impl CliSession {
             cli_version: self.cli_version.clone(),
             cache_engine: self.get_cache_engine()?,
             console: Arc::new(self.console.clone()),
+            sync_check: self.sync_check.get().cloned(),
             vcs: self.get_vcs_adapter()?,
             toolchain_config: Arc::clone(&self.toolchain_config),
             workspace_config: Arc::clone(&self.workspace_config),
@@ -106,6 +109,17 @@ impl CliSession {
         }))
     }
 
+    /// Enable check mode, so that sync operations track drift
+    /// instead of mutating files. Applies to app contexts created afterwards.
+    pub fn enable_sync_check(&self) -> miette::Result<Arc<SyncCheck>> {
+        let item = self.sync_check.get_or_try_init(|| {
+            self.get_cache_engine()
+                .map(|engine| Arc::new(SyncCheck::new(&engine.temp_dir.join("sync-check"))))
+        })?;
+
+        Ok(Arc::clone(item))
+    }
+
     pub fn get_cache_engine(&self) -> miette::Result<Arc<CacheEngine>> {
         let item = self
             .cache_engine
@@ -214,7 +228,7 @@ impl CliSession {
             self.cli.command,
             Commands::Bin(_)
                 | Commands::Docker {
-                    command: DockerCommands::Prune {}
+                    command: DockerCommands::Prune(_)
                 }
                 | Commands::Node { .. }
                 | Commands::Teardown
@@ -238,7 +252,20 @@ impl CliSession {
 impl AppSession for CliSession {
     /// Setup initial state for the session. Order is very important!!!
     async fn startup(&mut self) -> AppResult {
-        self.console.set_reporter(DefaultReporter::default());
+        // Use the CI reporter when running in CI, unless explicitly configured
+        let reporter = self.cli.reporter.unwrap_or_else(|| {
+            if ci_env::is_ci() && !is_test_env() {
+                ReporterType::Ci
+            } else {
+                ReporterType::Default
+            }
+        });
+
+        match reporter {
+            ReporterType::Ci => self.console.set_reporter(CiReporter::default()),
+            ReporterType::Default => self.console.set_reporter(DefaultReporter::default()),
+            ReporterType::Tui => self.console.set_reporter(TuiReporter::default()),
+        };
 
         // Determine paths
 
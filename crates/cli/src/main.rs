@@ -8,6 +8,8 @@ use moon_app::commands::migrate::MigrateCommands;
 use moon_app::commands::node::NodeCommands;
 use moon_app::commands::query::QueryCommands;
 use moon_app::commands::sync::SyncCommands;
+use moon_app::commands::syncs::projects::SyncProjectsArgs;
+use moon_app::commands::templates::TemplatesCommands;
 use moon_app::{commands, systems::bootstrap, Cli, CliSession, Commands};
 use starbase::diagnostics::IntoDiagnostic;
 use starbase::tracing::TracingOptions;
@@ -145,7 +147,7 @@ async fn main() -> MainResult {
                 }
                 Commands::Docker { command } => match command {
                     DockerCommands::File(args) => commands::docker::file(session, args).await,
-                    DockerCommands::Prune => commands::docker::prune(session).await,
+                    DockerCommands::Prune(args) => commands::docker::prune(session, args).await,
                     DockerCommands::Scaffold(args) => {
                         commands::docker::scaffold(session, args).await
                     }
@@ -153,11 +155,16 @@ async fn main() -> MainResult {
                 },
                 Commands::Ext(args) => commands::ext::ext(session, args).await,
                 Commands::Generate(args) => commands::generate::generate(session, args).await,
+                Commands::Imports(args) => commands::imports::imports(session, args).await,
                 Commands::Init(args) => commands::init::init(session, args).await,
                 Commands::Migrate {
                     command,
                     skip_touched_files_check,
                 } => match command {
+                    MigrateCommands::FromNx(mut args) => {
+                        args.skip_touched_files_check = skip_touched_files_check;
+                        commands::migrate::from_nx(session, args).await
+                    }
                     MigrateCommands::FromPackageJson(mut args) => {
                         args.skip_touched_files_check = skip_touched_files_check;
                         commands::migrate::from_package_json(session, args).await
@@ -169,6 +176,7 @@ async fn main() -> MainResult {
                         commands::node::run_script(session, args).await
                     }
                 },
+                Commands::Owners(args) => commands::owners::owners(session, args).await,
                 Commands::Project(args) => commands::project::project(session, args).await,
                 Commands::ProjectGraph(args) => {
                     commands::graph::project::project_graph(session, args).await
@@ -186,23 +194,35 @@ async fn main() -> MainResult {
                 },
                 Commands::Run(args) => commands::run::run(session, args).await,
                 Commands::Setup => commands::setup::setup(session).await,
-                Commands::Sync { command } => match command {
-                    Some(SyncCommands::Codeowners(args)) => {
+                Commands::Stats(args) => commands::stats::stats(session, args).await,
+                Commands::Sync { command, check } => match command {
+                    Some(SyncCommands::Codeowners(mut args)) => {
+                        args.check = check;
                         commands::syncs::codeowners::sync(session, args).await
                     }
-                    Some(SyncCommands::ConfigSchemas(args)) => {
+                    Some(SyncCommands::ConfigSchemas(mut args)) => {
+                        args.check = check;
                         commands::syncs::config_schemas::sync(session, args).await
                     }
-                    Some(SyncCommands::Hooks(args)) => {
+                    Some(SyncCommands::Hooks(mut args)) => {
+                        args.check = check;
                         commands::syncs::hooks::sync(session, args).await
                     }
-                    Some(SyncCommands::Projects) => commands::syncs::projects::sync(session).await,
-                    None => commands::sync::sync(session).await,
+                    Some(SyncCommands::Projects(mut args)) => {
+                        args.check = check;
+                        commands::syncs::projects::sync(session, args).await
+                    }
+                    None => commands::sync::sync(session, SyncProjectsArgs { check }).await,
                 },
                 Commands::Task(args) => commands::task::task(session, args).await,
                 Commands::TaskGraph(args) => commands::graph::task::task_graph(session, args).await,
                 Commands::Teardown => commands::teardown::teardown().await,
-                Commands::Templates(args) => commands::templates::templates(session, args).await,
+                Commands::Templates { command, args } => match command {
+                    Some(TemplatesCommands::Test(args)) => {
+                        commands::templates::test(session, args).await
+                    }
+                    None => commands::templates::templates(session, args).await,
+                },
                 Commands::Upgrade => commands::upgrade::upgrade(session).await,
             }
         })
@@ -1,6 +1,6 @@
 use moon_test_utils::{
     assert_snapshot, create_sandbox_with_config, get_project_graph_aliases_fixture_configs,
-    get_tasks_fixture_configs,
+    get_tasks_fixture_configs, predicates::prelude::*,
 };
 
 mod action_graph {
@@ -127,6 +127,49 @@ mod action_graph {
         assert_snapshot!(assert.output());
     }
 
+    #[test]
+    fn outputs_mermaid() {
+        let (workspace_config, toolchain_config, tasks_config) = get_tasks_fixture_configs();
+
+        let sandbox = create_sandbox_with_config(
+            "tasks",
+            Some(workspace_config),
+            Some(toolchain_config),
+            Some(tasks_config),
+        );
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("action-graph").arg("--mermaid").arg("basic:lint");
+        });
+
+        let output = assert.output();
+
+        assert!(predicate::str::starts_with("flowchart TD").eval(&output));
+        assert!(predicate::str::contains(r#"n4["RunTask(basic:lint)"]"#).eval(&output));
+        assert!(predicate::str::contains("n4 --> n3").eval(&output));
+    }
+
+    #[test]
+    fn outputs_graphml() {
+        let (workspace_config, toolchain_config, tasks_config) = get_tasks_fixture_configs();
+
+        let sandbox = create_sandbox_with_config(
+            "tasks",
+            Some(workspace_config),
+            Some(toolchain_config),
+            Some(tasks_config),
+        );
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("action-graph").arg("--graphml").arg("basic:lint");
+        });
+
+        let output = assert.output();
+
+        assert!(predicate::str::contains(r#"<data key="type">run-task</data>"#).eval(&output));
+        assert!(predicate::str::contains(r#"<data key="target">basic:lint</data>"#).eval(&output));
+    }
+
     mod aliases {
         use super::*;
 
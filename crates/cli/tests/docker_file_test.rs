@@ -141,4 +141,55 @@ mod dockerfile {
         assert!(file.contains("moon run with-config:serve"));
         assert!(file.contains("moon docker prune"));
     }
+
+    #[test]
+    fn supports_multiple_projects() {
+        let sandbox = create_moon_sandbox("dockerfile");
+
+        sandbox
+            .run_bin(|cmd| {
+                cmd.args(["docker", "file", "has-tasks,with-config", "--defaults"]);
+            })
+            .success();
+
+        let file = fs::read_to_string(sandbox.path().join("Dockerfile")).unwrap();
+
+        assert!(file.contains("moon docker scaffold has-tasks with-config"));
+        assert!(file.contains("FROM base AS start-has-tasks"));
+        assert!(file.contains("FROM base AS start-with-config"));
+        assert!(file.contains("moon docker prune with-config"));
+        assert!(file.contains("moon run with-config:serve"));
+    }
+
+    #[test]
+    fn supports_runtime_image() {
+        let sandbox = create_moon_sandbox("dockerfile");
+
+        sandbox
+            .run_bin(|cmd| {
+                cmd.args([
+                    "docker",
+                    "file",
+                    "has-tasks",
+                    "--defaults",
+                    "--buildTask",
+                    "build",
+                    "--runtimeImage",
+                    "gcr.io/distroless/base-debian12",
+                ]);
+            })
+            .success();
+
+        let file = fs::read_to_string(sandbox.path().join("has-tasks/Dockerfile")).unwrap();
+
+        assert!(file.contains("FROM build AS runtime"));
+        assert!(file.contains("FROM gcr.io/distroless/base-debian12 AS start"));
+        assert!(file.contains("WORKDIR /app/has-tasks"));
+        assert!(file.contains("COPY has-tasks/sbom.cdx.json /app/sbom.cdx.json"));
+
+        let sbom = fs::read_to_string(sandbox.path().join("has-tasks/sbom.cdx.json")).unwrap();
+
+        assert!(sbom.contains("\"bomFormat\": \"CycloneDX\""));
+        assert!(sbom.contains("\"bom-ref\": \"moon:project/has-tasks\""));
+    }
 }
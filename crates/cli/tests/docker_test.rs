@@ -427,6 +427,28 @@ mod prune {
                 .eval(&assert.output())
         );
     }
+
+    #[test]
+    fn errors_for_unscaffolded_project() {
+        let (workspace_config, toolchain_config, tasks_config) = get_node_fixture_configs();
+
+        let sandbox = create_sandbox_with_config(
+            "node",
+            Some(workspace_config),
+            Some(toolchain_config),
+            Some(tasks_config),
+        );
+
+        write_manifest(sandbox.path(), "node");
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("docker").arg("prune").arg("lifecycles");
+        });
+
+        assert!(
+            predicate::str::contains("Unable to prune project lifecycles").eval(&assert.output())
+        );
+    }
 }
 
 #[cfg(not(target_os = "linux"))]
@@ -446,3 +446,342 @@ mod frontmatter {
         .unwrap());
     }
 }
+
+mod update {
+    use super::*;
+
+    fn generate_with_file(content: &str) -> Sandbox {
+        let sandbox = generate_sandbox();
+        sandbox.create_file("templates/standard/update.txt", content);
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("generate").arg("standard").arg("./test");
+            })
+            .success();
+
+        sandbox
+    }
+
+    #[test]
+    fn writes_a_lock_file() {
+        let sandbox = generate_with_file("a\n");
+
+        let lock = fs::read_to_string(sandbox.path().join("test/.moon-template.lock")).unwrap();
+
+        assert!(lock.contains("\"template\": \"standard\""));
+        assert!(lock.contains("\"update.txt\": \"a\\n\""));
+    }
+
+    #[test]
+    fn errors_when_no_lock() {
+        let sandbox = generate_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("standard")
+                .arg("./test")
+                .arg("--update");
+        });
+
+        assert
+            .failure()
+            .stderr(predicate::str::contains("no template lock exists"));
+    }
+
+    #[test]
+    fn merges_template_and_local_changes() {
+        let sandbox = generate_with_file("a\nb\nc\nd\ne\n");
+        sandbox.create_file("test/update.txt", "a\nB\nc\nd\ne\n");
+        sandbox.create_file("templates/standard/update.txt", "a\nb\nc\nd\nE\n");
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("standard")
+                .arg("./test")
+                .arg("--update");
+        });
+
+        assert
+            .success()
+            .stdout(predicate::str::contains("updated ---> test/update.txt"));
+
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("test/update.txt")).unwrap(),
+            "a\nB\nc\nd\nE\n"
+        );
+    }
+
+    #[test]
+    fn adds_conflict_markers() {
+        let sandbox = generate_with_file("a\nb\nc\n");
+        sandbox.create_file("test/update.txt", "a\nlocal\nc\n");
+        sandbox.create_file("templates/standard/update.txt", "a\ntemplate\nc\n");
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("standard")
+                .arg("./test")
+                .arg("--update");
+        });
+
+        assert
+            .success()
+            .stdout(predicate::str::contains("conflict -> test/update.txt"))
+            .stdout(predicate::str::contains(
+                "Resolve the conflict markers in 1 file",
+            ));
+
+        let content = fs::read_to_string(sandbox.path().join("test/update.txt")).unwrap();
+
+        assert!(content.contains("<<<<<<<"));
+        assert!(content.contains(">>>>>>>"));
+    }
+
+    #[test]
+    fn doesnt_recreate_removed_files() {
+        let sandbox = generate_with_file("a\n");
+        fs::remove_file(sandbox.path().join("test/update.txt")).unwrap();
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("generate")
+                    .arg("standard")
+                    .arg("./test")
+                    .arg("--update");
+            })
+            .success();
+
+        assert!(!sandbox.path().join("test/update.txt").exists());
+    }
+}
+
+mod diff {
+    use super::*;
+
+    #[test]
+    fn fails_and_lists_new_files() {
+        let sandbox = generate_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("standard")
+                .arg("./test")
+                .arg("--dry-run")
+                .arg("--diff");
+        });
+
+        assert
+            .failure()
+            .stdout(predicate::str::contains("created --> test/file.ts"))
+            .stdout(predicate::str::contains("created --> test/image.jpg"))
+            .stdout(predicate::str::contains("5 files would be changed"));
+
+        assert!(!sandbox.path().join("test").exists());
+    }
+
+    #[test]
+    fn passes_when_nothing_changes() {
+        let sandbox = generate_sandbox();
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("generate").arg("standard").arg("./test");
+        });
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("standard")
+                .arg("./test")
+                .arg("--dry-run")
+                .arg("--diff");
+        });
+
+        assert
+            .success()
+            .stdout(predicate::str::contains("unchanged --> test/file.txt"))
+            .stdout(predicate::str::contains("replaced").not())
+            .stdout(predicate::str::contains("would be changed").not());
+    }
+
+    #[test]
+    fn prints_diff_of_modified_files() {
+        let sandbox = generate_sandbox();
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("generate").arg("standard").arg("./test");
+        });
+
+        sandbox.create_file("test/file.txt", "3");
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("standard")
+                .arg("./test")
+                .arg("--dry-run")
+                .arg("--diff");
+        });
+
+        assert
+            .failure()
+            .stdout(predicate::str::contains("--- a/test/file.txt"))
+            .stdout(predicate::str::contains("+++ b/test/file.txt"))
+            .stdout(predicate::str::contains("-3"))
+            .stdout(predicate::str::contains("+2"))
+            .stdout(predicate::str::contains("1 file would be changed"));
+
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("test/file.txt")).unwrap(),
+            "3"
+        );
+    }
+
+    #[test]
+    fn prints_merge_result_of_json_files() {
+        let sandbox = generate_sandbox();
+        sandbox.create_file("test/file.json", r#"{ "other": true }"#);
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("configs")
+                .arg("./test")
+                .arg("--dry-run")
+                .arg("--diff");
+        });
+
+        assert
+            .failure()
+            .stdout(predicate::str::contains("merged ---> test/file.json"))
+            .stdout(predicate::str::contains("+  \"other\": true"))
+            .stdout(predicate::str::contains("+  \"string\": \"abc\""));
+    }
+}
+
+mod post_generate {
+    use super::*;
+
+    fn create_post_template(sandbox: &Sandbox, post_generate: &str) {
+        sandbox.create_file(
+            "templates/post/template.yml",
+            format!(
+                r#"
+title: Post
+description: Post generation
+variables:
+  name:
+    type: string
+    default: 'post'
+postGenerate:
+{post_generate}
+"#
+            ),
+        );
+        sandbox.create_file(
+            "templates/post/moon.yml",
+            "tasks:\n  build:\n    command: 'echo built-{{ name }}'\n",
+        );
+    }
+
+    #[test]
+    fn runs_commands_with_variables() {
+        let sandbox = generate_sandbox();
+        create_post_template(&sandbox, "  commands: ['echo cmd-{{ name }}']");
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("post")
+                .arg("./post")
+                .arg("--defaults");
+        });
+
+        assert
+            .success()
+            .stdout(predicate::str::contains("Running echo cmd-post"));
+    }
+
+    #[test]
+    fn doesnt_run_when_dry_run() {
+        let sandbox = generate_sandbox();
+        create_post_template(&sandbox, "  commands: ['echo cmd-{{ name }}']");
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("post")
+                .arg("./post")
+                .arg("--defaults")
+                .arg("--dryRun");
+        });
+
+        assert
+            .success()
+            .stdout(predicate::str::contains("Running").not());
+    }
+
+    #[test]
+    fn reports_failed_commands() {
+        let sandbox = generate_sandbox();
+        create_post_template(&sandbox, "  commands: ['exit 1', 'echo unreachable']");
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("post")
+                .arg("./post")
+                .arg("--defaults");
+        });
+
+        assert
+            .failure()
+            .stdout(predicate::str::contains("Running echo unreachable").not())
+            .stderr(predicate::str::contains(
+                "Post-generation step exit 1 failed",
+            ));
+
+        assert!(sandbox.path().join("post/moon.yml").exists());
+    }
+
+    #[test]
+    fn adds_project_and_runs_targets() {
+        let sandbox = generate_sandbox();
+        create_post_template(
+            &sandbox,
+            "  addToWorkspace: true\n  targets: ['{{ name }}:build']",
+        );
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("post")
+                .arg("./post")
+                .arg("--defaults");
+        });
+
+        assert
+            .success()
+            .stdout(predicate::str::contains(
+                "Added project post to .moon/workspace.yml",
+            ))
+            .stdout(predicate::str::contains("built-post"));
+
+        assert!(
+            fs::read_to_string(sandbox.path().join(".moon/workspace.yml"))
+                .unwrap()
+                .contains("post")
+        );
+    }
+
+    #[test]
+    fn doesnt_add_existing_project() {
+        let sandbox = generate_sandbox();
+        sandbox.create_file(".moon/workspace.yml", "projects:\n  - '*'\n");
+        create_post_template(&sandbox, "  addToWorkspace: true");
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("generate")
+                .arg("post")
+                .arg("./post")
+                .arg("--defaults");
+        });
+
+        assert
+            .success()
+            .stdout(predicate::str::contains("Added project").not());
+    }
+}
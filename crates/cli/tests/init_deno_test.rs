@@ -0,0 +1,68 @@
+use moon_test_utils::{create_sandbox, predicates::prelude::*};
+use std::fs;
+
+mod init_deno {
+    use super::*;
+
+    #[test]
+    fn infers_version_from_dvmrc() {
+        let sandbox = create_sandbox("init-sandbox");
+        let root = sandbox.path().to_path_buf();
+        let config = root.join(".moon").join("toolchain.yml");
+
+        sandbox.create_file(".dvmrc", "2.1.4");
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("init")
+                .arg("deno")
+                .arg("--yes")
+                .arg("--to")
+                .arg(root);
+        });
+
+        let content = fs::read_to_string(config).unwrap();
+
+        assert!(predicate::str::contains("version: '2.1.4'").eval(&content));
+    }
+
+    #[test]
+    fn detects_lockfile_and_deps_file() {
+        let sandbox = create_sandbox("init-sandbox");
+        let root = sandbox.path().to_path_buf();
+        let config = root.join(".moon").join("toolchain.yml");
+
+        sandbox.create_file("deno.json", r#"{ "imports": {} }"#);
+        sandbox.create_file("deno.lock", "{}");
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("init")
+                .arg("deno")
+                .arg("--yes")
+                .arg("--to")
+                .arg(root);
+        });
+
+        let content = fs::read_to_string(config).unwrap();
+
+        assert!(predicate::str::contains("lockfile: true").eval(&content));
+        assert!(predicate::str::contains("depsFile: 'deno.json'").eval(&content));
+    }
+
+    #[test]
+    fn infers_projects_from_deno_workspace() {
+        let sandbox = create_sandbox("init-sandbox");
+        let root = sandbox.path().to_path_buf();
+        let config = root.join(".moon").join("workspace.yml");
+
+        sandbox.create_file("deno.json", r#"{ "workspace": ["./add", "./subtract"] }"#);
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("init").arg("--yes").arg("--to").arg(root);
+        });
+
+        let content = fs::read_to_string(config).unwrap();
+
+        assert!(predicate::str::contains("- 'add'").eval(&content));
+        assert!(predicate::str::contains("- 'subtract'").eval(&content));
+    }
+}
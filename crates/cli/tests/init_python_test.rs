@@ -0,0 +1,141 @@
+use moon_test_utils::{create_sandbox, predicates::prelude::*};
+use std::fs;
+
+mod init_python {
+    use super::*;
+
+    #[test]
+    fn infers_version_from_python_version() {
+        let sandbox = create_sandbox("init-sandbox");
+        let root = sandbox.path().to_path_buf();
+        let config = root.join(".moon").join("toolchain.yml");
+
+        sandbox.create_file(".python-version", "3.11");
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("init")
+                .arg("python")
+                .arg("--yes")
+                .arg("--to")
+                .arg(root);
+        });
+
+        let content = fs::read_to_string(config).unwrap();
+
+        assert!(predicate::str::contains("version: '3.11.0'").eval(&content));
+    }
+
+    #[test]
+    fn infers_version_from_pyproject() {
+        let sandbox = create_sandbox("init-sandbox");
+        let root = sandbox.path().to_path_buf();
+        let config = root.join(".moon").join("toolchain.yml");
+
+        sandbox.create_file(
+            "pyproject.toml",
+            "[project]\nname = \"app\"\nrequires-python = \">=3.12\"",
+        );
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("init")
+                .arg("python")
+                .arg("--yes")
+                .arg("--to")
+                .arg(root);
+        });
+
+        let content = fs::read_to_string(config).unwrap();
+
+        assert!(predicate::str::contains("version: '3.12.0'").eval(&content));
+    }
+
+    #[test]
+    fn detects_pip_from_requirements() {
+        let sandbox = create_sandbox("init-sandbox");
+        let root = sandbox.path().to_path_buf();
+        let config = root.join(".moon").join("toolchain.yml");
+
+        sandbox.create_file("requirements.txt", "");
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("init")
+                .arg("python")
+                .arg("--yes")
+                .arg("--to")
+                .arg(root);
+        });
+
+        let content = fs::read_to_string(config).unwrap();
+
+        assert!(predicate::str::contains("packageManager: 'pip'").eval(&content));
+    }
+
+    #[test]
+    fn detects_uv_from_lockfile() {
+        let sandbox = create_sandbox("init-sandbox");
+        let root = sandbox.path().to_path_buf();
+        let config = root.join(".moon").join("toolchain.yml");
+
+        sandbox.create_file("uv.lock", "");
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("init")
+                .arg("python")
+                .arg("--yes")
+                .arg("--to")
+                .arg(root);
+        });
+
+        let content = fs::read_to_string(config).unwrap();
+
+        assert!(predicate::str::contains("packageManager: 'uv'").eval(&content));
+    }
+
+    #[test]
+    fn detects_uv_workspace() {
+        let sandbox = create_sandbox("init-sandbox");
+        let root = sandbox.path().to_path_buf();
+        let config = root.join(".moon").join("toolchain.yml");
+
+        sandbox.create_file(
+            "pyproject.toml",
+            "[tool.uv]\nrequired-version = \">=0.5.1\"\n\n[tool.uv.workspace]\nmembers = [\"packages/*\"]",
+        );
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("init")
+                .arg("python")
+                .arg("--yes")
+                .arg("--to")
+                .arg(root);
+        });
+
+        let content = fs::read_to_string(config).unwrap();
+
+        assert!(predicate::str::contains("packageManager: 'uv'").eval(&content));
+        assert!(predicate::str::contains("version: '0.5.1'").eval(&content));
+        assert!(predicate::str::contains("rootVenvOnly: true").eval(&content));
+    }
+
+    #[test]
+    fn infers_projects_from_uv_workspace() {
+        let sandbox = create_sandbox("init-sandbox");
+        let root = sandbox.path().to_path_buf();
+        let config = root.join(".moon").join("workspace.yml");
+
+        sandbox.create_file(
+            "pyproject.toml",
+            "[tool.uv.workspace]\nmembers = [\"libs/*\", \"./apps/api\"]",
+        );
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("init").arg("--yes").arg("--to").arg(root);
+        });
+
+        let content = fs::read_to_string(config).unwrap();
+
+        assert!(predicate::str::contains("- 'libs/*'").eval(&content));
+        assert!(predicate::str::contains("- 'apps/api'").eval(&content));
+        assert!(!predicate::str::contains("packages/*").eval(&content));
+    }
+}
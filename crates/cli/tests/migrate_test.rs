@@ -95,3 +95,150 @@ mod from_package_json {
         assert.success();
     }
 }
+
+mod from_nx {
+    use super::*;
+
+    fn nx_sandbox() -> Sandbox {
+        let workspace_config = PartialWorkspaceConfig {
+            projects: Some(PartialWorkspaceProjects::Globs(string_vec!["apps/*"])),
+            ..PartialWorkspaceConfig::default()
+        };
+
+        create_sandbox_with_config(
+            "migrate-nx",
+            Some(workspace_config),
+            Some(get_default_toolchain()),
+            None,
+        )
+    }
+
+    #[test]
+    fn errors_if_no_nx_json() {
+        let sandbox = migrate_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.args(["migrate", "--skipTouchedFilesCheck", "from-nx"]);
+        });
+
+        assert.failure().stderr(contains("nx.json"));
+    }
+
+    #[test]
+    fn skips_ignored_and_output_projects() {
+        let sandbox = nx_sandbox();
+        sandbox.enable_git();
+        sandbox.create_file(".gitignore", "build/\n");
+
+        let project_json =
+            fs::read_to_string(sandbox.path().join("apps/web/project.json")).unwrap();
+
+        for dir in ["build/apps/web", "dist/apps/web", ".moon/cache/web"] {
+            sandbox.create_file(&format!("{dir}/project.json"), &project_json);
+        }
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.args(["migrate", "--skipTouchedFilesCheck", "from-nx"]);
+        });
+
+        assert.success();
+
+        assert!(sandbox.path().join("apps/web/moon.yml").exists());
+
+        for dir in ["build/apps/web", "dist/apps/web", ".moon/cache/web"] {
+            assert!(!sandbox.path().join(dir).join("moon.yml").exists());
+        }
+    }
+
+    #[test]
+    fn converts_named_inputs_to_file_groups() {
+        let sandbox = nx_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.args(["migrate", "--skipTouchedFilesCheck", "from-nx"]);
+        });
+
+        assert.success();
+
+        let tasks = fs::read_to_string(sandbox.path().join(".moon/tasks.yml")).unwrap();
+
+        assert!(tasks.contains("production:"));
+        assert!(tasks.contains("sharedGlobals:"));
+        assert!(tasks.contains("/tsconfig.base.json"));
+        assert!(tasks.contains("!**/*.spec.ts"));
+        assert!(tasks.contains("$NODE_ENV"));
+        assert!(!tasks.contains("@group"));
+    }
+
+    #[test]
+    fn converts_project_targets() {
+        let sandbox = nx_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.args(["migrate", "--skipTouchedFilesCheck", "from-nx"]);
+        });
+
+        assert.success();
+
+        let web = fs::read_to_string(sandbox.path().join("apps/web/moon.yml")).unwrap();
+
+        assert!(web.contains("type: application"));
+        assert!(web.contains("scope-web"));
+        assert!(web.contains("command: vite build"));
+        assert!(web.contains("^:build"));
+        assert!(web.contains("@group(production)"));
+        assert!(web.contains("dist"));
+        assert!(web.contains("script: echo one && echo two"));
+        assert!(web.contains("acme/utils:build"));
+        assert!(web.contains("preset: server"));
+        assert!(web.contains("command: nx run web:lint"));
+        assert!(!web.contains("id: web"));
+
+        let utils = fs::read_to_string(sandbox.path().join("libs/utils/moon.yml")).unwrap();
+
+        assert!(utils.contains("id: acme/utils"));
+        assert!(utils.contains("type: library"));
+        assert!(utils.contains("command: npm run build"));
+        assert!(utils.contains("command: noop"));
+        assert!(utils.contains("@group(sources)"));
+        assert!(utils.contains("src/**/*"));
+    }
+
+    #[test]
+    fn registers_unmatched_projects() {
+        let sandbox = nx_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.args(["migrate", "--skipTouchedFilesCheck", "from-nx"]);
+        });
+
+        assert.success();
+
+        let workspace = fs::read_to_string(sandbox.path().join(".moon/workspace.yml")).unwrap();
+
+        assert!(workspace.contains("apps/*"));
+        assert!(workspace.contains("acme/utils: libs/utils"));
+        assert!(!workspace.contains("web: apps/web"));
+    }
+
+    #[test]
+    fn reports_unmapped_settings() {
+        let sandbox = nx_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.args(["migrate", "--skipTouchedFilesCheck", "from-nx"]);
+        });
+
+        assert
+            .success()
+            .stdout(contains("plugin `@nx/eslint/plugin` infers targets"))
+            .stdout(contains(
+                "web: implicit dependency `!legacy` is not supported",
+            ))
+            .stdout(contains("web:build: input `^production` from dependencies"))
+            .stdout(contains(
+                "web:lint: executor `@nx/eslint:lint` is not supported",
+            ))
+            .stdout(contains("web:lint: configurations (fix) are not supported"));
+    }
+}
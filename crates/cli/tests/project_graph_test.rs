@@ -1,6 +1,6 @@
 use moon_test_utils::{
     assert_snapshot, create_sandbox_with_config, get_project_graph_aliases_fixture_configs,
-    get_projects_fixture_configs,
+    get_projects_fixture_configs, predicates::prelude::*, Sandbox,
 };
 
 #[test]
@@ -107,6 +107,93 @@ fn outputs_json() {
     assert_ne!(assert.output(), "{}");
 }
 
+mod formats {
+    use super::*;
+
+    fn projects_sandbox() -> Sandbox {
+        let (workspace_config, toolchain_config, tasks_config) = get_projects_fixture_configs();
+
+        create_sandbox_with_config(
+            "projects",
+            Some(workspace_config),
+            Some(toolchain_config),
+            Some(tasks_config),
+        )
+    }
+
+    #[test]
+    fn outputs_mermaid() {
+        let sandbox = projects_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("project-graph").arg("foo").arg("--mermaid");
+        });
+
+        let output = assert.output();
+
+        assert!(predicate::str::starts_with("flowchart TD").eval(&output));
+        assert!(predicate::str::contains(r#"n1["foo"]"#).eval(&output));
+        assert!(predicate::str::contains(r#"n1 -->|"production"| n0"#).eval(&output));
+    }
+
+    #[test]
+    fn outputs_mermaid_with_dependents() {
+        let sandbox = projects_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("project-graph")
+                .arg("bar")
+                .arg("--mermaid")
+                .arg("--dependents");
+        });
+
+        let output = assert.output();
+
+        assert!(predicate::str::contains(r#"["bar"]"#).eval(&output));
+        assert!(predicate::str::contains(r#"["foo"]"#).eval(&output));
+        assert!(!predicate::str::contains(r#"["baz"]"#).eval(&output));
+    }
+
+    #[test]
+    fn outputs_graphml() {
+        let sandbox = projects_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("project-graph").arg("foo").arg("--graphml");
+        });
+
+        let output = assert.output();
+
+        assert!(predicate::str::contains(
+            r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+        )
+        .eval(&output));
+        assert!(predicate::str::contains(
+            r#"<key id="language" for="node" attr.name="language" attr.type="string"/>"#
+        )
+        .eval(&output));
+        assert!(predicate::str::contains(r#"<data key="label">foo</data>"#).eval(&output));
+        assert!(
+            predicate::str::contains(r#"<edge id="e0" source="n1" target="n0">"#).eval(&output)
+        );
+    }
+
+    #[test]
+    fn outputs_cytoscape() {
+        let sandbox = projects_sandbox();
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("project-graph").arg("foo").arg("--cytoscape");
+        });
+
+        let output = assert.output();
+
+        assert!(predicate::str::contains(r#""elements": {"#).eval(&output));
+        assert!(predicate::str::contains(r#""label": "foo""#).eval(&output));
+        assert!(predicate::str::contains(r#""source": "1""#).eval(&output));
+    }
+}
+
 mod aliases {
     use super::*;
 
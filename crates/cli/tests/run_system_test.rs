@@ -321,6 +321,78 @@ mod unix {
         }
     }
 
+    mod resume {
+        use super::*;
+
+        #[test]
+        fn errors_if_no_previous_run() {
+            let sandbox = system_sandbox();
+
+            let assert = sandbox.run_moon(|cmd| {
+                cmd.arg("run").arg("--resume");
+            });
+
+            assert.failure().stderr(predicate::str::contains(
+                "There is no previous run to resume.",
+            ));
+        }
+
+        #[test]
+        fn persists_state_when_failed() {
+            let sandbox = system_sandbox();
+
+            sandbox.run_moon(|cmd| {
+                cmd.arg("run")
+                    .arg("unix:echo")
+                    .arg("unix:exitNonZero")
+                    .arg("--no-bail");
+            });
+
+            let state =
+                fs::read_to_string(sandbox.path().join(".moon/cache/states/resumeState.json"))
+                    .unwrap();
+
+            assert!(state.contains(r#""completedTargets":{"unix:echo":"#));
+            assert!(state.contains(r#""failedTargets":["unix:exitNonZero"]"#));
+        }
+
+        #[test]
+        fn doesnt_persist_state_when_successful() {
+            let sandbox = system_sandbox();
+
+            sandbox.run_moon(|cmd| {
+                cmd.arg("run").arg("unix:echo");
+            });
+
+            assert!(!sandbox
+                .path()
+                .join(".moon/cache/states/resumeState.json")
+                .exists());
+        }
+
+        #[test]
+        fn skips_completed_tasks() {
+            let sandbox = system_sandbox();
+
+            sandbox.run_moon(|cmd| {
+                cmd.arg("run")
+                    .arg("unix:echo")
+                    .arg("unix:exitNonZero")
+                    .arg("--no-bail");
+            });
+
+            let assert = sandbox.run_moon(|cmd| {
+                cmd.arg("run").arg("--resume");
+            });
+
+            let output = assert.output();
+
+            assert!(predicate::str::contains("skipping 1 completed tasks").eval(&output));
+            assert!(predicate::str::contains("unix:exitNonZero").eval(&output));
+            assert!(!predicate::str::contains("hello").eval(&output));
+        }
+    }
+
     mod affected_files {
         use super::*;
 
@@ -1,12 +1,32 @@
 use httpmock::prelude::*;
-use moon_config::PartialNotifierConfig;
+use moon_config::{PartialNotifierConfig, PartialNotifierWebhookConfig};
 use moon_test_utils::{create_sandbox_with_config, get_node_fixture_configs, Sandbox};
 
+fn sandbox_with_webhooks(webhooks: Vec<PartialNotifierWebhookConfig>) -> Sandbox {
+    let (mut workspace_config, toolchain_config, tasks_config) = get_node_fixture_configs();
+
+    workspace_config.notifier = Some(PartialNotifierConfig {
+        webhooks: Some(webhooks),
+        ..PartialNotifierConfig::default()
+    });
+
+    let sandbox = create_sandbox_with_config(
+        "node",
+        Some(workspace_config),
+        Some(toolchain_config),
+        Some(tasks_config),
+    );
+
+    sandbox.enable_git();
+    sandbox
+}
+
 fn sandbox(uri: String) -> Sandbox {
     let (mut workspace_config, toolchain_config, tasks_config) = get_node_fixture_configs();
 
     workspace_config.notifier = Some(PartialNotifierConfig {
         webhook_url: Some(format!("{uri}/webhook")),
+        ..PartialNotifierConfig::default()
     });
 
     let sandbox = create_sandbox_with_config(
@@ -103,3 +123,133 @@ async fn all_webhooks_have_same_uuid() {
 
     mock.assert_hits(22);
 }
+
+mod endpoints {
+    use super::*;
+
+    #[tokio::test]
+    async fn only_sends_allowed_events() {
+        let server = MockServer::start();
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/filtered")
+                .header("X-Moon-Event", "pipeline.completed");
+
+            then.status(200);
+        });
+
+        let sandbox = sandbox_with_webhooks(vec![PartialNotifierWebhookConfig {
+            events: Some(vec!["pipeline.completed".into()]),
+            url: Some(server.url("/filtered")),
+            ..PartialNotifierWebhookConfig::default()
+        }]);
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("run").arg("node:cjs");
+        });
+
+        mock.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn doesnt_send_failures_only_when_passing() {
+        let server = MockServer::start();
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/failures");
+            then.status(200);
+        });
+
+        let sandbox = sandbox_with_webhooks(vec![PartialNotifierWebhookConfig {
+            events: Some(vec!["pipeline.completed".into()]),
+            failures_only: Some(true),
+            url: Some(server.url("/failures")),
+            ..PartialNotifierWebhookConfig::default()
+        }]);
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("run").arg("node:cjs");
+        });
+
+        mock.assert_hits(0);
+    }
+
+    #[tokio::test]
+    async fn batches_action_events() {
+        let server = MockServer::start();
+
+        let batch_mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/batched")
+                .header("X-Moon-Event", "batch");
+
+            then.status(200);
+        });
+
+        let sandbox = sandbox_with_webhooks(vec![PartialNotifierWebhookConfig {
+            batch_size: Some(1000),
+            events: Some(vec!["action.*".into()]),
+            url: Some(server.url("/batched")),
+            ..PartialNotifierWebhookConfig::default()
+        }]);
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("run").arg("node:cjs");
+        });
+
+        // All action events are flushed in a single batch when the pipeline completes
+        batch_mock.assert_hits(1);
+    }
+
+    #[tokio::test]
+    async fn signs_payloads() {
+        let server = MockServer::start();
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/signed")
+                .header_exists("X-Moon-Signature");
+
+            then.status(200);
+        });
+
+        let sandbox = sandbox_with_webhooks(vec![PartialNotifierWebhookConfig {
+            events: Some(vec!["pipeline.*".into()]),
+            secret: Some("WEBHOOK_SECRET".into()),
+            url: Some(server.url("/signed")),
+            ..PartialNotifierWebhookConfig::default()
+        }]);
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("run")
+                .arg("node:cjs")
+                .env("WEBHOOK_SECRET", "abc123");
+        });
+
+        mock.assert_hits(2);
+    }
+
+    #[tokio::test]
+    async fn retries_failed_requests() {
+        let server = MockServer::start();
+
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/retry");
+            then.status(503);
+        });
+
+        let sandbox = sandbox_with_webhooks(vec![PartialNotifierWebhookConfig {
+            events: Some(vec!["pipeline.started".into()]),
+            retries: Some(2),
+            url: Some(server.url("/retry")),
+            ..PartialNotifierWebhookConfig::default()
+        }]);
+
+        sandbox.run_moon(|cmd| {
+            cmd.arg("run").arg("node:cjs");
+        });
+
+        mock.assert_hits(3);
+    }
+}
@@ -1,6 +1,8 @@
 use moon_common::Id;
 use moon_config::{PartialVcsConfig, PartialWorkspaceConfig, PartialWorkspaceProjects};
-use moon_test_utils::{create_sandbox_with_config, get_cases_fixture_configs};
+use moon_test_utils::{
+    create_sandbox_with_config, get_cases_fixture_configs, predicates::prelude::*,
+};
 use rustc_hash::FxHashMap;
 
 mod sync_codeowners {
@@ -45,6 +47,40 @@ mod sync_codeowners {
 
         assert!(!sandbox.path().join(".github/CODEOWNERS").exists());
     }
+
+    #[test]
+    fn check_fails_when_out_of_sync() {
+        let (workspace_config, _, _) = get_cases_fixture_configs();
+        let sandbox = create_sandbox_with_config("cases", Some(workspace_config), None, None);
+
+        let assert = sandbox.run_moon(|cmd| {
+            cmd.arg("sync").arg("codeowners").arg("--check");
+        });
+
+        assert
+            .failure()
+            .stdout(predicate::str::contains("b/.github/CODEOWNERS"));
+
+        assert!(!sandbox.path().join(".github/CODEOWNERS").exists());
+    }
+
+    #[test]
+    fn check_passes_when_in_sync() {
+        let (workspace_config, _, _) = get_cases_fixture_configs();
+        let sandbox = create_sandbox_with_config("cases", Some(workspace_config), None, None);
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("sync").arg("codeowners");
+            })
+            .success();
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("sync").arg("codeowners").arg("--check");
+            })
+            .success();
+    }
 }
 
 mod sync_config_schemas {
@@ -65,6 +101,20 @@ mod sync_config_schemas {
 
         assert!(sandbox.path().join(".moon/cache/schemas").exists());
     }
+
+    #[test]
+    fn check_doesnt_create_schemas_dir() {
+        let (workspace_config, _, _) = get_cases_fixture_configs();
+        let sandbox = create_sandbox_with_config("cases", Some(workspace_config), None, None);
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("sync").arg("config-schemas").arg("--check");
+            })
+            .failure();
+
+        assert!(!sandbox.path().join(".moon/cache/schemas").exists());
+    }
 }
 
 mod sync_hooks {
@@ -147,6 +197,39 @@ mod sync_hooks {
 
         assert!(!hooks_dir.exists());
     }
+
+    #[test]
+    fn check_doesnt_remove_hook_files() {
+        let (mut workspace_config, _, _) = get_cases_fixture_configs();
+
+        workspace_config.vcs = Some(PartialVcsConfig {
+            hooks: Some(FxHashMap::from_iter([(
+                "pre-commit".into(),
+                vec!["moon run :lint".into()],
+            )])),
+            ..Default::default()
+        });
+
+        let sandbox = create_sandbox_with_config("cases", Some(workspace_config), None, None);
+        sandbox.enable_git();
+
+        let hooks_dir = sandbox.path().join(".moon/hooks");
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("sync").arg("hooks");
+            })
+            .success();
+
+        sandbox
+            .run_moon(|cmd| {
+                cmd.arg("sync").arg("hooks").arg("--clean").arg("--check");
+            })
+            .failure();
+
+        assert!(hooks_dir.exists());
+        assert!(sandbox.path().join(".git/hooks/pre-commit").exists());
+    }
 }
 
 mod sync_projects {
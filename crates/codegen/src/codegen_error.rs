@@ -34,6 +34,24 @@ pub enum CodegenError {
     )]
     MissingTemplate(Id),
 
+    #[diagnostic(
+        code(codegen::template_lock::missing),
+        help = "Only code that was generated with a template lock can be updated."
+    )]
+    #[error(
+        "Unable to update generated code, as no template lock exists at {}.",
+        .0.style(Style::Path),
+    )]
+    MissingTemplateLock(PathBuf),
+
+    #[diagnostic(code(codegen::template_lock::mismatch))]
+    #[error(
+        "Unable to update generated code with template {}, as it was originally generated with template {}.",
+        .id.style(Style::Id),
+        .locked_id.style(Style::Id),
+    )]
+    TemplateLockMismatch { id: Id, locked_id: Id },
+
     #[diagnostic(code(codegen::template::duplicate))]
     #[error(
         "Found multiple templates with the same name {}.\nOriginal template at {}.\nCurrent template at {}.",
@@ -79,4 +97,26 @@ pub enum CodegenError {
         #[source]
         error: Box<tera::Error>,
     },
+
+    #[diagnostic(code(codegen::template_file::merge_toml))]
+    #[error(
+        "Failed to merge template file with TOML file {}.",
+        .path.style(Style::Path),
+    )]
+    MergeTomlFailed {
+        path: PathBuf,
+        #[source]
+        error: Box<toml_edit::TomlError>,
+    },
+
+    #[diagnostic(code(codegen::template::interpolate_value))]
+    #[error(
+        "Failed to interpolate variables into {}.",
+        .value.style(Style::Symbol),
+    )]
+    InterpolateValueFailed {
+        value: String,
+        #[source]
+        error: Box<tera::Error>,
+    },
 }
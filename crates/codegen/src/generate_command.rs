@@ -1,5 +1,6 @@
 use crate::codegen_error::CodegenError;
 use crate::template::Template;
+use crate::template_lock::TemplateLock;
 use clap::builder::{
     BoolValueParser, PossibleValuesParser, RangedI64ValueParser, StringValueParser,
 };
@@ -29,8 +30,16 @@ pub struct GenerateArgs {
     )]
     pub defaults: bool,
 
+    #[arg(
+        long,
+        requires = "dry_run",
+        help = "Print a diff of all files that would change, and exit with a non-zero code when there are changes"
+    )]
+    pub diff: bool,
+
     #[arg(
         long = "dryRun",
+        alias = "dry-run",
         help = "Run entire generator process without writing files"
     )]
     pub dry_run: bool,
@@ -41,6 +50,13 @@ pub struct GenerateArgs {
     #[arg(long, help = "Create a new template")]
     pub template: bool,
 
+    #[arg(
+        long,
+        requires = "dest",
+        help = "Update previously generated code with the latest template, using a three-way merge"
+    )]
+    pub update: bool,
+
     // Variable args (after --)
     #[arg(last = true, help = "Arguments to define as variable values")]
     pub vars: Vec<String>,
@@ -220,9 +236,21 @@ pub fn gather_variables(
     args: &GenerateArgs,
     template: &Template,
     console: &Console,
+    lock: Option<&TemplateLock>,
 ) -> miette::Result<TemplateContext> {
     let mut context = parse_args_into_variables(&args.vars, &template.config.variables)?;
 
+    // Inherit values from the previous generation, so that we don't prompt again
+    if let Some(lock) = lock {
+        for (name, value) in &lock.variables {
+            if !context.contains_key(name) && template.config.variables.contains_key(name) {
+                debug!(name, value = ?value, "Inheriting variable from template lock");
+
+                context.insert(name, value);
+            }
+        }
+    }
+
     debug!("Gathering variable values from defaults and user prompts");
 
     let mut variables = template.config.variables.iter().collect::<Vec<_>>();
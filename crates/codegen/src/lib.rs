@@ -4,15 +4,21 @@ mod codegen_error;
 mod filters;
 mod funcs;
 mod generate_command;
+mod merge;
 mod template;
 mod template_file;
+mod template_lock;
+mod template_tester;
 mod templates_command;
 
 pub use asset_file::*;
 pub use codegen::*;
 pub use codegen_error::*;
 pub use generate_command::*;
+pub use merge::*;
 pub use template::*;
 pub use template_file::*;
+pub use template_lock::*;
+pub use template_tester::*;
 pub use templates_command::*;
 pub use tera::Context as TemplateContext;
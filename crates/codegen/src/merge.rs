@@ -0,0 +1,185 @@
+use toml_edit::{DocumentMut, InlineTable, Item, Table, TomlError, Value};
+
+/// Deeply merge the next TOML document into the previous document. Tables
+/// are merged recursively, while all other values are replaced. Since the
+/// previous document is edited in place, its formatting and comments
+/// are preserved where possible.
+pub fn merge_toml(prev: &str, next: &str) -> Result<String, TomlError> {
+    let mut prev = prev.parse::<DocumentMut>()?;
+    let next = next.parse::<DocumentMut>()?;
+
+    merge_toml_table(prev.as_table_mut(), next.as_table());
+
+    Ok(prev.to_string())
+}
+
+fn merge_toml_table(prev: &mut Table, next: &Table) {
+    for (key, next_item) in next.iter() {
+        match (prev.get_mut(key), next_item) {
+            (Some(Item::Table(prev_table)), Item::Table(next_table)) => {
+                merge_toml_table(prev_table, next_table);
+            }
+            (
+                Some(Item::Value(Value::InlineTable(prev_table))),
+                Item::Value(Value::InlineTable(next_table)),
+            ) => {
+                merge_toml_inline_table(prev_table, next_table);
+            }
+            (Some(Item::Value(prev_value)), Item::Value(next_value)) => {
+                replace_toml_value(prev_value, next_value);
+            }
+            _ => {
+                prev.insert(key, next_item.clone());
+            }
+        };
+    }
+}
+
+fn merge_toml_inline_table(prev: &mut InlineTable, next: &InlineTable) {
+    for (key, next_value) in next.iter() {
+        match (prev.get_mut(key), next_value) {
+            (Some(Value::InlineTable(prev_table)), Value::InlineTable(next_table)) => {
+                merge_toml_inline_table(prev_table, next_table);
+            }
+            (Some(prev_value), next_value) => {
+                replace_toml_value(prev_value, next_value);
+            }
+            (None, next_value) => {
+                prev.insert(key, next_value.clone());
+            }
+        };
+    }
+
+    // Inserted values carry their decor from the other document,
+    // so normalize the spacing between key-value pairs
+    prev.fmt();
+}
+
+// Keep the whitespace and comments surrounding the previous value
+fn replace_toml_value(prev: &mut Value, next: &Value) {
+    let decor = prev.decor().clone();
+
+    *prev = next.clone();
+    *prev.decor_mut() = decor;
+}
+
+#[derive(Debug, Default)]
+struct IniSection {
+    name: String,
+    lines: Vec<String>,
+}
+
+fn is_ini_comment(line: &str) -> bool {
+    line.starts_with('#') || line.starts_with(';')
+}
+
+fn parse_ini_section_name(line: &str) -> Option<&str> {
+    let line = line.trim();
+
+    if line.starts_with('[') && line.ends_with(']') {
+        Some(line[1..line.len() - 1].trim())
+    } else {
+        None
+    }
+}
+
+fn parse_ini_key(line: &str) -> Option<&str> {
+    let line = line.trim();
+
+    if line.is_empty() || is_ini_comment(line) || parse_ini_section_name(line).is_some() {
+        return None;
+    }
+
+    line.split_once('=').map(|(key, _)| key.trim())
+}
+
+/// Split INI content into sections, where the first section is unnamed
+/// and contains all lines before the first section header.
+fn parse_ini_sections(content: &str) -> Vec<IniSection> {
+    let mut sections = vec![IniSection::default()];
+
+    for line in content.lines() {
+        if let Some(name) = parse_ini_section_name(line) {
+            sections.push(IniSection {
+                name: name.to_owned(),
+                lines: vec![line.to_owned()],
+            });
+        } else {
+            sections.last_mut().unwrap().lines.push(line.to_owned());
+        }
+    }
+
+    sections
+}
+
+/// Merge the next INI (or `.editorconfig`) content into the previous content
+/// by section. Existing keys are replaced in place, new keys are appended to
+/// the end of their section, and new sections are appended to the end of
+/// the file. Formatting and comments in the previous content are preserved.
+pub fn merge_ini(prev: &str, next: &str) -> String {
+    let mut sections = parse_ini_sections(prev);
+
+    for next_section in parse_ini_sections(next) {
+        let Some(prev_section) = sections
+            .iter_mut()
+            .find(|section| section.name == next_section.name)
+        else {
+            if next_section
+                .lines
+                .iter()
+                .any(|line| !line.trim().is_empty())
+            {
+                // Separate from the previous section with an empty line
+                if let Some(last) = sections.last_mut() {
+                    while last.lines.last().is_some_and(|line| line.trim().is_empty()) {
+                        last.lines.pop();
+                    }
+
+                    if !last.lines.is_empty() {
+                        last.lines.push(String::new());
+                    }
+                }
+
+                sections.push(next_section);
+            }
+
+            continue;
+        };
+
+        for next_line in &next_section.lines {
+            let Some(key) = parse_ini_key(next_line) else {
+                continue;
+            };
+
+            if let Some(prev_line) = prev_section
+                .lines
+                .iter_mut()
+                .find(|line| parse_ini_key(line) == Some(key))
+            {
+                *prev_line = next_line.to_owned();
+            } else {
+                // Insert after the last non-empty line of the section
+                let index = prev_section
+                    .lines
+                    .iter()
+                    .rposition(|line| !line.trim().is_empty())
+                    .map(|index| index + 1)
+                    .unwrap_or(0);
+
+                prev_section.lines.insert(index, next_line.to_owned());
+            }
+        }
+    }
+
+    let mut content = sections
+        .into_iter()
+        .flat_map(|section| section.lines)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if !content.is_empty() && (prev.ends_with('\n') || prev.is_empty()) {
+        content.push('\n');
+    }
+
+    content
+}
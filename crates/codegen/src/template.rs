@@ -1,6 +1,9 @@
 use crate::asset_file::AssetFile;
+use crate::merge::{merge_ini, merge_toml};
 use crate::template_file::{FileState, MergeType, TemplateFile};
+use crate::template_tester::{TEMPLATE_FIXTURES_DIR, TEMPLATE_SNAPSHOTS_DIR};
 use crate::{filters, funcs, CodegenError};
+use diffy::DiffOptions;
 use miette::IntoDiagnostic;
 use moon_common::path::{to_virtual_string, RelativePathBuf};
 use moon_common::Id;
@@ -124,6 +127,20 @@ impl Template {
             }
 
             let source_path = entry.path();
+
+            // These are used for testing the template, so skip them
+            if source_path
+                .strip_prefix(&self.root)
+                .ok()
+                .and_then(|rel| rel.components().next())
+                .is_some_and(|dir| {
+                    dir.as_os_str() == TEMPLATE_FIXTURES_DIR
+                        || dir.as_os_str() == TEMPLATE_SNAPSHOTS_DIR
+                })
+            {
+                continue;
+            }
+
             let source_content = fs::read_file_bytes(&source_path)?;
             let name =
                 self.interpolate_path(source_path.strip_prefix(&self.root).unwrap(), context)?;
@@ -266,6 +283,109 @@ impl Template {
         Ok(RelativePathBuf::from(path))
     }
 
+    /// Render the provided value with the template engine, so that
+    /// variables and filters can be used in configuration.
+    pub fn interpolate_value(&mut self, value: &str, context: &Context) -> miette::Result<String> {
+        Ok(self.engine.render_str(value, context).map_err(|error| {
+            CodegenError::InterpolateValueFailed {
+                value: value.to_owned(),
+                error: Box::new(error),
+            }
+        })?)
+    }
+
+    /// Return true if copying the asset file will create or change the destination.
+    pub fn has_asset_changed(&self, file: &AssetFile) -> miette::Result<bool> {
+        if !file.dest_path.exists() {
+            return Ok(true);
+        }
+
+        Ok(fs::read_file_bytes(&file.dest_path)? != file.content)
+    }
+
+    /// Return the content that will be written to the destination path,
+    /// which includes the result of merging with the existing file.
+    /// Merged JSON and YAML are formatted according to `.editorconfig`.
+    pub fn get_file_output(&self, file: &TemplateFile) -> miette::Result<String> {
+        if matches!(file.state, FileState::Merge) {
+            match file.is_mergeable() {
+                Some(MergeType::Json) => {
+                    let prev: json::JsonValue = json::read_file(&file.dest_path)?;
+                    let next: json::JsonValue = json::parse(&file.content)?;
+                    let editor_config = fs::get_editor_config_props(&file.dest_path)?;
+
+                    let mut output = json::format_with_identation(
+                        &json::merge(&prev, &next),
+                        &editor_config.indent,
+                    )?;
+                    editor_config.apply_eof(&mut output);
+
+                    return Ok(output);
+                }
+                Some(MergeType::Yaml) => {
+                    let prev: yaml::YamlValue = yaml::read_file(&file.dest_path)?;
+                    let next: yaml::YamlValue = yaml::parse(&file.content)?;
+                    let editor_config = fs::get_editor_config_props(&file.dest_path)?;
+
+                    let mut output = yaml::format_with_identation(
+                        &yaml::merge(&prev, &next),
+                        &editor_config.indent,
+                    )?;
+                    editor_config.apply_eof(&mut output);
+
+                    return Ok(output);
+                }
+                Some(MergeType::Toml) => {
+                    let prev = fs::read_file(&file.dest_path)?;
+
+                    return merge_toml(&prev, &file.content).map_err(|error| {
+                        CodegenError::MergeTomlFailed {
+                            path: file.dest_path.clone(),
+                            error: Box::new(error),
+                        }
+                        .into()
+                    });
+                }
+                Some(MergeType::Ini) => {
+                    let prev = fs::read_file(&file.dest_path)?;
+
+                    return Ok(merge_ini(&prev, &file.content));
+                }
+                None => {}
+            };
+        }
+
+        Ok(file.content.clone())
+    }
+
+    /// Create a unified diff between the existing destination file and the content
+    /// that will be written. Returns `None` if the file does not exist, or will not change.
+    pub fn diff_file(&self, file: &TemplateFile, label: &str) -> miette::Result<Option<String>> {
+        if !file.should_write() || !file.dest_path.exists() {
+            return Ok(None);
+        }
+
+        let prev = fs::read_file(&file.dest_path)?;
+        let next = self.get_file_output(file)?;
+
+        if prev == next {
+            return Ok(None);
+        }
+
+        debug!(
+            file = file.name.as_str(),
+            to = ?file.dest_path,
+            "Diffing template file with destination",
+        );
+
+        let mut options = DiffOptions::new();
+        options
+            .set_original_filename(format!("a/{label}"))
+            .set_modified_filename(format!("b/{label}"));
+
+        Ok(Some(options.create_patch(&prev, &next).to_string()))
+    }
+
     /// Copy the asset file to the defined destination path.
     pub fn copy_asset(&self, file: &AssetFile) -> miette::Result<()> {
         debug!(
@@ -289,25 +409,19 @@ impl Template {
                     "Merging template file with destination",
                 );
 
-                match file.is_mergeable() {
-                    Some(MergeType::Json) => {
-                        let prev: json::JsonValue = json::read_file(&file.dest_path)?;
-                        let next: json::JsonValue = json::parse(&file.content)?;
-
-                        json::write_file_with_config(
-                            &file.dest_path,
-                            &json::merge(&prev, &next),
-                            true,
-                        )?;
-                    }
-                    Some(MergeType::Yaml) => {
-                        let prev: yaml::YamlValue = yaml::read_file(&file.dest_path)?;
-                        let next: yaml::YamlValue = yaml::parse(&file.content)?;
+                if file.is_mergeable().is_some() {
+                    fs::write_file(&file.dest_path, self.get_file_output(file)?)?;
+                }
+            }
+            FileState::Conflict | FileState::Update => {
+                debug!(
+                    file = file.name.as_str(),
+                    to = ?file.dest_path,
+                    conflicted = matches!(file.state, FileState::Conflict),
+                    "Updating destination with merged template file",
+                );
 
-                        yaml::write_file_with_config(&file.dest_path, &yaml::merge(&prev, &next))?;
-                    }
-                    _ => {}
-                };
+                fs::write_file(&file.dest_path, &file.content)?;
             }
             FileState::Replace => {
                 debug!(
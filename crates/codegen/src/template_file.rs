@@ -1,20 +1,27 @@
+use diffy::{DiffOptions, Line};
 use moon_common::path::RelativePathBuf;
-use moon_config::TemplateFrontmatterConfig;
+use moon_config::{TemplateFrontmatterConfig, TemplateMergeType};
+use starbase_utils::fs;
 use std::path::{Path, PathBuf};
 use tracing::debug;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum MergeType {
+    Ini,
     Json,
+    Toml,
     Yaml,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum FileState {
+    Conflict,
     Create,
     Merge,
     Replace,
     Skip,
+    Unchanged,
+    Update,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -58,6 +65,15 @@ impl TemplateFile {
         let mut ext = self.name.as_str();
 
         if let Some(cfg) = &self.config {
+            if let Some(merge) = &cfg.merge {
+                return Some(match merge {
+                    TemplateMergeType::Ini => MergeType::Ini,
+                    TemplateMergeType::Json => MergeType::Json,
+                    TemplateMergeType::Toml => MergeType::Toml,
+                    TemplateMergeType::Yaml => MergeType::Yaml,
+                });
+            }
+
             if let Some(to) = &cfg.to {
                 ext = to;
             }
@@ -67,6 +83,10 @@ impl TemplateFile {
             return Some(MergeType::Json);
         } else if ext.ends_with(".yaml") || ext.ends_with(".yml") {
             return Some(MergeType::Yaml);
+        } else if ext.ends_with(".toml") {
+            return Some(MergeType::Toml);
+        } else if ext.ends_with(".ini") || ext.ends_with(".cfg") || ext.ends_with(".editorconfig") {
+            return Some(MergeType::Ini);
         }
 
         None
@@ -122,7 +142,65 @@ impl TemplateFile {
         self.set_content(content, dest)
     }
 
+    /// Merge the newly rendered content into the existing destination file,
+    /// using the previously rendered content as the common ancestor. Lines
+    /// that were changed in both will be wrapped in conflict markers.
+    /// Without a previous render, a two-way merge is used instead.
+    pub fn set_update_content(&mut self, base: Option<&str>) -> miette::Result<()> {
+        let current = fs::read_file(&self.dest_path)?;
+        let base = match base {
+            Some(base) => base.to_owned(),
+            None => get_common_lines(&current, &self.content),
+        };
+
+        let (content, conflicted) = match diffy::merge(&base, &current, &self.content) {
+            Ok(content) => (content, false),
+            Err(content) => (content, true),
+        };
+
+        debug!(
+            file = %self.name,
+            conflicted,
+            "Merged template file with previous and current content",
+        );
+
+        if content == current {
+            self.state = FileState::Skip;
+        } else {
+            self.state = if conflicted {
+                FileState::Conflict
+            } else {
+                FileState::Update
+            };
+            self.content = content;
+        }
+
+        Ok(())
+    }
+
     pub fn should_write(&self) -> bool {
-        !matches!(self.state, FileState::Skip)
+        !matches!(self.state, FileState::Skip | FileState::Unchanged)
     }
 }
+
+// Use the lines that both files have in common as the common ancestor,
+// so that only the lines that differ are wrapped in conflict markers
+fn get_common_lines(current: &str, next: &str) -> String {
+    let patch = DiffOptions::new()
+        .set_context_len(current.len() + next.len())
+        .create_patch(current, next);
+
+    if patch.hunks().is_empty() {
+        return current.to_owned();
+    }
+
+    patch
+        .hunks()
+        .iter()
+        .flat_map(|hunk| hunk.lines())
+        .filter_map(|line| match line {
+            Line::Context(line) => Some(*line),
+            _ => None,
+        })
+        .collect()
+}
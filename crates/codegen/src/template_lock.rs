@@ -0,0 +1,97 @@
+use crate::template::Template;
+use moon_common::path::to_virtual_string;
+use moon_common::Id;
+use serde::{Deserialize, Serialize};
+use starbase_utils::json;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tera::{Context, Value};
+use tracing::debug;
+
+pub const TEMPLATE_LOCK_NAME: &str = ".moon-template.lock";
+
+/// Records the template, and the variables, that were used to generate
+/// code into a destination, along with the content of every rendered file.
+/// The rendered content is used as the common ancestor when updating
+/// generated code with a three-way merge.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct TemplateLock {
+    /// Rendered content of each file, keyed by its path relative
+    /// from the destination.
+    pub files: BTreeMap<String, String>,
+
+    /// ID of the template that generated the code.
+    pub template: Id,
+
+    /// Values of all template variables at the time of generation.
+    pub variables: BTreeMap<String, Value>,
+
+    /// Version of the template, if configured.
+    pub version: Option<String>,
+}
+
+impl TemplateLock {
+    pub fn new(template: &Template, dest: &Path, context: &Context) -> Self {
+        let mut lock = Self {
+            template: template.id.clone(),
+            version: template.config.version.clone(),
+            ..Default::default()
+        };
+
+        for name in template.config.variables.keys() {
+            if let Some(value) = context.get(name) {
+                lock.variables.insert(name.to_owned(), value.to_owned());
+            }
+        }
+
+        for file in template.files.values() {
+            if file.is_skipped() {
+                continue;
+            }
+
+            if let Some(key) = Self::get_file_key(dest, &file.dest_path) {
+                lock.files.insert(key, file.content.clone());
+            }
+        }
+
+        lock
+    }
+
+    pub fn get_path(dest: &Path) -> PathBuf {
+        dest.join(TEMPLATE_LOCK_NAME)
+    }
+
+    pub fn get_file_key(dest: &Path, file: &Path) -> Option<String> {
+        file.strip_prefix(dest)
+            .ok()
+            .and_then(|rel| to_virtual_string(rel).ok())
+    }
+
+    /// Return the previously rendered content of the provided file, if it exists.
+    pub fn get_file_content(&self, dest: &Path, file: &Path) -> Option<&String> {
+        Self::get_file_key(dest, file).and_then(|key| self.files.get(&key))
+    }
+
+    pub fn load(dest: &Path) -> miette::Result<Option<Self>> {
+        let path = Self::get_path(dest);
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        debug!(lock = ?path, "Loading template lock from destination");
+
+        Ok(Some(json::read_file(path)?))
+    }
+
+    pub fn save(&self, dest: &Path) -> miette::Result<()> {
+        let path = Self::get_path(dest);
+
+        debug!(lock = ?path, "Saving template lock to destination");
+
+        json::write_file(path, self, true)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,472 @@
+use crate::template::Template;
+use crate::template_lock::TemplateLock;
+use diffy::DiffOptions;
+use moon_config::{TemplateVariable, TemplateVariableEnumDefault};
+use starbase_utils::{fs, yaml};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Component, Path, PathBuf};
+use tera::{Context, Value};
+use tracing::{debug, instrument};
+
+pub const TEMPLATE_FIXTURES_DIR: &str = "__fixtures__";
+pub const TEMPLATE_SNAPSHOTS_DIR: &str = "__snapshots__";
+
+/// Variable values to render a template with, loaded from a YAML file
+/// within the template's fixtures directory.
+#[derive(Debug, Default, PartialEq)]
+pub struct TemplateFixture {
+    pub name: String,
+    pub path: PathBuf,
+    pub variables: BTreeMap<String, Value>,
+}
+
+impl TemplateFixture {
+    pub fn load(path: PathBuf) -> miette::Result<Self> {
+        let name = fs::file_name(&path);
+        let name = name
+            .strip_suffix(".yaml")
+            .or_else(|| name.strip_suffix(".yml"))
+            .unwrap_or(&name)
+            .to_owned();
+
+        debug!(fixture = ?path, "Loading template fixture");
+
+        let variables: Option<BTreeMap<String, Value>> = yaml::read_file(&path)?;
+
+        Ok(Self {
+            name,
+            path,
+            variables: variables.unwrap_or_default(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TemplateTestIssue {
+    DuplicatePath { file: String, path: String },
+    InvalidEnumValue { name: String, value: Value },
+    InvalidPath { file: String, path: String },
+    InvalidVariableType { name: String, value: Value },
+    MissingRequiredVariable { name: String },
+    RenderFailed { error: String },
+    SnapshotChanged { file: String, diff: Option<String> },
+    SnapshotMissing { file: String },
+    SnapshotRemoved { file: String },
+    UnknownVariable { name: String },
+}
+
+impl fmt::Display for TemplateTestIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicatePath { file, path } => {
+                write!(
+                    f,
+                    "File {file} is written to {path}, which is written by another file"
+                )
+            }
+            Self::InvalidEnumValue { name, value } => {
+                write!(f, "Variable {name} has an unsupported enum value {value}")
+            }
+            Self::InvalidPath { file, path } => {
+                write!(
+                    f,
+                    "File {file} has a destination path {path} that does not resolve within the destination"
+                )
+            }
+            Self::InvalidVariableType { name, value } => {
+                write!(f, "Variable {name} has a value {value} of the wrong type")
+            }
+            Self::MissingRequiredVariable { name } => {
+                write!(f, "Variable {name} is required but has no value")
+            }
+            Self::RenderFailed { error } => {
+                write!(f, "Failed to render template: {error}")
+            }
+            Self::SnapshotChanged { file, .. } => {
+                write!(f, "File {file} does not match its snapshot")
+            }
+            Self::SnapshotMissing { file } => {
+                write!(f, "File {file} was rendered but has no snapshot")
+            }
+            Self::SnapshotRemoved { file } => {
+                write!(f, "File {file} has a snapshot but was not rendered")
+            }
+            Self::UnknownVariable { name } => {
+                write!(f, "Variable {name} is not configured in the template")
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct TemplateTestResult {
+    pub fixture: String,
+    pub issues: Vec<TemplateTestIssue>,
+    pub updated_snapshot: bool,
+}
+
+impl TemplateTestResult {
+    pub fn passed(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Renders a template with each of its fixtures into a temporary directory,
+/// and compares the output against the snapshots committed alongside it.
+pub struct TemplateTester<'app> {
+    pub template: &'app Template,
+
+    /// Directory in which fixtures are rendered.
+    pub temp_dir: PathBuf,
+
+    /// Overwrite snapshots with the rendered output, instead of comparing.
+    pub update_snapshots: bool,
+
+    workspace_root: &'app Path,
+}
+
+impl<'app> TemplateTester<'app> {
+    pub fn new(template: &'app Template, workspace_root: &'app Path, temp_dir: &Path) -> Self {
+        Self {
+            temp_dir: temp_dir.join(template.id.as_str()),
+            template,
+            update_snapshots: false,
+            workspace_root,
+        }
+    }
+
+    pub fn get_fixtures_dir(&self) -> PathBuf {
+        self.template.root.join(TEMPLATE_FIXTURES_DIR)
+    }
+
+    pub fn get_snapshot_dir(&self, fixture: &TemplateFixture) -> PathBuf {
+        self.template
+            .root
+            .join(TEMPLATE_SNAPSHOTS_DIR)
+            .join(&fixture.name)
+    }
+
+    pub fn load_fixtures(&self) -> miette::Result<Vec<TemplateFixture>> {
+        let dir = self.get_fixtures_dir();
+        let mut fixtures = vec![];
+
+        if !dir.exists() {
+            return Ok(fixtures);
+        }
+
+        for entry in fs::read_dir(dir)? {
+            let path = entry.path();
+
+            if path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|ext| ext == "yml" || ext == "yaml")
+            {
+                fixtures.push(TemplateFixture::load(path)?);
+            }
+        }
+
+        fixtures.sort_by(|a, d| a.name.cmp(&d.name));
+
+        Ok(fixtures)
+    }
+
+    #[instrument(skip(self))]
+    pub fn test_fixture(&self, fixture: &TemplateFixture) -> miette::Result<TemplateTestResult> {
+        let mut result = TemplateTestResult {
+            fixture: fixture.name.clone(),
+            ..Default::default()
+        };
+
+        debug!(
+            template = self.template.id.as_str(),
+            fixture = fixture.name.as_str(),
+            "Testing template with fixture",
+        );
+
+        let mut context = self.create_context(fixture, &mut result.issues);
+        let dest = self.temp_dir.join(&fixture.name);
+
+        context.insert("dest_dir", &dest);
+        context.insert("dest_rel_dir", &fixture.name);
+        context.insert("working_dir", self.workspace_root);
+        context.insert("workspace_root", self.workspace_root);
+
+        // Render into a clean destination
+        fs::remove_dir_all(&dest)?;
+
+        let mut template = self.template.clone();
+
+        if let Err(error) = template.load_files(&dest, &context) {
+            result.issues.push(TemplateTestIssue::RenderFailed {
+                error: error
+                    .chain()
+                    .map(|error| error.to_string())
+                    .collect::<Vec<_>>()
+                    .join(": "),
+            });
+
+            return Ok(result);
+        }
+
+        self.validate_paths(&template, &dest, &mut result.issues);
+
+        if !result.issues.is_empty() {
+            return Ok(result);
+        }
+
+        for file in template.files.values() {
+            if !file.is_skipped() {
+                fs::write_file(&file.dest_path, &file.content)?;
+            }
+        }
+
+        for asset in template.assets.values() {
+            template.copy_asset(asset)?;
+        }
+
+        // Compare against, or update, the snapshot
+        let snapshot_dir = self.get_snapshot_dir(fixture);
+
+        if self.update_snapshots {
+            debug!(snapshot = ?snapshot_dir, "Updating template snapshot");
+
+            fs::remove_dir_all(&snapshot_dir)?;
+            fs::create_dir_all(&snapshot_dir)?;
+
+            for (key, path) in collect_files(&dest)? {
+                fs::copy_file(path, snapshot_dir.join(key))?;
+            }
+
+            result.updated_snapshot = true;
+        } else {
+            self.compare_snapshot(&dest, &snapshot_dir, &mut result.issues)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Create a context from the fixture, falling back to the default value of each
+    /// variable, while validating that the provided values are acceptable.
+    fn create_context(
+        &self,
+        fixture: &TemplateFixture,
+        issues: &mut Vec<TemplateTestIssue>,
+    ) -> Context {
+        let mut context = Context::new();
+
+        for (name, value) in &fixture.variables {
+            if !self.template.config.variables.contains_key(name) {
+                issues.push(TemplateTestIssue::UnknownVariable { name: name.clone() });
+            }
+
+            context.insert(name, value);
+        }
+
+        let mut variables = self.template.config.variables.iter().collect::<Vec<_>>();
+        variables.sort_by(|a, d| a.0.cmp(d.0));
+
+        for (name, config) in variables {
+            let Some(value) = fixture.variables.get(name) else {
+                if config.is_required() && is_empty_default(config) {
+                    issues.push(TemplateTestIssue::MissingRequiredVariable { name: name.clone() });
+                }
+
+                insert_default_value(&mut context, name, config);
+
+                continue;
+            };
+
+            let valid_type = match config {
+                TemplateVariable::Boolean(_) => value.is_boolean(),
+                TemplateVariable::Enum(cfg) if cfg.is_multiple() => value.is_array(),
+                TemplateVariable::Enum(_) => value.is_string(),
+                TemplateVariable::Number(_) => value.is_i64(),
+                TemplateVariable::String(_) => value.is_string(),
+            };
+
+            if !valid_type {
+                issues.push(TemplateTestIssue::InvalidVariableType {
+                    name: name.clone(),
+                    value: value.clone(),
+                });
+
+                continue;
+            }
+
+            if let TemplateVariable::Enum(cfg) = config {
+                let values = cfg.get_values();
+                let selected = match value {
+                    Value::Array(list) => list.iter().collect::<Vec<_>>(),
+                    other => vec![other],
+                };
+
+                for item in selected {
+                    if !item
+                        .as_str()
+                        .is_some_and(|item| values.iter().any(|v| *v == item))
+                    {
+                        issues.push(TemplateTestIssue::InvalidEnumValue {
+                            name: name.clone(),
+                            value: item.clone(),
+                        });
+                    }
+                }
+            } else if config.is_required()
+                && (value.as_str().is_some_and(|v| v.is_empty()) || value.as_i64() == Some(0))
+            {
+                issues.push(TemplateTestIssue::MissingRequiredVariable { name: name.clone() });
+            }
+        }
+
+        context
+    }
+
+    /// Verify that each file, including those with a frontmatter `to` path,
+    /// resolves to a unique path within the destination.
+    fn validate_paths(
+        &self,
+        template: &Template,
+        dest: &Path,
+        issues: &mut Vec<TemplateTestIssue>,
+    ) {
+        let mut seen = BTreeMap::new();
+
+        for file in template.files.values() {
+            if file.is_skipped() {
+                continue;
+            }
+
+            let path = file
+                .config
+                .as_ref()
+                .and_then(|cfg| cfg.to.clone())
+                .unwrap_or_else(|| file.name.to_string());
+
+            let Some(key) = TemplateLock::get_file_key(dest, &file.dest_path)
+                .filter(|key| !key.is_empty() && is_within_dest(Path::new(key)))
+            else {
+                issues.push(TemplateTestIssue::InvalidPath {
+                    file: file.name.to_string(),
+                    path,
+                });
+
+                continue;
+            };
+
+            if seen.insert(key, file.name.to_string()).is_some() {
+                issues.push(TemplateTestIssue::DuplicatePath {
+                    file: file.name.to_string(),
+                    path,
+                });
+            }
+        }
+    }
+
+    fn compare_snapshot(
+        &self,
+        dest: &Path,
+        snapshot_dir: &Path,
+        issues: &mut Vec<TemplateTestIssue>,
+    ) -> miette::Result<()> {
+        let mut expected = collect_files(snapshot_dir)?;
+
+        for (key, path) in collect_files(dest)? {
+            let Some(snapshot_path) = expected.remove(&key) else {
+                issues.push(TemplateTestIssue::SnapshotMissing { file: key });
+                continue;
+            };
+
+            let prev = fs::read_file_bytes(&snapshot_path)?;
+            let next = fs::read_file_bytes(&path)?;
+
+            if prev == next {
+                continue;
+            }
+
+            // Binary files can't be diffed
+            let diff = match (String::from_utf8(prev), String::from_utf8(next)) {
+                (Ok(prev), Ok(next)) => {
+                    let mut options = DiffOptions::new();
+                    options
+                        .set_original_filename(format!("snapshot/{key}"))
+                        .set_modified_filename(format!("rendered/{key}"));
+
+                    Some(options.create_patch(&prev, &next).to_string())
+                }
+                _ => None,
+            };
+
+            issues.push(TemplateTestIssue::SnapshotChanged { file: key, diff });
+        }
+
+        for key in expected.into_keys() {
+            issues.push(TemplateTestIssue::SnapshotRemoved { file: key });
+        }
+
+        Ok(())
+    }
+}
+
+fn collect_files(dir: &Path) -> miette::Result<BTreeMap<String, PathBuf>> {
+    let mut files = BTreeMap::new();
+
+    if !dir.exists() {
+        return Ok(files);
+    }
+
+    for entry in fs::read_dir_all(dir)? {
+        let path = entry.path();
+
+        if let Some(key) = TemplateLock::get_file_key(dir, &path) {
+            files.insert(key, path);
+        }
+    }
+
+    Ok(files)
+}
+
+fn is_within_dest(path: &Path) -> bool {
+    let mut depth = 0;
+
+    for component in path.components() {
+        match component {
+            Component::Normal(_) => depth += 1,
+            Component::CurDir => {}
+            Component::ParentDir if depth > 0 => depth -= 1,
+            _ => return false,
+        };
+    }
+
+    depth > 0
+}
+
+fn is_empty_default(config: &TemplateVariable) -> bool {
+    match config {
+        TemplateVariable::Number(cfg) => cfg.default == 0,
+        TemplateVariable::String(cfg) => cfg.default.is_empty(),
+        _ => false,
+    }
+}
+
+fn insert_default_value(context: &mut Context, name: &str, config: &TemplateVariable) {
+    match config {
+        TemplateVariable::Boolean(cfg) => context.insert(name, &cfg.default),
+        TemplateVariable::Number(cfg) => context.insert(name, &cfg.default),
+        TemplateVariable::String(cfg) => context.insert(name, &cfg.default),
+        TemplateVariable::Enum(cfg) if cfg.is_multiple() => {
+            context.insert(name, &cfg.default.to_vec());
+        }
+        TemplateVariable::Enum(cfg) => {
+            let value = match &cfg.default {
+                TemplateVariableEnumDefault::String(def) => Some(def),
+                TemplateVariableEnumDefault::Vec(def) => {
+                    def.first().or_else(|| cfg.get_values().first().copied())
+                }
+            };
+
+            context.insert(name, &value.cloned().unwrap_or_default());
+        }
+    };
+}
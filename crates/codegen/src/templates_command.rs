@@ -1,9 +1,12 @@
 use crate::codegen::CodeGenerator;
+use crate::template::Template;
+use crate::template_tester::{TemplateTestIssue, TemplateTester};
 use clap::Args;
 use miette::IntoDiagnostic;
-use moon_common::color;
+use moon_common::{color, Id};
 use moon_console::Console;
 use std::collections::BTreeMap;
+use std::path::Path;
 
 #[derive(Args, Clone, Debug)]
 pub struct TemplatesArgs {
@@ -11,13 +14,24 @@ pub struct TemplatesArgs {
     pub filter: Option<String>,
 }
 
-pub async fn templates_command(
-    mut generator: CodeGenerator<'_>,
-    console: &Console,
-    args: &TemplatesArgs,
-) -> miette::Result<Option<u8>> {
-    generator.load_templates().await?;
+#[derive(Args, Clone, Debug)]
+pub struct TemplatesTestArgs {
+    #[arg(long, help = "Filter the templates based on this pattern")]
+    pub filter: Option<String>,
+
+    #[arg(
+        long,
+        short = 'u',
+        help = "Update snapshots with the rendered output, instead of comparing"
+    )]
+    pub update: bool,
+}
 
+fn filter_templates<'a>(
+    generator: &'a CodeGenerator<'_>,
+    console: &Console,
+    filter: Option<&String>,
+) -> miette::Result<Option<BTreeMap<&'a Id, &'a Template>>> {
     let mut templates = BTreeMap::from_iter(&generator.templates);
 
     if templates.is_empty() {
@@ -25,10 +39,10 @@ pub async fn templates_command(
             .err
             .write_line("There are no configured templates")?;
 
-        return Ok(Some(1));
+        return Ok(None);
     }
 
-    if let Some(filter) = &args.filter {
+    if let Some(filter) = filter {
         let pattern = regex::Regex::new(&format!("(?i){filter}")).into_diagnostic()?;
 
         templates.retain(|&id, _| pattern.is_match(id.as_str()));
@@ -39,10 +53,24 @@ pub async fn templates_command(
                 color::shell(filter)
             ))?;
 
-            return Ok(Some(1));
+            return Ok(None);
         }
     }
 
+    Ok(Some(templates))
+}
+
+pub async fn templates_command(
+    mut generator: CodeGenerator<'_>,
+    console: &Console,
+    args: &TemplatesArgs,
+) -> miette::Result<Option<u8>> {
+    generator.load_templates().await?;
+
+    let Some(templates) = filter_templates(&generator, console, args.filter.as_ref())? else {
+        return Ok(Some(1));
+    };
+
     let out = console.stdout();
 
     for (_, template) in templates {
@@ -94,3 +122,106 @@ pub async fn templates_command(
 
     Ok(None)
 }
+
+pub async fn templates_test_command(
+    mut generator: CodeGenerator<'_>,
+    console: &Console,
+    args: &TemplatesTestArgs,
+    workspace_root: &Path,
+    temp_dir: &Path,
+) -> miette::Result<Option<u8>> {
+    generator.load_templates().await?;
+
+    let Some(templates) = filter_templates(&generator, console, args.filter.as_ref())? else {
+        return Ok(Some(1));
+    };
+
+    let out = console.stdout();
+    let mut passed = 0;
+    let mut failed = 0;
+
+    for id in templates.into_keys() {
+        // Resolve extended templates
+        let template = generator.get_template(id)?;
+        let mut tester = TemplateTester::new(&template, workspace_root, temp_dir);
+        tester.update_snapshots = args.update;
+
+        let fixtures = tester.load_fixtures()?;
+
+        out.print_entry_header(&template.id)?;
+
+        if fixtures.is_empty() {
+            out.write_line(color::muted_light("No fixtures found"))?;
+            continue;
+        }
+
+        for fixture in fixtures {
+            let result = tester.test_fixture(&fixture)?;
+
+            if !result.passed() {
+                failed += 1;
+
+                out.write_line(format!(
+                    "{} {}",
+                    color::failure("✕"),
+                    color::label(&result.fixture)
+                ))?;
+
+                for issue in &result.issues {
+                    out.write_line(format!("  {} {issue}", color::muted("-")))?;
+
+                    if let TemplateTestIssue::SnapshotChanged {
+                        diff: Some(diff), ..
+                    } = issue
+                    {
+                        out.write_newline()?;
+
+                        for line in diff.lines() {
+                            out.write_line(format!(
+                                "    {}",
+                                if line.starts_with('+') && !line.starts_with("+++") {
+                                    color::success(line)
+                                } else if line.starts_with('-') && !line.starts_with("---") {
+                                    color::failure(line)
+                                } else {
+                                    color::muted_light(line)
+                                }
+                            ))?;
+                        }
+
+                        out.write_newline()?;
+                    }
+                }
+
+                continue;
+            }
+
+            passed += 1;
+
+            out.write_line(format!(
+                "{} {}{}",
+                color::success("✓"),
+                color::label(&result.fixture),
+                if result.updated_snapshot {
+                    color::muted(" (snapshot updated)")
+                } else {
+                    "".into()
+                }
+            ))?;
+        }
+    }
+
+    out.write_newline()?;
+    out.write_line(format!(
+        "{} passed, {} failed",
+        color::success(passed.to_string()),
+        if failed > 0 {
+            color::failure(failed.to_string())
+        } else {
+            color::muted_light(failed.to_string())
+        }
+    ))?;
+    out.flush()?;
+
+    Ok(if failed > 0 { Some(1) } else { None })
+}
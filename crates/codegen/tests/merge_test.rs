@@ -0,0 +1,143 @@
+use moon_codegen::{merge_ini, merge_toml};
+
+mod merge_toml {
+    use super::*;
+
+    #[test]
+    fn preserves_comments_and_formatting() {
+        let prev = r#"# Package
+[package]
+name = "app"   # the name
+version = "0.1.0"
+
+[dependencies]
+serde = "1.0"
+"#;
+        let next = r#"[package]
+version = "0.2.0"
+
+[dependencies]
+tokio = "1.0"
+"#;
+
+        assert_eq!(
+            merge_toml(prev, next).unwrap(),
+            r#"# Package
+[package]
+name = "app"   # the name
+version = "0.2.0"
+
+[dependencies]
+serde = "1.0"
+tokio = "1.0"
+"#
+        );
+    }
+
+    #[test]
+    fn merges_nested_tables() {
+        let prev = r#"[workspace.package]
+edition = "2021"
+"#;
+        let next = r#"[workspace.package]
+license = "MIT"
+
+[workspace.dependencies]
+serde = "1.0"
+"#;
+
+        assert_eq!(
+            merge_toml(prev, next).unwrap(),
+            r#"[workspace.package]
+edition = "2021"
+license = "MIT"
+
+[workspace.dependencies]
+serde = "1.0"
+"#
+        );
+    }
+
+    #[test]
+    fn merges_inline_tables() {
+        let prev = r#"serde = { version = "1.0", features = ["derive"] }
+"#;
+        let next = r#"serde = { version = "1.1", optional = true }
+"#;
+
+        assert_eq!(
+            merge_toml(prev, next).unwrap(),
+            r#"serde = { version = "1.1", features = ["derive"], optional = true }
+"#
+        );
+    }
+
+    #[test]
+    fn replaces_arrays() {
+        let prev = "members = [\"a\", \"b\"]\n";
+        let next = "members = [\"c\"]\n";
+
+        assert_eq!(merge_toml(prev, next).unwrap(), "members = [\"c\"]\n");
+    }
+
+    #[test]
+    fn errors_invalid_syntax() {
+        assert!(merge_toml("key = ", "key = 1").is_err());
+    }
+}
+
+mod merge_ini {
+    use super::*;
+
+    #[test]
+    fn replaces_existing_keys() {
+        let prev = "; comment\n[section]\nkey = old\nother = value\n";
+        let next = "[section]\nkey = new\n";
+
+        assert_eq!(
+            merge_ini(prev, next),
+            "; comment\n[section]\nkey = new\nother = value\n"
+        );
+    }
+
+    #[test]
+    fn appends_new_keys_to_section() {
+        let prev = "[a]\nfoo = 1\n\n[b]\nbar = 2\n";
+        let next = "[a]\nbaz = 3\n";
+
+        assert_eq!(
+            merge_ini(prev, next),
+            "[a]\nfoo = 1\nbaz = 3\n\n[b]\nbar = 2\n"
+        );
+    }
+
+    #[test]
+    fn appends_new_sections() {
+        let prev = "[a]\nfoo = 1\n";
+        let next = "[b]\nbar = 2\n";
+
+        assert_eq!(merge_ini(prev, next), "[a]\nfoo = 1\n\n[b]\nbar = 2\n");
+    }
+
+    #[test]
+    fn merges_global_keys() {
+        let prev = "root = true\n\n[*]\nindent_style = space\n";
+        let next = "root = false\ncharset = utf-8\n";
+
+        assert_eq!(
+            merge_ini(prev, next),
+            "root = false\ncharset = utf-8\n\n[*]\nindent_style = space\n"
+        );
+    }
+
+    #[test]
+    fn supports_editorconfig_globs() {
+        let prev = "[*]\nindent_size = 2\n\n[*.{md,mdx}]\ntrim_trailing_whitespace = false\n";
+        let next = "[*.{md,mdx}]\nindent_size = 4\n\n[*.rs]\nindent_size = 4\n";
+
+        assert_eq!(
+            merge_ini(prev, next),
+            "[*]\nindent_size = 2\n\n[*.{md,mdx}]\ntrim_trailing_whitespace = false\nindent_size = 4\n\n[*.rs]\nindent_size = 4\n"
+        );
+    }
+}
@@ -1,6 +1,8 @@
-use moon_codegen::{MergeType, TemplateFile};
+use moon_codegen::{FileState, MergeType, TemplateFile};
 use moon_common::path::RelativePathBuf;
-use moon_config::TemplateFrontmatterConfig;
+use moon_config::{TemplateFrontmatterConfig, TemplateMergeType};
+use starbase_sandbox::{create_empty_sandbox, Sandbox};
+use std::fs;
 use std::path::PathBuf;
 
 mod template_file {
@@ -59,6 +61,36 @@ mod template_file {
 
             assert_eq!(template.is_mergeable(), Some(MergeType::Json));
         }
+
+        #[test]
+        fn is_with_toml() {
+            let template = TemplateFile::new(RelativePathBuf::from("Cargo.toml"), PathBuf::new());
+
+            assert_eq!(template.is_mergeable(), Some(MergeType::Toml));
+        }
+
+        #[test]
+        fn is_with_ini() {
+            for name in ["file.ini", "setup.cfg", ".editorconfig"] {
+                let template = TemplateFile::new(RelativePathBuf::from(name), PathBuf::new());
+
+                assert_eq!(template.is_mergeable(), Some(MergeType::Ini));
+            }
+        }
+
+        #[test]
+        fn uses_merge_config() {
+            let mut template = TemplateFile::new(RelativePathBuf::from(".npmrc"), PathBuf::new());
+
+            assert_eq!(template.is_mergeable(), None);
+
+            template.config = Some(TemplateFrontmatterConfig {
+                merge: Some(TemplateMergeType::Ini),
+                ..TemplateFrontmatterConfig::default()
+            });
+
+            assert_eq!(template.is_mergeable(), Some(MergeType::Ini));
+        }
     }
 
     mod content {
@@ -138,4 +170,99 @@ mod template_file {
             create_file_with_content("---\nunknown: true\n---\n\nexport {};");
         }
     }
+    mod update_content {
+        use super::*;
+
+        const BASE: &str = "a\nb\nc\nd\ne\n";
+
+        fn create_file_with_current(current: &str, next: &str) -> (TemplateFile, Sandbox) {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("file.txt", current);
+
+            let mut file = TemplateFile::new(RelativePathBuf::from("file.txt"), PathBuf::new());
+            file.set_content(next, sandbox.path()).unwrap();
+
+            (file, sandbox)
+        }
+
+        #[test]
+        fn merges_non_overlapping_changes() {
+            let (mut file, sandbox) =
+                create_file_with_current("a\nB\nc\nd\ne\n", "a\nb\nc\nd\nE\n");
+
+            file.set_update_content(Some(BASE)).unwrap();
+
+            assert_eq!(file.state, FileState::Update);
+            assert_eq!(file.content, "a\nB\nc\nd\nE\n");
+            assert_eq!(
+                fs::read_to_string(sandbox.path().join("file.txt")).unwrap(),
+                "a\nB\nc\nd\ne\n"
+            );
+        }
+
+        #[test]
+        fn adds_conflict_markers() {
+            let (mut file, _sandbox) =
+                create_file_with_current("a\nb\nlocal\nd\ne\n", "a\nb\ntemplate\nd\ne\n");
+
+            file.set_update_content(Some(BASE)).unwrap();
+
+            assert_eq!(file.state, FileState::Conflict);
+            assert!(file.content.contains("<<<<<<<"));
+            assert!(file.content.contains("local"));
+            assert!(file.content.contains("======="));
+            assert!(file.content.contains("template"));
+            assert!(file.content.contains(">>>>>>>"));
+        }
+
+        #[test]
+        fn skips_when_template_unchanged() {
+            let (mut file, _sandbox) = create_file_with_current("a\nB\nc\nd\ne\n", BASE);
+
+            file.set_update_content(Some(BASE)).unwrap();
+
+            assert_eq!(file.state, FileState::Skip);
+        }
+
+        #[test]
+        fn skips_when_already_up_to_date() {
+            let (mut file, _sandbox) = create_file_with_current("a\nb\n", "a\nb\n");
+
+            file.set_update_content(None).unwrap();
+
+            assert_eq!(file.state, FileState::Skip);
+        }
+
+        #[test]
+        fn conflicts_without_a_base() {
+            let (mut file, _sandbox) = create_file_with_current("local\n", "template\n");
+
+            file.set_update_content(None).unwrap();
+
+            assert_eq!(file.state, FileState::Conflict);
+        }
+
+        #[test]
+        fn only_conflicts_differing_lines_without_a_base() {
+            let (mut file, _sandbox) =
+                create_file_with_current("a\nb\nlocal\nd\ne\n", "a\nb\ntemplate\nd\ne\n");
+
+            file.set_update_content(None).unwrap();
+
+            assert_eq!(file.state, FileState::Conflict);
+            assert!(file.content.starts_with("a\nb\n<<<<<<<"));
+            assert!(file.content.ends_with("d\ne\n"));
+        }
+
+        #[test]
+        fn merges_separate_changes_without_a_base() {
+            let (mut file, _sandbox) =
+                create_file_with_current("a\nlocal\nb\nc\n", "a\nb\nc\ntemplate\n");
+
+            file.set_update_content(None).unwrap();
+
+            assert_eq!(file.state, FileState::Update);
+            assert_eq!(file.content, "a\nlocal\nb\nc\ntemplate\n");
+        }
+    }
 }
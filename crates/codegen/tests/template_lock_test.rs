@@ -0,0 +1,103 @@
+use moon_codegen::{Template, TemplateContext, TemplateLock};
+use moon_common::Id;
+use starbase_sandbox::{create_empty_sandbox, Sandbox};
+use tera::Value;
+
+fn create_template_sandbox() -> Sandbox {
+    let sandbox = create_empty_sandbox();
+
+    sandbox.create_file(
+        "template/template.yml",
+        r#"
+title: Title
+description: Description
+version: '1.2.3'
+variables:
+  name:
+    type: string
+    default: ''
+"#,
+    );
+    sandbox.create_file("template/file.txt", "{{ name }}");
+    sandbox.create_file("template/nested/file.txt", "nested");
+    sandbox.create_file("template/skipped.txt", "---\nskip: true\n---\nskipped");
+
+    sandbox
+}
+
+fn create_context() -> TemplateContext {
+    let mut context = TemplateContext::new();
+    context.insert("name", "example");
+    context
+}
+
+mod template_lock {
+    use super::*;
+
+    #[test]
+    fn records_template_variables_and_files() {
+        let sandbox = create_template_sandbox();
+        let dest = sandbox.path().join("out");
+        let context = create_context();
+
+        let mut template =
+            Template::new(Id::raw("template"), sandbox.path().join("template")).unwrap();
+        template.load_files(&dest, &context).unwrap();
+
+        let lock = TemplateLock::new(&template, &dest, &context);
+
+        assert_eq!(lock.template, Id::raw("template"));
+        assert_eq!(lock.version.as_deref(), Some("1.2.3"));
+
+        // Only configured variables
+        assert_eq!(
+            lock.variables.into_iter().collect::<Vec<_>>(),
+            [("name".to_owned(), Value::String("example".into()))]
+        );
+
+        // Skips files that are never written
+        assert_eq!(
+            lock.files.into_iter().collect::<Vec<_>>(),
+            [
+                ("file.txt".to_owned(), "example".to_owned()),
+                ("nested/file.txt".to_owned(), "nested".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn returns_file_content_relative_from_dest() {
+        let sandbox = create_empty_sandbox();
+        let mut lock = TemplateLock::default();
+        lock.files
+            .insert("nested/file.txt".into(), "content".into());
+
+        assert_eq!(
+            lock.get_file_content(sandbox.path(), &sandbox.path().join("nested/file.txt")),
+            Some(&"content".to_owned())
+        );
+        assert_eq!(
+            lock.get_file_content(sandbox.path(), &sandbox.path().join("unknown.txt")),
+            None
+        );
+    }
+
+    #[test]
+    fn saves_and_loads() {
+        let sandbox = create_empty_sandbox();
+
+        assert_eq!(TemplateLock::load(sandbox.path()).unwrap(), None);
+
+        let mut lock = TemplateLock {
+            template: Id::raw("template"),
+            version: Some("1.0.0".into()),
+            ..Default::default()
+        };
+        lock.files.insert("file.txt".into(), "content".into());
+        lock.variables.insert("bool".into(), Value::Bool(true));
+        lock.save(sandbox.path()).unwrap();
+
+        assert!(sandbox.path().join(".moon-template.lock").exists());
+        assert_eq!(TemplateLock::load(sandbox.path()).unwrap(), Some(lock));
+    }
+}
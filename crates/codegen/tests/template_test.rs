@@ -3,7 +3,7 @@ use moon_common::Id;
 use moon_config::{GeneratorConfig, TemplateFrontmatterConfig};
 use moon_env::MoonEnvironment;
 use starbase_sandbox::{create_sandbox, locate_fixture};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 fn create_template_file() -> TemplateFile {
     TemplateFile::new("standard".into(), PathBuf::from("."))
@@ -387,6 +387,105 @@ mod template {
         }
     }
 
+    mod diff_file {
+        use super::*;
+        use moon_codegen::FileState;
+        use starbase_sandbox::create_empty_sandbox;
+        use starbase_utils::fs;
+
+        fn create_file(name: &str, content: &str, dest: &Path) -> TemplateFile {
+            let mut file = TemplateFile::new(name.into(), PathBuf::from("."));
+            file.set_content(content, dest).unwrap();
+            file
+        }
+
+        #[test]
+        fn none_when_dest_doesnt_exist() {
+            let sandbox = create_empty_sandbox();
+            let file = create_file("file.txt", "content", sandbox.path());
+
+            assert_eq!(
+                create_template().diff_file(&file, "file.txt").unwrap(),
+                None
+            );
+        }
+
+        #[test]
+        fn none_when_unchanged() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("file.txt", "content");
+
+            let file = create_file("file.txt", "content", sandbox.path());
+
+            assert_eq!(
+                create_template().diff_file(&file, "file.txt").unwrap(),
+                None
+            );
+        }
+
+        #[test]
+        fn creates_unified_diff() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("file.txt", "a\nb\n");
+
+            let file = create_file("file.txt", "a\nc\n", sandbox.path());
+            let diff = create_template()
+                .diff_file(&file, "file.txt")
+                .unwrap()
+                .unwrap();
+
+            assert!(diff.contains("--- a/file.txt"));
+            assert!(diff.contains("+++ b/file.txt"));
+            assert!(diff.contains("-b"));
+            assert!(diff.contains("+c"));
+        }
+
+        #[test]
+        fn diffs_merged_json() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("file.json", r#"{ "a": 1 }"#);
+
+            let mut file = create_file("file.json", r#"{ "b": 2 }"#, sandbox.path());
+            file.state = FileState::Merge;
+
+            let template = create_template();
+
+            assert_eq!(
+                template.get_file_output(&file).unwrap(),
+                "{\n  \"a\": 1,\n  \"b\": 2\n}\n"
+            );
+            assert!(template
+                .diff_file(&file, "file.json")
+                .unwrap()
+                .unwrap()
+                .contains("+  \"b\": 2"));
+        }
+
+        #[test]
+        fn writes_the_diffed_output() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file(
+                ".editorconfig",
+                "[*.yml]\nindent_style = space\nindent_size = 4",
+            );
+            sandbox.create_file("file.yml", "a:\n    - 1\n");
+
+            let mut file = create_file("file.yml", "b:\n  c: 2", sandbox.path());
+            file.state = FileState::Merge;
+
+            let template = create_template();
+            let output = template.get_file_output(&file).unwrap();
+
+            template.write_file(&file).unwrap();
+
+            assert_eq!(
+                fs::read_file(sandbox.path().join("file.yml")).unwrap(),
+                output
+            );
+            assert!(output.contains("b:\n    c: 2"));
+        }
+    }
+
     mod extending {
         use super::*;
         use starbase_sandbox::assert_snapshot;
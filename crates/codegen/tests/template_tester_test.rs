@@ -0,0 +1,302 @@
+use moon_codegen::{Template, TemplateFixture, TemplateTestIssue, TemplateTester};
+use moon_common::Id;
+use starbase_sandbox::{create_empty_sandbox, Sandbox};
+use starbase_utils::yaml;
+use std::fs;
+use tera::Value;
+
+fn create_template_sandbox() -> Sandbox {
+    let sandbox = create_empty_sandbox();
+
+    sandbox.create_file(
+        "template/template.yml",
+        r#"
+title: Title
+description: Description
+variables:
+  name:
+    type: string
+    default: ''
+    required: true
+  kind:
+    type: enum
+    values: [lib, app]
+    default: lib
+"#,
+    );
+    sandbox.create_file("template/file.txt", "{{ name }} {{ kind }}");
+    sandbox.create_file(
+        "template/to.txt",
+        "---\nto: '{{ name }}/to.txt'\n---\n{{ kind }}",
+    );
+    sandbox.create_file(
+        "template/__fixtures__/basic.yml",
+        "name: example\nkind: app\n",
+    );
+
+    sandbox
+}
+
+fn load_template(sandbox: &Sandbox) -> Template {
+    Template::new(Id::raw("template"), sandbox.path().join("template")).unwrap()
+}
+
+fn create_fixture(variables: &str) -> TemplateFixture {
+    TemplateFixture {
+        name: "test".into(),
+        variables: yaml::parse(variables).unwrap(),
+        ..Default::default()
+    }
+}
+
+mod template_tester {
+    use super::*;
+
+    #[test]
+    fn loads_fixtures() {
+        let sandbox = create_template_sandbox();
+        sandbox.create_file("template/__fixtures__/other.yaml", "name: other");
+        sandbox.create_file("template/__fixtures__/ignored.txt", "");
+
+        let template = load_template(&sandbox);
+        let temp_dir = sandbox.path().join("temp");
+        let tester = TemplateTester::new(&template, sandbox.path(), &temp_dir);
+        let fixtures = tester.load_fixtures().unwrap();
+
+        assert_eq!(
+            fixtures.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(),
+            ["basic", "other"]
+        );
+        assert_eq!(
+            fixtures[0].variables.get("kind"),
+            Some(&Value::String("app".into()))
+        );
+    }
+
+    #[test]
+    fn doesnt_render_fixtures_or_snapshots() {
+        let sandbox = create_template_sandbox();
+        sandbox.create_file("template/__snapshots__/basic/file.txt", "example app");
+
+        let mut template = load_template(&sandbox);
+        let mut context = tera::Context::new();
+        context.insert("name", "example");
+        context.insert("kind", "lib");
+
+        template.load_files(sandbox.path(), &context).unwrap();
+
+        assert_eq!(
+            template
+                .files
+                .keys()
+                .map(|key| key.as_str())
+                .collect::<Vec<_>>(),
+            ["file.txt", "to.txt"]
+        );
+    }
+
+    #[test]
+    fn fails_without_snapshot() {
+        let sandbox = create_template_sandbox();
+        let template = load_template(&sandbox);
+        let temp_dir = sandbox.path().join("temp");
+        let tester = TemplateTester::new(&template, sandbox.path(), &temp_dir);
+
+        let fixtures = tester.load_fixtures().unwrap();
+        let result = tester.test_fixture(&fixtures[0]).unwrap();
+
+        assert!(!result.passed());
+        assert_eq!(
+            result.issues,
+            [
+                TemplateTestIssue::SnapshotMissing {
+                    file: "example/to.txt".into()
+                },
+                TemplateTestIssue::SnapshotMissing {
+                    file: "file.txt".into()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn updates_snapshot_and_passes() {
+        let sandbox = create_template_sandbox();
+        let template = load_template(&sandbox);
+        let temp_dir = sandbox.path().join("temp");
+        let mut tester = TemplateTester::new(&template, sandbox.path(), &temp_dir);
+        tester.update_snapshots = true;
+
+        let fixtures = tester.load_fixtures().unwrap();
+        let result = tester.test_fixture(&fixtures[0]).unwrap();
+
+        assert!(result.passed());
+        assert!(result.updated_snapshot);
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("template/__snapshots__/basic/file.txt"))
+                .unwrap(),
+            "example app"
+        );
+        assert_eq!(
+            fs::read_to_string(
+                sandbox
+                    .path()
+                    .join("template/__snapshots__/basic/example/to.txt")
+            )
+            .unwrap(),
+            "app"
+        );
+
+        tester.update_snapshots = false;
+
+        let result = tester.test_fixture(&fixtures[0]).unwrap();
+
+        assert!(result.passed());
+        assert!(!result.updated_snapshot);
+    }
+
+    #[test]
+    fn fails_when_snapshot_changed() {
+        let sandbox = create_template_sandbox();
+        sandbox.create_file("template/__snapshots__/basic/file.txt", "example lib");
+        sandbox.create_file("template/__snapshots__/basic/example/to.txt", "app");
+        sandbox.create_file("template/__snapshots__/basic/removed.txt", "");
+
+        let template = load_template(&sandbox);
+        let temp_dir = sandbox.path().join("temp");
+        let tester = TemplateTester::new(&template, sandbox.path(), &temp_dir);
+
+        let fixtures = tester.load_fixtures().unwrap();
+        let result = tester.test_fixture(&fixtures[0]).unwrap();
+
+        assert_eq!(result.issues.len(), 2);
+
+        let TemplateTestIssue::SnapshotChanged { file, diff } = &result.issues[0] else {
+            panic!("Expected a snapshot change");
+        };
+
+        assert_eq!(file, "file.txt");
+        assert!(diff.as_ref().unwrap().contains("-example lib"));
+        assert!(diff.as_ref().unwrap().contains("+example app"));
+
+        assert_eq!(
+            result.issues[1],
+            TemplateTestIssue::SnapshotRemoved {
+                file: "removed.txt".into()
+            }
+        );
+    }
+
+    #[test]
+    fn validates_required_variables() {
+        let sandbox = create_template_sandbox();
+        let template = load_template(&sandbox);
+        let temp_dir = sandbox.path().join("temp");
+        let tester = TemplateTester::new(&template, sandbox.path(), &temp_dir);
+
+        let result = tester.test_fixture(&create_fixture("kind: lib")).unwrap();
+
+        assert!(result
+            .issues
+            .contains(&TemplateTestIssue::MissingRequiredVariable {
+                name: "name".into()
+            }));
+
+        let result = tester
+            .test_fixture(&create_fixture("name: ''\nkind: lib"))
+            .unwrap();
+
+        assert!(result
+            .issues
+            .contains(&TemplateTestIssue::MissingRequiredVariable {
+                name: "name".into()
+            }));
+    }
+
+    #[test]
+    fn validates_enum_values() {
+        let sandbox = create_template_sandbox();
+        let template = load_template(&sandbox);
+        let temp_dir = sandbox.path().join("temp");
+        let tester = TemplateTester::new(&template, sandbox.path(), &temp_dir);
+
+        let result = tester
+            .test_fixture(&create_fixture("name: example\nkind: unknown"))
+            .unwrap();
+
+        assert_eq!(
+            result.issues,
+            [TemplateTestIssue::InvalidEnumValue {
+                name: "kind".into(),
+                value: Value::String("unknown".into()),
+            }]
+        );
+    }
+
+    #[test]
+    fn validates_variable_types_and_names() {
+        let sandbox = create_template_sandbox();
+        let template = load_template(&sandbox);
+        let temp_dir = sandbox.path().join("temp");
+        let tester = TemplateTester::new(&template, sandbox.path(), &temp_dir);
+
+        let result = tester
+            .test_fixture(&create_fixture("name: 123\nunknown: true"))
+            .unwrap();
+
+        assert_eq!(
+            result.issues,
+            [
+                TemplateTestIssue::UnknownVariable {
+                    name: "unknown".into()
+                },
+                TemplateTestIssue::InvalidVariableType {
+                    name: "name".into(),
+                    value: Value::from(123),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn validates_to_paths() {
+        let sandbox = create_template_sandbox();
+        let template = load_template(&sandbox);
+        let temp_dir = sandbox.path().join("temp");
+        let tester = TemplateTester::new(&template, sandbox.path(), &temp_dir);
+
+        let result = tester
+            .test_fixture(&create_fixture("name: ../..\nkind: lib"))
+            .unwrap();
+
+        assert_eq!(
+            result.issues,
+            [TemplateTestIssue::InvalidPath {
+                file: "to.txt".into(),
+                path: "../../to.txt".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn validates_duplicate_paths() {
+        let sandbox = create_template_sandbox();
+        sandbox.create_file("template/dupe.txt", "---\nto: file.txt\n---\n");
+
+        let template = load_template(&sandbox);
+        let temp_dir = sandbox.path().join("temp");
+        let tester = TemplateTester::new(&template, sandbox.path(), &temp_dir);
+
+        let result = tester
+            .test_fixture(&create_fixture("name: example\nkind: lib"))
+            .unwrap();
+
+        assert_eq!(
+            result.issues,
+            [TemplateTestIssue::DuplicatePath {
+                file: "file.txt".into(),
+                path: "file.txt".into(),
+            }]
+        );
+    }
+}
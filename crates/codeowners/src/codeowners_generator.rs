@@ -1,13 +1,14 @@
+use moon_common::{remove_synced_file, write_synced_file, SyncCheck};
 use moon_config::{CodeownersConfig, OwnersConfig, OwnersPaths, VcsProvider};
-use starbase_utils::fs::{self, FsError};
-use std::fs::File;
-use std::io::Write;
+use starbase_utils::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tracing::{debug, trace};
 
 pub struct CodeownersGenerator {
     pub file_path: PathBuf,
-    file: File,
+    check: Option<Arc<SyncCheck>>,
+    content: String,
     provider: VcsProvider,
 }
 
@@ -25,7 +26,8 @@ impl CodeownersGenerator {
         });
 
         let mut generator = CodeownersGenerator {
-            file: fs::create_file(&file_path)?,
+            check: None,
+            content: String::new(),
             file_path,
             provider,
         };
@@ -36,6 +38,13 @@ impl CodeownersGenerator {
         Ok(generator)
     }
 
+    /// Track file changes in the provided check, instead of
+    /// writing to and removing from the file system.
+    pub fn with_check(mut self, check: Option<Arc<SyncCheck>>) -> Self {
+        self.check = check;
+        self
+    }
+
     pub fn add_project_entry(
         &mut self,
         id: &str,
@@ -156,19 +165,18 @@ impl CodeownersGenerator {
     pub fn cleanup(self) -> miette::Result<()> {
         debug!(file = ?self.file_path, "Removing CODEOWNERS file");
 
-        drop(self.file);
-
-        fs::remove_file(&self.file_path)?;
+        remove_synced_file(self.check.as_deref(), &self.file_path)?;
 
         Ok(())
     }
 
-    pub fn generate(mut self) -> miette::Result<bool> {
+    pub fn generate(self) -> miette::Result<bool> {
         debug!(file = ?self.file_path, "Generating and writing CODEOWNERS file");
 
-        self.file.flush().map_err(|error| FsError::Create {
-            path: self.file_path.to_path_buf(),
-            error: Box::new(error),
+        write_synced_file(self.check.as_deref(), &self.file_path, |file_path| {
+            fs::write_file(file_path, &self.content)?;
+
+            Ok(())
         })?;
 
         Ok(true)
@@ -183,10 +191,8 @@ impl CodeownersGenerator {
     }
 
     fn write<T: AsRef<str>>(&mut self, message: T) -> miette::Result<()> {
-        writeln!(self.file, "{}", message.as_ref()).map_err(|error| FsError::Create {
-            path: self.file_path.to_path_buf(),
-            error: Box::new(error),
-        })?;
+        self.content.push_str(message.as_ref());
+        self.content.push('\n');
 
         Ok(())
     }
@@ -0,0 +1,291 @@
+use moon_config::{CodeownersConfig, OwnersConfig, OwnersPaths, VcsProvider};
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use starbase_utils::glob::GlobSet;
+use std::collections::{BTreeMap, BTreeSet};
+use tracing::{debug, trace};
+
+/// A single path to owners entry, equivalent to a line in a CODEOWNERS file.
+struct CodeownersRule {
+    globs: Vec<String>,
+    owners: Vec<String>,
+    section: usize,
+}
+
+/// A group of entries, equivalent to a project or workspace block in a CODEOWNERS file.
+struct CodeownersSection {
+    // Groups are defined per project, and may share names across projects
+    custom_groups: FxHashMap<String, Vec<String>>,
+    project_id: Option<String>,
+    optional: bool,
+    required_approvals: u8,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeownersResolvedSection {
+    /// Files that matched an entry within this section.
+    pub files: BTreeSet<String>,
+
+    /// Whether approvals from this section are optional (GitLab only).
+    pub optional: bool,
+
+    /// Owners of the matched files.
+    pub owners: BTreeSet<String>,
+
+    /// Project that the section belongs to, or none for the workspace.
+    pub project_id: Option<String>,
+
+    /// The number of approvals required from the owners.
+    pub required_approvals: u8,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeownersResolution {
+    /// A mapping of files to their owners.
+    pub files: BTreeMap<String, BTreeSet<String>>,
+
+    /// Owners that are required to review the files.
+    pub required_reviewers: BTreeSet<String>,
+
+    /// Sections that contain at least 1 matched file.
+    pub sections: Vec<CodeownersResolvedSection>,
+
+    /// Files that do not have an owner.
+    pub unowned_files: BTreeSet<String>,
+}
+
+/// Resolves the owners of files, using the same entries and ordering
+/// that the `CodeownersGenerator` writes to the CODEOWNERS file.
+pub struct CodeownersResolver {
+    provider: VcsProvider,
+    rules: Vec<CodeownersRule>,
+    sections: Vec<CodeownersSection>,
+}
+
+impl CodeownersResolver {
+    pub fn new(provider: VcsProvider) -> CodeownersResolver {
+        debug!("Aggregating code owners for resolution");
+
+        CodeownersResolver {
+            provider,
+            rules: vec![],
+            sections: vec![],
+        }
+    }
+
+    pub fn add_project_entry(
+        &mut self,
+        id: &str,
+        source: &str,
+        config: &OwnersConfig,
+        root_config: &CodeownersConfig,
+    ) {
+        if config.paths.is_empty() {
+            return;
+        }
+
+        trace!(project_id = id, source, "Adding project entries");
+
+        let section = self.add_section(CodeownersSection {
+            custom_groups: config.custom_groups.clone(),
+            project_id: Some(id.to_owned()),
+            optional: config.optional,
+            required_approvals: config
+                .required_approvals
+                .or(root_config.required_approvals)
+                .unwrap_or(0),
+        });
+
+        let default_owners = config
+            .default_owner
+            .as_ref()
+            .map(|owner| vec![owner.to_owned()])
+            .unwrap_or_default();
+
+        // Project entries are always rooted to the project's source
+        let root = source.trim_start_matches("./").trim_matches('/');
+
+        let mut add_rule = |path: &str, owners: Vec<String>| {
+            let path = path.trim_start_matches('/');
+
+            self.rules.push(CodeownersRule {
+                globs: create_globs(&if root.is_empty() || root == "." {
+                    format!("/{path}")
+                } else {
+                    format!("/{root}/{path}")
+                }),
+                owners,
+                section,
+            });
+        };
+
+        match &config.paths {
+            OwnersPaths::List(paths) => {
+                for path in paths {
+                    add_rule(path, default_owners.clone());
+                }
+            }
+            OwnersPaths::Map(map) => {
+                for (path, owners) in map {
+                    if owners.is_empty() {
+                        add_rule(path, default_owners.clone());
+                    } else {
+                        add_rule(path, owners.clone());
+                    }
+                }
+            }
+        };
+    }
+
+    pub fn add_workspace_entries(&mut self, config: &CodeownersConfig) {
+        if config.global_paths.is_empty() {
+            return;
+        }
+
+        trace!("Adding workspace entries");
+
+        let section = self.add_section(CodeownersSection {
+            custom_groups: FxHashMap::default(),
+            project_id: None,
+            optional: false,
+            required_approvals: config.required_approvals.unwrap_or(0),
+        });
+
+        for (path, owners) in &config.global_paths {
+            if !owners.is_empty() {
+                self.rules.push(CodeownersRule {
+                    globs: create_globs(path),
+                    owners: owners.clone(),
+                    section,
+                });
+            }
+        }
+    }
+
+    pub fn resolve<I, V>(&self, files: I) -> miette::Result<CodeownersResolution>
+    where
+        I: IntoIterator<Item = V>,
+        V: AsRef<str>,
+    {
+        let globsets = self
+            .rules
+            .iter()
+            .map(|rule| GlobSet::new(&rule.globs))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut result = CodeownersResolution::default();
+        let mut sections = BTreeMap::<usize, CodeownersResolvedSection>::new();
+
+        for file in files {
+            let file = file.as_ref().replace('\\', "/");
+            let mut owners = BTreeSet::new();
+
+            for index in self.find_matching_rules(&globsets, &file) {
+                let rule = &self.rules[index];
+                let rule_owners = self.expand_owners(rule);
+
+                let section = sections.entry(rule.section).or_insert_with(|| {
+                    let section = &self.sections[rule.section];
+
+                    CodeownersResolvedSection {
+                        optional: section.optional,
+                        project_id: section.project_id.clone(),
+                        required_approvals: section.required_approvals,
+                        ..Default::default()
+                    }
+                });
+
+                section.files.insert(file.clone());
+                section.owners.extend(rule_owners.clone());
+                owners.extend(rule_owners);
+            }
+
+            if owners.is_empty() {
+                result.unowned_files.insert(file.clone());
+            }
+
+            result.files.insert(file, owners);
+        }
+
+        for section in sections.into_values() {
+            if !section.optional {
+                result.required_reviewers.extend(section.owners.clone());
+            }
+
+            result.sections.push(section);
+        }
+
+        Ok(result)
+    }
+
+    fn add_section(&mut self, mut section: CodeownersSection) -> usize {
+        // Only GitLab supports optional sections
+        section.optional = section.optional && matches!(self.provider, VcsProvider::GitLab);
+
+        self.sections.push(section);
+        self.sections.len() - 1
+    }
+
+    // Groups are only expanded within the section they were defined in
+    fn expand_owners(&self, rule: &CodeownersRule) -> Vec<String> {
+        let custom_groups = &self.sections[rule.section].custom_groups;
+
+        rule.owners
+            .iter()
+            .flat_map(|owner| match custom_groups.get(owner) {
+                Some(members) => members.clone(),
+                None => vec![owner.to_owned()],
+            })
+            .collect()
+    }
+
+    // The last matching entry takes precedence. For GitLab, each section
+    // is evaluated separately, so a file may match an entry in every section.
+    fn find_matching_rules(&self, globsets: &[GlobSet], file: &str) -> Vec<usize> {
+        let mut matches = BTreeMap::<usize, usize>::new();
+
+        for (index, rule) in self.rules.iter().enumerate() {
+            if globsets[index].is_match(file) {
+                let key = if matches!(self.provider, VcsProvider::GitLab) {
+                    rule.section
+                } else {
+                    0
+                };
+
+                matches.insert(key, index);
+            }
+        }
+
+        matches.into_values().collect()
+    }
+}
+
+// Convert a CODEOWNERS path into globs. Paths with a leading or inner slash
+// are relative to the workspace root, otherwise they match at any depth.
+// Paths that match a directory also match everything within it.
+fn create_globs(path: &str) -> Vec<String> {
+    let dir_only = path.ends_with('/');
+    let path = path.trim_end_matches('/');
+    let anchored = path.starts_with('/') || path.contains('/');
+    let path = path.trim_start_matches('/');
+
+    if path.is_empty() {
+        return vec!["**/*".into()];
+    }
+
+    let base = if anchored {
+        path.to_owned()
+    } else {
+        format!("**/{path}")
+    };
+
+    let mut globs = vec![format!("{base}/**/*")];
+
+    if !dir_only {
+        globs.push(base);
+    }
+
+    globs
+}
@@ -1,5 +1,7 @@
 mod codeowners_generator;
 mod codeowners_hash;
+mod codeowners_resolver;
 
 pub use codeowners_generator::*;
 pub use codeowners_hash::*;
+pub use codeowners_resolver::*;
@@ -0,0 +1,197 @@
+use moon_codeowners::{CodeownersResolution, CodeownersResolver};
+use moon_config::{CodeownersConfig, ConfigLoader, OwnersConfig, OwnersPaths, VcsProvider};
+use rustc_hash::FxHashMap;
+use starbase_sandbox::{create_empty_sandbox, locate_fixture};
+use std::collections::BTreeSet;
+use std::fs;
+
+fn resolve(provider: VcsProvider, with_workspace: bool, files: &[&str]) -> CodeownersResolution {
+    let sandbox = create_empty_sandbox();
+    let config_loader = ConfigLoader::default();
+
+    sandbox.create_file(
+        ".moon/workspace.yml",
+        fs::read_to_string(locate_fixture("workspace").join("workspace.yml")).unwrap(),
+    );
+
+    let mut resolver = CodeownersResolver::new(provider);
+    let workspace_config = config_loader.load_workspace_config(sandbox.path()).unwrap();
+
+    if with_workspace {
+        resolver.add_workspace_entries(&workspace_config.codeowners);
+    }
+
+    for project_fixture in ["custom-groups", "list-paths", "map-paths", "no-paths"] {
+        sandbox.create_file(
+            format!("{}/moon.yml", project_fixture),
+            fs::read_to_string(locate_fixture(project_fixture).join("moon.yml")).unwrap(),
+        );
+
+        let project_config = config_loader
+            .load_project_config_from_source(sandbox.path(), project_fixture)
+            .unwrap();
+
+        resolver.add_project_entry(
+            project_fixture,
+            project_fixture,
+            &project_config.owners,
+            &workspace_config.codeowners,
+        );
+    }
+
+    resolver.resolve(files).unwrap()
+}
+
+fn owners(list: &[&str]) -> BTreeSet<String> {
+    BTreeSet::from_iter(list.iter().map(|owner| owner.to_string()))
+}
+
+mod codeowners_resolver {
+    use super::*;
+
+    #[test]
+    fn uses_workspace_wildcard() {
+        let result = resolve(VcsProvider::GitHub, true, &["README.md", "a/b/c.txt"]);
+
+        assert_eq!(result.files["README.md"], owners(&["@admins"]));
+        assert_eq!(result.files["a/b/c.txt"], owners(&["@admins"]));
+        assert!(result.unowned_files.is_empty());
+    }
+
+    #[test]
+    fn last_entry_takes_precedence() {
+        let result = resolve(
+            VcsProvider::GitHub,
+            true,
+            &["index.js", "config/app.yml", "map-paths/config/app.yml"],
+        );
+
+        assert_eq!(result.files["index.js"], owners(&["@infra", "@frontend"]));
+        assert_eq!(result.files["config/app.yml"], owners(&["@infra"]));
+        assert_eq!(
+            result.files["map-paths/config/app.yml"],
+            owners(&["@backend"])
+        );
+    }
+
+    #[test]
+    fn rooted_paths_dont_match_nested_files() {
+        let result = resolve(
+            VcsProvider::GitHub,
+            true,
+            &["map-paths/lib.rs", "map-paths/src/lib.rs"],
+        );
+
+        assert_eq!(
+            result.files["map-paths/lib.rs"],
+            owners(&["@rust-dev", "@api-team"])
+        );
+        assert_eq!(result.files["map-paths/src/lib.rs"], owners(&["@admins"]));
+    }
+
+    #[test]
+    fn expands_custom_groups() {
+        let result = resolve(
+            VcsProvider::Bitbucket,
+            false,
+            &["custom-groups/path/with space/file.txt"],
+        );
+
+        assert_eq!(
+            result.files["custom-groups/path/with space/file.txt"],
+            owners(&["@@team", "@user"])
+        );
+    }
+
+    #[test]
+    fn scopes_custom_groups_to_their_project() {
+        let mut resolver = CodeownersResolver::new(VcsProvider::Bitbucket);
+        let root_config = CodeownersConfig::default();
+
+        for (id, members) in [("a", "@team-a"), ("b", "@team-b"), ("c", "")] {
+            resolver.add_project_entry(
+                id,
+                id,
+                &OwnersConfig {
+                    custom_groups: if members.is_empty() {
+                        FxHashMap::default()
+                    } else {
+                        FxHashMap::from_iter([("@@@group".into(), vec![members.into()])])
+                    },
+                    paths: OwnersPaths::List(vec!["**/*".into()]),
+                    default_owner: Some("@@@group".into()),
+                    ..OwnersConfig::default()
+                },
+                &root_config,
+            );
+        }
+
+        let result = resolver
+            .resolve(["a/file.txt", "b/file.txt", "c/file.txt"])
+            .unwrap();
+
+        assert_eq!(result.files["a/file.txt"], owners(&["@team-a"]));
+        assert_eq!(result.files["b/file.txt"], owners(&["@team-b"]));
+        assert_eq!(result.files["c/file.txt"], owners(&["@@@group"]));
+    }
+
+    #[test]
+    fn tracks_unowned_files() {
+        let result = resolve(
+            VcsProvider::GitHub,
+            false,
+            &["README.md", "map-paths/lib.rs"],
+        );
+
+        assert_eq!(result.unowned_files, owners(&["README.md"]));
+        assert_eq!(result.files["README.md"], owners(&[]));
+    }
+
+    #[test]
+    fn groups_by_section() {
+        let result = resolve(
+            VcsProvider::GitHub,
+            true,
+            &["map-paths/lib.rs", "map-paths/config/app.yml", "README.md"],
+        );
+
+        assert_eq!(result.sections.len(), 2);
+        assert_eq!(result.sections[0].project_id, None);
+        assert_eq!(result.sections[0].files, owners(&["README.md"]));
+        assert_eq!(result.sections[1].project_id, Some("map-paths".into()));
+        assert_eq!(result.sections[1].required_approvals, 3);
+        assert_eq!(
+            result.sections[1].owners,
+            owners(&["@api-team", "@backend", "@rust-dev"])
+        );
+        assert_eq!(
+            result.required_reviewers,
+            owners(&["@admins", "@api-team", "@backend", "@rust-dev"])
+        );
+    }
+
+    #[test]
+    fn matches_every_section_for_gitlab() {
+        let result = resolve(VcsProvider::GitLab, true, &["list-paths/index.ts"]);
+
+        assert_eq!(
+            result.files["list-paths/index.ts"],
+            owners(&["@admins", "@frontend"])
+        );
+        assert_eq!(result.sections.len(), 2);
+
+        // List paths section is optional
+        assert!(result.sections[1].optional);
+        assert_eq!(result.required_reviewers, owners(&["@admins"]));
+    }
+
+    #[test]
+    fn matches_only_last_section_for_github() {
+        let result = resolve(VcsProvider::GitHub, true, &["list-paths/index.ts"]);
+
+        assert_eq!(result.files["list-paths/index.ts"], owners(&["@frontend"]));
+        assert_eq!(result.sections.len(), 1);
+        assert!(!result.sections[0].optional);
+        assert_eq!(result.required_reviewers, owners(&["@frontend"]));
+    }
+}
@@ -5,8 +5,12 @@ mod id;
 mod macros;
 pub mod path;
 pub mod serde;
+#[cfg(not(target_arch = "wasm32"))]
+mod sync_check;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use env::*;
 pub use id::*;
 pub use starbase_styles::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use sync_check::*;
@@ -0,0 +1,184 @@
+use miette::IntoDiagnostic;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// A file whose current contents differ from the expected synced contents.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SyncDrift {
+    /// Current contents, or none if the file does not exist.
+    pub current: Option<String>,
+
+    /// Expected contents, or none if the file should not exist.
+    pub expected: Option<String>,
+
+    /// Absolute path to the file.
+    pub path: PathBuf,
+}
+
+/// The result of a sync check, with all drift that was detected.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SyncCheckResult {
+    /// Files that are out of sync.
+    pub files: Vec<SyncDrift>,
+
+    /// Operations that would have been executed (plugins, etc).
+    pub operations: Vec<String>,
+}
+
+impl SyncCheckResult {
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty() && self.operations.is_empty()
+    }
+}
+
+#[derive(Debug, Default)]
+struct SyncCheckState {
+    // Original path -> temporary copy, or none if removed
+    files: BTreeMap<PathBuf, Option<PathBuf>>,
+    operations: Vec<String>,
+    temp_count: usize,
+}
+
+/// Tracks files that would be mutated while syncing, without mutating them.
+/// Synced files are written to a temporary copy within the provided directory,
+/// instead of the original location, and are compared when finished.
+#[derive(Debug)]
+pub struct SyncCheck {
+    state: Mutex<SyncCheckState>,
+    temp_dir: PathBuf,
+}
+
+impl SyncCheck {
+    pub fn new(temp_dir: &Path) -> Self {
+        Self {
+            state: Mutex::new(SyncCheckState::default()),
+            temp_dir: temp_dir.to_path_buf(),
+        }
+    }
+
+    /// Write a synced file using the provided writer. The writer
+    /// receives a path to a temporary copy of the file.
+    pub fn write_file<F>(&self, path: &Path, writer: F) -> miette::Result<()>
+    where
+        F: FnOnce(&Path) -> miette::Result<()>,
+    {
+        let target = {
+            let mut state = self.state.lock().unwrap();
+
+            match state.files.get(path) {
+                Some(Some(temp_path)) => temp_path.to_owned(),
+                existing => {
+                    let removed = existing.is_some();
+
+                    // Preserve the file name, as some writers depend on it
+                    state.temp_count += 1;
+
+                    let temp_path = self
+                        .temp_dir
+                        .join(state.temp_count.to_string())
+                        .join(path.file_name().unwrap_or_default());
+
+                    fs::create_dir_all(temp_path.parent().unwrap()).into_diagnostic()?;
+
+                    // Start from the current contents, unless previously removed,
+                    // so that writers that preserve or mutate content still work
+                    if !removed && path.exists() {
+                        fs::copy(path, &temp_path).into_diagnostic()?;
+                    }
+
+                    state
+                        .files
+                        .insert(path.to_path_buf(), Some(temp_path.clone()));
+
+                    temp_path
+                }
+            }
+        };
+
+        writer(&target)
+    }
+
+    /// Remove a synced file. The removal is only recorded.
+    pub fn remove_file(&self, path: &Path) {
+        self.state
+            .lock()
+            .unwrap()
+            .files
+            .insert(path.to_path_buf(), None);
+    }
+
+    /// Record an operation that would have been executed.
+    pub fn record_operation(&self, label: impl AsRef<str>) {
+        self.state
+            .lock()
+            .unwrap()
+            .operations
+            .push(label.as_ref().to_owned());
+    }
+
+    /// Compare all synced files against their original location,
+    /// returning any that have drifted, and clean up temporary copies.
+    pub fn finish(&self) -> miette::Result<SyncCheckResult> {
+        let state = std::mem::take(&mut *self.state.lock().unwrap());
+
+        let read = |path: &Path| -> miette::Result<Option<String>> {
+            if path.exists() {
+                Ok(Some(fs::read_to_string(path).into_diagnostic()?))
+            } else {
+                Ok(None)
+            }
+        };
+
+        let mut result = SyncCheckResult {
+            operations: state.operations,
+            ..Default::default()
+        };
+
+        for (path, temp_path) in state.files {
+            let current = read(&path)?;
+            let expected = match &temp_path {
+                Some(temp_path) => read(temp_path)?,
+                None => None,
+            };
+
+            if current != expected {
+                result.files.push(SyncDrift {
+                    current,
+                    expected,
+                    path,
+                });
+            }
+        }
+
+        if self.temp_dir.exists() {
+            fs::remove_dir_all(&self.temp_dir).into_diagnostic()?;
+        }
+
+        Ok(result)
+    }
+}
+
+/// Write a synced file using the provided writer. When checking,
+/// the writer receives a path to a temporary copy of the file instead.
+pub fn write_synced_file<F>(check: Option<&SyncCheck>, path: &Path, writer: F) -> miette::Result<()>
+where
+    F: FnOnce(&Path) -> miette::Result<()>,
+{
+    match check {
+        Some(check) => check.write_file(path, writer),
+        None => writer(path),
+    }
+}
+
+/// Remove a synced file. When checking, the removal is only recorded.
+pub fn remove_synced_file(check: Option<&SyncCheck>, path: &Path) -> miette::Result<()> {
+    if let Some(check) = check {
+        check.remove_file(path);
+    } else if path.exists() {
+        fs::remove_file(path).into_diagnostic()?;
+    }
+
+    Ok(())
+}
@@ -12,6 +12,10 @@ cacheable!(
         /// The base Docker image.
         pub image: Option<String>,
 
+        /// The base Docker image for a runtime-only final stage. When defined,
+        /// only the build outputs and production dependencies are copied.
+        pub runtime_image: Option<String>,
+
         /// A task within the current project for starting the project.
         pub start_task: Option<Id>,
     }
@@ -2,7 +2,7 @@ use crate::language_platform::PlatformType;
 use crate::project::{PartialTaskOptionsConfig, TaskOptionsConfig};
 use crate::shapes::{InputPath, OneOrMany, OutputPath};
 use moon_common::{cacheable, Id};
-use moon_target::{Target, TargetScope};
+use moon_target::{Target, TargetLocator, TargetScope};
 use rustc_hash::FxHashMap;
 use schematic::{derive_enum, merge, Config, ConfigEnum, ValidateError};
 
@@ -54,6 +54,22 @@ pub(crate) fn validate_deps<D, C>(
             PartialTaskDependency::Target(target) => {
                 scope = &target.scope;
             }
+            PartialTaskDependency::Locator(locator) => {
+                let inner = match locator {
+                    TargetLocator::Exclude { locator, .. } => &**locator,
+                    locator => locator,
+                };
+
+                if let TargetLocator::GlobMatch {
+                    scope: Some(glob_scope),
+                    ..
+                } = inner
+                {
+                    scope = glob_scope;
+                } else {
+                    continue;
+                }
+            }
         };
 
         if matches!(scope, TargetScope::All) {
@@ -151,6 +167,10 @@ cacheable!(
         /// A task referenced by target.
         Target(Target),
 
+        /// Tasks referenced by a glob or exclusion locator, which are
+        /// resolved when the task's dependencies are built.
+        Locator(TargetLocator),
+
         /// A task referenced by target, with additional parameters to pass through.
         #[setting(nested)]
         Config(TaskDependencyConfig),
@@ -158,10 +178,18 @@ cacheable!(
 );
 
 impl TaskDependency {
-    pub fn into_config(self) -> TaskDependencyConfig {
+    pub fn into_config(self) -> Option<TaskDependencyConfig> {
+        match self {
+            Self::Config(config) => Some(config),
+            Self::Locator(_) => None,
+            Self::Target(target) => Some(TaskDependencyConfig::new(target)),
+        }
+    }
+
+    pub fn into_locator(self) -> Option<TargetLocator> {
         match self {
-            Self::Config(config) => config,
-            Self::Target(target) => TaskDependencyConfig::new(target),
+            Self::Locator(locator) => Some(locator),
+            _ => None,
         }
     }
 }
@@ -1,4 +1,15 @@
-use schematic::Config;
+use schematic::{derive_enum, Config, ConfigEnum};
+
+derive_enum!(
+    /// The format in which to merge a template file with an existing file.
+    #[derive(ConfigEnum, Copy)]
+    pub enum TemplateMergeType {
+        Ini,
+        Json,
+        Toml,
+        Yaml,
+    }
+);
 
 /// Docs: https://moonrepo.dev/docs/config/template#frontmatter
 #[derive(Clone, Config, Debug, Eq, PartialEq)]
@@ -10,6 +21,7 @@ pub struct TemplateFrontmatterConfig {
     pub schema: String,
 
     pub force: bool,
+    pub merge: Option<TemplateMergeType>,
     pub to: Option<String>,
     pub skip: bool,
 }
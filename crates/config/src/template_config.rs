@@ -217,6 +217,22 @@ impl TemplateVariable {
     }
 }
 
+/// Configures actions to run after code has been generated.
+#[derive(Clone, Config, Debug, Eq, PartialEq)]
+pub struct TemplatePostGenerateConfig {
+    /// Add the destination as a project to the `projects` setting
+    /// in `.moon/workspace.yml`, if not already configured.
+    pub add_to_workspace: bool,
+
+    /// A list of shell commands to execute in order, from the destination.
+    /// Supports variable interpolation.
+    pub commands: Vec<String>,
+
+    /// A list of targets to run through the action pipeline,
+    /// after all commands have executed. Supports variable interpolation.
+    pub targets: Vec<String>,
+}
+
 /// Configures a template and its files to be scaffolded.
 /// Docs: https://moonrepo.dev/docs/config/template
 #[derive(Clone, Config, Debug, PartialEq)]
@@ -241,6 +257,10 @@ pub struct TemplateConfig {
     /// Overrides the ID of the template, instead of using the folder name.
     pub id: Option<Id>,
 
+    /// Actions to run after code has been generated.
+    #[setting(nested)]
+    pub post_generate: TemplatePostGenerateConfig,
+
     /// A human-readable title for the template.
     #[setting(validate = validate::not_empty)]
     pub title: String,
@@ -249,4 +269,8 @@ pub struct TemplateConfig {
     /// Variables can also be populated by passing command line arguments.
     #[setting(nested)]
     pub variables: FxHashMap<String, TemplateVariable>,
+
+    /// The version of the template, which is recorded in the lock file
+    /// of generated code, and compared against when updating.
+    pub version: Option<String>,
 }
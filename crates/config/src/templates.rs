@@ -18,6 +18,10 @@ pub fn load_toolchain_node_config_template() -> &'static str {
     include_str!("../templates/toolchain_node.yml")
 }
 
+pub fn load_toolchain_python_config_template() -> &'static str {
+    include_str!("../templates/toolchain_python.yml")
+}
+
 pub fn load_toolchain_rust_config_template() -> &'static str {
     include_str!("../templates/toolchain_rust.yml")
 }
@@ -1,15 +1,79 @@
+use chrono::NaiveDate;
 use moon_common::Id;
 use rustc_hash::FxHashMap;
 use schematic::Config;
 
+/// Denies relationships between projects that match the provided globs.
+#[derive(Clone, Config, Debug, PartialEq)]
+pub struct ConstraintsDenyConfig {
+    /// List of project ID globs that the rule applies to.
+    pub from: Vec<String>,
+
+    /// An optional reason for the rule, displayed in violations.
+    pub reason: Option<String>,
+
+    /// List of project ID globs that cannot be depended on.
+    pub to: Vec<String>,
+}
+
+/// Allows a relationship between projects that would otherwise be
+/// denied by a layer or deny rule.
+#[derive(Clone, Config, Debug, PartialEq)]
+pub struct ConstraintsExceptionConfig {
+    /// The date (YYYY-MM-DD) after which the exception no longer applies.
+    pub expires: Option<NaiveDate>,
+
+    /// A project ID glob of the dependent project.
+    pub from: String,
+
+    /// An optional reason for the exception.
+    pub reason: Option<String>,
+
+    /// A project ID glob of the dependency project.
+    pub to: String,
+}
+
+/// A layer within a layered architecture.
+#[derive(Clone, Config, Debug, PartialEq)]
+pub struct ConstraintsLayerConfig {
+    /// Unique ID of the layer.
+    pub id: Id,
+
+    /// List of project ID globs that belong to the layer.
+    pub projects: Vec<String>,
+}
+
 /// Configures boundaries and constraints between projects.
 #[derive(Clone, Config, Debug, PartialEq)]
 pub struct ConstraintsConfig {
+    /// List of rules that deny relationships between projects,
+    /// based on project ID globs.
+    #[setting(nested)]
+    pub deny_relationships: Vec<ConstraintsDenyConfig>,
+
     /// Enforces relationships between projects based on each project's
     /// `type` setting.
     #[setting(default = true)]
     pub enforce_project_type_relationships: bool,
 
+    /// List of relationships that are allowed, even when denied by
+    /// `layers` or `denyRelationships`. Can be configured to expire.
+    #[setting(nested)]
+    pub exceptions: Vec<ConstraintsExceptionConfig>,
+
+    /// List of layers, ordered from highest to lowest. A project within a
+    /// layer may only depend on projects within the same or a lower layer.
+    #[setting(nested)]
+    pub layers: Vec<ConstraintsLayerConfig>,
+
+    /// The maximum number of projects, including transitive projects,
+    /// that a project may depend on.
+    pub max_dependencies: Option<usize>,
+
+    /// The maximum number of projects, including transitive projects,
+    /// that may depend on a project.
+    pub max_dependents: Option<usize>,
+
     /// Enforces relationships between projects based on each project's
     /// `tags` setting. Requires a mapping of tags, to acceptable tags.
     pub tag_relationships: FxHashMap<Id, Vec<Id>>,
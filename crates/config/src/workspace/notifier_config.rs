@@ -1,4 +1,53 @@
-use schematic::{validate, Config};
+use rustc_hash::FxHashMap;
+use schematic::{validate, Config, ValidateError, ValidateResult};
+
+const MAX_WEBHOOK_RETRIES: u8 = 10;
+
+fn validate_retries<D, C>(value: &u8, _data: &D, _context: &C, _finalize: bool) -> ValidateResult {
+    if *value > MAX_WEBHOOK_RETRIES {
+        return Err(ValidateError::new(format!(
+            "retries cannot be greater than {MAX_WEBHOOK_RETRIES}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Configures a single webhook endpoint, and which events are sent to it.
+#[derive(Clone, Config, Debug, PartialEq)]
+pub struct NotifierWebhookConfig {
+    /// Group high-volume action and task events into batches of this size,
+    /// and send each batch as a single request. When `0`, batching is disabled.
+    pub batch_size: usize,
+
+    /// List of event types to send, with support for a trailing
+    /// wildcard (`action.*`). When empty, all events are sent.
+    pub events: Vec<String>,
+
+    /// Only send events that represent a failure, such as a failed
+    /// action, or a pipeline that did not complete successfully.
+    pub failures_only: bool,
+
+    /// HTTP headers to inject into every request.
+    pub headers: FxHashMap<String, String>,
+
+    /// The maximum amount of times to retry a failed request,
+    /// with an exponential backoff between each attempt. Cannot exceed 10.
+    #[setting(default = 3, validate = validate_retries)]
+    pub retries: u8,
+
+    /// The name of an environment variable that contains a secret, used to
+    /// sign payloads with HMAC-SHA256 via the `X-Moon-Signature` header.
+    pub secret: Option<String>,
+
+    /// A Tera template that is rendered to create the request body,
+    /// instead of using the default JSON payload.
+    pub template: Option<String>,
+
+    /// A secure URL in which to send webhooks to.
+    #[setting(validate = validate::url_secure)]
+    pub url: String,
+}
 
 /// Configures how and where notifications are sent.
 #[derive(Clone, Config, Debug, PartialEq)]
@@ -6,4 +55,9 @@ pub struct NotifierConfig {
     /// A secure URL in which to send webhooks to.
     #[setting(validate = validate::url_secure)]
     pub webhook_url: Option<String>,
+
+    /// A list of webhook endpoints, each with their own event
+    /// filtering, payload template, and signing secret.
+    #[setting(nested)]
+    pub webhooks: Vec<NotifierWebhookConfig>,
 }
@@ -666,6 +666,7 @@ workspace:
                         file: ProjectDockerFileConfig {
                             build_task: Some(Id::raw("build")),
                             image: Some("node:latest".into()),
+                            runtime_image: None,
                             start_task: Some(Id::raw("start")),
                         },
                         scaffold: ProjectDockerScaffoldConfig {
@@ -7,7 +7,7 @@ use moon_config::{
     FilePath, InputPath, OneOrMany, OutputPath, PlatformType, TaskArgs, TaskConfig, TaskDependency,
     TaskDependencyConfig, TaskMergeStrategy, TaskOutputStyle, TaskType,
 };
-use moon_target::Target;
+use moon_target::{Target, TargetLocator};
 use rustc_hash::FxHashMap;
 use schematic::{ConfigLoader as BaseLoader, Format};
 use std::path::Path;
@@ -220,12 +220,40 @@ deps:
             test_parse_config("deps: ['bad target']", load_config_from_code);
         }
 
+        #[test]
+        fn supports_locators() {
+            let config = test_parse_config(
+                r"
+deps:
+  - app-*:build
+  - ^:build-*
+  - '!legacy:build'
+",
+                load_config_from_code,
+            );
+
+            assert_eq!(
+                config.deps,
+                Some(vec![
+                    TaskDependency::Locator(TargetLocator::parse("app-*:build").unwrap()),
+                    TaskDependency::Locator(TargetLocator::parse("^:build-*").unwrap()),
+                    TaskDependency::Locator(TargetLocator::parse("!legacy:build").unwrap()),
+                ])
+            );
+        }
+
         #[test]
         #[should_panic(expected = "target scope not supported as a task dependency")]
         fn errors_on_all_scope() {
             test_parse_config("deps: [':task']", load_config_from_code);
         }
 
+        #[test]
+        #[should_panic(expected = "target scope not supported as a task dependency")]
+        fn errors_on_all_scope_glob() {
+            test_parse_config("deps: [':task-*']", load_config_from_code);
+        }
+
         #[test]
         #[should_panic(expected = "a target field is required")]
         fn errors_if_using_object_with_no_target() {
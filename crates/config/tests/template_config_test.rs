@@ -1,6 +1,8 @@
 mod utils;
 
-use moon_config::{ConfigLoader, TemplateConfig, TemplateVariableEnumDefault};
+use moon_config::{
+    ConfigLoader, TemplateConfig, TemplatePostGenerateConfig, TemplateVariableEnumDefault,
+};
 use rustc_hash::FxHashMap;
 use std::path::Path;
 use utils::*;
@@ -14,7 +16,7 @@ mod template_config {
 
     #[test]
     #[should_panic(
-        expected = "unknown field `unknown`, expected one of `$schema`, `description`, `destination`, `extends`, `id`, `title`, `variables`"
+        expected = "unknown field `unknown`, expected one of `$schema`, `description`, `destination`, `extends`, `id`, `postGenerate`, `title`, `variables`, `version`"
     )]
     fn error_unknown_field() {
         test_load_config("template.yml", "unknown: 123", |path| {
@@ -33,6 +35,43 @@ mod template_config {
         assert_eq!(config.title, "title");
         assert_eq!(config.description, "description");
         assert_eq!(config.variables, FxHashMap::default());
+        assert_eq!(config.version, None);
+    }
+
+    #[test]
+    fn loads_post_generate() {
+        let config = test_load_config(
+            "template.yml",
+            r#"
+title: title
+description: description
+postGenerate:
+  addToWorkspace: true
+  commands: ['npm install']
+  targets: ['{{ name }}:build']
+"#,
+            load_config_from_root,
+        );
+
+        assert_eq!(
+            config.post_generate,
+            TemplatePostGenerateConfig {
+                add_to_workspace: true,
+                commands: vec!["npm install".into()],
+                targets: vec!["{{ name }}:build".into()],
+            }
+        );
+    }
+
+    #[test]
+    fn loads_version() {
+        let config = test_load_config(
+            "template.yml",
+            "title: title\ndescription: description\nversion: '1.2.3'",
+            load_config_from_root,
+        );
+
+        assert_eq!(config.version, Some("1.2.3".into()));
     }
 
     mod title {
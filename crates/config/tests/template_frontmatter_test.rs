@@ -1,6 +1,6 @@
 mod utils;
 
-use moon_config::TemplateFrontmatterConfig;
+use moon_config::{TemplateFrontmatterConfig, TemplateMergeType};
 use utils::*;
 
 mod template_frontmatter {
@@ -8,7 +8,7 @@ mod template_frontmatter {
 
     #[test]
     #[should_panic(
-        expected = "unknown field `title`, expected one of `$schema`, `force`, `to`, `skip`"
+        expected = "unknown field `title`, expected one of `$schema`, `force`, `merge`, `to`, `skip`"
     )]
     fn error_unknown_field() {
         test_parse_config("title: test", |code| TemplateFrontmatterConfig::parse(code));
@@ -59,4 +59,18 @@ mod template_frontmatter {
     fn invalid_to() {
         test_parse_config("to: true", |code| TemplateFrontmatterConfig::parse(code));
     }
+
+    #[test]
+    fn can_set_merge() {
+        let config =
+            test_parse_config("merge: toml", |code| TemplateFrontmatterConfig::parse(code));
+
+        assert_eq!(config.merge, Some(TemplateMergeType::Toml));
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown variant `xml`")]
+    fn invalid_merge() {
+        test_parse_config("merge: xml", |code| TemplateFrontmatterConfig::parse(code));
+    }
 }
@@ -1,9 +1,11 @@
 mod utils;
 
+use chrono::NaiveDate;
 use httpmock::prelude::*;
 use moon_common::Id;
 use moon_config::{
-    ConfigLoader, ExtensionConfig, FilePath, GlobPath, TemplateLocator, VcsProvider,
+    ConfigLoader, ConstraintsDenyConfig, ConstraintsExceptionConfig, ConstraintsLayerConfig,
+    ExtensionConfig, FilePath, GlobPath, NotifierWebhookConfig, TemplateLocator, VcsProvider,
     WorkspaceConfig, WorkspaceProjects,
 };
 use proto_core::warpgate::UrlLocator;
@@ -351,6 +353,81 @@ constraints:
             );
         }
 
+        #[test]
+        fn can_set_architecture_rules() {
+            let config = test_load_config(
+                FILENAME,
+                r"
+constraints:
+  layers:
+    - id: ui
+      projects: ['app-*']
+    - id: domain
+      projects: ['domain-*']
+  denyRelationships:
+    - from: ['app-*']
+      to: ['internal-*']
+      reason: 'Use the public API'
+  exceptions:
+    - from: 'app-legacy'
+      to: 'internal-db'
+      expires: '2025-12-31'
+  maxDependencies: 10
+  maxDependents: 20
+",
+                load_config_from_root,
+            );
+
+            assert_eq!(
+                config.constraints.layers,
+                vec![
+                    ConstraintsLayerConfig {
+                        id: Id::raw("ui"),
+                        projects: vec!["app-*".into()],
+                    },
+                    ConstraintsLayerConfig {
+                        id: Id::raw("domain"),
+                        projects: vec!["domain-*".into()],
+                    }
+                ]
+            );
+            assert_eq!(
+                config.constraints.deny_relationships,
+                vec![ConstraintsDenyConfig {
+                    from: vec!["app-*".into()],
+                    reason: Some("Use the public API".into()),
+                    to: vec!["internal-*".into()],
+                }]
+            );
+            assert_eq!(
+                config.constraints.exceptions,
+                vec![ConstraintsExceptionConfig {
+                    expires: Some(NaiveDate::from_ymd_opt(2025, 12, 31).unwrap()),
+                    from: "app-legacy".into(),
+                    reason: None,
+                    to: "internal-db".into(),
+                }]
+            );
+            assert_eq!(config.constraints.max_dependencies, Some(10));
+            assert_eq!(config.constraints.max_dependents, Some(20));
+        }
+
+        #[test]
+        #[should_panic(expected = "input contains invalid characters")]
+        fn errors_on_invalid_exception_date() {
+            test_load_config(
+                FILENAME,
+                r"
+constraints:
+  exceptions:
+    - from: 'a'
+      to: 'b'
+      expires: 'tomorrow'
+",
+                load_config_from_root,
+            );
+        }
+
         #[test]
         #[should_panic(expected = "Invalid format for bad id")]
         fn errors_on_invalid_tag_format() {
@@ -584,6 +661,7 @@ hasher:
             let config = test_load_config(FILENAME, "notifier: {}", load_config_from_root);
 
             assert!(config.notifier.webhook_url.is_none());
+            assert!(config.notifier.webhooks.is_empty());
         }
 
         #[test]
@@ -603,6 +681,74 @@ notifier:
             );
         }
 
+        #[test]
+        fn can_set_webhooks() {
+            let config = test_load_config(
+                FILENAME,
+                r#"
+notifier:
+  webhooks:
+    - url: 'https://domain.com/metrics'
+      batchSize: 50
+    - url: 'https://hooks.slack.com/services/abc'
+      events: ['pipeline.completed']
+      failuresOnly: true
+      secret: 'SLACK_WEBHOOK_SECRET'
+      template: '{"text": "Pipeline failed"}'
+"#,
+                load_config_from_root,
+            );
+
+            assert_eq!(
+                config.notifier.webhooks,
+                vec![
+                    NotifierWebhookConfig {
+                        batch_size: 50,
+                        url: "https://domain.com/metrics".into(),
+                        ..Default::default()
+                    },
+                    NotifierWebhookConfig {
+                        events: vec!["pipeline.completed".into()],
+                        failures_only: true,
+                        secret: Some("SLACK_WEBHOOK_SECRET".into()),
+                        template: Some(r#"{"text": "Pipeline failed"}"#.into()),
+                        url: "https://hooks.slack.com/services/abc".into(),
+                        ..Default::default()
+                    }
+                ]
+            );
+            assert_eq!(config.notifier.webhooks[0].retries, 3);
+        }
+
+        #[test]
+        #[should_panic(expected = "retries cannot be greater than 10")]
+        fn errors_on_too_many_webhook_retries() {
+            test_load_config(
+                FILENAME,
+                r"
+notifier:
+  webhooks:
+    - url: 'https://domain.com/some/url'
+      retries: 50
+",
+                load_config_from_root,
+            );
+        }
+
+        #[test]
+        #[should_panic(expected = "only secure URLs are allowed")]
+        fn errors_on_non_https_webhook() {
+            test_load_config(
+                FILENAME,
+                r"
+notifier:
+  webhooks:
+    - url: 'http://domain.com/some/url'
+",
+                load_config_from_root,
+            );
+        }
+
         #[test]
         #[should_panic(expected = "not a valid url: relative URL without a base")]
         fn errors_on_invalid_url() {
@@ -878,6 +1024,7 @@ extensions:
                         Id::raw("a"),
                         vec![Id::raw("b"), Id::raw("c")]
                     )]),
+                    ..ConstraintsConfig::default()
                 }
             );
             assert_eq!(
@@ -916,7 +1063,8 @@ extensions:
             assert_eq!(
                 config.notifier,
                 NotifierConfig {
-                    webhook_url: Some("http://localhost".into())
+                    webhook_url: Some("http://localhost".into()),
+                    webhooks: vec![]
                 }
             );
             assert_eq!(
@@ -0,0 +1,169 @@
+use moon_common::path::{WorkspaceRelativePath, WorkspaceRelativePathBuf};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+static ANSI: Lazy<Regex> = Lazy::new(|| Regex::new(r"\x1b\[[0-9;]*[A-Za-z]").unwrap());
+
+// file(1,2): error TS1234: message
+static TSC: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?P<file>[^\s(]+)\((?P<line>\d+),(?P<col>\d+)\): (?P<level>error|warning) (?P<message>.+)$").unwrap()
+});
+
+// file:1:2: error: message
+// file:1:2 - error TS1234: message
+static GENERIC: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?P<file>[^\s:]+):(?P<line>\d+):(?P<col>\d+):? (?:- )?(?P<level>error|warning)(?:\[[\w-]+\])?:? (?P<message>.+)$").unwrap()
+});
+
+// error[E1234]: message
+//   --> file:1:2
+static RUST_MESSAGE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?P<level>error|warning)(?:\[(?P<code>[\w-]+)\])?: (?P<message>.+)$").unwrap()
+});
+
+static RUST_LOCATION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\s*--> (?P<file>[^:]+):(?P<line>\d+):(?P<col>\d+)$").unwrap());
+
+/// Maximum annotations to emit for a single task, as providers
+/// will truncate them anyways.
+pub const MAX_ANNOTATIONS: usize = 10;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AnnotationLevel {
+    Error,
+    Warning,
+}
+
+impl AnnotationLevel {
+    fn parse(value: &str) -> Self {
+        if value == "warning" {
+            Self::Warning
+        } else {
+            Self::Error
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Annotation {
+    pub column: Option<usize>,
+    pub file: Option<WorkspaceRelativePathBuf>,
+    pub level: AnnotationLevel,
+    pub line: Option<usize>,
+    pub message: String,
+    pub title: Option<String>,
+}
+
+impl Annotation {
+    pub fn new(level: AnnotationLevel, message: impl AsRef<str>) -> Self {
+        Self {
+            column: None,
+            file: None,
+            level,
+            line: None,
+            message: message.as_ref().to_owned(),
+            title: None,
+        }
+    }
+
+    /// Format as a GitHub Actions workflow command.
+    /// https://docs.github.com/en/actions/reference/workflow-commands-for-github-actions
+    pub fn to_github_command(&self) -> String {
+        let mut props = vec![];
+
+        if let Some(file) = &self.file {
+            props.push(format!("file={}", escape_github_property(file.as_str())));
+        }
+
+        if let Some(line) = self.line {
+            props.push(format!("line={line}"));
+        }
+
+        if let Some(column) = self.column {
+            props.push(format!("col={column}"));
+        }
+
+        if let Some(title) = &self.title {
+            props.push(format!("title={}", escape_github_property(title)));
+        }
+
+        format!(
+            "::{}{}::{}",
+            match self.level {
+                AnnotationLevel::Error => "error",
+                AnnotationLevel::Warning => "warning",
+            },
+            if props.is_empty() {
+                String::new()
+            } else {
+                format!(" {}", props.join(","))
+            },
+            escape_github_data(&self.message)
+        )
+    }
+}
+
+/// Extract file/line annotations from task output, by matching against
+/// common compiler and linter error formats. File paths are resolved
+/// relative to the task's working directory.
+pub fn extract_annotations(output: &str, working_dir: &WorkspaceRelativePath) -> Vec<Annotation> {
+    let output = ANSI.replace_all(output, "");
+    let mut annotations = vec![];
+    let mut pending: Option<Annotation> = None;
+
+    for line in output.lines() {
+        let line = line.trim_end();
+
+        if let Some(caps) = TSC.captures(line).or_else(|| GENERIC.captures(line)) {
+            let mut annotation = Annotation::new(
+                AnnotationLevel::parse(&caps["level"]),
+                caps["message"].trim(),
+            );
+            annotation.file = Some(resolve_file(&caps["file"], working_dir));
+            annotation.line = caps["line"].parse().ok();
+            annotation.column = caps["col"].parse().ok();
+            annotations.push(annotation);
+            pending = None;
+        } else if let Some(caps) = RUST_MESSAGE.captures(line) {
+            let mut annotation = Annotation::new(
+                AnnotationLevel::parse(&caps["level"]),
+                caps["message"].trim(),
+            );
+            annotation.title = caps.name("code").map(|code| code.as_str().to_owned());
+            pending = Some(annotation);
+        } else if let Some(caps) = RUST_LOCATION.captures(line) {
+            // Messages without a location (summaries) are ignored
+            if let Some(mut annotation) = pending.take() {
+                annotation.file = Some(resolve_file(&caps["file"], working_dir));
+                annotation.line = caps["line"].parse().ok();
+                annotation.column = caps["col"].parse().ok();
+                annotations.push(annotation);
+            }
+        }
+
+        if annotations.len() >= MAX_ANNOTATIONS {
+            break;
+        }
+    }
+
+    annotations
+}
+
+fn resolve_file(file: &str, working_dir: &WorkspaceRelativePath) -> WorkspaceRelativePathBuf {
+    working_dir
+        .join(file.replace('\\', "/").trim_start_matches("./"))
+        .normalize()
+}
+
+fn escape_github_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn escape_github_property(value: &str) -> String {
+    escape_github_data(value)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
@@ -0,0 +1,323 @@
+use crate::annotations::*;
+use crate::default_reporter::DefaultReporter;
+use ci_env::CiProvider;
+use miette::IntoDiagnostic;
+use moon_action::{Action, ActionNode, ActionStatus, Operation, OperationList};
+use moon_console::*;
+use moon_target::Target;
+use moon_time as time;
+use std::env;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::debug;
+
+/// A reporter that wraps the default reporter, and emits provider-native
+/// annotations, collapsible sections, and job summaries for CI providers.
+pub struct CiReporter {
+    out: Arc<ConsoleBuffer>,
+    fallback: DefaultReporter,
+    provider: CiProvider,
+}
+
+impl Default for CiReporter {
+    fn default() -> Self {
+        Self::new(ci_env::detect_provider())
+    }
+}
+
+impl CiReporter {
+    pub fn new(provider: CiProvider) -> Self {
+        Self {
+            out: Arc::new(ConsoleBuffer::empty(ConsoleStream::Stdout)),
+            fallback: DefaultReporter::default(),
+            provider,
+        }
+    }
+
+    fn is_github(&self) -> bool {
+        matches!(self.provider, CiProvider::GithubActions)
+    }
+
+    fn is_gitlab(&self) -> bool {
+        matches!(self.provider, CiProvider::Gitlab)
+    }
+
+    fn print_annotations(
+        &self,
+        target: &Target,
+        operations: &OperationList,
+        item: &TaskReportItem,
+    ) -> miette::Result<()> {
+        let Some(operation) = operations.get_last_execution() else {
+            return Ok(());
+        };
+
+        if !operation.has_failed() {
+            return Ok(());
+        }
+
+        let mut annotations = vec![];
+
+        if let Some(output) = operation.get_output() {
+            for stream in [&output.stderr, &output.stdout].into_iter().flatten() {
+                annotations.extend(extract_annotations(stream, &item.working_dir));
+            }
+        }
+
+        annotations.truncate(MAX_ANNOTATIONS);
+
+        // No patterns matched, so annotate the task itself
+        if annotations.is_empty() {
+            annotations.push(Annotation::new(
+                AnnotationLevel::Error,
+                format!(
+                    "Task {target} failed to run ({})",
+                    operation.get_output_status()
+                ),
+            ));
+        }
+
+        for mut annotation in annotations {
+            annotation.title = Some(match annotation.title {
+                Some(title) => format!("{target} ({title})"),
+                None => target.to_string(),
+            });
+
+            self.out.write_line(annotation.to_github_command())?;
+        }
+
+        Ok(())
+    }
+
+    fn print_section_start(&self, target: &Target) -> miette::Result<()> {
+        self.out.write_line(format!(
+            "\x1b[0Ksection_start:{}:{}[collapsed=true]\r\x1b[0K{}",
+            get_timestamp(),
+            get_section_name(target),
+            target
+        ))
+    }
+
+    fn print_section_end(&self, target: &Target) -> miette::Result<()> {
+        self.out.write_line(format!(
+            "\x1b[0Ksection_end:{}:{}\r\x1b[0K",
+            get_timestamp(),
+            get_section_name(target),
+        ))
+    }
+
+    fn write_job_summary(
+        &self,
+        actions: &[Action],
+        item: &PipelineReportItem,
+    ) -> miette::Result<()> {
+        let Ok(summary_path) = env::var("GITHUB_STEP_SUMMARY") else {
+            return Ok(());
+        };
+
+        if summary_path.is_empty() {
+            return Ok(());
+        }
+
+        debug!(path = &summary_path, "Writing job summary");
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&summary_path)
+            .into_diagnostic()?;
+
+        file.write_all(format_job_summary(actions, item).as_bytes())
+            .into_diagnostic()?;
+
+        Ok(())
+    }
+}
+
+impl Reporter for CiReporter {
+    fn inherit_streams(&mut self, err: Arc<ConsoleBuffer>, out: Arc<ConsoleBuffer>) {
+        self.fallback.inherit_streams(err, Arc::clone(&out));
+        self.out = out;
+    }
+
+    fn on_pipeline_started(&self, nodes: &[&ActionNode]) -> miette::Result<()> {
+        self.fallback.on_pipeline_started(nodes)
+    }
+
+    fn on_pipeline_completed(
+        &self,
+        actions: &[Action],
+        item: &PipelineReportItem,
+        error: Option<&miette::Report>,
+    ) -> miette::Result<()> {
+        self.fallback.on_pipeline_completed(actions, item, error)?;
+
+        if self.is_github() && !actions.is_empty() {
+            self.write_job_summary(actions, item)?;
+        }
+
+        Ok(())
+    }
+
+    fn on_action_started(&self, action: &Action) -> miette::Result<()> {
+        self.fallback.on_action_started(action)
+    }
+
+    fn on_action_completed(
+        &self,
+        action: &Action,
+        error: Option<&miette::Report>,
+    ) -> miette::Result<()> {
+        self.fallback.on_action_completed(action, error)
+    }
+
+    fn on_task_started(
+        &self,
+        target: &Target,
+        attempt: &Operation,
+        item: &TaskReportItem,
+    ) -> miette::Result<()> {
+        // Only open the section on the first attempt, so that
+        // retries are contained within the same section
+        if self.is_gitlab() && item.attempt_current <= 1 {
+            self.print_section_start(target)?;
+        }
+
+        self.fallback.on_task_started(target, attempt, item)
+    }
+
+    fn on_task_running(&self, target: &Target, secs: u32) -> miette::Result<()> {
+        self.fallback.on_task_running(target, secs)
+    }
+
+    fn on_task_finished(
+        &self,
+        target: &Target,
+        attempt: &Operation,
+        item: &TaskReportItem,
+        error: Option<&miette::Report>,
+    ) -> miette::Result<()> {
+        self.fallback.on_task_finished(target, attempt, item, error)
+    }
+
+    fn on_task_completed(
+        &self,
+        target: &Target,
+        operations: &OperationList,
+        item: &TaskReportItem,
+        error: Option<&miette::Report>,
+    ) -> miette::Result<()> {
+        self.fallback
+            .on_task_completed(target, operations, item, error)?;
+
+        if self.is_gitlab() && operations.get_last_execution().is_some() {
+            self.print_section_end(target)?;
+        }
+
+        if self.is_github() {
+            self.print_annotations(target, operations, item)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Format a markdown summary of all tasks that ran in the pipeline,
+/// grouped by status, for use in a CI provider's job summary.
+pub fn format_job_summary(actions: &[Action], item: &PipelineReportItem) -> String {
+    let mut ran = vec![];
+    let mut cached = vec![];
+    let mut failed = vec![];
+    let mut skipped = vec![];
+
+    for action in actions {
+        let ActionNode::RunTask(node) = &*action.node else {
+            continue;
+        };
+
+        let row = format!(
+            "| `{}` | {} |",
+            node.target,
+            action
+                .duration
+                .and_then(time::elapsed_opt)
+                .unwrap_or_else(|| "-".into())
+        );
+
+        match action.status {
+            ActionStatus::Cached | ActionStatus::CachedFromRemote => cached.push(row),
+            ActionStatus::Passed => ran.push(row),
+            ActionStatus::Aborted
+            | ActionStatus::Failed
+            | ActionStatus::Invalid
+            | ActionStatus::TimedOut => failed.push(row),
+            ActionStatus::Skipped => skipped.push(row),
+            ActionStatus::Running => {}
+        };
+    }
+
+    let mut summary = vec![
+        "### moon".to_owned(),
+        String::new(),
+        "| Ran | Cached | Failed | Skipped | Time |".into(),
+        "| --: | --: | --: | --: | --: |".into(),
+        format!(
+            "| {} | {} | {} | {} | {} |",
+            ran.len(),
+            cached.len(),
+            failed.len(),
+            skipped.len(),
+            time::elapsed(item.duration.unwrap_or_default())
+        ),
+        String::new(),
+    ];
+
+    for (title, rows) in [
+        ("Failed", failed),
+        ("Ran", ran),
+        ("Cached", cached),
+        ("Skipped", skipped),
+    ] {
+        if rows.is_empty() {
+            continue;
+        }
+
+        summary.push(format!(
+            "<details><summary>{title} ({})</summary>",
+            rows.len()
+        ));
+        summary.push(String::new());
+        summary.push("| Task | Duration |".into());
+        summary.push("| :-- | --: |".into());
+        summary.extend(rows);
+        summary.push(String::new());
+        summary.push("</details>".into());
+        summary.push(String::new());
+    }
+
+    summary.push(String::new());
+    summary.join("\n")
+}
+
+fn get_section_name(target: &Target) -> String {
+    target
+        .as_str()
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() {
+                ch.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn get_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
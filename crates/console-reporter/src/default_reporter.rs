@@ -155,7 +155,7 @@ impl DefaultReporter {
         Ok(())
     }
 
-    fn print_pipeline_failures(&self, actions: &[Action]) -> miette::Result<()> {
+    pub fn print_pipeline_failures(&self, actions: &[Action]) -> miette::Result<()> {
         for action in actions {
             if !action.has_failed() {
                 continue;
@@ -181,7 +181,7 @@ impl DefaultReporter {
         Ok(())
     }
 
-    fn print_pipeline_stats(
+    pub fn print_pipeline_stats(
         &self,
         actions: &[Action],
         item: &PipelineReportItem,
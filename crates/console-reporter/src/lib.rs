@@ -1,3 +1,13 @@
+mod annotations;
+mod ci_reporter;
 mod default_reporter;
+mod reporter_type;
+mod tui_reporter;
+mod tui_state;
 
+pub use annotations::*;
+pub use ci_reporter::*;
 pub use default_reporter::*;
+pub use reporter_type::*;
+pub use tui_reporter::*;
+pub use tui_state::*;
@@ -0,0 +1,24 @@
+use clap::ValueEnum;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
+pub enum ReporterType {
+    Ci,
+    #[default]
+    Default,
+    Tui,
+}
+
+impl fmt::Display for ReporterType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Ci => "ci",
+                Self::Default => "default",
+                Self::Tui => "tui",
+            }
+        )
+    }
+}
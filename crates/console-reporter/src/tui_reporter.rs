@@ -0,0 +1,467 @@
+use crate::default_reporter::DefaultReporter;
+use crate::tui_state::*;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use miette::IntoDiagnostic;
+use moon_action::{Action, ActionNode, Operation, OperationList};
+use moon_common::{color, is_test_env};
+use moon_console::*;
+use moon_process::ProcessRegistry;
+use moon_target::Target;
+use moon_time as time;
+use parking_lot::Mutex;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{spawn, JoinHandle};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+const MAX_OUTPUT_LINES: usize = 20;
+
+/// A full-screen interactive reporter that renders a live list of actions,
+/// with collapsible output panes. When the terminal is not interactive,
+/// or the pipeline contains interactive/persistent tasks, this will
+/// fallback to the default reporter.
+pub struct TuiReporter {
+    active: Arc<AtomicBool>,
+    err: Arc<ConsoleBuffer>,
+    out: Arc<ConsoleBuffer>,
+    fallback: DefaultReporter,
+    handle: Mutex<Option<JoinHandle<()>>>,
+    state: Arc<Mutex<TuiState>>,
+}
+
+impl Default for TuiReporter {
+    fn default() -> Self {
+        Self {
+            active: Arc::new(AtomicBool::new(false)),
+            err: Arc::new(ConsoleBuffer::empty(ConsoleStream::Stderr)),
+            out: Arc::new(ConsoleBuffer::empty(ConsoleStream::Stdout)),
+            fallback: DefaultReporter::default(),
+            handle: Mutex::new(None),
+            state: Arc::new(Mutex::new(TuiState::default())),
+        }
+    }
+}
+
+impl TuiReporter {
+    fn is_active(&self) -> bool {
+        self.active.load(Ordering::Acquire)
+    }
+
+    fn can_activate(&self, nodes: &[&ActionNode]) -> bool {
+        !is_test_env()
+            && !self.out.is_quiet()
+            && self.out.is_terminal()
+            && nodes.iter().all(|node| node.is_standard())
+    }
+
+    fn start_rendering(&self) {
+        debug!("Starting interactive reporter");
+
+        // Hold all console output until the TUI has closed,
+        // otherwise it will be written over the top of it
+        self.err.pause();
+        self.out.pause();
+        self.active.store(true, Ordering::Release);
+
+        let active = Arc::clone(&self.active);
+        let state = Arc::clone(&self.state);
+        let err = Arc::clone(&self.err);
+        let out = Arc::clone(&self.out);
+
+        *self.handle.lock() = Some(spawn(move || {
+            if let Err(error) = render_loop(&active, &state) {
+                warn!("Failed to render interactive reporter: {error}");
+            }
+
+            // The TUI may have been exited early by the user,
+            // so continue with the default output from here on
+            active.store(false, Ordering::Release);
+
+            let _ = err.resume();
+            let _ = out.resume();
+        }));
+    }
+
+    fn stop_rendering(&self) -> miette::Result<()> {
+        self.active.store(false, Ordering::Release);
+
+        if let Some(handle) = self.handle.lock().take() {
+            let _ = handle.join();
+        }
+
+        self.err.resume()?;
+        self.out.resume()?;
+
+        Ok(())
+    }
+}
+
+impl Reporter for TuiReporter {
+    fn inherit_streams(&mut self, err: Arc<ConsoleBuffer>, out: Arc<ConsoleBuffer>) {
+        self.fallback
+            .inherit_streams(Arc::clone(&err), Arc::clone(&out));
+        self.err = err;
+        self.out = out;
+    }
+
+    fn requires_captured_output(&self) -> bool {
+        self.is_active()
+    }
+
+    fn on_pipeline_started(&self, nodes: &[&ActionNode]) -> miette::Result<()> {
+        if !self.can_activate(nodes) {
+            return self.fallback.on_pipeline_started(nodes);
+        }
+
+        {
+            let mut state = self.state.lock();
+
+            for node in nodes {
+                state.queue(get_node_label(node));
+            }
+        }
+
+        self.start_rendering();
+
+        Ok(())
+    }
+
+    fn on_pipeline_completed(
+        &self,
+        actions: &[Action],
+        item: &PipelineReportItem,
+        error: Option<&miette::Report>,
+    ) -> miette::Result<()> {
+        if self.handle.lock().is_none() {
+            return self.fallback.on_pipeline_completed(actions, item, error);
+        }
+
+        self.stop_rendering()?;
+
+        if actions.is_empty() || self.out.is_quiet() {
+            return Ok(());
+        }
+
+        // Output was never printed while the TUI was active,
+        // so render the failures before the final stats
+        if actions.iter().any(|action| action.has_failed()) {
+            self.out.print_header("Review")?;
+            self.fallback.print_pipeline_failures(actions)?;
+        }
+
+        self.out.write_newline()?;
+        self.fallback.print_pipeline_stats(actions, item)?;
+        self.out.write_newline()?;
+
+        Ok(())
+    }
+
+    fn on_action_started(&self, action: &Action) -> miette::Result<()> {
+        if !self.is_active() {
+            return self.fallback.on_action_started(action);
+        }
+
+        self.state.lock().start(get_action_label(action));
+
+        Ok(())
+    }
+
+    fn on_action_completed(
+        &self,
+        action: &Action,
+        error: Option<&miette::Report>,
+    ) -> miette::Result<()> {
+        // Always track state, in case the TUI was exited early
+        self.state.lock().finish(
+            get_action_label(action),
+            TuiEntryStatus::from_action(action),
+            action.duration,
+            get_action_output(action),
+        );
+
+        if !self.is_active() {
+            return self.fallback.on_action_completed(action, error);
+        }
+
+        Ok(())
+    }
+
+    fn on_task_started(
+        &self,
+        target: &Target,
+        attempt: &Operation,
+        item: &TaskReportItem,
+    ) -> miette::Result<()> {
+        if !self.is_active() {
+            return self.fallback.on_task_started(target, attempt, item);
+        }
+
+        Ok(())
+    }
+
+    fn on_task_running(&self, target: &Target, secs: u32) -> miette::Result<()> {
+        if !self.is_active() {
+            return self.fallback.on_task_running(target, secs);
+        }
+
+        Ok(())
+    }
+
+    fn on_task_finished(
+        &self,
+        target: &Target,
+        attempt: &Operation,
+        item: &TaskReportItem,
+        error: Option<&miette::Report>,
+    ) -> miette::Result<()> {
+        if !self.is_active() {
+            return self.fallback.on_task_finished(target, attempt, item, error);
+        }
+
+        Ok(())
+    }
+
+    fn on_task_completed(
+        &self,
+        target: &Target,
+        operations: &OperationList,
+        item: &TaskReportItem,
+        error: Option<&miette::Report>,
+    ) -> miette::Result<()> {
+        if !self.is_active() {
+            return self
+                .fallback
+                .on_task_completed(target, operations, item, error);
+        }
+
+        Ok(())
+    }
+}
+
+fn render_loop(active: &AtomicBool, state: &Mutex<TuiState>) -> miette::Result<()> {
+    let mut stdout = io::stdout();
+
+    terminal::enable_raw_mode().into_diagnostic()?;
+    execute!(stdout, EnterAlternateScreen, Hide).into_diagnostic()?;
+
+    // Always restore the terminal, even on failure
+    let result = handle_events(&mut stdout, active, state);
+
+    execute!(stdout, LeaveAlternateScreen, Show).into_diagnostic()?;
+    terminal::disable_raw_mode().into_diagnostic()?;
+
+    result
+}
+
+fn handle_events(
+    stdout: &mut impl Write,
+    active: &AtomicBool,
+    state: &Mutex<TuiState>,
+) -> miette::Result<()> {
+    while active.load(Ordering::Acquire) {
+        render_frame(stdout, &state.lock())?;
+
+        if !event::poll(Duration::from_millis(100)).into_diagnostic()? {
+            continue;
+        }
+
+        let Event::Key(key) = event::read().into_diagnostic()? else {
+            continue;
+        };
+
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        let mut state = state.lock();
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => state.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => state.select_next(),
+            KeyCode::Enter | KeyCode::Char(' ') => state.toggle_expanded(),
+            KeyCode::Char('f') => state.toggle_filter(),
+            // Raw mode swallows the signal, so cancel the pipeline manually
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                ProcessRegistry::instance().terminate_running();
+                break;
+            }
+            // Exit the TUI and continue with the default output
+            KeyCode::Char('q') | KeyCode::Esc => {
+                break;
+            }
+            _ => {}
+        };
+    }
+
+    Ok(())
+}
+
+fn render_frame(stdout: &mut impl Write, state: &TuiState) -> miette::Result<()> {
+    let (width, height) = terminal::size().into_diagnostic()?;
+    let width = width as usize;
+    let height = height as usize;
+
+    // Build all rows first, so that we can scroll to the selected entry
+    let mut rows: Vec<String> = vec![];
+    let mut selected_row = 0;
+
+    for (position, index) in state.get_visible_indices().into_iter().enumerate() {
+        let entry = &state.entries[index];
+        let is_selected = position == state.selected;
+
+        if is_selected {
+            selected_row = rows.len();
+        }
+
+        rows.push(format_entry_row(entry, is_selected, width));
+
+        if entry.expanded && !entry.output.is_empty() {
+            let skip = entry.output.len().saturating_sub(MAX_OUTPUT_LINES);
+
+            if skip > 0 {
+                rows.push(color::muted(truncate(
+                    &format!("    │ ... {skip} more lines"),
+                    width,
+                )));
+            }
+
+            for line in entry.output.iter().skip(skip) {
+                rows.push(format!(
+                    "    {} {}",
+                    color::muted("│"),
+                    truncate(line, width.saturating_sub(6))
+                ));
+            }
+        }
+    }
+
+    if rows.is_empty() {
+        rows.push(color::muted_light("    No actions to display"));
+    }
+
+    // Header and footer take 2 rows each
+    let body_height = height.saturating_sub(4).max(1);
+    let offset = selected_row.saturating_sub(body_height.saturating_sub(1));
+
+    queue!(stdout, MoveTo(0, 0), Clear(ClearType::CurrentLine)).into_diagnostic()?;
+    queue!(stdout, Print(format_header(state))).into_diagnostic()?;
+
+    for (line, row) in rows.iter().skip(offset).take(body_height).enumerate() {
+        queue!(
+            stdout,
+            MoveTo(0, line as u16 + 2),
+            Clear(ClearType::CurrentLine),
+            Print(row)
+        )
+        .into_diagnostic()?;
+    }
+
+    queue!(
+        stdout,
+        Clear(ClearType::FromCursorDown),
+        MoveTo(0, height.saturating_sub(1) as u16),
+        Clear(ClearType::CurrentLine),
+        Print(color::muted(truncate(
+            "↑/↓ navigate · enter toggle output · f filter failures · q exit view · ctrl+c cancel",
+            width
+        )))
+    )
+    .into_diagnostic()?;
+
+    stdout.flush().into_diagnostic()?;
+
+    Ok(())
+}
+
+fn format_header(state: &TuiState) -> String {
+    let counts = state.get_counts();
+    let mut parts = vec![];
+
+    if counts.running > 0 {
+        parts.push(color::label(format!("{} running", counts.running)));
+    }
+
+    if counts.queued > 0 {
+        parts.push(color::muted_light(format!("{} queued", counts.queued)));
+    }
+
+    if counts.passed > 0 {
+        parts.push(color::success(format!(
+            "{} completed ({} cached)",
+            counts.passed, counts.cached
+        )));
+    }
+
+    if counts.failed > 0 {
+        parts.push(color::failure(format!("{} failed", counts.failed)));
+    }
+
+    if counts.skipped > 0 {
+        parts.push(color::muted_light(format!("{} skipped", counts.skipped)));
+    }
+
+    if matches!(state.filter, TuiFilter::Failures) {
+        parts.push(color::invalid("showing failures only"));
+    }
+
+    parts.join(&color::muted(" · "))
+}
+
+fn format_entry_row(entry: &TuiEntry, selected: bool, width: usize) -> String {
+    let marker = if selected {
+        color::label("❯")
+    } else {
+        " ".into()
+    };
+    let toggle = if entry.output.is_empty() {
+        " "
+    } else if entry.expanded {
+        "▾"
+    } else {
+        "▸"
+    };
+
+    let status = match entry.status {
+        TuiEntryStatus::Queued => color::muted("queued"),
+        TuiEntryStatus::Running => color::label("run   "),
+        TuiEntryStatus::Passed => color::success("pass  "),
+        TuiEntryStatus::Cached => color::label("cached"),
+        TuiEntryStatus::Failed => color::failure("fail  "),
+        TuiEntryStatus::Skipped => color::muted_light("skip  "),
+    };
+
+    let elapsed = entry
+        .get_elapsed()
+        .and_then(time::elapsed_opt)
+        .unwrap_or_default();
+
+    // 2 for the marker, 2 for the toggle, 7 for the status
+    let label = truncate(
+        &entry.label,
+        width.saturating_sub(11 + elapsed.chars().count() + 1),
+    );
+
+    format!(
+        "{marker} {toggle} {status} {label} {}",
+        color::muted(elapsed)
+    )
+}
+
+fn truncate(value: &str, width: usize) -> String {
+    if value.chars().count() <= width {
+        return value.to_owned();
+    }
+
+    let mut result = value
+        .chars()
+        .take(width.saturating_sub(1))
+        .collect::<String>();
+    result.push('…');
+    result
+}
@@ -0,0 +1,253 @@
+use moon_action::{Action, ActionNode, ActionStatus};
+use rustc_hash::FxHashMap;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TuiEntryStatus {
+    Queued,
+    Running,
+    Passed,
+    Cached,
+    Failed,
+    Skipped,
+}
+
+impl TuiEntryStatus {
+    pub fn from_action(action: &Action) -> Self {
+        match action.status {
+            ActionStatus::Cached | ActionStatus::CachedFromRemote => Self::Cached,
+            ActionStatus::Passed => Self::Passed,
+            ActionStatus::Aborted
+            | ActionStatus::Failed
+            | ActionStatus::Invalid
+            | ActionStatus::TimedOut => Self::Failed,
+            ActionStatus::Skipped => Self::Skipped,
+            ActionStatus::Running => Self::Running,
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        !matches!(self, Self::Queued | Self::Running)
+    }
+}
+
+#[derive(Debug)]
+pub struct TuiEntry {
+    pub duration: Option<Duration>,
+    pub expanded: bool,
+    pub label: String,
+    pub output: Vec<String>,
+    pub started_at: Option<Instant>,
+    pub status: TuiEntryStatus,
+}
+
+impl TuiEntry {
+    pub fn new(label: impl AsRef<str>) -> Self {
+        Self {
+            duration: None,
+            expanded: false,
+            label: label.as_ref().to_owned(),
+            output: vec![],
+            started_at: None,
+            status: TuiEntryStatus::Queued,
+        }
+    }
+
+    pub fn get_elapsed(&self) -> Option<Duration> {
+        self.duration
+            .or_else(|| self.started_at.map(|start| start.elapsed()))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TuiFilter {
+    #[default]
+    All,
+    Failures,
+}
+
+#[derive(Debug, Default)]
+pub struct TuiCounts {
+    pub cached: usize,
+    pub failed: usize,
+    pub passed: usize,
+    pub queued: usize,
+    pub running: usize,
+    pub skipped: usize,
+}
+
+/// State of the interactive reporter, separate from the rendering layer,
+/// so that it can be mutated from pipeline events and keyboard input.
+#[derive(Debug, Default)]
+pub struct TuiState {
+    pub entries: Vec<TuiEntry>,
+    pub filter: TuiFilter,
+    pub selected: usize,
+
+    // Label -> entry index
+    lookup: FxHashMap<String, usize>,
+}
+
+impl TuiState {
+    pub fn get_counts(&self) -> TuiCounts {
+        let mut counts = TuiCounts::default();
+
+        for entry in &self.entries {
+            match entry.status {
+                TuiEntryStatus::Queued => counts.queued += 1,
+                TuiEntryStatus::Running => counts.running += 1,
+                TuiEntryStatus::Passed => counts.passed += 1,
+                TuiEntryStatus::Cached => {
+                    counts.cached += 1;
+                    counts.passed += 1;
+                }
+                TuiEntryStatus::Failed => counts.failed += 1,
+                TuiEntryStatus::Skipped => counts.skipped += 1,
+            };
+        }
+
+        counts
+    }
+
+    pub fn get_entry(&self, label: &str) -> Option<&TuiEntry> {
+        self.lookup.get(label).map(|index| &self.entries[*index])
+    }
+
+    pub fn get_selected_entry(&self) -> Option<&TuiEntry> {
+        self.get_visible_indices()
+            .get(self.selected)
+            .map(|index| &self.entries[*index])
+    }
+
+    /// Return indices of all entries that match the current filter,
+    /// ordered by running, then completed, then queued.
+    pub fn get_visible_indices(&self) -> Vec<usize> {
+        let mut indices = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| match self.filter {
+                TuiFilter::All => true,
+                TuiFilter::Failures => entry.status == TuiEntryStatus::Failed,
+            })
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        indices.sort_by_key(|index| match self.entries[*index].status {
+            TuiEntryStatus::Running => 0,
+            TuiEntryStatus::Queued => 2,
+            _ => 1,
+        });
+
+        indices
+    }
+
+    pub fn queue(&mut self, label: impl AsRef<str>) {
+        let label = label.as_ref();
+
+        if self.lookup.contains_key(label) {
+            return;
+        }
+
+        self.lookup.insert(label.to_owned(), self.entries.len());
+        self.entries.push(TuiEntry::new(label));
+    }
+
+    pub fn start(&mut self, label: impl AsRef<str>) {
+        let label = label.as_ref();
+
+        self.queue(label);
+
+        if let Some(index) = self.lookup.get(label) {
+            let entry = &mut self.entries[*index];
+            entry.status = TuiEntryStatus::Running;
+            entry.started_at = Some(Instant::now());
+        }
+    }
+
+    pub fn finish(
+        &mut self,
+        label: impl AsRef<str>,
+        status: TuiEntryStatus,
+        duration: Option<Duration>,
+        output: Vec<String>,
+    ) {
+        let label = label.as_ref();
+
+        self.queue(label);
+
+        if let Some(index) = self.lookup.get(label) {
+            let entry = &mut self.entries[*index];
+            entry.status = status;
+            entry.duration = duration.or_else(|| entry.get_elapsed());
+            entry.output = output;
+
+            // Automatically expand failures so they are visible immediately
+            if status == TuiEntryStatus::Failed {
+                entry.expanded = true;
+            }
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        let total = self.get_visible_indices().len();
+
+        if total > 0 && self.selected + 1 < total {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn toggle_expanded(&mut self) {
+        if let Some(index) = self.get_visible_indices().get(self.selected) {
+            let entry = &mut self.entries[*index];
+            entry.expanded = !entry.expanded;
+        }
+    }
+
+    pub fn toggle_filter(&mut self) {
+        self.filter = match self.filter {
+            TuiFilter::All => TuiFilter::Failures,
+            TuiFilter::Failures => TuiFilter::All,
+        };
+        self.selected = 0;
+    }
+}
+
+pub fn get_action_label(action: &Action) -> String {
+    get_node_label(&action.node)
+}
+
+pub fn get_node_label(node: &ActionNode) -> String {
+    match node {
+        ActionNode::RunTask(inner) => inner.target.to_string(),
+        _ => node.label(),
+    }
+}
+
+pub fn get_action_output(action: &Action) -> Vec<String> {
+    let mut lines = vec![];
+
+    if let Some(operation) = action
+        .operations
+        .get_last_execution()
+        .or_else(|| action.operations.get_last_process())
+    {
+        if let Some(output) = operation.get_output() {
+            for stream in [&output.stdout, &output.stderr].into_iter().flatten() {
+                lines.extend(stream.trim().lines().map(|line| line.to_owned()));
+            }
+        }
+    }
+
+    if lines.is_empty() {
+        if let Some(error) = &action.error {
+            lines.extend(error.lines().map(|line| line.to_owned()));
+        }
+    }
+
+    lines
+}
@@ -0,0 +1,125 @@
+use moon_common::path::WorkspaceRelativePathBuf;
+use moon_console_reporter::*;
+
+fn extract(output: &str) -> Vec<Annotation> {
+    extract_annotations(output, &WorkspaceRelativePathBuf::from("packages/app"))
+}
+
+mod annotations {
+    use super::*;
+
+    #[test]
+    fn extracts_typescript_errors() {
+        let list = extract(
+            "src/index.ts(10,5): error TS2322: Type 'string' is not assignable to type 'number'.",
+        );
+
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].level, AnnotationLevel::Error);
+        assert_eq!(
+            list[0].file.as_ref().unwrap().as_str(),
+            "packages/app/src/index.ts"
+        );
+        assert_eq!(list[0].line, Some(10));
+        assert_eq!(list[0].column, Some(5));
+        assert_eq!(
+            list[0].message,
+            "TS2322: Type 'string' is not assignable to type 'number'."
+        );
+    }
+
+    #[test]
+    fn extracts_typescript_pretty_errors() {
+        let list = extract("src/index.ts:1:7 - error TS2322: Type mismatch.");
+
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].line, Some(1));
+        assert_eq!(list[0].column, Some(7));
+        assert_eq!(list[0].message, "TS2322: Type mismatch.");
+    }
+
+    #[test]
+    fn extracts_generic_errors_and_warnings() {
+        let list = extract(
+            "./lib/main.c:3:12: warning: unused variable 'x'\nlib/main.c:4:1: error: expected ';'",
+        );
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].level, AnnotationLevel::Warning);
+        assert_eq!(
+            list[0].file.as_ref().unwrap().as_str(),
+            "packages/app/lib/main.c"
+        );
+        assert_eq!(list[1].level, AnnotationLevel::Error);
+        assert_eq!(list[1].message, "expected ';'");
+    }
+
+    #[test]
+    fn extracts_rust_errors() {
+        let list = extract(
+            r#"
+error[E0308]: mismatched types
+ --> src/main.rs:4:18
+  |
+4 |     let x: i32 = "a";
+  |                  ^^^ expected `i32`, found `&str`
+
+error: aborting due to 1 previous error
+"#,
+        );
+
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].title.as_deref(), Some("E0308"));
+        assert_eq!(list[0].message, "mismatched types");
+        assert_eq!(
+            list[0].file.as_ref().unwrap().as_str(),
+            "packages/app/src/main.rs"
+        );
+        assert_eq!(list[0].line, Some(4));
+        assert_eq!(list[0].column, Some(18));
+    }
+
+    #[test]
+    fn strips_ansi_codes() {
+        let list = extract("\x1b[31msrc/a.ts(1,1): error TS1005: ';' expected.\x1b[0m");
+
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].message, "TS1005: ';' expected.");
+    }
+
+    #[test]
+    fn caps_the_amount_of_annotations() {
+        let output = (1..=20)
+            .map(|i| format!("src/a.ts({i},1): error TS1005: ';' expected."))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert_eq!(extract(&output).len(), MAX_ANNOTATIONS);
+    }
+
+    #[test]
+    fn ignores_unrelated_output() {
+        assert!(extract("Compiling app v0.1.0\nFinished in 2s").is_empty());
+    }
+
+    #[test]
+    fn formats_github_commands() {
+        let mut annotation = Annotation::new(AnnotationLevel::Error, "100% broken\nbadly");
+        annotation.file = Some("src/a,b.ts".into());
+        annotation.line = Some(1);
+        annotation.column = Some(2);
+        annotation.title = Some("app:build".into());
+
+        assert_eq!(
+            annotation.to_github_command(),
+            "::error file=src/a%2Cb.ts,line=1,col=2,title=app%3Abuild::100%25 broken%0Abadly"
+        );
+    }
+
+    #[test]
+    fn formats_github_commands_without_props() {
+        let annotation = Annotation::new(AnnotationLevel::Warning, "oops");
+
+        assert_eq!(annotation.to_github_command(), "::warning::oops");
+    }
+}
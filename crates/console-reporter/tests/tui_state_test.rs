@@ -0,0 +1,100 @@
+use moon_console_reporter::*;
+
+fn create_state() -> TuiState {
+    let mut state = TuiState::default();
+    state.queue("a:build");
+    state.queue("b:build");
+    state.queue("c:build");
+    state
+}
+
+mod tui_state {
+    use super::*;
+
+    #[test]
+    fn queues_unique_entries() {
+        let mut state = create_state();
+        state.queue("a:build");
+
+        assert_eq!(state.entries.len(), 3);
+        assert_eq!(state.get_counts().queued, 3);
+    }
+
+    #[test]
+    fn tracks_status_changes() {
+        let mut state = create_state();
+        state.start("a:build");
+        state.start("b:build");
+        state.finish("b:build", TuiEntryStatus::Cached, None, vec![]);
+
+        let counts = state.get_counts();
+
+        assert_eq!(counts.running, 1);
+        assert_eq!(counts.passed, 1);
+        assert_eq!(counts.cached, 1);
+        assert_eq!(counts.queued, 1);
+    }
+
+    #[test]
+    fn orders_running_before_completed_and_queued() {
+        let mut state = create_state();
+        state.finish("a:build", TuiEntryStatus::Passed, None, vec![]);
+        state.start("c:build");
+
+        let labels = state
+            .get_visible_indices()
+            .into_iter()
+            .map(|index| state.entries[index].label.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(labels, ["c:build", "a:build", "b:build"]);
+    }
+
+    #[test]
+    fn expands_failures_automatically() {
+        let mut state = create_state();
+        state.finish(
+            "a:build",
+            TuiEntryStatus::Failed,
+            None,
+            vec!["error".into()],
+        );
+
+        let entry = state.get_entry("a:build").unwrap();
+
+        assert!(entry.expanded);
+        assert_eq!(entry.output, ["error"]);
+    }
+
+    #[test]
+    fn filters_to_failures() {
+        let mut state = create_state();
+        state.finish("a:build", TuiEntryStatus::Passed, None, vec![]);
+        state.finish("b:build", TuiEntryStatus::Failed, None, vec![]);
+        state.toggle_filter();
+
+        assert_eq!(state.filter, TuiFilter::Failures);
+        assert_eq!(state.get_visible_indices().len(), 1);
+        assert_eq!(state.get_selected_entry().unwrap().label, "b:build");
+
+        state.toggle_filter();
+
+        assert_eq!(state.get_visible_indices().len(), 3);
+    }
+
+    #[test]
+    fn navigates_within_bounds() {
+        let mut state = create_state();
+
+        state.select_previous();
+        assert_eq!(state.selected, 0);
+
+        state.select_next();
+        state.select_next();
+        state.select_next();
+        assert_eq!(state.selected, 2);
+
+        state.toggle_expanded();
+        assert!(state.get_selected_entry().unwrap().expanded);
+    }
+}
@@ -18,6 +18,7 @@ pub enum ConsoleStream {
 pub struct ConsoleBuffer {
     buffer: Arc<Mutex<Vec<u8>>>,
     channel: Option<Sender<bool>>,
+    paused: Arc<AtomicBool>,
     stream: ConsoleStream,
 
     pub(crate) handle: Option<JoinHandle<()>>,
@@ -29,11 +30,15 @@ impl ConsoleBuffer {
     fn internal_new(stream: ConsoleStream, with_handle: bool) -> Self {
         let buffer = Arc::new(Mutex::new(Vec::new()));
         let buffer_clone = Arc::clone(&buffer);
+        let paused = Arc::new(AtomicBool::new(false));
+        let paused_clone = Arc::clone(&paused);
         let (tx, rx) = mpsc::channel();
 
         // Every 100ms, flush the buffer
         let handle = if with_handle {
-            Some(spawn(move || flush_on_loop(buffer_clone, stream, rx)))
+            Some(spawn(move || {
+                flush_on_loop(buffer_clone, paused_clone, stream, rx)
+            }))
         } else {
             None
         };
@@ -42,6 +47,7 @@ impl ConsoleBuffer {
             buffer,
             channel: Some(tx),
             handle,
+            paused,
             stream,
             quiet: None,
             test_mode: false,
@@ -62,6 +68,7 @@ impl ConsoleBuffer {
         Self {
             buffer: Arc::new(Mutex::new(Vec::new())),
             channel: None,
+            paused: Arc::new(AtomicBool::new(false)),
             stream,
             handle: None,
             quiet: None,
@@ -82,6 +89,22 @@ impl ConsoleBuffer {
             .is_some_and(|quiet| quiet.load(Ordering::Relaxed))
     }
 
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    /// Stop flushing to the underlying stream, and hold all written data
+    /// in the buffer until [`ConsoleBuffer::resume`] is called. This is
+    /// useful when another renderer has taken control of the terminal.
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::Release);
+    }
+
+    pub fn resume(&self) -> miette::Result<()> {
+        self.paused.store(false, Ordering::Release);
+        self.flush()
+    }
+
     pub fn close(&self) -> miette::Result<()> {
         trace!(
             "Closing {} stream",
@@ -91,7 +114,8 @@ impl ConsoleBuffer {
             }
         );
 
-        self.flush()?;
+        // Never lose buffered output on close
+        self.resume()?;
 
         // Send the closed message
         if let Some(channel) = &self.channel {
@@ -102,6 +126,10 @@ impl ConsoleBuffer {
     }
 
     pub fn flush(&self) -> miette::Result<()> {
+        if self.is_paused() {
+            return Ok(());
+        }
+
         flush(&mut self.buffer.lock(), self.stream).into_diagnostic()?;
 
         Ok(())
@@ -123,7 +151,7 @@ impl ConsoleBuffer {
 
             op(&mut buffer);
 
-            if buffer.len() >= 1024 {
+            if buffer.len() >= 1024 && !self.is_paused() {
                 flush(&mut buffer, self.stream).into_diagnostic()?;
             }
         }
@@ -177,6 +205,7 @@ impl Clone for ConsoleBuffer {
     fn clone(&self) -> Self {
         Self {
             buffer: Arc::clone(&self.buffer),
+            paused: Arc::clone(&self.paused),
             stream: self.stream,
             quiet: self.quiet.clone(),
             test_mode: self.test_mode,
@@ -200,11 +229,18 @@ fn flush(buffer: &mut Vec<u8>, stream: ConsoleStream) -> io::Result<()> {
     }
 }
 
-fn flush_on_loop(buffer: Arc<Mutex<Vec<u8>>>, stream: ConsoleStream, receiver: Receiver<bool>) {
+fn flush_on_loop(
+    buffer: Arc<Mutex<Vec<u8>>>,
+    paused: Arc<AtomicBool>,
+    stream: ConsoleStream,
+    receiver: Receiver<bool>,
+) {
     loop {
         sleep(Duration::from_millis(100));
 
-        let _ = flush(&mut buffer.lock(), stream);
+        if !paused.load(Ordering::Relaxed) {
+            let _ = flush(&mut buffer.lock(), stream);
+        }
 
         // Has the thread been closed?
         match receiver.try_recv() {
@@ -2,6 +2,7 @@ use crate::buffer::ConsoleBuffer;
 use crate::console::ConsoleTheme;
 use miette::Error as Report;
 use moon_action::{Action, ActionNode, ActionPipelineStatus, Operation, OperationList};
+use moon_common::path::WorkspaceRelativePathBuf;
 use moon_config::TaskOutputStyle;
 use moon_target::Target;
 use std::sync::Arc;
@@ -22,6 +23,7 @@ pub struct TaskReportItem {
     pub output_prefix: Option<String>,
     pub output_streamed: bool,
     pub output_style: Option<TaskOutputStyle>,
+    pub working_dir: WorkspaceRelativePathBuf,
 }
 
 pub trait Reporter: Send + Sync {
@@ -29,6 +31,12 @@ pub trait Reporter: Send + Sync {
 
     fn inherit_theme(&mut self, _theme: Arc<ConsoleTheme>) {}
 
+    // Reporters that take over the terminal render task output
+    // themselves, so the output must be captured and not streamed
+    fn requires_captured_output(&self) -> bool {
+        false
+    }
+
     fn on_pipeline_started(&self, _nodes: &[&ActionNode]) -> miette::Result<()> {
         Ok(())
     }
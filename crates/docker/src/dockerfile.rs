@@ -1,16 +1,58 @@
 use miette::IntoDiagnostic;
 use moon_common::Id;
 use moon_target::Target;
+use serde::Serialize;
 use tera::{Context, Tera};
 
+#[derive(Debug, Default, Serialize)]
+pub struct DockerfileProject {
+    pub build_task: Option<Target>,
+    pub id: Id,
+    pub start_task: Option<Target>,
+
+    /// Command and arguments to run the project with, when using a runtime image.
+    pub runtime_command: Vec<String>,
+
+    /// Workspace relative directory to run the command from, when using a runtime image.
+    pub runtime_dir: String,
+
+    /// Workspace relative files and directories to copy into the runtime image.
+    pub runtime_files: Vec<String>,
+
+    /// Workspace relative path to an SBOM to copy into the runtime image.
+    pub sbom_file: Option<String>,
+}
+
 #[derive(Debug, Default)]
 pub struct GenerateDockerfileOptions {
-    pub build_task: Option<Target>,
     pub disable_toolchain: bool,
     pub image: String,
-    pub project: Id,
+    pub projects: Vec<DockerfileProject>,
     pub prune: bool,
-    pub start_task: Option<Target>,
+    pub runtime_image: Option<String>,
+}
+
+#[derive(Serialize)]
+struct DockerfileStage<'opts> {
+    #[serde(flatten)]
+    project: &'opts DockerfileProject,
+    entrypoint: Option<String>,
+    stage: String,
+}
+
+impl<'opts> DockerfileStage<'opts> {
+    fn new(project: &'opts DockerfileProject) -> miette::Result<Self> {
+        Ok(Self {
+            project,
+            // Use the exec form, so that the shell isn't required
+            entrypoint: if project.runtime_command.is_empty() {
+                None
+            } else {
+                Some(serde_json::to_string(&project.runtime_command).into_diagnostic()?)
+            },
+            stage: create_stage_name(&project.id),
+        })
+    }
 }
 
 pub fn generate_dockerfile(mut options: GenerateDockerfileOptions) -> miette::Result<String> {
@@ -25,15 +67,44 @@ pub fn generate_dockerfile(mut options: GenerateDockerfileOptions) -> miette::Re
     let mut context = Context::new();
     context.insert("disable_toolchain", &options.disable_toolchain);
     context.insert("image", &options.image);
-    context.insert("project", &options.project);
     context.insert("prune", &options.prune);
+    context.insert("runtime_image", &options.runtime_image);
+    context.insert("multiple", &(options.projects.len() > 1));
+    context.insert(
+        "project_ids",
+        &options
+            .projects
+            .iter()
+            .map(|project| project.id.as_str())
+            .collect::<Vec<_>>()
+            .join(" "),
+    );
+    context.insert(
+        "build_tasks",
+        &options
+            .projects
+            .iter()
+            .filter_map(|project| project.build_task.as_ref())
+            .collect::<Vec<_>>(),
+    );
 
-    if let Some(task) = &options.build_task {
-        context.insert("build_task", task);
-    }
+    // A single project uses generic stage names,
+    // while multiple projects have a final stage for each
+    if let [project] = options.projects.as_slice() {
+        context.insert("project", &DockerfileStage::new(project)?);
 
-    if let Some(task) = &options.start_task {
-        context.insert("start_task", task);
+        if let Some(task) = &project.start_task {
+            context.insert("start_task", task);
+        }
+    } else {
+        context.insert(
+            "projects",
+            &options
+                .projects
+                .iter()
+                .map(DockerfileStage::new)
+                .collect::<miette::Result<Vec<_>>>()?,
+        );
     }
 
     let result = Tera::one_off(
@@ -45,3 +116,21 @@ pub fn generate_dockerfile(mut options: GenerateDockerfileOptions) -> miette::Re
 
     Ok(result)
 }
+
+// Stage names only support lowercase alphanumeric characters, dashes,
+// underscores, and periods, so replace everything else.
+fn create_stage_name(id: &Id) -> String {
+    id.as_str()
+        .to_lowercase()
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' || ch == '.' {
+                ch
+            } else {
+                '-'
+            }
+        })
+        .collect::<String>()
+        .trim_matches('-')
+        .to_owned()
+}
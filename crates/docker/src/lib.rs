@@ -1,3 +1,7 @@
 mod dockerfile;
+mod prune;
+mod sbom;
 
 pub use dockerfile::*;
+pub use prune::*;
+pub use sbom::*;
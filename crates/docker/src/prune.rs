@@ -0,0 +1,82 @@
+use rustc_hash::FxHashSet;
+use starbase_utils::fs;
+use std::path::Path;
+use tracing::debug;
+
+// Directories within a profile that are only used while compiling
+const CARGO_BUILD_DIRS: [&str; 5] = [".fingerprint", "build", "deps", "examples", "incremental"];
+
+fn is_cargo_profile_dir(dir: &Path) -> bool {
+    dir.join(".fingerprint").exists() || dir.join("deps").exists()
+}
+
+fn prune_cargo_profile_dir(dir: &Path, keep_names: &FxHashSet<String>) -> miette::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+
+        if path.is_dir() {
+            if CARGO_BUILD_DIRS.contains(&name.as_ref()) {
+                fs::remove_dir_all(&path)?;
+            }
+
+            continue;
+        }
+
+        // Artifacts are named after the crate, with varying extensions
+        // (app, app.d, app.exe, libapp.rlib, etc)
+        let stem = name.split('.').next().unwrap_or_default();
+
+        if !keep_names.contains(stem) {
+            fs::remove_file(&path)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Remove artifacts from a Cargo `target` directory that are not required by the
+/// provided crates. Compilation directories are removed from each profile (including
+/// cross-compiled profiles), and only the final artifacts of the crates are kept.
+pub fn prune_cargo_target_dir(target_dir: &Path, crate_names: &[String]) -> miette::Result<()> {
+    let mut keep_names = FxHashSet::default();
+
+    for name in crate_names {
+        let lib_name = name.replace('-', "_");
+
+        keep_names.insert(format!("lib{lib_name}"));
+        keep_names.insert(lib_name);
+        keep_names.insert(name.to_owned());
+    }
+
+    for entry in fs::read_dir(target_dir)? {
+        let path = entry.path();
+
+        if !path.is_dir() {
+            continue;
+        }
+
+        // Cross-compiled profiles are nested within a target triple directory
+        let profile_dirs = if is_cargo_profile_dir(&path) {
+            vec![path]
+        } else {
+            fs::read_dir(&path)?
+                .into_iter()
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir() && is_cargo_profile_dir(path))
+                .collect()
+        };
+
+        for profile_dir in profile_dirs {
+            debug!(
+                profile_dir = ?profile_dir,
+                "Pruning Cargo profile directory"
+            );
+
+            prune_cargo_profile_dir(&profile_dir, &keep_names)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use miette::IntoDiagnostic;
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SbomComponentType {
+    Application,
+    #[default]
+    Library,
+}
+
+/// A component within a CycloneDX SBOM, either a workspace project,
+/// or a third-party package resolved from a lockfile.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct SbomComponent {
+    #[serde(rename = "type")]
+    pub type_of: SbomComponentType,
+
+    #[serde(rename = "bom-ref")]
+    pub bom_ref: String,
+
+    pub name: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purl: Option<String>,
+}
+
+impl SbomComponent {
+    /// Create a component for a project within the workspace.
+    pub fn project(id: &str) -> Self {
+        Self {
+            bom_ref: format!("moon:project/{id}"),
+            name: id.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// Create a component for a third-party package, where the ecosystem
+    /// is a package URL type, like `cargo`, `npm`, or `pypi`.
+    pub fn package(ecosystem: &str, name: &str, version: &str) -> Self {
+        // Scoped npm packages must encode the namespace
+        let purl = format!("pkg:{ecosystem}/{}@{version}", name.replacen('@', "%40", 1));
+
+        Self {
+            bom_ref: purl.clone(),
+            name: name.to_owned(),
+            version: Some(version.to_owned()),
+            purl: Some(purl),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct GenerateSbomOptions {
+    pub components: Vec<SbomComponent>,
+    pub project_id: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Sbom<'opts> {
+    bom_format: &'static str,
+    spec_version: &'static str,
+    version: u8,
+    metadata: SbomMetadata,
+    components: &'opts [SbomComponent],
+}
+
+#[derive(Serialize)]
+struct SbomMetadata {
+    component: SbomComponent,
+}
+
+/// Generate a CycloneDX SBOM (in JSON) for the provided project and components.
+/// Timestamps and serial numbers are omitted, so that the output is deterministic.
+pub fn generate_sbom(mut options: GenerateSbomOptions) -> miette::Result<String> {
+    options.components.sort_by(|a, b| {
+        a.name
+            .cmp(&b.name)
+            .then_with(|| a.version.cmp(&b.version))
+            .then_with(|| a.bom_ref.cmp(&b.bom_ref))
+    });
+    options.components.dedup_by(|a, b| a.bom_ref == b.bom_ref);
+
+    let sbom = Sbom {
+        bom_format: "CycloneDX",
+        spec_version: "1.5",
+        version: 1,
+        metadata: SbomMetadata {
+            component: SbomComponent {
+                type_of: SbomComponentType::Application,
+                ..SbomComponent::project(&options.project_id)
+            },
+        },
+        components: &options.components,
+    };
+
+    serde_json::to_string_pretty(&sbom).into_diagnostic()
+}
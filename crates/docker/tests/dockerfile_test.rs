@@ -1,11 +1,55 @@
 use moon_common::Id;
-use moon_docker::{generate_dockerfile, GenerateDockerfileOptions};
+use moon_docker::{generate_dockerfile, DockerfileProject, GenerateDockerfileOptions};
 use moon_target::Target;
 use starbase_sandbox::assert_snapshot;
 
 fn create_options() -> GenerateDockerfileOptions {
     GenerateDockerfileOptions {
-        project: Id::raw("app"),
+        projects: vec![DockerfileProject {
+            id: Id::raw("app"),
+            ..Default::default()
+        }],
+        ..Default::default()
+    }
+}
+
+fn create_runtime_project(id: &str, dir: &str) -> DockerfileProject {
+    DockerfileProject {
+        build_task: Some(Target::parse(&format!("{id}:build")).unwrap()),
+        id: Id::raw(id),
+        start_task: Some(Target::parse(&format!("{id}:start")).unwrap()),
+        runtime_command: vec!["node".into(), "dist/index.js".into()],
+        runtime_dir: dir.into(),
+        runtime_files: vec![
+            format!("{dir}/dist"),
+            format!("{dir}/package.json"),
+            "node_modules".into(),
+            "package.json".into(),
+        ],
+        sbom_file: None,
+    }
+}
+
+fn create_multiple_options() -> GenerateDockerfileOptions {
+    GenerateDockerfileOptions {
+        projects: vec![
+            DockerfileProject {
+                build_task: Some(Target::parse("api:compile").unwrap()),
+                id: Id::raw("api"),
+                start_task: Some(Target::parse("api:serve").unwrap()),
+                ..Default::default()
+            },
+            DockerfileProject {
+                build_task: Some(Target::parse("web:build").unwrap()),
+                id: Id::raw("@scope/web"),
+                start_task: Some(Target::parse("web:start").unwrap()),
+                ..Default::default()
+            },
+            DockerfileProject {
+                id: Id::raw("worker"),
+                ..Default::default()
+            },
+        ],
         ..Default::default()
     }
 }
@@ -29,8 +73,8 @@ mod dockerfile {
     #[test]
     fn with_tasks() {
         let mut options = create_options();
-        options.build_task = Some(Target::parse("app:compile").unwrap());
-        options.start_task = Some(Target::parse("app:serve").unwrap());
+        options.projects[0].build_task = Some(Target::parse("app:compile").unwrap());
+        options.projects[0].start_task = Some(Target::parse("app:serve").unwrap());
 
         assert_snapshot!(generate_dockerfile(options).unwrap());
     }
@@ -39,8 +83,55 @@ mod dockerfile {
     fn with_prune() {
         let mut options = create_options();
         options.prune = true;
-        options.build_task = Some(Target::parse("app:compile").unwrap());
-        options.start_task = Some(Target::parse("app:serve").unwrap());
+        options.projects[0].build_task = Some(Target::parse("app:compile").unwrap());
+        options.projects[0].start_task = Some(Target::parse("app:serve").unwrap());
+
+        assert_snapshot!(generate_dockerfile(options).unwrap());
+    }
+
+    #[test]
+    fn with_multiple_projects() {
+        assert_snapshot!(generate_dockerfile(create_multiple_options()).unwrap());
+    }
+
+    #[test]
+    fn with_multiple_projects_and_prune() {
+        let mut options = create_multiple_options();
+        options.prune = true;
+
+        assert_snapshot!(generate_dockerfile(options).unwrap());
+    }
+
+    #[test]
+    fn with_runtime_image() {
+        let mut options = create_options();
+        options.prune = true;
+        options.runtime_image = Some("gcr.io/distroless/nodejs22-debian12".into());
+        options.projects = vec![create_runtime_project("app", "apps/app")];
+        options.projects[0].sbom_file = Some("apps/app/sbom.cdx.json".into());
+
+        assert_snapshot!(generate_dockerfile(options).unwrap());
+    }
+
+    #[test]
+    fn with_runtime_image_without_start_task() {
+        let mut options = create_options();
+        options.runtime_image = Some("debian:bookworm-slim".into());
+        options.projects[0].runtime_files = vec!["app/dist".into()];
+
+        assert_snapshot!(generate_dockerfile(options).unwrap());
+    }
+
+    #[test]
+    fn with_multiple_projects_and_runtime_image() {
+        let mut options = create_multiple_options();
+        options.prune = true;
+        options.runtime_image = Some("gcr.io/distroless/nodejs22-debian12".into());
+        options.projects = vec![
+            create_runtime_project("api", "apps/api"),
+            create_runtime_project("web", "apps/web"),
+        ];
+        options.projects[1].sbom_file = Some("web.cdx.json".into());
 
         assert_snapshot!(generate_dockerfile(options).unwrap());
     }
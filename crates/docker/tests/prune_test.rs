@@ -0,0 +1,82 @@
+use moon_docker::prune_cargo_target_dir;
+use starbase_sandbox::create_empty_sandbox;
+
+mod prune_cargo_target_dir {
+    use super::*;
+
+    #[test]
+    fn removes_compilation_dirs() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("target/release/.fingerprint/app/file", "");
+        sandbox.create_file("target/release/build/app/file", "");
+        sandbox.create_file("target/release/deps/app-hash", "");
+        sandbox.create_file("target/release/examples/example", "");
+        sandbox.create_file("target/release/incremental/app/file", "");
+
+        prune_cargo_target_dir(&sandbox.path().join("target"), &["app".into()]).unwrap();
+
+        let release = sandbox.path().join("target/release");
+
+        assert!(release.exists());
+        assert!(!release.join(".fingerprint").exists());
+        assert!(!release.join("build").exists());
+        assert!(!release.join("deps").exists());
+        assert!(!release.join("examples").exists());
+        assert!(!release.join("incremental").exists());
+    }
+
+    #[test]
+    fn keeps_artifacts_for_crates() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("target/CACHEDIR.TAG", "");
+        sandbox.create_file("target/release/deps/app-hash", "");
+        sandbox.create_file("target/release/app", "");
+        sandbox.create_file("target/release/app.d", "");
+        sandbox.create_file("target/release/libshared_utils.rlib", "");
+        sandbox.create_file("target/release/other", "");
+        sandbox.create_file("target/release/libother.rlib", "");
+
+        prune_cargo_target_dir(
+            &sandbox.path().join("target"),
+            &["app".into(), "shared-utils".into()],
+        )
+        .unwrap();
+
+        let release = sandbox.path().join("target/release");
+
+        assert!(sandbox.path().join("target/CACHEDIR.TAG").exists());
+        assert!(release.join("app").exists());
+        assert!(release.join("app.d").exists());
+        assert!(release.join("libshared_utils.rlib").exists());
+        assert!(!release.join("other").exists());
+        assert!(!release.join("libother.rlib").exists());
+    }
+
+    #[test]
+    fn supports_cross_compiled_profiles() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("target/x86_64-unknown-linux-musl/release/deps/app-hash", "");
+        sandbox.create_file("target/x86_64-unknown-linux-musl/release/app", "");
+        sandbox.create_file("target/x86_64-unknown-linux-musl/release/other", "");
+
+        prune_cargo_target_dir(&sandbox.path().join("target"), &["app".into()]).unwrap();
+
+        let release = sandbox
+            .path()
+            .join("target/x86_64-unknown-linux-musl/release");
+
+        assert!(release.join("app").exists());
+        assert!(!release.join("other").exists());
+        assert!(!release.join("deps").exists());
+    }
+
+    #[test]
+    fn ignores_non_profile_dirs() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("target/doc/index.html", "");
+
+        prune_cargo_target_dir(&sandbox.path().join("target"), &["app".into()]).unwrap();
+
+        assert!(sandbox.path().join("target/doc/index.html").exists());
+    }
+}
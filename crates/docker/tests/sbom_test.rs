@@ -0,0 +1,44 @@
+use moon_docker::{generate_sbom, GenerateSbomOptions, SbomComponent};
+use starbase_sandbox::assert_snapshot;
+
+mod sbom {
+    use super::*;
+
+    #[test]
+    fn renders_empty() {
+        let options = GenerateSbomOptions {
+            project_id: "app".into(),
+            ..Default::default()
+        };
+
+        assert_snapshot!(generate_sbom(options).unwrap());
+    }
+
+    #[test]
+    fn renders_sorted_components() {
+        let options = GenerateSbomOptions {
+            components: vec![
+                SbomComponent::project("utils"),
+                SbomComponent::package("npm", "lodash", "4.17.21"),
+                SbomComponent::package("cargo", "serde", "1.0.0"),
+                SbomComponent::package("npm", "@scope/pkg", "1.2.3"),
+                SbomComponent::package("npm", "lodash", "4.17.21"),
+            ],
+            project_id: "app".into(),
+        };
+
+        assert_snapshot!(generate_sbom(options).unwrap());
+    }
+
+    #[test]
+    fn encodes_scoped_package_urls() {
+        assert_eq!(
+            SbomComponent::package("npm", "@scope/pkg", "1.2.3").purl,
+            Some("pkg:npm/%40scope/pkg@1.2.3".into())
+        );
+        assert_eq!(
+            SbomComponent::package("pypi", "requests", "2.0.0").purl,
+            Some("pkg:pypi/requests@2.0.0".into())
+        );
+    }
+}
@@ -1,9 +1,10 @@
 use crate::graph_traits::*;
 use petgraph::dot::{Config, Dot};
-use petgraph::graph::DiGraph;
+use petgraph::graph::{DiGraph, NodeIndex};
 use petgraph::visit::{EdgeRef, NodeRef};
 use serde::Serialize;
 use starbase_utils::json;
+use std::collections::BTreeMap;
 use std::fmt::{Debug, Display};
 
 #[derive(Serialize)]
@@ -54,3 +55,214 @@ pub trait GraphToJson<N: Serialize, E: Serialize, K>: GraphData<N, E, K> {
         )?)
     }
 }
+
+/// A list of attribute names and values for a node.
+pub type GraphNodeAttributes = Vec<(&'static str, String)>;
+
+pub trait GraphAttributes<N> {
+    /// Return attributes for the node, for formats that support them.
+    fn get_node_attributes(&self, _node: &N) -> GraphNodeAttributes {
+        vec![]
+    }
+}
+
+pub trait GraphToMermaid<N: Display, E: Display, K>: GraphData<N, E, K> {
+    /// Format graph as a Mermaid flowchart.
+    fn to_mermaid(&self) -> String {
+        format_mermaid(self.get_graph())
+    }
+}
+
+pub trait GraphToGraphMl<N: Display, E: Display, K>:
+    GraphData<N, E, K> + GraphAttributes<N>
+{
+    /// Format graph as a GraphML document, with node attributes.
+    fn to_graphml(&self) -> String {
+        format_graphml(self.get_graph(), |_, node| self.get_node_attributes(node))
+    }
+}
+
+pub trait GraphToCytoscape<N: Display, E: Display, K>:
+    GraphData<N, E, K> + GraphAttributes<N>
+{
+    /// Format graph as Cytoscape.js JSON elements, with node attributes.
+    fn to_cytoscape(&self) -> miette::Result<String> {
+        format_cytoscape(self.get_graph(), |_, node| self.get_node_attributes(node))
+    }
+}
+
+fn escape_mermaid(value: &str) -> String {
+    value.replace('"', "#quot;")
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Format a graph as a top-down Mermaid flowchart.
+pub fn format_mermaid<N: Display, E: Display>(graph: &DiGraph<N, E>) -> String {
+    let mut lines = vec!["flowchart TD".to_owned()];
+
+    for index in graph.node_indices() {
+        lines.push(format!(
+            "    n{}[\"{}\"]",
+            index.index(),
+            escape_mermaid(&graph[index].to_string())
+        ));
+    }
+
+    for edge in graph.edge_references() {
+        let label = edge.weight().to_string();
+
+        lines.push(if label.is_empty() {
+            format!(
+                "    n{} --> n{}",
+                edge.source().index(),
+                edge.target().index()
+            )
+        } else {
+            format!(
+                "    n{} -->|\"{}\"| n{}",
+                edge.source().index(),
+                escape_mermaid(&label),
+                edge.target().index()
+            )
+        });
+    }
+
+    lines.join("\n")
+}
+
+/// Format a graph as a GraphML document. Attribute keys are declared
+/// in the order they are first encountered.
+pub fn format_graphml<N: Display, E: Display>(
+    graph: &DiGraph<N, E>,
+    get_attributes: impl Fn(NodeIndex, &N) -> GraphNodeAttributes,
+) -> String {
+    let mut keys = vec![];
+    let mut nodes = vec![];
+    let mut edges = vec![];
+
+    for index in graph.node_indices() {
+        let node = &graph[index];
+        let mut data = vec![format!(
+            "      <data key=\"label\">{}</data>",
+            escape_xml(&node.to_string())
+        )];
+
+        for (key, value) in get_attributes(index, node) {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+
+            data.push(format!(
+                "      <data key=\"{key}\">{}</data>",
+                escape_xml(&value)
+            ));
+        }
+
+        nodes.push(format!(
+            "    <node id=\"n{}\">\n{}\n    </node>",
+            index.index(),
+            data.join("\n")
+        ));
+    }
+
+    for edge in graph.edge_references() {
+        edges.push(format!(
+            "    <edge id=\"e{}\" source=\"n{}\" target=\"n{}\">",
+            edge.id().index(),
+            edge.source().index(),
+            edge.target().index(),
+        ));
+        edges.push(format!(
+            "      <data key=\"edge_label\">{}</data>",
+            escape_xml(&edge.weight().to_string())
+        ));
+        edges.push("    </edge>".to_owned());
+    }
+
+    let mut lines = vec![
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_owned(),
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#.to_owned(),
+        r#"  <key id="label" for="node" attr.name="label" attr.type="string"/>"#.to_owned(),
+    ];
+
+    for key in keys {
+        lines.push(format!(
+            r#"  <key id="{key}" for="node" attr.name="{key}" attr.type="string"/>"#
+        ));
+    }
+
+    lines.push(
+        r#"  <key id="edge_label" for="edge" attr.name="label" attr.type="string"/>"#.to_owned(),
+    );
+    lines.push(r#"  <graph id="G" edgedefault="directed">"#.to_owned());
+    lines.extend(nodes);
+    lines.extend(edges);
+    lines.push("  </graph>".to_owned());
+    lines.push("</graphml>".to_owned());
+
+    lines.join("\n")
+}
+
+#[derive(Serialize)]
+struct CytoscapeElement {
+    data: BTreeMap<&'static str, String>,
+}
+
+#[derive(Serialize)]
+struct CytoscapeElements {
+    nodes: Vec<CytoscapeElement>,
+    edges: Vec<CytoscapeElement>,
+}
+
+#[derive(Serialize)]
+struct CytoscapeGraph {
+    elements: CytoscapeElements,
+}
+
+/// Format a graph as Cytoscape.js JSON, which can be loaded
+/// with `cy.json()` or imported into Cytoscape desktop.
+pub fn format_cytoscape<N: Display, E: Display>(
+    graph: &DiGraph<N, E>,
+    get_attributes: impl Fn(NodeIndex, &N) -> GraphNodeAttributes,
+) -> miette::Result<String> {
+    let mut nodes = vec![];
+    let mut edges = vec![];
+
+    for index in graph.node_indices() {
+        let node = &graph[index];
+        let mut data = BTreeMap::from_iter(get_attributes(index, node));
+        data.insert("id", index.index().to_string());
+        data.insert("label", node.to_string());
+
+        nodes.push(CytoscapeElement { data });
+    }
+
+    for edge in graph.edge_references() {
+        edges.push(CytoscapeElement {
+            data: BTreeMap::from_iter([
+                (
+                    "id",
+                    format!("{} -> {}", edge.source().index(), edge.target().index()),
+                ),
+                ("label", edge.weight().to_string()),
+                ("source", edge.source().index().to_string()),
+                ("target", edge.target().index().to_string()),
+            ]),
+        });
+    }
+
+    Ok(json::format(
+        &CytoscapeGraph {
+            elements: CytoscapeElements { nodes, edges },
+        },
+        true,
+    )?)
+}
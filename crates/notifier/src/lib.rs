@@ -1,3 +1,5 @@
+mod webhook_endpoint;
 mod webhooks;
 
+pub use webhook_endpoint::*;
 pub use webhooks::*;
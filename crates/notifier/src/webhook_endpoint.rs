@@ -0,0 +1,160 @@
+use hmac::{Hmac, Mac};
+use miette::IntoDiagnostic;
+use moon_common::color;
+use moon_config::NotifierWebhookConfig;
+use serde::Serialize;
+use serde_json::Value;
+use sha2::Sha256;
+use starbase_utils::json;
+use std::env;
+use std::mem;
+use tera::{Context, Tera};
+use tracing::warn;
+
+pub const SIGNATURE_HEADER: &str = "X-Moon-Signature";
+pub const EVENT_HEADER: &str = "X-Moon-Event";
+
+/// Sign the request body with HMAC-SHA256, and return the signature
+/// in the format `sha256=<hex>`, as popularized by GitHub.
+pub fn sign_payload(secret: &str, body: &str) -> String {
+    // HMAC can take a key of any size, so this never fails
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+
+    mac.update(body.as_bytes());
+
+    format!("sha256={:x}", mac.finalize().into_bytes())
+}
+
+/// Action and task events are emitted for every node in the
+/// pipeline, so these are the only events that can be batched.
+pub fn is_batchable_event(name: &str) -> bool {
+    name.starts_with("action.") || name.starts_with("task.")
+}
+
+fn matches_event_pattern(pattern: &str, name: &str) -> bool {
+    if pattern == "*" || pattern == name {
+        return true;
+    }
+
+    pattern
+        .strip_suffix('*')
+        .is_some_and(|prefix| name.starts_with(prefix))
+}
+
+#[derive(Debug)]
+pub struct WebhookRequest {
+    pub body: String,
+    pub headers: Vec<(String, String)>,
+    pub retries: u8,
+    pub url: String,
+}
+
+pub struct WebhookEndpoint {
+    pub config: NotifierWebhookConfig,
+    pub enabled: bool,
+    pub verified: bool,
+
+    batch: Vec<Value>,
+    secret: Option<String>,
+}
+
+impl WebhookEndpoint {
+    pub fn new(config: NotifierWebhookConfig) -> Self {
+        let secret = config.secret.as_ref().and_then(|name| match env::var(name) {
+            Ok(value) if !value.is_empty() => Some(value),
+            _ => {
+                warn!(
+                    url = &config.url,
+                    "Webhook secret environment variable {} is not set, payloads will not be signed",
+                    color::property(name),
+                );
+
+                None
+            }
+        });
+
+        Self {
+            batch: vec![],
+            config,
+            enabled: true,
+            secret,
+            verified: false,
+        }
+    }
+
+    /// Return true if the event should be sent to this endpoint,
+    /// based on the configured event allowlist and failure filter.
+    pub fn accepts(&self, name: &str, failed: bool) -> bool {
+        if self.config.failures_only && !failed {
+            return false;
+        }
+
+        self.config.events.is_empty()
+            || self
+                .config
+                .events
+                .iter()
+                .any(|pattern| matches_event_pattern(pattern, name))
+    }
+
+    pub fn is_batching(&self, name: &str) -> bool {
+        self.config.batch_size > 0 && is_batchable_event(name)
+    }
+
+    /// Add the payload to the current batch, and return all
+    /// buffered payloads once the batch size has been reached.
+    pub fn push_batch(&mut self, payload: Value) -> Option<Vec<Value>> {
+        self.batch.push(payload);
+
+        if self.batch.len() >= self.config.batch_size {
+            return self.take_batch();
+        }
+
+        None
+    }
+
+    pub fn take_batch(&mut self) -> Option<Vec<Value>> {
+        if self.batch.is_empty() {
+            None
+        } else {
+            Some(mem::take(&mut self.batch))
+        }
+    }
+
+    /// Create the request body, either by rendering the configured
+    /// template, or by serializing the payload as JSON.
+    pub fn create_body<T: Serialize>(&self, payload: &T) -> miette::Result<String> {
+        match &self.config.template {
+            Some(template) => Tera::one_off(
+                template,
+                &Context::from_serialize(payload).into_diagnostic()?,
+                false,
+            )
+            .into_diagnostic(),
+            None => Ok(json::format(payload, false)?),
+        }
+    }
+
+    pub fn create_request(&self, name: &str, body: String) -> WebhookRequest {
+        let mut headers = self
+            .config
+            .headers
+            .iter()
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            .collect::<Vec<_>>();
+
+        headers.sort();
+        headers.push((EVENT_HEADER.into(), name.into()));
+
+        if let Some(secret) = &self.secret {
+            headers.push((SIGNATURE_HEADER.into(), sign_payload(secret, &body)));
+        }
+
+        WebhookRequest {
+            body,
+            headers,
+            retries: self.config.retries,
+            url: self.config.url.clone(),
+        }
+    }
+}
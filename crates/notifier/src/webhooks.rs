@@ -1,13 +1,21 @@
+use crate::webhook_endpoint::{WebhookEndpoint, WebhookRequest};
 use ci_env::{get_environment, CiEnvironment};
+use miette::IntoDiagnostic;
 use moon_common::color;
+use moon_config::NotifierWebhookConfig;
 use moon_time::chrono::NaiveDateTime;
 use moon_time::now_timestamp;
 use serde::Serialize;
-use starbase_utils::json;
+use serde_json::Value;
+use std::time::Duration;
 use tokio::task::JoinHandle;
 use tracing::{debug, trace, warn};
 use uuid::Uuid;
 
+/// Base delay between retries, which is doubled after each attempt.
+const RETRY_DELAY: Duration = Duration::from_millis(250);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct WebhookPayload<'data, T: Serialize> {
@@ -23,55 +31,159 @@ pub struct WebhookPayload<'data, T: Serialize> {
     pub uuid: &'data str,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookBatchPayload<'data> {
+    pub created_at: NaiveDateTime,
+
+    pub environment: Option<&'data CiEnvironment>,
+
+    pub events: Vec<Value>,
+
+    #[serde(rename = "type")]
+    pub type_of: &'data str,
+
+    pub uuid: &'data str,
+}
+
 pub async fn notify_webhook(
     url: String,
     body: String,
 ) -> Result<reqwest::Response, reqwest::Error> {
-    reqwest::Client::new()
-        .post(url)
-        .body(body)
+    send_request(
+        &reqwest::Client::new(),
+        &WebhookRequest {
+            body,
+            headers: vec![],
+            retries: 0,
+            url,
+        },
+    )
+    .await
+}
+
+async fn send_request(
+    client: &reqwest::Client,
+    request: &WebhookRequest,
+) -> Result<reqwest::Response, reqwest::Error> {
+    let mut builder = client
+        .post(&request.url)
+        .body(request.body.clone())
         .header("Accept", "application/json")
         .header("Content-Type", "application/json")
         .header("Connection", "keep-alive")
-        .header("Keep-Alive", "timeout=30, max=120")
-        .send()
-        .await
+        .header("Keep-Alive", "timeout=30, max=120");
+
+    for (key, value) in &request.headers {
+        builder = builder.header(key, value);
+    }
+
+    builder.send().await
+}
+
+/// Send the request, and retry with an exponential backoff when the
+/// request failed to send, or the server responded with a 429 or 5xx.
+/// Returns true if the request was successful.
+pub async fn notify_webhook_with_retries(client: reqwest::Client, request: WebhookRequest) -> bool {
+    let mut attempt = 0;
+
+    loop {
+        let retryable = match send_request(&client, &request).await {
+            Ok(response) => {
+                let status = response.status();
+
+                if status.is_success() {
+                    return true;
+                }
+
+                status.is_server_error() || status.as_u16() == 429
+            }
+            Err(_) => true,
+        };
+
+        if !retryable || attempt >= request.retries {
+            return false;
+        }
+
+        attempt += 1;
+
+        trace!(
+            url = &request.url,
+            "Webhook request failed, retrying (attempt {} of {})",
+            attempt,
+            request.retries
+        );
+
+        tokio::time::sleep(get_retry_delay(attempt)).await;
+    }
+}
+
+/// Double the delay for each attempt, capped at a maximum.
+fn get_retry_delay(attempt: u8) -> Duration {
+    2u32.checked_pow(u32::from(attempt.saturating_sub(1)))
+        .and_then(|factor| RETRY_DELAY.checked_mul(factor))
+        .unwrap_or(MAX_RETRY_DELAY)
+        .min(MAX_RETRY_DELAY)
+}
+
+fn create_batch_request(
+    endpoint: &WebhookEndpoint,
+    environment: Option<&CiEnvironment>,
+    uuid: &str,
+    events: Vec<Value>,
+) -> miette::Result<WebhookRequest> {
+    trace!(
+        "Posting batch of {} webhook events to endpoint",
+        events.len()
+    );
+
+    let body = endpoint.create_body(&WebhookBatchPayload {
+        created_at: now_timestamp(),
+        environment,
+        events,
+        type_of: "batch",
+        uuid,
+    })?;
+
+    Ok(endpoint.create_request("batch", body))
 }
 
 pub struct WebhooksNotifier {
-    enabled: bool,
+    client: reqwest::Client,
+    endpoints: Vec<WebhookEndpoint>,
     environment: Option<CiEnvironment>,
     requests: Vec<JoinHandle<()>>,
-    url: String,
     uuid: String,
-    verified: bool,
 }
 
 impl WebhooksNotifier {
-    pub fn new(url: String) -> Self {
-        debug!("Creating webhooks notifier for {}", color::url(&url));
+    pub fn new(configs: Vec<NotifierWebhookConfig>) -> Self {
+        for config in &configs {
+            debug!("Creating webhooks notifier for {}", color::url(&config.url));
+        }
 
         WebhooksNotifier {
-            enabled: true,
+            client: reqwest::Client::new(),
             environment: get_environment(),
             requests: vec![],
-            uuid: if url.contains("127.0.0.1") {
+            uuid: if configs
+                .iter()
+                .any(|config| config.url.contains("127.0.0.1"))
+            {
                 "XXXX-XXXX-XXXX-XXXX".into()
             } else {
                 Uuid::new_v4().to_string()
             },
-            url,
-            verified: false,
+            endpoints: configs.into_iter().map(WebhookEndpoint::new).collect(),
         }
     }
 
-    pub async fn notify<T: Serialize>(&mut self, name: &str, event: T) -> miette::Result<()> {
-        if !self.enabled {
-            return Ok(());
-        }
-
-        trace!("Posting webhook event {} to endpoint", color::id(name));
-
+    pub async fn notify<T: Serialize>(
+        &mut self,
+        name: &str,
+        event: T,
+        failed: bool,
+    ) -> miette::Result<()> {
         let payload = WebhookPayload {
             created_at: now_timestamp(),
             environment: self.environment.as_ref(),
@@ -79,37 +191,99 @@ impl WebhooksNotifier {
             type_of: name,
             uuid: &self.uuid,
         };
-        let body = json::format(&payload, false)?;
-        let url = self.url.to_owned();
+        let mut batch_value = None;
+        let mut requests = vec![];
 
-        // For the first event, we want to ensure that the webhook URL is valid
-        // by sending the request and checking for a failure. If failed,
-        // we will disable subsequent requests from being called.
-        if !self.verified {
-            let response = notify_webhook(url, body).await;
+        for (index, endpoint) in self.endpoints.iter_mut().enumerate() {
+            if !endpoint.enabled || !endpoint.accepts(name, failed) {
+                continue;
+            }
+
+            if endpoint.is_batching(name) {
+                if batch_value.is_none() {
+                    batch_value = Some(serde_json::to_value(&payload).into_diagnostic()?);
+                }
 
-            if response.is_err() || !response.unwrap().status().is_success() {
-                self.enabled = false;
+                if let Some(events) = endpoint.push_batch(batch_value.clone().unwrap()) {
+                    requests.push((
+                        index,
+                        create_batch_request(endpoint, payload.environment, &self.uuid, events)?,
+                    ));
+                }
 
-                warn!("Failed to send webhook event, subsequent webhook requests will be disabled");
+                continue;
             }
 
-            self.verified = true;
+            trace!("Posting webhook event {} to endpoint", color::id(name));
+
+            requests.push((
+                index,
+                endpoint.create_request(name, endpoint.create_body(&payload)?),
+            ));
         }
-        // For every other event, we will make the request and ignore the result.
-        // We will also avoid awaiting the request to not slow down the overall runner.
-        else {
-            self.requests.push(tokio::spawn(async {
-                let _ = notify_webhook(url, body).await;
-            }));
+
+        drop(payload);
+
+        for (index, request) in requests {
+            self.send(index, request).await;
+        }
+
+        Ok(())
+    }
+
+    pub async fn flush(&mut self) -> miette::Result<()> {
+        for index in 0..self.endpoints.len() {
+            if let Some(events) = self.endpoints[index].take_batch() {
+                let request = create_batch_request(
+                    &self.endpoints[index],
+                    self.environment.as_ref(),
+                    &self.uuid,
+                    events,
+                )?;
+
+                self.send(index, request).await;
+            }
         }
 
         Ok(())
     }
 
     pub async fn wait_for_requests(&mut self) {
+        if let Err(error) = self.flush().await {
+            warn!("Failed to send batched webhook events: {error}");
+        }
+
         for future in self.requests.drain(0..) {
             let _ = future.await;
         }
     }
+
+    async fn send(&mut self, index: usize, request: WebhookRequest) {
+        let endpoint = &mut self.endpoints[index];
+
+        // For the first event, we want to ensure that the webhook URL is valid
+        // by sending the request and checking for a failure. If failed,
+        // we will disable subsequent requests from being called.
+        if !endpoint.verified {
+            if !notify_webhook_with_retries(self.client.clone(), request).await {
+                endpoint.enabled = false;
+
+                warn!(
+                    url = &endpoint.config.url,
+                    "Failed to send webhook event, subsequent webhook requests will be disabled"
+                );
+            }
+
+            endpoint.verified = true;
+        }
+        // For every other event, we will make the request and ignore the result.
+        // We will also avoid awaiting the request to not slow down the overall runner.
+        else {
+            let client = self.client.clone();
+
+            self.requests.push(tokio::spawn(async move {
+                notify_webhook_with_retries(client, request).await;
+            }));
+        }
+    }
 }
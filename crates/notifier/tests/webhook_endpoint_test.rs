@@ -0,0 +1,221 @@
+use moon_config::NotifierWebhookConfig;
+use moon_notifier::*;
+use serde_json::json;
+
+fn create_endpoint(config: NotifierWebhookConfig) -> WebhookEndpoint {
+    WebhookEndpoint::new(NotifierWebhookConfig {
+        url: "https://domain.com/webhook".into(),
+        ..config
+    })
+}
+
+mod webhook_endpoint {
+    use super::*;
+
+    mod accepts {
+        use super::*;
+
+        #[test]
+        fn all_events_by_default() {
+            let endpoint = create_endpoint(NotifierWebhookConfig::default());
+
+            assert!(endpoint.accepts("action.started", false));
+            assert!(endpoint.accepts("pipeline.completed", true));
+        }
+
+        #[test]
+        fn only_allowed_events() {
+            let endpoint = create_endpoint(NotifierWebhookConfig {
+                events: vec!["pipeline.completed".into(), "task.*".into()],
+                ..Default::default()
+            });
+
+            assert!(endpoint.accepts("pipeline.completed", false));
+            assert!(endpoint.accepts("task.running", false));
+            assert!(endpoint.accepts("task.ran", false));
+            assert!(!endpoint.accepts("pipeline.started", false));
+            assert!(!endpoint.accepts("action.completed", false));
+        }
+
+        #[test]
+        fn supports_catch_all_wildcard() {
+            let endpoint = create_endpoint(NotifierWebhookConfig {
+                events: vec!["*".into()],
+                ..Default::default()
+            });
+
+            assert!(endpoint.accepts("workspace.synced", false));
+        }
+
+        #[test]
+        fn only_failures() {
+            let endpoint = create_endpoint(NotifierWebhookConfig {
+                events: vec!["pipeline.completed".into()],
+                failures_only: true,
+                ..Default::default()
+            });
+
+            assert!(endpoint.accepts("pipeline.completed", true));
+            assert!(!endpoint.accepts("pipeline.completed", false));
+            assert!(!endpoint.accepts("action.completed", true));
+        }
+    }
+
+    mod batching {
+        use super::*;
+
+        #[test]
+        fn disabled_by_default() {
+            let endpoint = create_endpoint(NotifierWebhookConfig::default());
+
+            assert!(!endpoint.is_batching("action.completed"));
+        }
+
+        #[test]
+        fn only_batches_high_volume_events() {
+            let endpoint = create_endpoint(NotifierWebhookConfig {
+                batch_size: 10,
+                ..Default::default()
+            });
+
+            assert!(endpoint.is_batching("action.started"));
+            assert!(endpoint.is_batching("task.ran"));
+            assert!(!endpoint.is_batching("pipeline.completed"));
+            assert!(!endpoint.is_batching("tool.installed"));
+        }
+
+        #[test]
+        fn returns_batch_when_full() {
+            let mut endpoint = create_endpoint(NotifierWebhookConfig {
+                batch_size: 2,
+                ..Default::default()
+            });
+
+            assert_eq!(endpoint.push_batch(json!(1)), None);
+            assert_eq!(
+                endpoint.push_batch(json!(2)),
+                Some(vec![json!(1), json!(2)])
+            );
+            assert_eq!(endpoint.push_batch(json!(3)), None);
+            assert_eq!(endpoint.take_batch(), Some(vec![json!(3)]));
+            assert_eq!(endpoint.take_batch(), None);
+        }
+    }
+
+    mod body {
+        use super::*;
+
+        #[test]
+        fn serializes_json_by_default() {
+            let endpoint = create_endpoint(NotifierWebhookConfig::default());
+
+            assert_eq!(
+                endpoint
+                    .create_body(&json!({ "type": "pipeline.completed" }))
+                    .unwrap(),
+                r#"{"type":"pipeline.completed"}"#
+            );
+        }
+
+        #[test]
+        fn renders_template() {
+            let endpoint = create_endpoint(NotifierWebhookConfig {
+                template: Some(r#"{"text": "Pipeline {{ event.status }} ({{ type }})"}"#.into()),
+                ..Default::default()
+            });
+
+            assert_eq!(
+                endpoint
+                    .create_body(&json!({
+                        "event": { "status": "aborted" },
+                        "type": "pipeline.completed"
+                    }))
+                    .unwrap(),
+                r#"{"text": "Pipeline aborted (pipeline.completed)"}"#
+            );
+        }
+
+        #[test]
+        fn errors_for_invalid_template() {
+            let endpoint = create_endpoint(NotifierWebhookConfig {
+                template: Some("{{ unclosed".into()),
+                ..Default::default()
+            });
+
+            assert!(endpoint.create_body(&json!({})).is_err());
+        }
+    }
+
+    mod request {
+        use super::*;
+
+        #[test]
+        fn includes_event_and_custom_headers() {
+            let endpoint = create_endpoint(NotifierWebhookConfig {
+                headers: [("Authorization".to_owned(), "Bearer abc".to_owned())]
+                    .into_iter()
+                    .collect(),
+                ..Default::default()
+            });
+
+            let request = endpoint.create_request("task.ran", "{}".into());
+
+            assert_eq!(
+                request.headers,
+                [
+                    ("Authorization".to_owned(), "Bearer abc".to_owned()),
+                    (EVENT_HEADER.to_owned(), "task.ran".to_owned()),
+                ]
+            );
+            assert_eq!(request.retries, 3);
+            assert_eq!(request.url, "https://domain.com/webhook");
+        }
+
+        #[test]
+        fn signs_payload_when_secret_is_set() {
+            std::env::set_var("MOON_TEST_WEBHOOK_SECRET", "shh");
+
+            let endpoint = create_endpoint(NotifierWebhookConfig {
+                secret: Some("MOON_TEST_WEBHOOK_SECRET".into()),
+                ..Default::default()
+            });
+
+            let request = endpoint.create_request("task.ran", "{}".into());
+
+            assert_eq!(
+                request.headers.last().unwrap(),
+                &(SIGNATURE_HEADER.to_owned(), sign_payload("shh", "{}"))
+            );
+
+            std::env::remove_var("MOON_TEST_WEBHOOK_SECRET");
+        }
+
+        #[test]
+        fn doesnt_sign_payload_when_secret_is_missing() {
+            let endpoint = create_endpoint(NotifierWebhookConfig {
+                secret: Some("MOON_TEST_WEBHOOK_SECRET_MISSING".into()),
+                ..Default::default()
+            });
+
+            let request = endpoint.create_request("task.ran", "{}".into());
+
+            assert!(request
+                .headers
+                .iter()
+                .all(|(key, _)| key != SIGNATURE_HEADER));
+        }
+    }
+
+    mod signing {
+        use super::*;
+
+        #[test]
+        fn creates_hmac_sha256_signature() {
+            // Reference value from RFC 4231, test case 2
+            assert_eq!(
+                sign_payload("Jefe", "what do ya want for nothing?"),
+                "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+            );
+        }
+    }
+}
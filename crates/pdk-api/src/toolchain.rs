@@ -1,6 +1,8 @@
 use crate::common::*;
+use rustc_hash::FxHashMap;
 use schematic::Schema;
-use warpgate_api::api_struct;
+use serde_json::Value;
+use warpgate_api::{api_enum, api_struct, VirtualPath};
 
 // METADATA
 
@@ -24,11 +26,92 @@ api_struct!(
     }
 );
 
+// INITIALIZE TOOLCHAIN
+
+api_enum!(
+    /// The type of prompt to render, and its default value.
+    #[serde(tag = "type", rename_all = "kebab-case")]
+    pub enum PromptType {
+        /// Confirm with a yes or no answer.
+        Confirm { default: bool },
+
+        /// Input a string value.
+        Input { default: String },
+
+        /// Select a single value from a list of options.
+        Select {
+            default_index: usize,
+            options: Vec<String>,
+        },
+    }
+);
+
+impl Default for PromptType {
+    fn default() -> Self {
+        Self::Confirm { default: false }
+    }
+}
+
+api_struct!(
+    /// A prompt to render when initializing a toolchain. The answer
+    /// will be stored as the value of the setting.
+    pub struct SettingPrompt {
+        /// Additional information about the setting.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// Whether to render the prompt in minimal mode.
+        pub minimal: bool,
+
+        /// The question to ask.
+        pub question: String,
+
+        /// Name of the setting to store the answer in.
+        pub setting: String,
+
+        /// The type of prompt.
+        #[serde(rename = "type")]
+        pub type_of: PromptType,
+    }
+);
+
+api_struct!(
+    /// Input passed to the `initialize_toolchain` function.
+    pub struct InitializeToolchainInput {
+        /// Current moon context.
+        pub context: MoonContext,
+    }
+);
+
+api_struct!(
+    /// Output returned from the `initialize_toolchain` function.
+    pub struct InitializeToolchainOutput {
+        /// A URL to the toolchain's configuration documentation.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub config_url: Option<String>,
+
+        /// Settings to include in the configuration, that do not require a prompt.
+        pub default_settings: FxHashMap<String, Value>,
+
+        /// A URL to the toolchain's documentation.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub docs_url: Option<String>,
+
+        /// A list of prompts to render, in order.
+        pub prompts: Vec<SettingPrompt>,
+    }
+);
+
 // SYNC WORKSPACE
 
 api_struct!(
     /// Input passed to the `sync_workspace` function.
     pub struct SyncWorkspaceInput {
+        /// Whether only checking for drift. Files must not be written,
+        /// but returned as `changed_files`, and returned operations
+        /// will not be executed, and are considered drift.
+        pub check: bool,
+
         /// Current moon context.
         pub context: MoonContext,
     }
@@ -37,11 +120,27 @@ api_struct!(
 api_struct!(
     /// Output returned from the `sync_workspace` function.
     pub struct SyncWorkspaceOutput {
+        /// Files that were changed, or would be changed when checking,
+        /// with their expected contents. Used to render a diff of drift.
+        pub changed_files: Vec<SyncFileChange>,
+
         /// Operations to perform.
         pub operations: Vec<Operation>,
     }
 );
 
+api_struct!(
+    /// A file that was changed, or would be changed, while syncing.
+    pub struct SyncFileChange {
+        /// Expected contents of the file, or none if it should be removed.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub content: Option<String>,
+
+        /// Virtual path to the file.
+        pub path: VirtualPath,
+    }
+);
+
 // SYNC PROJECT
 
 // api_struct!(
@@ -1,7 +1,10 @@
+use chrono::NaiveDate;
 use miette::Diagnostic;
 use moon_common::{Id, Style, Stylize};
-use moon_config::{DependencyScope, StackType};
+use moon_config::{ConstraintsConfig, DependencyScope, StackType};
 use moon_project::{Project, ProjectType};
+use starbase_utils::glob::GlobSet;
+use std::slice;
 use thiserror::Error;
 
 #[derive(Error, Debug, Diagnostic)]
@@ -34,6 +37,58 @@ pub enum ProjectConstraintsError {
         dep_id: Id,
         allowed: String,
     },
+
+    #[diagnostic(code(project_constraints::invalid_layer_relationship))]
+    #[error(
+        "Invalid layer relationship. Project {} in layer {} cannot depend on project {} in the higher layer {}.\n\nThis can be customized with the {} and {} settings.",
+        .source_id.style(Style::Id),
+        .source_layer.style(Style::Id),
+        .dep_id.style(Style::Id),
+        .dep_layer.style(Style::Id),
+        "constraints.layers".style(Style::Property),
+        "constraints.exceptions".style(Style::Property),
+    )]
+    InvalidLayerRelationship {
+        source_id: Id,
+        source_layer: Id,
+        dep_id: Id,
+        dep_layer: Id,
+        #[help]
+        help: Option<String>,
+    },
+
+    #[diagnostic(code(project_constraints::denied_relationship))]
+    #[error(
+        "Denied project relationship. Project {} cannot depend on project {}{}\n\nThis can be customized with the {} and {} settings.",
+        .source_id.style(Style::Id),
+        .dep_id.style(Style::Id),
+        .reason.as_ref().map(|reason| format!(": {reason}")).unwrap_or_else(|| ".".into()),
+        "constraints.denyRelationships".style(Style::Property),
+        "constraints.exceptions".style(Style::Property),
+    )]
+    DeniedRelationship {
+        source_id: Id,
+        dep_id: Id,
+        reason: Option<String>,
+        #[help]
+        help: Option<String>,
+    },
+
+    #[diagnostic(code(project_constraints::max_dependencies))]
+    #[error(
+        "Project {} depends on {count} projects (including transitive dependencies), which exceeds the limit of {limit}.\n\nThis can be customized with the {} setting.",
+        .id.style(Style::Id),
+        "constraints.maxDependencies".style(Style::Property),
+    )]
+    MaxDependenciesExceeded { id: Id, count: usize, limit: usize },
+
+    #[diagnostic(code(project_constraints::max_dependents))]
+    #[error(
+        "Project {} is depended on by {count} projects (including transitive dependents), which exceeds the limit of {limit}.\n\nThis can be customized with the {} setting.",
+        .id.style(Style::Id),
+        "constraints.maxDependents".style(Style::Property),
+    )]
+    MaxDependentsExceeded { id: Id, count: usize, limit: usize },
 }
 
 pub fn enforce_project_type_relationships(
@@ -169,3 +224,185 @@ pub fn enforce_tag_relationships(
     }
     .into())
 }
+
+fn matches_globs(id: &Id, patterns: &[String]) -> miette::Result<bool> {
+    if patterns.is_empty() {
+        return Ok(false);
+    }
+
+    Ok(GlobSet::new(patterns)?.is_match(id.as_str()))
+}
+
+enum ExceptionState {
+    Active,
+    Expired(NaiveDate),
+    Missing,
+}
+
+impl ExceptionState {
+    fn is_active(&self) -> bool {
+        matches!(self, Self::Active)
+    }
+
+    fn get_help(&self) -> Option<String> {
+        match self {
+            Self::Expired(expires) => Some(format!(
+                "An exception for this relationship expired on {expires}."
+            )),
+            _ => None,
+        }
+    }
+}
+
+fn find_exception(
+    source: &Project,
+    dependency: &Project,
+    config: &ConstraintsConfig,
+    today: NaiveDate,
+) -> miette::Result<ExceptionState> {
+    let mut state = ExceptionState::Missing;
+
+    for exception in &config.exceptions {
+        if !matches_globs(&source.id, slice::from_ref(&exception.from))?
+            || !matches_globs(&dependency.id, slice::from_ref(&exception.to))?
+        {
+            continue;
+        }
+
+        match exception.expires {
+            Some(expires) if expires < today => {
+                state = ExceptionState::Expired(expires);
+            }
+            _ => {
+                return Ok(ExceptionState::Active);
+            }
+        };
+    }
+
+    Ok(state)
+}
+
+fn find_layer<'a>(
+    project: &Project,
+    config: &'a ConstraintsConfig,
+) -> miette::Result<Option<(usize, &'a Id)>> {
+    for (index, layer) in config.layers.iter().enumerate() {
+        if matches_globs(&project.id, &layer.projects)? {
+            return Ok(Some((index, &layer.id)));
+        }
+    }
+
+    Ok(None)
+}
+
+pub fn enforce_layer_relationships(
+    source: &Project,
+    dependency: &Project,
+    dependency_scope: &DependencyScope,
+    config: &ConstraintsConfig,
+    today: NaiveDate,
+) -> miette::Result<()> {
+    if config.layers.is_empty()
+        || matches!(
+            dependency_scope,
+            DependencyScope::Build | DependencyScope::Root
+        )
+    {
+        return Ok(());
+    }
+
+    // Projects not within a layer are not constrained
+    let (Some((source_index, source_layer)), Some((dep_index, dep_layer))) =
+        (find_layer(source, config)?, find_layer(dependency, config)?)
+    else {
+        return Ok(());
+    };
+
+    // Layers are ordered from highest to lowest, and can only depend downwards
+    if dep_index >= source_index {
+        return Ok(());
+    }
+
+    let exception = find_exception(source, dependency, config, today)?;
+
+    if exception.is_active() {
+        return Ok(());
+    }
+
+    Err(ProjectConstraintsError::InvalidLayerRelationship {
+        source_id: source.id.clone(),
+        source_layer: source_layer.clone(),
+        dep_id: dependency.id.clone(),
+        dep_layer: dep_layer.clone(),
+        help: exception.get_help(),
+    }
+    .into())
+}
+
+pub fn enforce_denied_relationships(
+    source: &Project,
+    dependency: &Project,
+    dependency_scope: &DependencyScope,
+    config: &ConstraintsConfig,
+    today: NaiveDate,
+) -> miette::Result<()> {
+    if matches!(
+        dependency_scope,
+        DependencyScope::Build | DependencyScope::Root
+    ) {
+        return Ok(());
+    }
+
+    for rule in &config.deny_relationships {
+        if !matches_globs(&source.id, &rule.from)? || !matches_globs(&dependency.id, &rule.to)? {
+            continue;
+        }
+
+        let exception = find_exception(source, dependency, config, today)?;
+
+        if exception.is_active() {
+            return Ok(());
+        }
+
+        return Err(ProjectConstraintsError::DeniedRelationship {
+            source_id: source.id.clone(),
+            dep_id: dependency.id.clone(),
+            reason: rule.reason.clone(),
+            help: exception.get_help(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+pub fn enforce_dependency_limits(
+    project: &Project,
+    dependencies_count: usize,
+    dependents_count: usize,
+    config: &ConstraintsConfig,
+) -> miette::Result<()> {
+    if let Some(limit) = config.max_dependencies {
+        if dependencies_count > limit {
+            return Err(ProjectConstraintsError::MaxDependenciesExceeded {
+                id: project.id.clone(),
+                count: dependencies_count,
+                limit,
+            }
+            .into());
+        }
+    }
+
+    if let Some(limit) = config.max_dependents {
+        if dependents_count > limit {
+            return Err(ProjectConstraintsError::MaxDependentsExceeded {
+                id: project.id.clone(),
+                count: dependents_count,
+                limit,
+            }
+            .into());
+        }
+    }
+
+    Ok(())
+}
@@ -1,7 +1,14 @@
+use chrono::NaiveDate;
 use moon_common::Id;
-use moon_config::{DependencyScope, StackType};
+use moon_config::{
+    ConstraintsConfig, ConstraintsDenyConfig, ConstraintsExceptionConfig, ConstraintsLayerConfig,
+    DependencyScope, StackType,
+};
 use moon_project::{Project, ProjectConfig, ProjectType};
-use moon_project_constraints::{enforce_project_type_relationships, enforce_tag_relationships};
+use moon_project_constraints::{
+    enforce_denied_relationships, enforce_dependency_limits, enforce_layer_relationships,
+    enforce_project_type_relationships, enforce_tag_relationships,
+};
 
 fn create_project(id: &str, type_of: ProjectType) -> Project {
     Project {
@@ -11,6 +18,23 @@ fn create_project(id: &str, type_of: ProjectType) -> Project {
     }
 }
 
+fn create_project_with_id(id: &str) -> Project {
+    create_project(id, ProjectType::Unknown)
+}
+
+fn today() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 6, 1).unwrap()
+}
+
+fn create_exception(from: &str, to: &str, expires: Option<&str>) -> ConstraintsExceptionConfig {
+    ConstraintsExceptionConfig {
+        expires: expires.map(|date| date.parse().unwrap()),
+        from: from.into(),
+        reason: None,
+        to: to.into(),
+    }
+}
+
 fn create_project_with_tags(id: &str, tags: Vec<Id>) -> Project {
     Project {
         id: Id::raw(id),
@@ -654,3 +678,256 @@ mod by_tag {
         .unwrap();
     }
 }
+
+mod by_layer {
+    use super::*;
+
+    fn create_config() -> ConstraintsConfig {
+        ConstraintsConfig {
+            layers: vec![
+                ConstraintsLayerConfig {
+                    id: Id::raw("ui"),
+                    projects: vec!["ui-*".into(), "web".into()],
+                },
+                ConstraintsLayerConfig {
+                    id: Id::raw("domain"),
+                    projects: vec!["domain-*".into()],
+                },
+                ConstraintsLayerConfig {
+                    id: Id::raw("infra"),
+                    projects: vec!["infra-*".into()],
+                },
+            ],
+            ..ConstraintsConfig::default()
+        }
+    }
+
+    #[test]
+    fn allows_lower_and_same_layers() {
+        let config = create_config();
+
+        for (source, dep) in [
+            ("web", "ui-button"),
+            ("web", "domain-user"),
+            ("ui-button", "infra-db"),
+            ("domain-user", "infra-db"),
+            ("domain-user", "domain-post"),
+        ] {
+            enforce_layer_relationships(
+                &create_project_with_id(source),
+                &create_project_with_id(dep),
+                &DependencyScope::Production,
+                &config,
+                today(),
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn ignores_projects_without_a_layer() {
+        enforce_layer_relationships(
+            &create_project_with_id("infra-db"),
+            &create_project_with_id("utils"),
+            &DependencyScope::Production,
+            &create_config(),
+            today(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn ignores_build_and_root_scopes() {
+        for scope in [DependencyScope::Build, DependencyScope::Root] {
+            enforce_layer_relationships(
+                &create_project_with_id("infra-db"),
+                &create_project_with_id("web"),
+                &scope,
+                &create_config(),
+                today(),
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Invalid layer relationship. Project infra-db in layer infra cannot depend"
+    )]
+    fn fails_for_higher_layers() {
+        enforce_layer_relationships(
+            &create_project_with_id("infra-db"),
+            &create_project_with_id("domain-user"),
+            &DependencyScope::Development,
+            &create_config(),
+            today(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn allows_with_exception() {
+        let mut config = create_config();
+        config.exceptions = vec![
+            create_exception("infra-*", "domain-user", None),
+            create_exception("domain-user", "web", Some("2025-06-01")),
+        ];
+
+        enforce_layer_relationships(
+            &create_project_with_id("infra-db"),
+            &create_project_with_id("domain-user"),
+            &DependencyScope::Production,
+            &config,
+            today(),
+        )
+        .unwrap();
+
+        enforce_layer_relationships(
+            &create_project_with_id("domain-user"),
+            &create_project_with_id("web"),
+            &DependencyScope::Production,
+            &config,
+            today(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn fails_with_expired_exception() {
+        let mut config = create_config();
+        config.exceptions = vec![create_exception(
+            "infra-db",
+            "domain-user",
+            Some("2025-05-31"),
+        )];
+
+        let error = enforce_layer_relationships(
+            &create_project_with_id("infra-db"),
+            &create_project_with_id("domain-user"),
+            &DependencyScope::Production,
+            &config,
+            today(),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.help().unwrap().to_string(),
+            "An exception for this relationship expired on 2025-05-31."
+        );
+    }
+}
+
+mod by_deny {
+    use super::*;
+
+    fn create_config() -> ConstraintsConfig {
+        ConstraintsConfig {
+            deny_relationships: vec![ConstraintsDenyConfig {
+                from: vec!["app-*".into()],
+                to: vec!["internal-*".into(), "legacy".into()],
+                reason: Some("use the public API instead".into()),
+            }],
+            ..ConstraintsConfig::default()
+        }
+    }
+
+    #[test]
+    fn allows_unmatched_projects() {
+        for (source, dep) in [("app-web", "utils"), ("lib", "internal-db")] {
+            enforce_denied_relationships(
+                &create_project_with_id(source),
+                &create_project_with_id(dep),
+                &DependencyScope::Production,
+                &create_config(),
+                today(),
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Denied project relationship. Project app-web cannot depend on project"
+    )]
+    fn fails_for_matching_projects() {
+        enforce_denied_relationships(
+            &create_project_with_id("app-web"),
+            &create_project_with_id("legacy"),
+            &DependencyScope::Peer,
+            &create_config(),
+            today(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn allows_with_exception() {
+        let mut config = create_config();
+        config.exceptions = vec![create_exception("app-web", "legacy", Some("2025-12-31"))];
+
+        enforce_denied_relationships(
+            &create_project_with_id("app-web"),
+            &create_project_with_id("legacy"),
+            &DependencyScope::Production,
+            &config,
+            today(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Denied project relationship")]
+    fn fails_with_expired_exception() {
+        let mut config = create_config();
+        config.exceptions = vec![create_exception("app-web", "legacy", Some("2024-12-31"))];
+
+        enforce_denied_relationships(
+            &create_project_with_id("app-web"),
+            &create_project_with_id("legacy"),
+            &DependencyScope::Production,
+            &config,
+            today(),
+        )
+        .unwrap();
+    }
+}
+
+mod by_limits {
+    use super::*;
+
+    fn create_config() -> ConstraintsConfig {
+        ConstraintsConfig {
+            max_dependencies: Some(5),
+            max_dependents: Some(10),
+            ..ConstraintsConfig::default()
+        }
+    }
+
+    #[test]
+    fn allows_within_limits() {
+        enforce_dependency_limits(&create_project_with_id("foo"), 5, 10, &create_config()).unwrap();
+    }
+
+    #[test]
+    fn allows_without_limits() {
+        enforce_dependency_limits(
+            &create_project_with_id("foo"),
+            100,
+            100,
+            &ConstraintsConfig::default(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Project foo depends on 6 projects")]
+    fn fails_when_dependencies_exceeded() {
+        enforce_dependency_limits(&create_project_with_id("foo"), 6, 0, &create_config()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "Project foo is depended on by 11 projects")]
+    fn fails_when_dependents_exceeded() {
+        enforce_dependency_limits(&create_project_with_id("foo"), 0, 11, &create_config()).unwrap();
+    }
+}
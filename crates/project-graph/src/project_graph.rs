@@ -288,3 +288,37 @@ impl GraphConversions<Project, DependencyScope, Id> for ProjectGraph {}
 impl GraphToDot<Project, DependencyScope, Id> for ProjectGraph {}
 
 impl GraphToJson<Project, DependencyScope, Id> for ProjectGraph {}
+
+impl GraphAttributes<Project> for ProjectGraph {
+    fn get_node_attributes(&self, node: &Project) -> GraphNodeAttributes {
+        let mut attributes = vec![
+            ("type", node.type_of.to_string()),
+            ("language", node.language.to_string()),
+            ("stack", node.stack.to_string()),
+        ];
+
+        if !node.config.tags.is_empty() {
+            attributes.push((
+                "tags",
+                node.config
+                    .tags
+                    .iter()
+                    .map(|tag| tag.as_str())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            ));
+        }
+
+        if let Some(owner) = &node.config.owners.default_owner {
+            attributes.push(("owner", owner.to_owned()));
+        }
+
+        attributes
+    }
+}
+
+impl GraphToMermaid<Project, DependencyScope, Id> for ProjectGraph {}
+
+impl GraphToGraphMl<Project, DependencyScope, Id> for ProjectGraph {}
+
+impl GraphToCytoscape<Project, DependencyScope, Id> for ProjectGraph {}
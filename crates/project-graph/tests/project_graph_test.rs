@@ -1168,6 +1168,49 @@ mod project_graph {
         }
     }
 
+    mod dependency_limits {
+        use super::*;
+
+        async fn generate_limits_project_graph(
+            max_dependencies: Option<usize>,
+            max_dependents: Option<usize>,
+        ) -> WorkspaceGraph {
+            let sandbox = create_sandbox("type-constraints");
+
+            append_file(sandbox.path().join("app/moon.yml"), "dependsOn: [library]");
+            append_file(
+                sandbox.path().join("library/moon.yml"),
+                "dependsOn: [library-other]",
+            );
+
+            let mut mock = create_workspace_graph_mocker(sandbox.path());
+
+            mock.workspace_config.constraints.max_dependencies = max_dependencies;
+            mock.workspace_config.constraints.max_dependents = max_dependents;
+
+            mock.build_workspace_graph().await
+        }
+
+        #[tokio::test]
+        async fn allows_within_limits() {
+            generate_limits_project_graph(Some(2), Some(2)).await;
+        }
+
+        #[tokio::test]
+        #[should_panic(
+            expected = "Project app depends on 2 projects (including transitive dependencies)"
+        )]
+        async fn errors_when_transitive_dependencies_exceeded() {
+            generate_limits_project_graph(Some(1), None).await;
+        }
+
+        #[tokio::test]
+        #[should_panic(expected = "Project library-other is depended on by 2 projects")]
+        async fn errors_when_transitive_dependents_exceeded() {
+            generate_limits_project_graph(None, Some(1)).await;
+        }
+    }
+
     mod type_constraints {
         use super::*;
 
@@ -1473,6 +1516,23 @@ mod project_graph {
             assert_eq!(get_ids_from_projects(projects), vec!["a", "c"]);
         }
 
+        #[tokio::test]
+        async fn by_project_owner() {
+            let graph = generate_workspace_graph("query").await;
+
+            let projects = graph
+                .query_projects(build_query("projectOwner=@org/python").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["c"]);
+
+            let projects = graph
+                .query_projects(build_query("projectOwner~@org/*").unwrap())
+                .unwrap();
+
+            assert_eq!(get_ids_from_projects(projects), vec!["a", "c"]);
+        }
+
         #[tokio::test]
         async fn by_project_source() {
             let graph = generate_workspace_graph("query").await;
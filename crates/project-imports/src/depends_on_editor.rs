@@ -0,0 +1,68 @@
+use moon_yaml_editor::YamlEditor;
+use starbase_utils::yaml::YamlValue;
+
+/// Update the `dependsOn` setting within the provided YAML content, by removing
+/// entries that match the filter, and appending the added IDs. The content is
+/// edited line by line, so that formatting and comments elsewhere are preserved.
+pub fn update_depends_on<F>(
+    content: &str,
+    should_remove: F,
+    added: &[String],
+) -> miette::Result<String>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut editor = YamlEditor::new(content);
+    let added_lines = added
+        .iter()
+        .map(|id| format!("- {}", quote(id)))
+        .collect::<Vec<_>>();
+
+    let Some(index) = editor.find_key(None, "dependsOn") else {
+        if added.is_empty() {
+            return Ok(content.to_owned());
+        }
+
+        let index = editor.insert_key(None, "dependsOn");
+        editor.append_to_block(index, added_lines);
+
+        return Ok(editor.into_content());
+    };
+
+    // Not a sequence, so leave it for validation to report
+    if !matches!(
+        editor.parse_value(index)?,
+        YamlValue::Sequence(_) | YamlValue::Null
+    ) {
+        return Ok(content.to_owned());
+    }
+
+    // Flow sequences are converted to block sequences
+    editor.expand_value(index)?;
+
+    let kept_count = editor.remove_items(index, |entry| {
+        get_entry_name(entry).is_some_and(&should_remove)
+    });
+
+    if kept_count == 0 && added_lines.is_empty() {
+        editor.set_inline_value(index, "[]");
+    } else {
+        editor.append_to_block(index, added_lines);
+    }
+
+    Ok(editor.into_content())
+}
+
+// Entries may reference a project by ID or alias,
+// either as a string or as an object with an `id` field
+fn get_entry_name(entry: &YamlValue) -> Option<&str> {
+    match entry {
+        YamlValue::String(name) => Some(name.as_str()),
+        YamlValue::Mapping(map) => map.get("id").and_then(|id| id.as_str()),
+        _ => None,
+    }
+}
+
+fn quote(id: &str) -> String {
+    format!("'{}'", id.replace('\'', "''"))
+}
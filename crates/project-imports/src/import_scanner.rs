@@ -0,0 +1,121 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::BTreeSet;
+
+static JS_FROM: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?:^|[;\n])[ \t]*(?:import|export)[ \t\n]+[^'";]*?[ \t\n]*from[ \t\n]*['"]([^'"\n]+)['"]"#)
+        .unwrap()
+});
+
+static JS_SIDE_EFFECT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?:^|[;\n])[ \t]*import[ \t]*['"]([^'"\n]+)['"]"#).unwrap());
+
+static JS_CALL: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r#"(?:^|[^A-Za-z0-9_$.])(?:require|import)[ \t]*\([ \t\n]*['"]([^'"\n]+)['"][ \t\n]*\)"#,
+    )
+    .unwrap()
+});
+
+static RUST_USE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?m)^[ \t]*(?:pub(?:\([^)]*\))?[ \t]+)?use[ \t]+(?:::)?([A-Za-z_][A-Za-z0-9_]*)")
+        .unwrap()
+});
+
+static RUST_EXTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?m)^[ \t]*(?:pub[ \t]+)?extern[ \t]+crate[ \t]+([A-Za-z_][A-Za-z0-9_]*)").unwrap()
+});
+
+// Crates can be referenced by path without a `use` declaration,
+// for example, `#[tokio::main]` or `serde_json::to_string()`
+static RUST_PATH: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?:^|[^A-Za-z0-9_:])(?:::)?([A-Za-z_][A-Za-z0-9_]*)::").unwrap());
+
+static PY_IMPORT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?m)^[ \t]*import[ \t]+([^\n#]+)").unwrap());
+
+static PY_FROM: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?m)^[ \t]*from[ \t]+([A-Za-z_][A-Za-z0-9_.]*)[ \t]+import").unwrap()
+});
+
+/// Extract the package name from a JavaScript module specifier, for example,
+/// `@scope/pkg/sub/path` becomes `@scope/pkg`. Relative paths, absolute paths,
+/// and protocol imports (`node:fs`) are ignored.
+pub fn get_js_package_name(specifier: &str) -> Option<&str> {
+    if specifier.starts_with('.') || specifier.starts_with('/') || specifier.contains(':') {
+        return None;
+    }
+
+    let mut parts = specifier.splitn(3, '/');
+    let first = parts.next()?;
+
+    if first.starts_with('@') {
+        let second = parts.next()?;
+
+        return Some(&specifier[..first.len() + 1 + second.len()]);
+    }
+
+    Some(first)
+}
+
+/// Extract package names imported by JavaScript or TypeScript source code,
+/// supporting `import`, `export ... from`, `require()`, and `import()`.
+pub fn extract_js_imports(content: &str) -> BTreeSet<String> {
+    let mut imports = BTreeSet::new();
+
+    for pattern in [&JS_FROM, &JS_SIDE_EFFECT, &JS_CALL] {
+        for caps in pattern.captures_iter(content) {
+            if let Some(name) = caps.get(1).and_then(|m| get_js_package_name(m.as_str())) {
+                imports.insert(name.to_owned());
+            }
+        }
+    }
+
+    imports
+}
+
+/// Extract crate names imported by Rust source code, supporting
+/// `use` declarations, `extern crate`, and paths (`name::item`).
+pub fn extract_rust_imports(content: &str) -> BTreeSet<String> {
+    let mut imports = BTreeSet::new();
+
+    for pattern in [&RUST_USE, &RUST_EXTERN, &RUST_PATH] {
+        for caps in pattern.captures_iter(content) {
+            let name = caps.get(1).unwrap().as_str();
+
+            if !matches!(
+                name,
+                "crate" | "self" | "Self" | "super" | "std" | "core" | "alloc"
+            ) {
+                imports.insert(name.to_owned());
+            }
+        }
+    }
+
+    imports
+}
+
+/// Extract top-level module names imported by Python source code, supporting
+/// `import` and `from ... import` statements. Relative imports are ignored.
+pub fn extract_python_imports(content: &str) -> BTreeSet<String> {
+    let mut imports = BTreeSet::new();
+
+    for caps in PY_IMPORT.captures_iter(content) {
+        for module in caps.get(1).unwrap().as_str().split(',') {
+            let module = module.trim();
+            let module = module.split([' ', '\t']).next().unwrap_or(module);
+
+            if let Some(name) = module.split('.').next().filter(|name| !name.is_empty()) {
+                imports.insert(name.to_owned());
+            }
+        }
+    }
+
+    for caps in PY_FROM.captures_iter(content) {
+        if let Some(name) = caps.get(1).unwrap().as_str().split('.').next() {
+            imports.insert(name.to_owned());
+        }
+    }
+
+    imports
+}
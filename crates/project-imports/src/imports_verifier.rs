@@ -0,0 +1,472 @@
+use crate::depends_on_editor::update_depends_on;
+use crate::import_scanner::*;
+use miette::IntoDiagnostic;
+use moon_common::path::to_virtual_string;
+use moon_common::{color, Id};
+use moon_config::{DependencyScope, DependencySource, LanguageType};
+use moon_process::{output_to_trimmed_string, Command};
+use moon_project::Project;
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use starbase_utils::{fs, glob};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::{debug, instrument, trace, warn};
+
+const JS_GLOBS: [&str; 8] = [
+    "**/*.js", "**/*.jsx", "**/*.cjs", "**/*.mjs", "**/*.ts", "**/*.tsx", "**/*.cts", "**/*.mts",
+];
+
+const IGNORE_GLOBS: [&str; 7] = [
+    "!**/node_modules/**",
+    "!**/target/**",
+    "!**/.venv/**",
+    "!**/venv/**",
+    "!**/__pycache__/**",
+    "!**/dist/**",
+    "!**/build/**",
+];
+
+#[derive(Debug, Deserialize)]
+struct CargoDependency {
+    path: Option<PathBuf>,
+    rename: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoTarget {
+    kind: Vec<String>,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoPackage {
+    dependencies: Vec<CargoDependency>,
+    manifest_path: PathBuf,
+    targets: Vec<CargoTarget>,
+}
+
+impl CargoPackage {
+    // Crates are imported by the name of their library target,
+    // which may differ from the package name
+    fn get_lib_name(&self) -> Option<&str> {
+        self.targets
+            .iter()
+            .find(|target| {
+                target
+                    .kind
+                    .iter()
+                    .any(|kind| kind.ends_with("lib") || kind == "proc-macro")
+            })
+            .map(|target| target.name.as_str())
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct CargoMetadata {
+    packages: Vec<CargoPackage>,
+    workspace_root: PathBuf,
+}
+
+impl CargoMetadata {
+    // Cargo reports canonical paths, which may differ from project roots when
+    // the workspace is symlinked, so find the Cargo workspace root as it's seen
+    // from the project, by matching the project against a package directory
+    fn find_workspace_root(&self, project_root: &Path) -> PathBuf {
+        project_root
+            .ancestors()
+            .skip(1)
+            .find(|dir| {
+                project_root.strip_prefix(dir).is_ok_and(|rel| {
+                    let package_dir = self.workspace_root.join(rel);
+
+                    self.packages
+                        .iter()
+                        .any(|package| package.manifest_path.parent() == Some(&package_dir))
+                })
+            })
+            .unwrap_or(project_root)
+            .to_path_buf()
+    }
+}
+
+/// The result of verifying a project's source imports.
+#[derive(Debug, Default, PartialEq)]
+pub struct ProjectImportsReport {
+    pub project: Id,
+
+    /// Projects that are imported but not declared as a dependency,
+    /// mapped to the files (relative from the project root) that import them.
+    pub undeclared: BTreeMap<Id, BTreeSet<String>>,
+
+    /// Projects that are explicitly declared in `dependsOn`,
+    /// but are never imported.
+    pub unused: BTreeSet<Id>,
+}
+
+impl ProjectImportsReport {
+    pub fn is_valid(&self) -> bool {
+        self.undeclared.is_empty() && self.unused.is_empty()
+    }
+}
+
+/// Scans the source files of projects for imports, and maps them to
+/// workspace projects, to verify them against declared dependencies.
+pub struct ImportsVerifier {
+    aliases: FxHashMap<Id, String>,
+    js_names: FxHashMap<String, Id>,
+    python_names: FxHashMap<String, Id>,
+    rust_deps: FxHashMap<Id, FxHashMap<String, Id>>,
+    rust_names: FxHashMap<String, Id>,
+    roots: Vec<(Id, PathBuf)>,
+}
+
+impl ImportsVerifier {
+    pub async fn new(projects: &[Arc<Project>]) -> miette::Result<Self> {
+        let mut verifier = Self {
+            aliases: FxHashMap::default(),
+            js_names: FxHashMap::default(),
+            python_names: FxHashMap::default(),
+            rust_deps: FxHashMap::default(),
+            rust_names: FxHashMap::default(),
+            roots: vec![],
+        };
+        let mut rust_projects = vec![];
+
+        for project in projects {
+            let mut names = vec![project.id.to_string()];
+
+            if let Some(alias) = &project.alias {
+                names.push(alias.to_owned());
+                verifier
+                    .aliases
+                    .insert(project.id.clone(), alias.to_owned());
+            }
+
+            match project.language {
+                LanguageType::JavaScript | LanguageType::TypeScript => {
+                    for name in names {
+                        verifier.js_names.insert(name, project.id.clone());
+                    }
+                }
+                LanguageType::Python => {
+                    for name in names {
+                        verifier
+                            .python_names
+                            .insert(normalize_module_name(&name), project.id.clone());
+                    }
+                }
+                LanguageType::Rust => {
+                    for name in names {
+                        verifier
+                            .rust_names
+                            .insert(normalize_module_name(&name), project.id.clone());
+                    }
+
+                    rust_projects.push(project);
+                }
+                _ => {}
+            };
+
+            verifier
+                .roots
+                .push((project.id.clone(), project.root.clone()));
+        }
+
+        verifier.load_rust_crates(&rust_projects).await?;
+
+        Ok(verifier)
+    }
+
+    /// Load crate metadata through Cargo, so that imports are mapped by their
+    /// crate name, and by the name of each dependency within the importing
+    /// crate's manifest, which accounts for renamed and workspace dependencies.
+    async fn load_rust_crates(&mut self, projects: &[&Arc<Project>]) -> miette::Result<()> {
+        let mut packages: FxHashMap<PathBuf, CargoPackage> = FxHashMap::default();
+
+        for project in projects {
+            let manifest_path = project.root.join("Cargo.toml");
+
+            if !manifest_path.exists() || packages.contains_key(&project.root) {
+                continue;
+            }
+
+            let metadata = load_cargo_metadata(&manifest_path).await?;
+            let root = metadata.find_workspace_root(&project.root);
+            let relocate = |path: &Path| match path.strip_prefix(&metadata.workspace_root) {
+                Ok(rel) => root.join(rel),
+                Err(_) => path.to_path_buf(),
+            };
+
+            // Metadata includes all packages within the Cargo workspace
+            for mut package in metadata.packages {
+                let Some(dir) = package.manifest_path.parent().map(relocate) else {
+                    continue;
+                };
+
+                for dep in &mut package.dependencies {
+                    dep.path = dep.path.as_deref().map(relocate);
+                }
+
+                packages.insert(dir, package);
+            }
+        }
+
+        if packages.is_empty() {
+            return Ok(());
+        }
+
+        let project_ids = projects
+            .iter()
+            .map(|project| (project.root.clone(), project.id.clone()))
+            .collect::<FxHashMap<_, _>>();
+
+        for (root, package) in &packages {
+            let Some(project_id) = project_ids.get(root) else {
+                continue;
+            };
+
+            if let Some(name) = package.get_lib_name() {
+                self.rust_names
+                    .insert(normalize_module_name(name), project_id.clone());
+            }
+
+            let mut deps = FxHashMap::default();
+
+            for dep in &package.dependencies {
+                let Some(dep_root) = &dep.path else {
+                    continue;
+                };
+
+                let Some(dep_id) = project_ids.get(dep_root) else {
+                    continue;
+                };
+
+                let name = match &dep.rename {
+                    Some(name) => Some(name.as_str()),
+                    None => packages.get(dep_root).and_then(|dep| dep.get_lib_name()),
+                };
+
+                if let Some(name) = name {
+                    deps.insert(normalize_module_name(name), dep_id.clone());
+                }
+            }
+
+            self.rust_deps.insert(project_id.clone(), deps);
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    pub fn verify(&self, project: &Project) -> miette::Result<ProjectImportsReport> {
+        debug!(
+            project_id = project.id.as_str(),
+            "Verifying source imports against declared dependencies"
+        );
+
+        let mut report = ProjectImportsReport {
+            project: project.id.clone(),
+            ..Default::default()
+        };
+        let mut imported = BTreeSet::new();
+
+        for (file, deps) in self.scan_project(project)? {
+            for dep_id in deps {
+                if dep_id == project.id {
+                    continue;
+                }
+
+                if !project.dependencies.iter().any(|dep| dep.id == dep_id) {
+                    report
+                        .undeclared
+                        .entry(dep_id.clone())
+                        .or_default()
+                        .insert(file.clone());
+                }
+
+                imported.insert(dep_id);
+            }
+        }
+
+        for dep in &project.dependencies {
+            if matches!(dep.source, DependencySource::Explicit)
+                && !matches!(dep.scope, DependencyScope::Build | DependencyScope::Root)
+                && !imported.contains(&dep.id)
+            {
+                report.unused.insert(dep.id.clone());
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Update the `dependsOn` setting in the provided project config file,
+    /// by adding undeclared dependencies and removing unused dependencies.
+    /// If the file does not exist, it will be created.
+    #[instrument(skip(self, report))]
+    pub fn fix(&self, config_path: &Path, report: &ProjectImportsReport) -> miette::Result<()> {
+        if report.is_valid() {
+            return Ok(());
+        }
+
+        debug!(
+            project_id = report.project.as_str(),
+            config = ?config_path,
+            "Updating dependsOn to match source imports"
+        );
+
+        let content = if config_path.exists() {
+            fs::read_file(config_path)?
+        } else {
+            String::new()
+        };
+
+        let content = update_depends_on(
+            &content,
+            |name| self.is_unused(report, name),
+            &report
+                .undeclared
+                .keys()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>(),
+        )?;
+
+        fs::write_file(config_path, content)?;
+
+        Ok(())
+    }
+
+    fn is_unused(&self, report: &ProjectImportsReport, name: &str) -> bool {
+        report.unused.iter().any(|id| {
+            id.as_str() == name || self.aliases.get(id).is_some_and(|alias| alias == name)
+        })
+    }
+
+    /// Scan all source files within the project, excluding nested projects,
+    /// and return the workspace projects imported by each file.
+    fn scan_project(&self, project: &Project) -> miette::Result<BTreeMap<String, BTreeSet<Id>>> {
+        let nested_roots = self
+            .roots
+            .iter()
+            .filter(|(id, root)| id != &project.id && root.starts_with(&project.root))
+            .map(|(_, root)| root)
+            .collect::<Vec<_>>();
+
+        let mut patterns = vec![];
+        patterns.extend(JS_GLOBS);
+        patterns.extend(["**/*.rs", "**/*.py"]);
+        patterns.extend(IGNORE_GLOBS);
+
+        let mut results = BTreeMap::new();
+
+        for file in glob::walk_files(&project.root, patterns)? {
+            if nested_roots.iter().any(|root| file.starts_with(root)) {
+                continue;
+            }
+
+            let Some(deps) = self.scan_file(project, &file)? else {
+                continue;
+            };
+
+            if !deps.is_empty() {
+                results.insert(get_file_key(&project.root, &file), deps);
+            }
+        }
+
+        Ok(results)
+    }
+
+    fn scan_file(&self, project: &Project, file: &Path) -> miette::Result<Option<BTreeSet<Id>>> {
+        let Some(ext) = file.extension().and_then(|ext| ext.to_str()) else {
+            return Ok(None);
+        };
+
+        let (imports, names, local_names) = match ext {
+            "rs" => (
+                extract_rust_imports(&fs::read_file(file)?),
+                &self.rust_names,
+                self.rust_deps.get(&project.id),
+            ),
+            "py" => (
+                extract_python_imports(&fs::read_file(file)?)
+                    .into_iter()
+                    .map(|name| normalize_module_name(&name))
+                    .collect(),
+                &self.python_names,
+                None,
+            ),
+            _ => (
+                extract_js_imports(&fs::read_file(file)?),
+                &self.js_names,
+                None,
+            ),
+        };
+
+        trace!(file = ?file, imports = ?imports, "Extracted imports from file");
+
+        Ok(Some(
+            imports
+                .into_iter()
+                .filter_map(|name| {
+                    local_names
+                        .and_then(|local| local.get(&name))
+                        .or_else(|| names.get(&name))
+                        .cloned()
+                })
+                .collect(),
+        ))
+    }
+}
+
+fn get_file_key(root: &Path, file: &Path) -> String {
+    file.strip_prefix(root)
+        .ok()
+        .and_then(|rel| to_virtual_string(rel).ok())
+        .unwrap_or_else(|| file.to_string_lossy().to_string())
+}
+
+async fn load_cargo_metadata(manifest_path: &Path) -> miette::Result<CargoMetadata> {
+    let output = match Command::new("cargo")
+        .args([
+            "metadata",
+            "--format-version",
+            "1",
+            "--no-deps",
+            "--manifest-path",
+        ])
+        .arg(manifest_path)
+        .without_shell()
+        .set_error_on_nonzero(false)
+        .exec_capture_output()
+        .await
+    {
+        Ok(output) => output,
+        Err(error) => {
+            warn!(
+                "Unable to run {}, falling back to project IDs for crate names: {error}",
+                color::shell("cargo metadata"),
+            );
+
+            return Ok(CargoMetadata::default());
+        }
+    };
+
+    if !output.status.success() {
+        warn!(
+            manifest = ?manifest_path,
+            "Unable to load Cargo metadata, falling back to project IDs for crate names: {}",
+            output_to_trimmed_string(&output.stderr),
+        );
+
+        return Ok(CargoMetadata::default());
+    }
+
+    serde_json::from_slice(&output.stdout).into_diagnostic()
+}
+
+// Crate and module names use underscores, while package names may use dashes
+fn normalize_module_name(name: &str) -> String {
+    name.to_lowercase().replace(['-', '.'], "_")
+}
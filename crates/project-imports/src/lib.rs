@@ -0,0 +1,6 @@
+mod depends_on_editor;
+mod import_scanner;
+mod imports_verifier;
+
+pub use import_scanner::*;
+pub use imports_verifier::*;
@@ -0,0 +1,142 @@
+use moon_project_imports::*;
+use std::collections::BTreeSet;
+
+fn set(items: &[&str]) -> BTreeSet<String> {
+    items.iter().map(|item| item.to_string()).collect()
+}
+
+mod js {
+    use super::*;
+
+    #[test]
+    fn gets_package_names() {
+        assert_eq!(get_js_package_name("react"), Some("react"));
+        assert_eq!(get_js_package_name("lodash/get"), Some("lodash"));
+        assert_eq!(get_js_package_name("@scope/pkg"), Some("@scope/pkg"));
+        assert_eq!(
+            get_js_package_name("@scope/pkg/sub/path"),
+            Some("@scope/pkg")
+        );
+    }
+
+    #[test]
+    fn ignores_non_package_specifiers() {
+        assert_eq!(get_js_package_name("./file"), None);
+        assert_eq!(get_js_package_name("../file"), None);
+        assert_eq!(get_js_package_name("/abs/file"), None);
+        assert_eq!(get_js_package_name("node:fs"), None);
+        assert_eq!(get_js_package_name("@scope"), None);
+    }
+
+    #[test]
+    fn extracts_imports() {
+        let content = r#"
+import a from 'pkg-a';
+import { b, c } from "pkg-b/sub";
+import * as d from '@scope/pkg-c';
+import type { E } from '@scope/pkg-d/types';
+import {
+  f,
+  g,
+} from 'pkg-e';
+import 'pkg-f/styles.css';
+export { h } from 'pkg-g';
+export * from './local';
+"#;
+
+        assert_eq!(
+            extract_js_imports(content),
+            set(&[
+                "@scope/pkg-c",
+                "@scope/pkg-d",
+                "pkg-a",
+                "pkg-b",
+                "pkg-e",
+                "pkg-f",
+                "pkg-g"
+            ])
+        );
+    }
+
+    #[test]
+    fn extracts_require_and_dynamic_imports() {
+        let content = r#"
+const a = require('pkg-a');
+const b = await import("pkg-b");
+const c = lazy(() => import('@scope/pkg-c'));
+const d = obj.require('pkg-d');
+"#;
+
+        assert_eq!(
+            extract_js_imports(content),
+            set(&["@scope/pkg-c", "pkg-a", "pkg-b"])
+        );
+    }
+}
+
+mod rust {
+    use super::*;
+
+    #[test]
+    fn extracts_imports() {
+        let content = r#"
+use foo::bar;
+use ::baz::{self, qux};
+pub use pub_crate::Thing;
+pub(crate) use scoped_crate::*;
+extern crate legacy_crate;
+use std::collections::HashMap;
+use core::fmt;
+use crate::local;
+use self::local;
+use super::local;
+"#;
+
+        assert_eq!(
+            extract_rust_imports(content),
+            set(&["baz", "foo", "legacy_crate", "pub_crate", "scoped_crate"])
+        );
+    }
+
+    #[test]
+    fn extracts_path_usages() {
+        let content = r#"
+#[tokio::main]
+async fn main() {
+    let value = serde_json::to_string(&::nested::Item::new()).unwrap();
+    let list = Vec::<String>::new();
+    Self::call(std::env::args(), crate::local::func());
+}
+
+#[derive(serde::Serialize)]
+struct Item;
+"#;
+
+        assert_eq!(
+            extract_rust_imports(content),
+            set(&["Vec", "nested", "serde", "serde_json", "tokio"])
+        );
+    }
+}
+
+mod python {
+    use super::*;
+
+    #[test]
+    fn extracts_imports() {
+        let content = r#"
+import os
+import foo.bar
+import baz as b, qux
+from pkg.sub import thing
+from .local import other
+from . import sibling
+    import nested # comment
+"#;
+
+        assert_eq!(
+            extract_python_imports(content),
+            set(&["baz", "foo", "nested", "os", "pkg", "qux"])
+        );
+    }
+}
@@ -0,0 +1,385 @@
+use moon_common::Id;
+use moon_config::{DependencyConfig, DependencyScope, DependencySource, LanguageType};
+use moon_project::Project;
+use moon_project_imports::*;
+use starbase_sandbox::{create_empty_sandbox, Sandbox};
+use starbase_utils::yaml;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+fn create_project(
+    sandbox: &Sandbox,
+    id: &str,
+    source: &str,
+    language: LanguageType,
+    deps: &[&str],
+) -> Project {
+    Project {
+        id: Id::raw(id),
+        dependencies: deps
+            .iter()
+            .map(|dep| DependencyConfig::new(Id::raw(dep)))
+            .collect(),
+        language,
+        root: sandbox.path().join(source),
+        source: source.into(),
+        ..Project::default()
+    }
+}
+
+async fn create_verifier(projects: &[Project]) -> ImportsVerifier {
+    ImportsVerifier::new(
+        &projects
+            .iter()
+            .map(|project| Arc::new(project.clone()))
+            .collect::<Vec<_>>(),
+    )
+    .await
+    .unwrap()
+}
+
+fn undeclared(items: &[(&str, &[&str])]) -> BTreeMap<Id, BTreeSet<String>> {
+    items
+        .iter()
+        .map(|(id, files)| {
+            (
+                Id::raw(id),
+                files.iter().map(|file| file.to_string()).collect(),
+            )
+        })
+        .collect()
+}
+
+mod javascript {
+    use super::*;
+
+    #[tokio::test]
+    async fn passes_when_imports_match() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(
+            "app/src/index.ts",
+            "import { a } from 'a';\nimport b from '@scope/b';",
+        );
+        sandbox.create_file("a/index.js", "");
+        sandbox.create_file("b/index.js", "");
+
+        let mut b = create_project(&sandbox, "b", "b", LanguageType::JavaScript, &[]);
+        b.alias = Some("@scope/b".into());
+
+        let app = create_project(
+            &sandbox,
+            "app",
+            "app",
+            LanguageType::TypeScript,
+            &["a", "b"],
+        );
+        let verifier = create_verifier(&[
+            app.clone(),
+            create_project(&sandbox, "a", "a", LanguageType::JavaScript, &[]),
+            b,
+        ])
+        .await;
+
+        assert!(verifier.verify(&app).unwrap().is_valid());
+    }
+
+    #[tokio::test]
+    async fn reports_undeclared_and_unused() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("app/src/index.ts", "import { a } from 'a';");
+        sandbox.create_file("app/src/other.tsx", "const a = require('a/sub');");
+        sandbox.create_file("a/index.js", "");
+        sandbox.create_file("b/index.js", "");
+
+        let app = create_project(&sandbox, "app", "app", LanguageType::TypeScript, &["b"]);
+        let verifier = create_verifier(&[
+            app.clone(),
+            create_project(&sandbox, "a", "a", LanguageType::JavaScript, &[]),
+            create_project(&sandbox, "b", "b", LanguageType::JavaScript, &[]),
+        ])
+        .await;
+
+        let report = verifier.verify(&app).unwrap();
+
+        assert_eq!(
+            report.undeclared,
+            undeclared(&[("a", &["src/index.ts", "src/other.tsx"])])
+        );
+        assert_eq!(report.unused, BTreeSet::from([Id::raw("b")]));
+    }
+
+    #[tokio::test]
+    async fn ignores_implicit_and_build_deps() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("app/index.ts", "");
+
+        let mut app = create_project(&sandbox, "app", "app", LanguageType::TypeScript, &[]);
+        app.dependencies = vec![
+            DependencyConfig {
+                id: Id::raw("a"),
+                source: DependencySource::Implicit,
+                ..Default::default()
+            },
+            DependencyConfig {
+                id: Id::raw("b"),
+                scope: DependencyScope::Build,
+                ..Default::default()
+            },
+        ];
+
+        let verifier = create_verifier(&[app.clone()]).await;
+
+        assert!(verifier.verify(&app).unwrap().is_valid());
+    }
+
+    #[tokio::test]
+    async fn ignores_node_modules_and_nested_projects() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("app/node_modules/x/index.js", "import a from 'a';");
+        sandbox.create_file("app/nested/index.js", "import a from 'a';");
+
+        let app = create_project(&sandbox, "app", "app", LanguageType::TypeScript, &[]);
+        let verifier = create_verifier(&[
+            app.clone(),
+            create_project(
+                &sandbox,
+                "nested",
+                "app/nested",
+                LanguageType::JavaScript,
+                &[],
+            ),
+            create_project(&sandbox, "a", "a", LanguageType::JavaScript, &[]),
+        ])
+        .await;
+
+        assert!(verifier.verify(&app).unwrap().is_valid());
+    }
+
+    #[tokio::test]
+    async fn ignores_projects_of_other_languages() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("app/index.js", "import a from 'a';");
+
+        let app = create_project(&sandbox, "app", "app", LanguageType::JavaScript, &[]);
+        let verifier = create_verifier(&[
+            app.clone(),
+            create_project(&sandbox, "a", "a", LanguageType::Rust, &[]),
+        ])
+        .await;
+
+        assert!(verifier.verify(&app).unwrap().is_valid());
+    }
+}
+
+mod rust {
+    use super::*;
+
+    #[tokio::test]
+    async fn maps_crate_names_from_manifest() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(
+            "Cargo.toml",
+            "[workspace]\nmembers = [\"crates/*\"]\n\n[workspace.dependencies]\nlib = { path = \"crates/lib\" }",
+        );
+        sandbox.create_file(
+            "crates/app/Cargo.toml",
+            "[package]\nname = \"app\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n[dependencies]\nshared = { package = \"moon-common\", path = \"../common\" }\nlib = { workspace = true }",
+        );
+        sandbox.create_file(
+            "crates/app/src/main.rs",
+            "use shared::Id;\nextern crate renamed_lib;\nfn main() {}",
+        );
+        sandbox.create_file(
+            "crates/common/Cargo.toml",
+            "[package]\nname = \"moon-common\"\nversion = \"0.0.0\"\nedition = \"2021\"",
+        );
+        sandbox.create_file("crates/common/src/lib.rs", "");
+        sandbox.create_file(
+            "crates/lib/Cargo.toml",
+            "[package]\nname = \"lib\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n[lib]\nname = \"renamed_lib\"",
+        );
+        sandbox.create_file("crates/lib/src/lib.rs", "");
+
+        let app = create_project(&sandbox, "app", "crates/app", LanguageType::Rust, &[]);
+        let verifier = create_verifier(&[
+            app.clone(),
+            create_project(&sandbox, "common", "crates/common", LanguageType::Rust, &[]),
+            create_project(&sandbox, "lib", "crates/lib", LanguageType::Rust, &[]),
+        ])
+        .await;
+
+        let report = verifier.verify(&app).unwrap();
+
+        assert_eq!(
+            report.undeclared,
+            undeclared(&[("common", &["src/main.rs"]), ("lib", &["src/main.rs"])])
+        );
+    }
+
+    #[tokio::test]
+    async fn counts_path_usages() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(
+            "app/src/main.rs",
+            "#[derive(macros::Builder)]\nstruct App;\n\nfn main() {\n    shared::run();\n}",
+        );
+        sandbox.create_file("macros/src/lib.rs", "");
+        sandbox.create_file("shared/src/lib.rs", "");
+
+        let app = create_project(
+            &sandbox,
+            "app",
+            "app",
+            LanguageType::Rust,
+            &["macros", "shared"],
+        );
+        let verifier = create_verifier(&[
+            app.clone(),
+            create_project(&sandbox, "macros", "macros", LanguageType::Rust, &[]),
+            create_project(&sandbox, "shared", "shared", LanguageType::Rust, &[]),
+        ])
+        .await;
+
+        assert!(verifier.verify(&app).unwrap().is_valid());
+    }
+}
+
+mod python {
+    use super::*;
+
+    #[tokio::test]
+    async fn maps_module_names() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(
+            "app/main.py",
+            "import shared_utils\nfrom other.sub import x",
+        );
+
+        let app = create_project(&sandbox, "app", "app", LanguageType::Python, &["other"]);
+        let verifier = create_verifier(&[
+            app.clone(),
+            create_project(
+                &sandbox,
+                "shared-utils",
+                "shared",
+                LanguageType::Python,
+                &[],
+            ),
+            create_project(&sandbox, "other", "other", LanguageType::Python, &[]),
+        ])
+        .await;
+
+        let report = verifier.verify(&app).unwrap();
+
+        assert_eq!(
+            report.undeclared,
+            undeclared(&[("shared-utils", &["main.py"])])
+        );
+        assert!(report.unused.is_empty());
+    }
+}
+
+mod fix {
+    use super::*;
+
+    fn create_report() -> ProjectImportsReport {
+        ProjectImportsReport {
+            project: Id::raw("app"),
+            undeclared: undeclared(&[("a", &["index.ts"])]),
+            unused: BTreeSet::from([Id::raw("b"), Id::raw("c")]),
+        }
+    }
+
+    #[tokio::test]
+    async fn updates_depends_on() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(
+            "app/moon.yml",
+            "language: typescript\ndependsOn:\n  - b\n  - id: c\n    scope: development\n  - d\n",
+        );
+
+        let mut c = create_project(&sandbox, "c", "c", LanguageType::TypeScript, &[]);
+        c.alias = Some("@scope/c".into());
+
+        let verifier = create_verifier(&[c]).await;
+        let config_path = sandbox.path().join("app/moon.yml");
+
+        verifier.fix(&config_path, &create_report()).unwrap();
+
+        assert_eq!(
+            yaml::read_file::<_, yaml::YamlValue>(config_path).unwrap(),
+            yaml::parse::<_, yaml::YamlValue>("language: typescript\ndependsOn:\n- d\n- a\n")
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn preserves_comments_and_formatting() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(
+            "app/moon.yml",
+            "# Project settings\nlanguage: 'typescript'\n\ndependsOn:\n  # Shared code\n  - 'b'\n  - 'd' # Required\n\ntags: ['app']\n",
+        );
+
+        let verifier = create_verifier(&[]).await;
+        let config_path = sandbox.path().join("app/moon.yml");
+
+        verifier.fix(&config_path, &create_report()).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(config_path).unwrap(),
+            "# Project settings\nlanguage: 'typescript'\n\ndependsOn:\n  # Shared code\n  - 'd' # Required\n  - 'a'\n\ntags: ['app']\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn converts_flow_sequences() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("app/moon.yml", "dependsOn: ['b', 'd']\ntags: []\n");
+
+        let verifier = create_verifier(&[]).await;
+        let config_path = sandbox.path().join("app/moon.yml");
+
+        verifier.fix(&config_path, &create_report()).unwrap();
+
+        assert_eq!(
+            yaml::read_file::<_, yaml::YamlValue>(config_path).unwrap(),
+            yaml::parse::<_, yaml::YamlValue>("dependsOn:\n- d\n- a\ntags: []\n").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn matches_aliases() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("app/moon.yml", "dependsOn:\n  - '@scope/c'\n");
+
+        let mut c = create_project(&sandbox, "c", "c", LanguageType::TypeScript, &[]);
+        c.alias = Some("@scope/c".into());
+
+        let verifier = create_verifier(&[c]).await;
+        let config_path = sandbox.path().join("app/moon.yml");
+
+        verifier.fix(&config_path, &create_report()).unwrap();
+
+        assert_eq!(
+            yaml::read_file::<_, yaml::YamlValue>(config_path).unwrap(),
+            yaml::parse::<_, yaml::YamlValue>("dependsOn:\n- a\n").unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn creates_config_if_missing() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("app/index.ts", "");
+
+        let verifier = create_verifier(&[]).await;
+        let config_path = sandbox.path().join("app/moon.yml");
+
+        verifier.fix(&config_path, &create_report()).unwrap();
+
+        assert_eq!(
+            yaml::read_file::<_, yaml::YamlValue>(config_path).unwrap(),
+            yaml::parse::<_, yaml::YamlValue>("dependsOn:\n- a\n").unwrap()
+        );
+    }
+}
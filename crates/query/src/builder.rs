@@ -17,6 +17,7 @@ pub enum Field<'l> {
     Project(FieldValues<'l>),
     ProjectAlias(FieldValues<'l>),
     ProjectName(FieldValues<'l>),
+    ProjectOwner(FieldValues<'l>),
     ProjectSource(FieldValues<'l>),
     ProjectStack(Vec<StackType>),
     ProjectType(Vec<ProjectType>),
@@ -123,6 +124,7 @@ fn build_criteria(ast: Vec<AstNode<'_>>) -> miette::Result<Criteria<'_>> {
                     "project" => Field::Project(value),
                     "projectAlias" => Field::ProjectAlias(value),
                     "projectName" => Field::ProjectName(value),
+                    "projectOwner" => Field::ProjectOwner(value),
                     "projectSource" => Field::ProjectSource(value),
                     "projectStack" => {
                         Field::ProjectStack(build_criteria_enum::<StackType>(&field, &op, value)?)
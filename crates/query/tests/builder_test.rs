@@ -387,6 +387,40 @@ mod mql_build {
         }
     }
 
+    mod project_owner {
+        use super::*;
+
+        #[test]
+        fn owner_eq() {
+            assert_eq!(
+                build_query("projectOwner=@org/team").unwrap(),
+                Criteria {
+                    op: LogicalOperator::And,
+                    conditions: vec![Condition::Field {
+                        field: Field::ProjectOwner(value_list(["@org/team"])),
+                        op: ComparisonOperator::Equal,
+                    }],
+                    input: Some("projectOwner=@org/team".into())
+                }
+            );
+        }
+
+        #[test]
+        fn owner_like() {
+            assert_eq!(
+                build_query("projectOwner~@org/*").unwrap(),
+                Criteria {
+                    op: LogicalOperator::And,
+                    conditions: vec![Condition::Field {
+                        field: Field::ProjectOwner(value_list(["@org/*"])),
+                        op: ComparisonOperator::Like,
+                    }],
+                    input: Some("projectOwner~@org/*".into())
+                }
+            );
+        }
+    }
+
     mod project_source {
         use super::*;
 
@@ -0,0 +1,256 @@
+use moon_common::Id;
+use moon_target::*;
+
+mod target_locator {
+    use super::*;
+
+    mod glob {
+        use super::*;
+
+        #[test]
+        fn all_scope() {
+            assert_eq!(
+                TargetLocator::parse(":build-*").unwrap(),
+                TargetLocator::GlobMatch {
+                    original: String::from(":build-*"),
+                    scope: Some(TargetScope::All),
+                    project_glob: None,
+                    task_glob: String::from("build-*"),
+                }
+            );
+
+            assert_eq!(
+                TargetLocator::parse("*:build").unwrap(),
+                TargetLocator::GlobMatch {
+                    original: String::from("*:build"),
+                    scope: Some(TargetScope::All),
+                    project_glob: None,
+                    task_glob: String::from("build"),
+                }
+            );
+        }
+
+        #[test]
+        fn deps_scope() {
+            assert_eq!(
+                TargetLocator::parse("^:build-*").unwrap(),
+                TargetLocator::GlobMatch {
+                    original: String::from("^:build-*"),
+                    scope: Some(TargetScope::Deps),
+                    project_glob: None,
+                    task_glob: String::from("build-*"),
+                }
+            );
+        }
+
+        #[test]
+        fn self_scope() {
+            assert_eq!(
+                TargetLocator::parse("~:build-*").unwrap(),
+                TargetLocator::GlobMatch {
+                    original: String::from("~:build-*"),
+                    scope: Some(TargetScope::OwnSelf),
+                    project_glob: None,
+                    task_glob: String::from("build-*"),
+                }
+            );
+        }
+
+        #[test]
+        fn tag_scope() {
+            assert_eq!(
+                TargetLocator::parse("#tag:build-*").unwrap(),
+                TargetLocator::GlobMatch {
+                    original: String::from("#tag:build-*"),
+                    scope: None,
+                    project_glob: Some(String::from("#tag")),
+                    task_glob: String::from("build-*"),
+                }
+            );
+
+            assert_eq!(
+                TargetLocator::parse("#tag-*:build-*").unwrap(),
+                TargetLocator::GlobMatch {
+                    original: String::from("#tag-*:build-*"),
+                    scope: None,
+                    project_glob: Some(String::from("#tag-*")),
+                    task_glob: String::from("build-*"),
+                }
+            );
+        }
+
+        #[test]
+        fn project_scope() {
+            assert_eq!(
+                TargetLocator::parse("project:build-*").unwrap(),
+                TargetLocator::GlobMatch {
+                    original: String::from("project:build-*"),
+                    scope: None,
+                    project_glob: Some(String::from("project")),
+                    task_glob: String::from("build-*"),
+                }
+            );
+
+            assert_eq!(
+                TargetLocator::parse("proj-*:build-*").unwrap(),
+                TargetLocator::GlobMatch {
+                    original: String::from("proj-*:build-*"),
+                    scope: None,
+                    project_glob: Some(String::from("proj-*")),
+                    task_glob: String::from("build-*"),
+                }
+            );
+        }
+    }
+
+    mod target {
+        use super::*;
+
+        #[test]
+        #[should_panic(expected = "Invalid target $:build")]
+        fn errors_invalid() {
+            TargetLocator::parse("$:build").unwrap();
+        }
+
+        #[test]
+        fn all_scope() {
+            assert_eq!(
+                TargetLocator::parse(":build").unwrap(),
+                TargetLocator::Qualified(Target::parse(":build").unwrap())
+            );
+        }
+
+        #[test]
+        fn deps_scope() {
+            assert_eq!(
+                TargetLocator::parse("^:build").unwrap(),
+                TargetLocator::Qualified(Target::parse("^:build").unwrap())
+            );
+        }
+
+        #[test]
+        fn self_scope() {
+            assert_eq!(
+                TargetLocator::parse("~:build").unwrap(),
+                TargetLocator::Qualified(Target::parse("~:build").unwrap())
+            );
+        }
+
+        #[test]
+        fn dependents_scope() {
+            assert_eq!(
+                TargetLocator::parse("+project:build").unwrap(),
+                TargetLocator::Qualified(Target::parse("+project:build").unwrap())
+            );
+        }
+
+        #[test]
+        fn tag_scope() {
+            assert_eq!(
+                TargetLocator::parse("#tag:build").unwrap(),
+                TargetLocator::Qualified(Target::parse("#tag:build").unwrap())
+            );
+        }
+
+        #[test]
+        fn project_scope() {
+            assert_eq!(
+                TargetLocator::parse("project:build").unwrap(),
+                TargetLocator::Qualified(Target::parse("project:build").unwrap())
+            );
+        }
+    }
+
+    mod exclude {
+        use super::*;
+
+        #[test]
+        #[should_panic(expected = "Invalid target exclusion !")]
+        fn errors_empty() {
+            TargetLocator::parse("!").unwrap();
+        }
+
+        #[test]
+        #[should_panic(expected = "Invalid target exclusion !!project:build")]
+        fn errors_nested() {
+            TargetLocator::parse("!!project:build").unwrap();
+        }
+
+        #[test]
+        fn target() {
+            assert_eq!(
+                TargetLocator::parse("!project:build").unwrap(),
+                TargetLocator::Exclude {
+                    original: String::from("!project:build"),
+                    locator: Box::new(TargetLocator::Qualified(
+                        Target::parse("project:build").unwrap()
+                    )),
+                }
+            );
+        }
+
+        #[test]
+        fn glob() {
+            assert_eq!(
+                TargetLocator::parse("!legacy-*:test").unwrap(),
+                TargetLocator::Exclude {
+                    original: String::from("!legacy-*:test"),
+                    locator: Box::new(TargetLocator::GlobMatch {
+                        original: String::from("legacy-*:test"),
+                        scope: None,
+                        project_glob: Some(String::from("legacy-*")),
+                        task_glob: String::from("test"),
+                    }),
+                }
+            );
+        }
+
+        #[test]
+        fn project_without_task() {
+            assert_eq!(
+                TargetLocator::parse("!legacy").unwrap(),
+                TargetLocator::Exclude {
+                    original: String::from("!legacy"),
+                    locator: Box::new(TargetLocator::GlobMatch {
+                        original: String::from("legacy:*"),
+                        scope: None,
+                        project_glob: Some(String::from("legacy")),
+                        task_glob: String::from("*"),
+                    }),
+                }
+            );
+        }
+
+        #[test]
+        fn tag_without_task() {
+            let locator = TargetLocator::parse("!#deprecated").unwrap();
+
+            assert!(locator.is_exclusion());
+            assert_eq!(locator.as_str(), "!#deprecated");
+            assert_eq!(
+                locator,
+                TargetLocator::Exclude {
+                    original: String::from("!#deprecated"),
+                    locator: Box::new(TargetLocator::GlobMatch {
+                        original: String::from("#deprecated:*"),
+                        scope: None,
+                        project_glob: Some(String::from("#deprecated")),
+                        task_glob: String::from("*"),
+                    }),
+                }
+            );
+        }
+    }
+
+    mod cwd {
+        use super::*;
+
+        #[test]
+        fn returns_task() {
+            assert_eq!(
+                TargetLocator::parse("build").unwrap(),
+                TargetLocator::TaskFromWorkingDir(Id::raw("build"))
+            );
+        }
+    }
+}
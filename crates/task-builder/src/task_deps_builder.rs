@@ -1,16 +1,23 @@
 use crate::tasks_builder_error::TasksBuilderError;
 use moon_common::Id;
 use moon_config::{DependencyConfig, TaskDependencyConfig};
-use moon_task::{Target, TargetScope, Task, TaskOptions};
+use moon_task::{Target, TargetError, TargetLocator, TargetScope, Task, TaskOptions};
 use std::mem;
 
 pub trait TasksQuerent {
+    fn query_dependent_projects(&self, project_id: &Id) -> miette::Result<Vec<&Id>>;
+    fn query_projects_by_glob(&self, glob: &str) -> miette::Result<Vec<&Id>>;
     fn query_projects_by_tag(&self, tag: &str) -> miette::Result<Vec<&Id>>;
     fn query_tasks(
         &self,
         project_ids: Vec<&Id>,
         task_id: &Id,
     ) -> miette::Result<Vec<(&Target, &TaskOptions)>>;
+    fn query_tasks_by_glob(
+        &self,
+        project_ids: Vec<&Id>,
+        task_glob: &str,
+    ) -> miette::Result<Vec<(&Target, &TaskOptions)>>;
 }
 
 pub struct TaskDepsBuilder<'proj> {
@@ -23,8 +30,32 @@ pub struct TaskDepsBuilder<'proj> {
 impl TaskDepsBuilder<'_> {
     pub fn build(self) -> miette::Result<()> {
         let mut deps = vec![];
+        let mut dep_configs = mem::take(&mut self.task.deps);
+        let mut glob_locators = vec![];
+        let mut excluded_locators = vec![];
+
+        for locator in mem::take(&mut self.task.deps_locators) {
+            match locator {
+                // !scope:task
+                TargetLocator::Exclude { locator, .. } => {
+                    excluded_locators.push(*locator);
+                }
+                // scope-*:task-*
+                TargetLocator::GlobMatch { .. } => {
+                    glob_locators.push(locator);
+                }
+                // scope:task
+                TargetLocator::Qualified(target) => {
+                    dep_configs.push(TaskDependencyConfig::new(target));
+                }
+                // task
+                TargetLocator::TaskFromWorkingDir(task_id) => {
+                    dep_configs.push(TaskDependencyConfig::new(Target::new_self(task_id)?));
+                }
+            };
+        }
 
-        for dep_config in mem::take(&mut self.task.deps) {
+        for dep_config in dep_configs {
             let (project_ids, skip_if_missing) = match &dep_config.target.scope {
                 // :task
                 TargetScope::All => {
@@ -34,6 +65,15 @@ impl TaskDepsBuilder<'_> {
                     }
                     .into());
                 }
+                // +project:task
+                TargetScope::Dependents(project_id) => (
+                    self.querent
+                        .query_dependent_projects(project_id)?
+                        .into_iter()
+                        .filter(|id| *id != self.project_id)
+                        .collect(),
+                    dep_config.optional.unwrap_or(true),
+                ),
                 // ^:task
                 TargetScope::Deps => (
                     self.project_dependencies
@@ -86,12 +126,7 @@ impl TaskDepsBuilder<'_> {
             }
 
             for (dep_task_target, dep_task_options) in results {
-                // Avoid circular references
-                if dep_task_target
-                    .get_project_id()
-                    .is_some_and(|id| id == self.project_id)
-                    && dep_task_target.task_id == self.task.target.task_id
-                {
+                if self.is_circular(dep_task_target) {
                     continue;
                 }
 
@@ -105,11 +140,119 @@ impl TaskDepsBuilder<'_> {
             }
         }
 
+        // Globs may not match anything, so treat them as optional
+        for locator in &glob_locators {
+            for (dep_task_target, dep_task_options) in self.query_locator(locator)? {
+                if self.is_circular(dep_task_target) {
+                    continue;
+                }
+
+                self.check_and_push_dep(
+                    dep_task_target,
+                    dep_task_options,
+                    &TaskDependencyConfig::new(dep_task_target.to_owned()),
+                    &mut deps,
+                    true,
+                )?;
+            }
+        }
+
+        // And lastly, remove dependencies that have been excluded
+        if !excluded_locators.is_empty() {
+            let mut excluded_targets = vec![];
+
+            for locator in &excluded_locators {
+                excluded_targets.extend(
+                    self.query_locator(locator)?
+                        .into_iter()
+                        .map(|(target, _)| target.to_owned()),
+                );
+            }
+
+            deps.retain(|dep| !excluded_targets.contains(&dep.target));
+        }
+
         self.task.deps = deps;
 
         Ok(())
     }
 
+    fn is_circular(&self, dep_task_target: &Target) -> bool {
+        dep_task_target
+            .get_project_id()
+            .is_some_and(|id| id == self.project_id)
+            && dep_task_target.task_id == self.task.target.task_id
+    }
+
+    fn query_locator(
+        &self,
+        locator: &TargetLocator,
+    ) -> miette::Result<Vec<(&Target, &TaskOptions)>> {
+        let (project_ids, task_glob) = match locator {
+            TargetLocator::GlobMatch {
+                scope,
+                project_glob,
+                task_glob,
+                ..
+            } => {
+                let project_ids = match (project_glob, scope) {
+                    // proj-*:task, #tag-*:task
+                    (Some(glob), _) => self.querent.query_projects_by_glob(glob)?,
+                    // ^:task-*
+                    (None, Some(TargetScope::Deps)) => self
+                        .project_dependencies
+                        .iter()
+                        .map(|dep| &dep.id)
+                        .collect(),
+                    // ~:task-*
+                    (None, Some(TargetScope::OwnSelf)) => vec![self.project_id],
+                    // :task-*
+                    _ => {
+                        return Err(TasksBuilderError::UnsupportedLocatorScopeInDeps {
+                            dep: locator.as_str().to_owned(),
+                            task: self.task.target.to_owned(),
+                        }
+                        .into());
+                    }
+                };
+
+                (project_ids, task_glob.as_str())
+            }
+            TargetLocator::Qualified(target) => {
+                let project_ids = match &target.scope {
+                    TargetScope::All => {
+                        return Err(TasksBuilderError::UnsupportedTargetScopeInDeps {
+                            dep: target.to_owned(),
+                            task: self.task.target.to_owned(),
+                        }
+                        .into());
+                    }
+                    TargetScope::Dependents(project_id) => {
+                        self.querent.query_dependent_projects(project_id)?
+                    }
+                    TargetScope::Deps => self
+                        .project_dependencies
+                        .iter()
+                        .map(|dep| &dep.id)
+                        .collect(),
+                    TargetScope::OwnSelf => vec![self.project_id],
+                    TargetScope::Project(project_id) => vec![project_id],
+                    TargetScope::Tag(tag) => self.querent.query_projects_by_tag(tag)?,
+                };
+
+                return self.querent.query_tasks(project_ids, &target.task_id);
+            }
+            TargetLocator::TaskFromWorkingDir(task_id) => {
+                return self.querent.query_tasks(vec![self.project_id], task_id);
+            }
+            TargetLocator::Exclude { original, .. } => {
+                return Err(TargetError::InvalidExclusion(format!("!{original}")).into());
+            }
+        };
+
+        self.querent.query_tasks_by_glob(project_ids, task_glob)
+    }
+
     fn check_and_push_dep(
         &self,
         dep_task_target: &Target,
@@ -331,10 +331,21 @@ impl<'proj> TasksBuilder<'proj> {
             }
 
             if let Some(deps) = &config.deps {
+                task.deps_locators = self.merge_vec(
+                    task.deps_locators,
+                    deps.iter()
+                        .cloned()
+                        .filter_map(|dep| dep.into_locator())
+                        .collect(),
+                    task.options.merge_deps,
+                    index,
+                    true,
+                );
+
                 let deps = deps
                     .iter()
                     .cloned()
-                    .map(|dep| dep.into_config())
+                    .filter_map(|dep| dep.into_config())
                     .collect::<Vec<_>>();
 
                 task.deps = self.merge_vec(
@@ -467,6 +478,22 @@ impl<'proj> TasksBuilder<'proj> {
             );
         }
 
+        let global_deps_locators = self
+            .implicit_deps
+            .iter()
+            .filter_map(|dep| (*dep).to_owned().into_locator())
+            .collect::<Vec<_>>();
+
+        if !global_deps_locators.is_empty() {
+            task.deps_locators = self.merge_vec(
+                task.deps_locators,
+                global_deps_locators,
+                TaskMergeStrategy::Append,
+                1000,
+                true,
+            );
+        }
+
         if !global_inputs.is_empty() {
             task.inputs = self.merge_vec(
                 task.inputs,
@@ -697,7 +724,7 @@ impl<'proj> TasksBuilder<'proj> {
         let global_deps = self
             .implicit_deps
             .iter()
-            .map(|dep| (*dep).to_owned().into_config())
+            .filter_map(|dep| (*dep).to_owned().into_config())
             .collect::<Vec<_>>();
 
         if !global_deps.is_empty() {
@@ -77,4 +77,12 @@ pub enum TasksBuilderError {
         .task.style(Style::Label),
     )]
     UnsupportedTargetScopeInDeps { dep: Target, task: Target },
+
+    #[diagnostic(code(task_builder::unsupported_target_scope))]
+    #[error(
+        "Invalid dependency {} for {}. All (:) scope is not supported.",
+        .dep.style(Style::Label),
+        .task.style(Style::Label),
+    )]
+    UnsupportedLocatorScopeInDeps { dep: String, task: Target },
 }
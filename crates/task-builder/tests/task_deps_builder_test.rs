@@ -1,16 +1,47 @@
 use moon_common::Id;
 use moon_config::*;
-use moon_task::{Target, Task, TaskOptions};
+use moon_task::{Target, TargetLocator, Task, TaskOptions};
 use moon_task_builder::{TaskDepsBuilder, TasksQuerent};
 use rustc_hash::FxHashMap;
 
 #[derive(Default)]
 struct TestQuerent {
     pub data: FxHashMap<Target, TaskOptions>,
+    pub dependent_ids: Vec<Id>,
     pub tag_ids: Vec<Id>,
 }
 
+// Only supports trailing wildcards, which is enough for testing
+fn matches_glob(glob: &str, value: &str) -> bool {
+    match glob.strip_suffix('*') {
+        Some(prefix) => value.starts_with(prefix),
+        None => glob == value,
+    }
+}
+
 impl TasksQuerent for TestQuerent {
+    fn query_dependent_projects(&self, _project_id: &Id) -> miette::Result<Vec<&Id>> {
+        Ok(self.dependent_ids.iter().collect())
+    }
+
+    fn query_projects_by_glob(&self, glob: &str) -> miette::Result<Vec<&Id>> {
+        if glob.starts_with('#') {
+            return Ok(self.tag_ids.iter().collect());
+        }
+
+        let mut ids = self
+            .data
+            .keys()
+            .filter_map(|target| target.get_project_id())
+            .filter(|id| matches_glob(glob, id.as_str()))
+            .collect::<Vec<_>>();
+
+        ids.sort();
+        ids.dedup();
+
+        Ok(ids)
+    }
+
     fn query_projects_by_tag(&self, _tag: &str) -> miette::Result<Vec<&Id>> {
         Ok(self.tag_ids.iter().collect())
     }
@@ -34,6 +65,32 @@ impl TasksQuerent for TestQuerent {
             })
             .collect::<Vec<_>>())
     }
+
+    fn query_tasks_by_glob(
+        &self,
+        project_ids: Vec<&Id>,
+        task_glob: &str,
+    ) -> miette::Result<Vec<(&Target, &TaskOptions)>> {
+        let mut results = self
+            .data
+            .iter()
+            .filter_map(|(target, options)| {
+                let project_id = target.get_project_id()?;
+
+                if matches_glob(task_glob, target.task_id.as_str())
+                    && project_ids.contains(&project_id)
+                {
+                    Some((target, options))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        results.sort_by(|a, b| a.0.cmp(b.0));
+
+        Ok(results)
+    }
 }
 
 fn create_task() -> Task {
@@ -56,6 +113,7 @@ fn build_task_deps_with_data(task: &mut Task, data: FxHashMap<Target, TaskOption
         querent: Box::new(TestQuerent {
             data,
             tag_ids: vec![],
+            ..Default::default()
         }),
         project_id: &project_id,
         project_dependencies: &project_dependencies,
@@ -184,6 +242,162 @@ mod task_deps_builder {
         }
     }
 
+    mod dependents_scope {
+        use super::*;
+
+        #[test]
+        fn returns_each_dependent_task() {
+            let mut task = create_task();
+            task.deps.push(TaskDependencyConfig::new(
+                Target::parse("+project:build").unwrap(),
+            ));
+
+            let project_id = Id::raw("project");
+            let project_dependencies = vec![];
+
+            TaskDepsBuilder {
+                querent: Box::new(TestQuerent {
+                    data: FxHashMap::from_iter([
+                        (Target::parse("foo:build").unwrap(), TaskOptions::default()),
+                        (Target::parse("bar:build").unwrap(), TaskOptions::default()),
+                        (Target::parse("baz:test").unwrap(), TaskOptions::default()),
+                    ]),
+                    dependent_ids: vec![Id::raw("foo"), Id::raw("baz")],
+                    ..Default::default()
+                }),
+                project_id: &project_id,
+                project_dependencies: &project_dependencies,
+                task: &mut task,
+            }
+            .build()
+            .unwrap();
+
+            assert_eq!(
+                task.deps,
+                vec![TaskDependencyConfig::new(
+                    Target::parse("foo:build").unwrap()
+                )]
+            );
+        }
+    }
+
+    mod locators {
+        use super::*;
+
+        fn build_with_locators(task: &mut Task, locators: &[&str]) {
+            task.deps_locators = locators
+                .iter()
+                .map(|locator| TargetLocator::parse(locator).unwrap())
+                .collect();
+
+            let project_id = Id::raw("project");
+            let project_dependencies = vec![DependencyConfig::new(Id::raw("app-a"))];
+
+            TaskDepsBuilder {
+                querent: Box::new(TestQuerent {
+                    data: FxHashMap::from_iter([
+                        (
+                            Target::parse("app-a:build").unwrap(),
+                            TaskOptions::default(),
+                        ),
+                        (
+                            Target::parse("app-b:build").unwrap(),
+                            TaskOptions::default(),
+                        ),
+                        (Target::parse("app-b:test").unwrap(), TaskOptions::default()),
+                        (Target::parse("lib:build").unwrap(), TaskOptions::default()),
+                        (
+                            Target::parse("project:build").unwrap(),
+                            TaskOptions::default(),
+                        ),
+                        (
+                            Target::parse("project:typecheck").unwrap(),
+                            TaskOptions::default(),
+                        ),
+                    ]),
+                    tag_ids: vec![Id::raw("app-b")],
+                    ..Default::default()
+                }),
+                project_id: &project_id,
+                project_dependencies: &project_dependencies,
+                task,
+            }
+            .build()
+            .unwrap();
+        }
+
+        #[test]
+        fn resolves_project_globs() {
+            let mut task = create_task();
+
+            build_with_locators(&mut task, &["app-*:build"]);
+
+            assert_eq!(
+                task.deps,
+                vec![
+                    TaskDependencyConfig::new(Target::parse("app-a:build").unwrap()),
+                    TaskDependencyConfig::new(Target::parse("app-b:build").unwrap()),
+                ]
+            );
+        }
+
+        #[test]
+        fn resolves_task_globs_in_self_and_deps_scopes() {
+            let mut task = create_task();
+
+            build_with_locators(&mut task, &["~:type*", "^:bui*"]);
+
+            assert_eq!(
+                task.deps,
+                vec![
+                    TaskDependencyConfig::new(Target::parse("project:typecheck").unwrap()),
+                    TaskDependencyConfig::new(Target::parse("app-a:build").unwrap()),
+                ]
+            );
+        }
+
+        #[test]
+        fn excludes_targets() {
+            let mut task = create_task();
+            task.deps.push(TaskDependencyConfig::new(
+                Target::parse("lib:build").unwrap(),
+            ));
+
+            build_with_locators(&mut task, &["app-*:build", "!app-a:build", "!lib"]);
+
+            assert_eq!(
+                task.deps,
+                vec![TaskDependencyConfig::new(
+                    Target::parse("app-b:build").unwrap()
+                )]
+            );
+        }
+
+        #[test]
+        fn excludes_targets_by_tag() {
+            let mut task = create_task();
+
+            build_with_locators(&mut task, &["app-*:build", "!#deprecated"]);
+
+            assert_eq!(
+                task.deps,
+                vec![TaskDependencyConfig::new(
+                    Target::parse("app-a:build").unwrap()
+                )]
+            );
+        }
+
+        #[test]
+        #[should_panic(
+            expected = "Invalid dependency :build-* for project:task. All (:) scope is not"
+        )]
+        fn errors_for_all_scope() {
+            let mut task = create_task();
+
+            build_with_locators(&mut task, &[":build-*"]);
+        }
+    }
+
     mod parent_deps_scope {
         use super::*;
 
@@ -220,6 +434,7 @@ mod task_deps_builder {
                         (Target::parse("baz:build").unwrap(), TaskOptions::default()),
                     ]),
                     tag_ids: vec![],
+                    ..Default::default()
                 }),
                 project_id: &project_id,
                 project_dependencies: &project_dependencies,
@@ -261,6 +476,7 @@ mod task_deps_builder {
                         (Target::parse("qux:build").unwrap(), TaskOptions::default()),
                     ]),
                     tag_ids: vec![],
+                    ..Default::default()
                 }),
                 project_id: &project_id,
                 project_dependencies: &project_dependencies,
@@ -297,6 +513,7 @@ mod task_deps_builder {
                 querent: Box::new(TestQuerent {
                     data: FxHashMap::default(),
                     tag_ids: vec![],
+                    ..Default::default()
                 }),
                 project_id: &project_id,
                 project_dependencies: &project_dependencies,
@@ -527,6 +744,7 @@ mod task_deps_builder {
                         (Target::parse("baz:build").unwrap(), TaskOptions::default()),
                     ]),
                     tag_ids: vec![Id::raw("foo"), Id::raw("baz")],
+                    ..Default::default()
                 }),
                 project_id: &project_id,
                 project_dependencies: &project_dependencies,
@@ -562,6 +780,7 @@ mod task_deps_builder {
                         (Target::parse("baz:test").unwrap(), TaskOptions::default()),
                     ]),
                     tag_ids: vec![Id::raw("foo"), Id::raw("baz")],
+                    ..Default::default()
                 }),
                 project_id: &project_id,
                 project_dependencies: &project_dependencies,
@@ -594,6 +813,7 @@ mod task_deps_builder {
                 querent: Box::new(TestQuerent {
                     data: FxHashMap::from_iter([]),
                     tag_ids: vec![Id::raw("foo"), Id::raw("baz")],
+                    ..Default::default()
                 }),
                 project_id: &project_id,
                 project_dependencies: &project_dependencies,
@@ -620,6 +840,7 @@ mod task_deps_builder {
                         TaskOptions::default(),
                     )]),
                     tag_ids: vec![Id::raw("project")],
+                    ..Default::default()
                 }),
                 project_id: &project_id,
                 project_dependencies: &project_dependencies,
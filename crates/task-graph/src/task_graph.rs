@@ -201,3 +201,32 @@ impl GraphConversions<Task, DependencyType, Target> for TaskGraph {}
 impl GraphToDot<Task, DependencyType, Target> for TaskGraph {}
 
 impl GraphToJson<Task, DependencyType, Target> for TaskGraph {}
+
+impl GraphAttributes<Task> for TaskGraph {
+    fn get_node_attributes(&self, node: &Task) -> GraphNodeAttributes {
+        let mut attributes = vec![("type", node.type_of.to_string())];
+
+        // Inherit the project's attributes, excluding the project type
+        if let Some(project) = node
+            .target
+            .get_project_id()
+            .and_then(|id| self.project_graph.get_unexpanded(id).ok())
+        {
+            attributes.push(("project", project.id.to_string()));
+            attributes.extend(
+                self.project_graph
+                    .get_node_attributes(project)
+                    .into_iter()
+                    .filter(|(key, _)| *key != "type"),
+            );
+        }
+
+        attributes
+    }
+}
+
+impl GraphToMermaid<Task, DependencyType, Target> for TaskGraph {}
+
+impl GraphToGraphMl<Task, DependencyType, Target> for TaskGraph {}
+
+impl GraphToCytoscape<Task, DependencyType, Target> for TaskGraph {}
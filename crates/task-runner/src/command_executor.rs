@@ -294,15 +294,22 @@ impl<'task> CommandExecutor<'task> {
     fn prepare_state(&mut self, context: &ActionContext, report_item: &mut TaskReportItem) {
         let is_primary = context.is_primary_target(&self.task.target);
 
+        // If the reporter renders output itself (like the TUI), we must always
+        // capture stdout/stderr, unless the task explicitly requires stdin.
+        let capture_output =
+            self.app.console.reporter.requires_captured_output() && !self.interactive;
+
         // When a task is configured as local (no caching), or the interactive flag is passed,
         // we don't "capture" stdout/stderr (which breaks stdin) and let it stream natively.
-        if !self.task.options.cache && context.primary_targets.len() == 1 {
+        if !self.task.options.cache && context.primary_targets.len() == 1 && !capture_output {
             self.interactive = true;
         }
 
         // When the primary target, always stream the output for a better developer experience.
         // However, transitive targets can opt into streaming as well.
-        self.stream = if let Some(output_style) = &self.task.options.output_style {
+        self.stream = if capture_output {
+            false
+        } else if let Some(output_style) = &self.task.options.output_style {
             matches!(output_style, TaskOutputStyle::Stream)
         } else {
             is_primary || is_ci_env()
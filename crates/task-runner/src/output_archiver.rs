@@ -101,6 +101,17 @@ impl OutputArchiver<'_> {
                         return Ok(true);
                     }
                 }
+                TargetScope::Dependents(project_locator) => {
+                    if self
+                        .project
+                        .dependencies
+                        .iter()
+                        .any(|dep| &dep.id == project_locator)
+                        && is_matching_task
+                    {
+                        return Ok(true);
+                    }
+                }
                 TargetScope::Deps => return Err(TargetError::NoDepsInRunContext.into()),
                 TargetScope::OwnSelf => return Err(TargetError::NoSelfInRunContext.into()),
             };
@@ -9,6 +9,7 @@ use moon_action_context::{ActionContext, TargetState};
 use moon_api::Moonbase;
 use moon_app_context::AppContext;
 use moon_cache::CacheItem;
+use moon_common::path::WorkspaceRelativePathBuf;
 use moon_console::TaskReportItem;
 use moon_platform::PlatformManager;
 use moon_process::ProcessError;
@@ -75,6 +76,11 @@ impl<'task> TaskRunner<'task> {
             remote_state: None,
             report_item: TaskReportItem {
                 output_style: task.options.output_style,
+                working_dir: if task.options.run_from_workspace_root {
+                    WorkspaceRelativePathBuf::default()
+                } else {
+                    project.source.clone()
+                },
                 ..Default::default()
             },
             target_state: None,
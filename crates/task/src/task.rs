@@ -7,7 +7,7 @@ use moon_common::{
 use moon_config::{
     InputPath, OutputPath, PlatformType, TaskDependencyConfig, TaskPreset, TaskType,
 };
-use moon_target::Target;
+use moon_target::{Target, TargetLocator};
 use once_cell::sync::OnceCell;
 use rustc_hash::{FxHashMap, FxHashSet};
 use starbase_utils::glob;
@@ -41,6 +41,9 @@ cacheable!(
 
         pub deps: Vec<TaskDependencyConfig>,
 
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub deps_locators: Vec<TargetLocator>,
+
         #[serde(skip_serializing_if = "Option::is_none")]
         pub description: Option<String>,
 
@@ -259,6 +262,7 @@ impl Default for Task {
             args: vec![],
             command: String::from("noop"),
             deps: vec![],
+            deps_locators: vec![],
             description: None,
             env: FxHashMap::default(),
             id: Id::default(),
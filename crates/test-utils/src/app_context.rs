@@ -29,6 +29,7 @@ pub fn generate_app_context_from_sandbox(root: &Path) -> AppContext {
         cli_version: Version::parse(env!("CARGO_PKG_VERSION")).unwrap(),
         cache_engine: Arc::new(CacheEngine::new(root).unwrap()),
         console: Arc::new(Console::new_testing()),
+        sync_check: None,
         toolchain_config: Arc::new(toolchain_config),
         vcs: Arc::new(Box::new(vcs)),
         working_dir: root.to_owned(),
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use moon_pdk_api::{
-    MoonContext, SyncWorkspaceInput, SyncWorkspaceOutput, ToolchainMetadataInput,
-    ToolchainMetadataOutput,
+    InitializeToolchainInput, InitializeToolchainOutput, MoonContext, SyncWorkspaceInput,
+    SyncWorkspaceOutput, ToolchainMetadataInput, ToolchainMetadataOutput,
 };
 use moon_plugin::{Plugin, PluginContainer, PluginId, PluginRegistration, PluginType};
 use proto_core::Tool;
@@ -20,10 +20,30 @@ pub struct ToolchainPlugin {
 }
 
 impl ToolchainPlugin {
+    #[instrument(skip_all)]
+    pub async fn initialize_toolchain(
+        &self,
+        context: MoonContext,
+    ) -> miette::Result<Option<InitializeToolchainOutput>> {
+        if !self.plugin.has_func("initialize_toolchain").await {
+            return Ok(None);
+        }
+
+        debug!(toolchain_id = self.id.as_str(), "Initializing toolchain");
+
+        let output: InitializeToolchainOutput = self
+            .plugin
+            .call_func_with("initialize_toolchain", InitializeToolchainInput { context })
+            .await?;
+
+        Ok(Some(output))
+    }
+
     #[instrument(skip_all)]
     pub async fn sync_workspace(
         &self,
         context: MoonContext,
+        check: bool,
     ) -> miette::Result<Option<SyncWorkspaceOutput>> {
         if !self.plugin.has_func("sync_workspace").await {
             return Ok(None);
@@ -33,7 +53,7 @@ impl ToolchainPlugin {
 
         let output: SyncWorkspaceOutput = self
             .plugin
-            .call_func_with("sync_workspace", SyncWorkspaceInput { context })
+            .call_func_with("sync_workspace", SyncWorkspaceInput { check, context })
             .await?;
 
         Ok(Some(output))
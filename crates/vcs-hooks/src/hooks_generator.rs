@@ -1,9 +1,12 @@
-use moon_common::{color, consts, is_docker, path};
+use moon_common::{
+    color, consts, is_docker, path, remove_synced_file, write_synced_file, SyncCheck,
+};
 use moon_config::{VcsConfig, VcsHookFormat};
 use moon_vcs::BoxedVcs;
 use rustc_hash::FxHashMap;
 use starbase_utils::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use tracing::{debug, instrument, warn};
 
 pub enum ShellType {
@@ -29,6 +32,7 @@ impl ShellType {
 }
 
 pub struct HooksGenerator<'app> {
+    check: Option<Arc<SyncCheck>>,
     config: &'app VcsConfig,
     output_dir: PathBuf,
     shell: ShellType,
@@ -38,6 +42,7 @@ pub struct HooksGenerator<'app> {
 impl<'app> HooksGenerator<'app> {
     pub fn new(vcs: &'app BoxedVcs, config: &'app VcsConfig, workspace_root: &Path) -> Self {
         Self {
+            check: None,
             config,
             output_dir: workspace_root.join(consts::CONFIG_DIRNAME).join("hooks"),
             shell: ShellType::detect(),
@@ -45,6 +50,13 @@ impl<'app> HooksGenerator<'app> {
         }
     }
 
+    /// Track file changes in the provided check, instead of
+    /// writing to and removing from the file system.
+    pub fn with_check(mut self, check: Option<Arc<SyncCheck>>) -> Self {
+        self.check = check;
+        self
+    }
+
     #[instrument(skip_all)]
     pub async fn cleanup(self) -> miette::Result<()> {
         debug!("Cleaning up {} hooks", self.config.manager);
@@ -57,13 +69,21 @@ impl<'app> HooksGenerator<'app> {
             if hook_path.exists() {
                 debug!(file = ?hook_path, "Removing {} hook", color::file(hook_name));
 
-                fs::remove_file(&hook_path)?;
+                remove_synced_file(self.check.as_deref(), &hook_path)?;
             }
         }
 
         debug!(dir = ?self.output_dir, "Removing local hooks");
 
-        fs::remove_dir_all(&self.output_dir)?;
+        if self.check.is_some() {
+            if self.output_dir.exists() {
+                for file in fs::read_dir_all(&self.output_dir)? {
+                    remove_synced_file(self.check.as_deref(), &file.path())?;
+                }
+            }
+        } else {
+            fs::remove_dir_all(&self.output_dir)?;
+        }
 
         Ok(())
     }
@@ -178,10 +198,12 @@ impl<'app> HooksGenerator<'app> {
     }
 
     fn create_file(&self, file_path: &Path, contents: String) -> miette::Result<()> {
-        fs::write_file(file_path, contents)?;
-        fs::update_perms(file_path, Some(0o0775))?;
+        write_synced_file(self.check.as_deref(), file_path, |file_path| {
+            fs::write_file(file_path, contents)?;
+            fs::update_perms(file_path, Some(0o0775))?;
 
-        Ok(())
+            Ok(())
+        })
     }
 
     fn create_hook_file(
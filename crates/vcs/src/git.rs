@@ -12,7 +12,9 @@ use once_cell::sync::Lazy;
 use regex::Regex;
 use rustc_hash::FxHashSet;
 use semver::Version;
+use starbase_utils::fs;
 use std::collections::BTreeMap;
+use std::ffi::OsStr;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::{cmp, env};
@@ -76,6 +78,14 @@ pub enum GitError {
         error: Box<ignore::Error>,
     },
 
+    #[diagnostic(code(git::unstaged::restore_failed))]
+    #[error(
+        "Failed to restore unstaged changes, as they conflict with changes made while running. Unstaged changes have been saved to {}, and can be applied with {}.",
+        .path.style(Style::Path),
+        "git apply".style(Style::Shell),
+    )]
+    RestoreUnstagedFailed { path: PathBuf },
+
     #[diagnostic(code(git::repository::extract_slug))]
     #[error("Failed to extract a repository slug from git remote candidates.")]
     ExtractRepoSlugFailed,
@@ -584,6 +594,38 @@ impl Git {
 
 #[async_trait]
 impl Vcs for Git {
+    #[instrument(skip(self))]
+    async fn checkout_revision(&self, revision: &str, dir: &Path) -> miette::Result<PathBuf> {
+        // Remove a stale checkout from a previous run
+        if dir.exists() {
+            self.remove_checkout(dir).await?;
+        }
+
+        debug!(
+            revision,
+            dir = ?dir,
+            "Checking out revision into a detached worktree"
+        );
+
+        self.process
+            .run_command_without_cache(
+                self.process.create_command([
+                    OsStr::new("worktree"),
+                    OsStr::new("add"),
+                    OsStr::new("--detach"),
+                    dir.as_os_str(),
+                    OsStr::new(revision),
+                ]),
+                true,
+            )
+            .await?;
+
+        Ok(match &self.root_prefix {
+            Some(prefix) => prefix.to_logical_path(dir),
+            None => dir.to_path_buf(),
+        })
+    }
+
     async fn get_local_branch(&self) -> miette::Result<Arc<String>> {
         if self.is_version_supported(">=2.22.0").await? {
             return self.process.run(["branch", "--show-current"], true).await;
@@ -608,6 +650,22 @@ impl Vcs for Git {
             .await
     }
 
+    async fn get_file_contents_at_revision(
+        &self,
+        revision: &str,
+        file: &str,
+    ) -> miette::Result<Arc<String>> {
+        // Prefix with ./ so that the path is relative to the workspace root,
+        // and not the repository root
+        self.process
+            .run_command_without_cache(
+                self.process
+                    .create_command(["show", &format!("{revision}:./{file}")]),
+                false,
+            )
+            .await
+    }
+
     #[instrument(skip_all)]
     async fn get_file_hashes(
         &self,
@@ -830,6 +888,115 @@ impl Vcs for Git {
         Ok(touched_files)
     }
 
+    #[instrument(skip(self))]
+    async fn remove_checkout(&self, dir: &Path) -> miette::Result<()> {
+        let result = self
+            .process
+            .run_command_without_cache(
+                self.process.create_command([
+                    OsStr::new("worktree"),
+                    OsStr::new("remove"),
+                    OsStr::new("--force"),
+                    dir.as_os_str(),
+                ]),
+                true,
+            )
+            .await;
+
+        // The directory may not be a registered worktree,
+        // so remove it manually and prune the references
+        if result.is_err() || dir.exists() {
+            fs::remove_dir_all(dir)?;
+
+            self.process
+                .run_command_without_cache(self.process.create_command(["worktree", "prune"]), true)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn restore_unstaged_changes(&self, patch_file: &Path) -> miette::Result<()> {
+        debug!(patch_file = ?patch_file, "Restoring unstaged changes");
+
+        // Patch paths are relative from the repository root
+        let mut command = self.process.create_command([
+            OsStr::new("apply"),
+            OsStr::new("--whitespace=nowarn"),
+            patch_file.as_os_str(),
+        ]);
+        command.cwd(&self.repository_root);
+
+        // Keep the patch file on failure, so that changes aren't lost
+        if self
+            .process
+            .run_command_without_cache(command, true)
+            .await
+            .is_err()
+        {
+            return Err(GitError::RestoreUnstagedFailed {
+                path: patch_file.to_path_buf(),
+            }
+            .into());
+        }
+
+        fs::remove_file(patch_file)?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn stage_files(&self, files: &[String]) -> miette::Result<()> {
+        if files.is_empty() {
+            return Ok(());
+        }
+
+        let mut args = vec!["add", "--"];
+        args.extend(files.iter().map(|file| file.as_str()));
+
+        self.process
+            .run_command_without_cache(self.process.create_command(args), true)
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn stash_unstaged_changes(&self, patch_file: &Path) -> miette::Result<bool> {
+        let mut command = self.process.create_command([
+            "diff",
+            "--binary",
+            "--no-color",
+            "--no-ext-diff",
+            "--ignore-submodules",
+        ]);
+        command.cwd(&self.repository_root);
+
+        let patch = self
+            .process
+            .run_command_without_cache(command, false)
+            .await?;
+
+        if patch.trim().is_empty() {
+            return Ok(false);
+        }
+
+        debug!(patch_file = ?patch_file, "Stashing unstaged changes");
+
+        fs::write_file(patch_file, patch.as_bytes())?;
+
+        // Revert the working tree to match the index
+        let mut command = self.process.create_command(["checkout", "--", "."]);
+        command.cwd(&self.repository_root);
+
+        self.process
+            .run_command_without_cache(command, true)
+            .await?;
+
+        Ok(true)
+    }
+
     async fn get_version(&self) -> miette::Result<Version> {
         let version = self
             .process
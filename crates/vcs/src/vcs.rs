@@ -10,6 +10,10 @@ use std::sync::Arc;
 
 #[async_trait]
 pub trait Vcs: Debug {
+    /// Checkout the provided revision into a detached working tree within the
+    /// provided directory, and return an absolute path to the workspace root within it.
+    async fn checkout_revision(&self, revision: &str, dir: &Path) -> miette::Result<PathBuf>;
+
     /// Get the local checkout branch name.
     async fn get_local_branch(&self) -> miette::Result<Arc<String>>;
 
@@ -22,6 +26,14 @@ pub trait Vcs: Debug {
     /// Get the revision hash/number of the default branch's HEAD.
     async fn get_default_branch_revision(&self) -> miette::Result<Arc<String>>;
 
+    /// Get the contents of a file at the provided revision. File *must* be
+    /// relative from the workspace root.
+    async fn get_file_contents_at_revision(
+        &self,
+        revision: &str,
+        file: &str,
+    ) -> miette::Result<Arc<String>>;
+
     /// Get a map of hashes for the provided files. Files *must* be relative from
     /// the workspace root.
     async fn get_file_hashes(
@@ -60,6 +72,22 @@ pub trait Vcs: Debug {
         revision: &str,
     ) -> miette::Result<TouchedFiles>;
 
+    /// Remove a working tree that was created with `checkout_revision`.
+    async fn remove_checkout(&self, dir: &Path) -> miette::Result<()>;
+
+    /// Restore unstaged changes that were stashed with `stash_unstaged_changes`,
+    /// by applying the patch file, and then removing it.
+    async fn restore_unstaged_changes(&self, patch_file: &Path) -> miette::Result<()>;
+
+    /// Add the provided files to the index. Files *must* be relative from
+    /// the workspace root.
+    async fn stage_files(&self, files: &[String]) -> miette::Result<()>;
+
+    /// Write unstaged changes of tracked files to the provided patch file, and then
+    /// revert them from the working tree, so that it only contains staged changes.
+    /// Returns false if there were no unstaged changes.
+    async fn stash_unstaged_changes(&self, patch_file: &Path) -> miette::Result<bool>;
+
     /// Get the version of the current VCS binary
     async fn get_version(&self) -> miette::Result<Version>;
 
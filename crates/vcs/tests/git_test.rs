@@ -497,6 +497,214 @@ mod file_tree {
     }
 }
 
+mod checkout {
+    use super::*;
+
+    #[tokio::test]
+    async fn checks_out_revision_into_dir() {
+        let (sandbox, git) = create_git_sandbox("touched");
+
+        sandbox.run_git(|cmd| {
+            cmd.args(["checkout", "-b", "current"]);
+        });
+
+        sandbox.create_file("existing.txt", "changed");
+
+        sandbox.run_git(|cmd| {
+            cmd.args(["commit", "-a", "-m", "Change"]);
+        });
+
+        let dir = sandbox.path().join("base");
+        let root = git.checkout_revision("master", &dir).await.unwrap();
+
+        assert_eq!(root, dir);
+        assert!(root.join("delete-me.txt").exists());
+        assert_ne!(
+            fs::read_to_string(root.join("existing.txt")).unwrap(),
+            "changed"
+        );
+
+        git.remove_checkout(&dir).await.unwrap();
+
+        assert!(!dir.exists());
+    }
+
+    #[tokio::test]
+    async fn replaces_stale_dir() {
+        let (sandbox, git) = create_git_sandbox("touched");
+
+        sandbox.create_file("base/stale.txt", "");
+
+        let dir = sandbox.path().join("base");
+        let root = git.checkout_revision("master", &dir).await.unwrap();
+
+        assert!(!root.join("stale.txt").exists());
+        assert!(root.join("existing.txt").exists());
+    }
+
+    #[tokio::test]
+    async fn returns_nested_workspace_root() {
+        let (sandbox, git) = create_nested_git_sandbox();
+
+        let dir = sandbox.path().join("base");
+        let root = git.checkout_revision("master", &dir).await.unwrap();
+
+        assert_eq!(root, dir.join("frontend"));
+        assert!(root.join("file.js").exists());
+    }
+}
+
+mod file_contents {
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_contents_at_revision() {
+        let (sandbox, git) = create_git_sandbox("touched");
+
+        sandbox.run_git(|cmd| {
+            cmd.args(["checkout", "-b", "current"]);
+        });
+
+        sandbox.create_file("existing.txt", "changed");
+
+        sandbox.run_git(|cmd| {
+            cmd.args(["commit", "-a", "-m", "Change"]);
+        });
+
+        assert_eq!(
+            git.get_file_contents_at_revision("master", "existing.txt")
+                .await
+                .unwrap()
+                .as_str(),
+            ""
+        );
+        assert_eq!(
+            git.get_file_contents_at_revision("current", "existing.txt")
+                .await
+                .unwrap()
+                .as_str(),
+            "changed"
+        );
+    }
+
+    #[tokio::test]
+    async fn errors_if_file_missing() {
+        let (_sandbox, git) = create_git_sandbox("touched");
+
+        assert!(git
+            .get_file_contents_at_revision("master", "unknown.txt")
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn supports_nested_workspace_root() {
+        let (sandbox, git) = create_nested_git_sandbox();
+
+        sandbox.create_file("frontend/file.js", "export {};");
+
+        sandbox.run_git(|cmd| {
+            cmd.args(["commit", "-a", "-m", "Change"]);
+        });
+
+        assert_eq!(
+            git.get_file_contents_at_revision("HEAD", "file.js")
+                .await
+                .unwrap()
+                .as_str(),
+            "export {};"
+        );
+    }
+}
+
+mod unstaged_changes {
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_false_if_no_changes() {
+        let (sandbox, git) = create_git_sandbox("touched");
+        let patch_file = sandbox.path().join(".git/unstaged.patch");
+
+        sandbox.create_file("existing.txt", "staged");
+
+        sandbox.run_git(|cmd| {
+            cmd.args(["add", "existing.txt"]);
+        });
+
+        assert!(!git.stash_unstaged_changes(&patch_file).await.unwrap());
+        assert!(!patch_file.exists());
+    }
+
+    #[tokio::test]
+    async fn stashes_and_restores() {
+        let (sandbox, git) = create_git_sandbox("touched");
+        let patch_file = sandbox.path().join(".git/unstaged.patch");
+
+        sandbox.create_file("existing.txt", "staged");
+
+        sandbox.run_git(|cmd| {
+            cmd.args(["add", "existing.txt"]);
+        });
+
+        sandbox.create_file("existing.txt", "unstaged");
+        sandbox.create_file("rename-me.txt", "unstaged");
+
+        assert!(git.stash_unstaged_changes(&patch_file).await.unwrap());
+        assert!(patch_file.exists());
+
+        // Only staged content remains
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("existing.txt")).unwrap(),
+            "staged"
+        );
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("rename-me.txt")).unwrap(),
+            ""
+        );
+
+        git.restore_unstaged_changes(&patch_file).await.unwrap();
+
+        assert!(!patch_file.exists());
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("existing.txt")).unwrap(),
+            "unstaged"
+        );
+        assert_eq!(
+            fs::read_to_string(sandbox.path().join("rename-me.txt")).unwrap(),
+            "unstaged"
+        );
+    }
+
+    #[tokio::test]
+    async fn errors_and_keeps_patch_if_conflicting() {
+        let (sandbox, git) = create_git_sandbox("touched");
+        let patch_file = sandbox.path().join(".git/unstaged.patch");
+
+        sandbox.create_file("existing.txt", "unstaged");
+
+        assert!(git.stash_unstaged_changes(&patch_file).await.unwrap());
+
+        sandbox.create_file("existing.txt", "conflict");
+
+        assert!(git.restore_unstaged_changes(&patch_file).await.is_err());
+        assert!(patch_file.exists());
+    }
+
+    #[tokio::test]
+    async fn stages_files() {
+        let (sandbox, git) = create_git_sandbox("touched");
+
+        sandbox.create_file("existing.txt", "changed");
+
+        git.stage_files(&["existing.txt".into()]).await.unwrap();
+
+        assert_eq!(
+            git.get_touched_files().await.unwrap().staged,
+            create_touched_set(["existing.txt"])
+        );
+    }
+}
+
 mod touched_files {
     use super::*;
 
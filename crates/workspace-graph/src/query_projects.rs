@@ -1,5 +1,6 @@
 use crate::WorkspaceGraph;
 use moon_common::{color, Id};
+use moon_config::OwnersPaths;
 use moon_project_graph::Project;
 use moon_query::*;
 use std::{fmt::Debug, sync::Arc};
@@ -96,6 +97,13 @@ impl WorkspaceGraph {
                             }
                         }
                         Field::ProjectName(ids) => condition.matches(ids, &project.id),
+                        Field::ProjectOwner(owners) => condition.matches_list(
+                            owners,
+                            &get_project_owners(project)
+                                .iter()
+                                .map(|o| o.as_str())
+                                .collect::<Vec<_>>(),
+                        ),
                         Field::ProjectSource(sources) => {
                             condition.matches(sources, project.source.as_str())
                         }
@@ -167,3 +175,23 @@ impl WorkspaceGraph {
         Ok(true)
     }
 }
+
+/// Return the default owner, and all owners of paths, for the project.
+fn get_project_owners(project: &Project) -> Vec<String> {
+    let owners_config = &project.config.owners;
+    let mut owners = vec![];
+
+    if let Some(owner) = &owners_config.default_owner {
+        owners.push(owner.to_owned());
+    }
+
+    if let OwnersPaths::Map(map) = &owners_config.paths {
+        for owner in map.values().flatten() {
+            if !owners.contains(owner) {
+                owners.push(owner.to_owned());
+            }
+        }
+    }
+
+    owners
+}
@@ -3,47 +3,104 @@ use moon_common::Id;
 use moon_task::{Target, TaskOptions};
 use moon_task_builder::TasksQuerent;
 use rustc_hash::FxHashMap;
+use starbase_utils::glob::GlobSet;
 
 pub struct WorkspaceBuilderTasksQuerent<'builder> {
     pub project_data: &'builder FxHashMap<Id, ProjectBuildData>,
+    pub projects_by_dependency: &'builder FxHashMap<Id, Vec<Id>>,
     pub projects_by_tag: &'builder FxHashMap<Id, Vec<Id>>,
     pub task_data: &'builder FxHashMap<Target, TaskBuildData>,
 }
 
-impl TasksQuerent for WorkspaceBuilderTasksQuerent<'_> {
-    fn query_projects_by_tag(&self, tag: &str) -> miette::Result<Vec<&Id>> {
-        Ok(self
-            .projects_by_tag
-            .get(tag)
-            .map(|list| list.iter().collect())
-            .unwrap_or_default())
-    }
-
-    fn query_tasks(
+impl WorkspaceBuilderTasksQuerent<'_> {
+    fn filter_tasks(
         &self,
         project_ids: Vec<&Id>,
-        task_id: &Id,
-    ) -> miette::Result<Vec<(&Target, &TaskOptions)>> {
+        matches_task: impl Fn(&Id) -> bool,
+    ) -> Vec<(&Target, &TaskOptions)> {
         // May be an alias!
         let project_ids = project_ids
             .iter()
             .map(|id| ProjectBuildData::resolve_id(id, self.project_data))
             .collect::<Vec<_>>();
 
-        let results = self
-            .task_data
+        self.task_data
             .iter()
             .filter_map(|(target, data)| {
                 let project_id = target.get_project_id()?;
 
-                if &target.task_id == task_id && project_ids.contains(project_id) {
+                if matches_task(&target.task_id) && project_ids.contains(project_id) {
                     Some((target, &data.options))
                 } else {
                     None
                 }
             })
-            .collect::<Vec<_>>();
+            .collect()
+    }
+}
+
+impl TasksQuerent for WorkspaceBuilderTasksQuerent<'_> {
+    fn query_dependent_projects(&self, project_id: &Id) -> miette::Result<Vec<&Id>> {
+        // May be an alias!
+        let project_id = ProjectBuildData::resolve_id(project_id, self.project_data);
+
+        Ok(self
+            .projects_by_dependency
+            .get(&project_id)
+            .map(|list| list.iter().collect())
+            .unwrap_or_default())
+    }
+
+    fn query_projects_by_glob(&self, glob: &str) -> miette::Result<Vec<&Id>> {
+        let mut results = vec![];
+
+        if let Some(tag_glob) = glob.strip_prefix('#') {
+            let globset = GlobSet::new([tag_glob])?;
+
+            for (tag, ids) in self.projects_by_tag {
+                if globset.is_match(tag.as_str()) {
+                    results.extend(ids);
+                }
+            }
+        } else {
+            let globset = GlobSet::new([glob])?;
+
+            results.extend(
+                self.project_data
+                    .keys()
+                    .filter(|id| globset.is_match(id.as_str())),
+            );
+        }
+
+        results.sort();
+        results.dedup();
 
         Ok(results)
     }
+
+    fn query_projects_by_tag(&self, tag: &str) -> miette::Result<Vec<&Id>> {
+        Ok(self
+            .projects_by_tag
+            .get(tag)
+            .map(|list| list.iter().collect())
+            .unwrap_or_default())
+    }
+
+    fn query_tasks(
+        &self,
+        project_ids: Vec<&Id>,
+        task_id: &Id,
+    ) -> miette::Result<Vec<(&Target, &TaskOptions)>> {
+        Ok(self.filter_tasks(project_ids, |id| id == task_id))
+    }
+
+    fn query_tasks_by_glob(
+        &self,
+        project_ids: Vec<&Id>,
+        task_glob: &str,
+    ) -> miette::Result<Vec<(&Target, &TaskOptions)>> {
+        let globset = GlobSet::new([task_glob])?;
+
+        Ok(self.filter_tasks(project_ids, |id| globset.is_match(id.as_str())))
+    }
 }
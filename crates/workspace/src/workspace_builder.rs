@@ -16,11 +16,15 @@ use moon_config::{
 };
 use moon_project::Project;
 use moon_project_builder::{ProjectBuilder, ProjectBuilderContext};
-use moon_project_constraints::{enforce_project_type_relationships, enforce_tag_relationships};
+use moon_project_constraints::{
+    enforce_denied_relationships, enforce_dependency_limits, enforce_layer_relationships,
+    enforce_project_type_relationships, enforce_tag_relationships,
+};
 use moon_project_graph::{ProjectGraph, ProjectGraphError, ProjectMetadata};
 use moon_task::{Target, Task};
 use moon_task_builder::TaskDepsBuilder;
 use moon_task_graph::{GraphExpanderContext, NodeState, TaskGraph, TaskGraphError, TaskMetadata};
+use moon_time::now_timestamp;
 use moon_vcs::BoxedVcs;
 use moon_workspace_graph::WorkspaceGraph;
 use petgraph::prelude::*;
@@ -51,6 +55,10 @@ pub struct WorkspaceBuilder<'app> {
     #[serde(skip)]
     context: Option<Arc<WorkspaceBuilderContext<'app>>>,
 
+    /// Projects grouped by the projects they depend on, for use in
+    /// task dependency resolution.
+    projects_by_dependency: FxHashMap<Id, Vec<Id>>,
+
     /// Projects grouped by tag, for use in task dependency resolution.
     projects_by_tag: FxHashMap<Id, Vec<Id>>,
 
@@ -92,6 +100,7 @@ impl<'app> WorkspaceBuilder<'app> {
 
         let mut graph = WorkspaceBuilder {
             context: Some(Arc::new(context)),
+            projects_by_dependency: FxHashMap::default(),
             projects_by_tag: FxHashMap::default(),
             project_data: FxHashMap::default(),
             project_graph: DiGraph::default(),
@@ -318,6 +327,13 @@ impl<'app> WorkspaceBuilder<'app> {
                 .push(id.clone());
         }
 
+        for dep_config in &project.dependencies {
+            self.projects_by_dependency
+                .entry(dep_config.id.to_owned())
+                .or_default()
+                .push(id.clone());
+        }
+
         // Then persist task build data
         for task in project.tasks.values() {
             self.task_data.insert(
@@ -497,6 +513,7 @@ impl<'app> WorkspaceBuilder<'app> {
         TaskDepsBuilder {
             querent: Box::new(WorkspaceBuilderTasksQuerent {
                 project_data: &self.project_data,
+                projects_by_dependency: &self.projects_by_dependency,
                 projects_by_tag: &self.projects_by_tag,
                 task_data: &self.task_data,
             }),
@@ -573,16 +590,24 @@ impl<'app> WorkspaceBuilder<'app> {
         debug!("Enforcing project constraints");
 
         let context = self.context();
-        let type_relationships = context
-            .workspace_config
-            .constraints
-            .enforce_project_type_relationships;
-        let tag_relationships = &context.workspace_config.constraints.tag_relationships;
-
-        if !type_relationships && tag_relationships.is_empty() {
+        let constraints = &context.workspace_config.constraints;
+        let type_relationships = constraints.enforce_project_type_relationships;
+        let tag_relationships = &constraints.tag_relationships;
+        let path_relationships =
+            !constraints.layers.is_empty() || !constraints.deny_relationships.is_empty();
+        let dependency_limits =
+            constraints.max_dependencies.is_some() || constraints.max_dependents.is_some();
+
+        if !type_relationships
+            && tag_relationships.is_empty()
+            && !path_relationships
+            && !dependency_limits
+        {
             return Ok(());
         }
 
+        let today = now_timestamp().date();
+
         let default_scope = DependencyScope::Build;
 
         for (project_index, project_state) in self.project_graph.node_references() {
@@ -620,12 +645,55 @@ impl<'app> WorkspaceBuilder<'app> {
                 for (source_tag, required_tags) in tag_relationships {
                     enforce_tag_relationships(project, source_tag, dep, required_tags)?;
                 }
+
+                if path_relationships {
+                    enforce_layer_relationships(project, dep, dep_scope, constraints, today)?;
+                    enforce_denied_relationships(project, dep, dep_scope, constraints, today)?;
+                }
+            }
+
+            if dependency_limits {
+                enforce_dependency_limits(
+                    project,
+                    self.count_transitive_projects(project_index, Direction::Outgoing),
+                    self.count_transitive_projects(project_index, Direction::Incoming),
+                    constraints,
+                )?;
             }
         }
 
         Ok(())
     }
 
+    /// Count all projects that are transitively reachable from the provided
+    /// project in the given direction, excluding implicit relationships.
+    fn count_transitive_projects(&self, project_index: NodeIndex, direction: Direction) -> usize {
+        let mut seen = FxHashSet::default();
+        let mut queue = vec![project_index];
+
+        while let Some(index) = queue.pop() {
+            for edge in self.project_graph.edges_directed(index, direction) {
+                if matches!(
+                    edge.weight(),
+                    DependencyScope::Build | DependencyScope::Root
+                ) {
+                    continue;
+                }
+
+                let next_index = match direction {
+                    Direction::Outgoing => edge.target(),
+                    Direction::Incoming => edge.source(),
+                };
+
+                if next_index != project_index && seen.insert(next_index) {
+                    queue.push(next_index);
+                }
+            }
+        }
+
+        seen.len()
+    }
+
     /// When caching the graph, we must hash all project and workspace
     /// config files that are required to invalidate the cache.
     async fn hash_required_configs(
@@ -0,0 +1,383 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use starbase_utils::yaml::{self, YamlMapping, YamlValue};
+
+static KEY_LINE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r##"^(?P<key>[ \t]*(?:'[^']*'|"[^"]*"|[^ \t'"#-][^:#]*?)[ \t]*:)(?:[ \t]+(?P<value>.*))?$"##,
+    )
+    .unwrap()
+});
+
+/// Edits YAML content line by line, so that formatting and comments
+/// outside of the edited settings are preserved. Keys are referenced by
+/// the index of their line, and their block includes all following lines
+/// that are indented further, or sequence items at the same indentation.
+#[derive(Debug, Default)]
+pub struct YamlEditor {
+    lines: Vec<String>,
+}
+
+impl YamlEditor {
+    pub fn new(content: &str) -> Self {
+        Self {
+            lines: content.lines().map(String::from).collect(),
+        }
+    }
+
+    /// Find the line of a key within the block of the parent key,
+    /// or at the root of the document if no parent is provided.
+    pub fn find_key(&self, parent: Option<usize>, key: &str) -> Option<usize> {
+        let (start, end, indent) = match parent {
+            Some(index) => (
+                index + 1,
+                self.find_block_end(index),
+                self.get_child_indent(index),
+            ),
+            None => (0, self.lines.len(), String::new()),
+        };
+
+        let pattern = Regex::new(&format!(
+            r#"^{}['"]?{}['"]?[ \t]*:(?:[ \t]+.*)?$"#,
+            regex::escape(&indent),
+            regex::escape(key)
+        ))
+        .unwrap();
+
+        (start..end).find(|index| pattern.is_match(&self.lines[*index]))
+    }
+
+    /// Return the value on the same line as the key, excluding comments.
+    pub fn get_inline_value(&self, index: usize) -> Option<&str> {
+        KEY_LINE
+            .captures(&self.lines[index])
+            .and_then(|caps| caps.name("value"))
+            .map(|value| strip_comment(value.as_str()))
+            .filter(|value| !value.is_empty())
+    }
+
+    /// Parse and return the value of the key, which may be inline or a block.
+    pub fn parse_value(&self, index: usize) -> miette::Result<YamlValue> {
+        let indent = get_indent(&self.lines[index]).len();
+        let content = self.lines[index..self.find_block_end(index)]
+            .iter()
+            .map(|line| line.get(indent..).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let config: YamlValue = yaml::parse(content)?;
+
+        Ok(config
+            .as_mapping()
+            .and_then(|map| map.values().next())
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    /// Convert an inline value (flow sequence or mapping) of the key into a block,
+    /// so that its entries can be edited line by line. Scalars are left as-is.
+    pub fn expand_value(&mut self, index: usize) -> miette::Result<()> {
+        let Some(value) = self.get_inline_value(index) else {
+            return Ok(());
+        };
+
+        let is_null = value == "null" || value == "~";
+        let entries = match self.parse_value(index)? {
+            YamlValue::Sequence(list) if list.is_empty() => vec![],
+            YamlValue::Mapping(map) if map.is_empty() => vec![],
+            value @ (YamlValue::Sequence(_) | YamlValue::Mapping(_)) => {
+                yaml::format(&value)?.lines().map(String::from).collect()
+            }
+            _ if is_null => vec![],
+            _ => return Ok(()),
+        };
+
+        let indent = format!("{}  ", get_indent(&self.lines[index]));
+        let mut replacement = vec![self.get_key_prefix(index)];
+
+        for line in entries {
+            replacement.push(format!("{indent}{line}"));
+        }
+
+        self.lines
+            .splice(index..self.find_block_end(index), replacement);
+
+        Ok(())
+    }
+
+    /// Replace the value on the same line as the key, for example, with `[]`.
+    pub fn set_inline_value(&mut self, index: usize, value: &str) {
+        self.lines[index] = format!("{} {value}", self.get_key_prefix(index));
+    }
+
+    /// Return the line ranges of each sequence item within the block of the key.
+    pub fn get_items(&self, index: usize) -> Vec<(usize, usize)> {
+        let end = self.find_block_end(index);
+        let Some(dash_indent) = self.lines[index + 1..end]
+            .iter()
+            .find(|line| is_item_line(line))
+            .map(|line| get_indent(line).len())
+        else {
+            return vec![];
+        };
+
+        let mut items = vec![];
+        let mut current = index + 1;
+
+        while current < end {
+            let line = &self.lines[current];
+
+            if !is_item_line(line) || get_indent(line).len() != dash_indent {
+                current += 1;
+                continue;
+            }
+
+            let item_start = current;
+
+            current += 1;
+
+            while current < end {
+                let line = &self.lines[current];
+
+                if line.trim().is_empty() || get_indent(line).len() <= dash_indent {
+                    break;
+                }
+
+                current += 1;
+            }
+
+            items.push((item_start, current));
+        }
+
+        items
+    }
+
+    /// Parse and return the value of a sequence item.
+    pub fn parse_item(&self, (start, end): (usize, usize)) -> Option<YamlValue> {
+        let indent = get_indent(&self.lines[start]).len();
+        let content = self.lines[start..end]
+            .iter()
+            .map(|line| line.get(indent..).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        yaml::parse::<_, YamlValue>(content)
+            .ok()
+            .and_then(|list| list.as_sequence().and_then(|list| list.first()).cloned())
+    }
+
+    /// Remove sequence items from the block of the key that match the filter,
+    /// and return the number of items that were kept.
+    pub fn remove_items<F>(&mut self, index: usize, filter: F) -> usize
+    where
+        F: Fn(&YamlValue) -> bool,
+    {
+        let items = self.get_items(index);
+        let mut kept = 0;
+
+        for item in items.into_iter().rev() {
+            if self.parse_item(item).is_some_and(|value| filter(&value)) {
+                self.lines.drain(item.0..item.1);
+            } else {
+                kept += 1;
+            }
+        }
+
+        kept
+    }
+
+    /// Append lines to the end of the key's block, using the indentation of
+    /// existing entries, or 2 spaces deeper than the key if there are none.
+    pub fn append_to_block(&mut self, index: usize, lines: Vec<String>) {
+        let indent = self.get_child_indent(index);
+        let end = self.find_block_end(index);
+
+        self.lines.splice(
+            end..end,
+            lines.into_iter().map(|line| format!("{indent}{line}")),
+        );
+    }
+
+    /// Append lines to the end of the document, separated by an empty line,
+    /// and return the index of the first appended line.
+    pub fn append_to_root(&mut self, lines: Vec<String>) -> usize {
+        // An empty document may be written as an empty flow mapping or null,
+        // which can't be followed by keys
+        if self
+            .lines
+            .iter()
+            .filter(|line| is_content_line(line))
+            .all(|line| matches!(line.trim(), "{}" | "~" | "null"))
+        {
+            self.lines.retain(|line| !is_content_line(line));
+        }
+
+        while self.lines.last().is_some_and(|line| line.trim().is_empty()) {
+            self.lines.pop();
+        }
+
+        if !self.lines.is_empty() {
+            self.lines.push(String::new());
+        }
+
+        let index = self.lines.len();
+
+        self.lines.extend(lines);
+
+        index
+    }
+
+    /// Insert an empty key at the end of the parent's block (or the document),
+    /// and return the index of its line.
+    pub fn insert_key(&mut self, parent: Option<usize>, key: &str) -> usize {
+        match parent {
+            Some(index) => {
+                self.append_to_block(index, vec![format!("{key}:")]);
+                self.find_block_end(index) - 1
+            }
+            None => self.append_to_root(vec![format!("{key}:")]),
+        }
+    }
+
+    /// Move the entries within the key's block under a new child key.
+    pub fn nest_block(&mut self, index: usize, key: &str) -> usize {
+        let end = self.find_block_end(index);
+
+        for line in &mut self.lines[index + 1..end] {
+            if !line.is_empty() {
+                line.insert_str(0, "  ");
+            }
+        }
+
+        let indent = get_indent(&self.lines[index]).to_owned();
+
+        self.lines.insert(index + 1, format!("{indent}  {key}:"));
+
+        index + 1
+    }
+
+    /// Set the value of a key within the parent's block (or the document),
+    /// by replacing the existing key and its block, or appending it.
+    pub fn set_entry(
+        &mut self,
+        parent: Option<usize>,
+        key: &str,
+        value: YamlValue,
+    ) -> miette::Result<()> {
+        let lines = format_mapping([(YamlValue::String(key.to_owned()), value)])?;
+
+        match self.find_key(parent, key) {
+            Some(index) => {
+                let indent = get_indent(&self.lines[index]).to_owned();
+
+                self.lines.splice(
+                    index..self.find_block_end(index),
+                    lines.into_iter().map(|line| format!("{indent}{line}")),
+                );
+            }
+            None => match parent {
+                Some(index) => self.append_to_block(index, lines),
+                None => {
+                    self.append_to_root(lines);
+                }
+            },
+        };
+
+        Ok(())
+    }
+
+    /// Return the edited content, with a trailing newline.
+    pub fn into_content(self) -> String {
+        let mut content = self.lines.join("\n");
+        content.push('\n');
+        content
+    }
+
+    // The block ends after the last line that is indented further than the key,
+    // or is a sequence item at the same indentation, excluding trailing empty
+    // lines and comments, as they belong to what follows
+    fn find_block_end(&self, index: usize) -> usize {
+        let indent = get_indent(&self.lines[index]).len();
+        let mut end = index + 1;
+
+        for (current, line) in self.lines.iter().enumerate().skip(index + 1) {
+            if !is_content_line(line) {
+                continue;
+            }
+
+            let line_indent = get_indent(line).len();
+
+            if line_indent > indent || (line_indent == indent && is_item_line(line)) {
+                end = current + 1;
+            } else {
+                break;
+            }
+        }
+
+        end
+    }
+
+    fn get_child_indent(&self, index: usize) -> String {
+        self.lines[index + 1..self.find_block_end(index)]
+            .iter()
+            .find(|line| is_content_line(line))
+            .map(|line| get_indent(line).to_owned())
+            .unwrap_or_else(|| format!("{}  ", get_indent(&self.lines[index])))
+    }
+
+    fn get_key_prefix(&self, index: usize) -> String {
+        KEY_LINE
+            .captures(&self.lines[index])
+            .and_then(|caps| caps.name("key"))
+            .map(|key| key.as_str().to_owned())
+            .unwrap_or_else(|| self.lines[index].clone())
+    }
+}
+
+/// Format the value as a sequence item, split into lines.
+pub fn format_item(value: &YamlValue) -> miette::Result<Vec<String>> {
+    Ok(yaml::format(&YamlValue::Sequence(vec![value.to_owned()]))?
+        .lines()
+        .map(String::from)
+        .collect())
+}
+
+/// Format the entries as a mapping, split into lines.
+pub fn format_mapping<I>(entries: I) -> miette::Result<Vec<String>>
+where
+    I: IntoIterator<Item = (YamlValue, YamlValue)>,
+{
+    Ok(yaml::format(&YamlValue::Mapping(
+        entries.into_iter().collect::<YamlMapping>(),
+    ))?
+    .lines()
+    .map(String::from)
+    .collect())
+}
+
+fn get_indent(line: &str) -> &str {
+    &line[..line.len() - line.trim_start().len()]
+}
+
+fn is_content_line(line: &str) -> bool {
+    let line = line.trim_start();
+
+    !line.is_empty() && !line.starts_with('#')
+}
+
+fn is_item_line(line: &str) -> bool {
+    let line = line.trim_start();
+
+    line == "-" || line.starts_with("- ")
+}
+
+fn strip_comment(value: &str) -> &str {
+    if value.starts_with('#') {
+        return "";
+    }
+
+    match value.find(" #") {
+        Some(index) => value[..index].trim(),
+        None => value.trim(),
+    }
+}
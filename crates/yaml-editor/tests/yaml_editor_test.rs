@@ -0,0 +1,155 @@
+use moon_yaml_editor::*;
+use starbase_utils::yaml::YamlValue;
+
+mod find_key {
+    use super::*;
+
+    #[test]
+    fn finds_root_and_nested_keys() {
+        let editor = YamlEditor::new("a: 1\n'b':\n  c: 2\n  d:\n    c: 3\nc: 4\n");
+
+        assert_eq!(editor.find_key(None, "a"), Some(0));
+        assert_eq!(editor.find_key(None, "b"), Some(1));
+        assert_eq!(editor.find_key(None, "c"), Some(5));
+        assert_eq!(editor.find_key(Some(1), "c"), Some(2));
+        assert_eq!(editor.find_key(Some(3), "c"), Some(4));
+        assert_eq!(editor.find_key(Some(1), "a"), None);
+    }
+
+    #[test]
+    fn returns_inline_values_without_comments() {
+        let editor = YamlEditor::new("a: 1 # one\nb: # empty\nc:\n");
+
+        assert_eq!(editor.get_inline_value(0), Some("1"));
+        assert_eq!(editor.get_inline_value(1), None);
+        assert_eq!(editor.get_inline_value(2), None);
+    }
+}
+
+mod expand_value {
+    use super::*;
+
+    #[test]
+    fn converts_flow_sequences() {
+        let mut editor = YamlEditor::new("# List\nlist: [a, b] # items\nother: true\n");
+        editor.expand_value(1).unwrap();
+
+        assert_eq!(
+            editor.into_content(),
+            "# List\nlist:\n  - a\n  - b\nother: true\n"
+        );
+    }
+
+    #[test]
+    fn converts_flow_mappings() {
+        let mut editor = YamlEditor::new("map: { a: 1 }\n");
+        editor.expand_value(0).unwrap();
+
+        assert_eq!(editor.into_content(), "map:\n  a: 1\n");
+    }
+
+    #[test]
+    fn converts_null() {
+        let mut editor = YamlEditor::new("map: ~\n");
+        editor.expand_value(0).unwrap();
+
+        assert_eq!(editor.into_content(), "map:\n");
+    }
+
+    #[test]
+    fn leaves_scalars() {
+        let mut editor = YamlEditor::new("value: true\n");
+        editor.expand_value(0).unwrap();
+
+        assert_eq!(editor.into_content(), "value: true\n");
+    }
+}
+
+mod sequences {
+    use super::*;
+
+    #[test]
+    fn removes_and_appends_items() {
+        let mut editor = YamlEditor::new(
+            "list:\n  # Comment\n  - a\n  - id: b\n    other: true\n  - c # Keep\n\nnext: true\n",
+        );
+
+        let kept = editor.remove_items(0, |value| {
+            value.as_str() == Some("a") || value.get("id").and_then(|id| id.as_str()) == Some("b")
+        });
+
+        editor.append_to_block(0, vec!["- d".into()]);
+
+        assert_eq!(kept, 1);
+        assert_eq!(
+            editor.into_content(),
+            "list:\n  # Comment\n  - c # Keep\n  - d\n\nnext: true\n"
+        );
+    }
+
+    #[test]
+    fn supports_items_at_key_indentation() {
+        let mut editor = YamlEditor::new("list:\n- a\nnext: true\n");
+        editor.append_to_block(0, vec!["- b".into()]);
+
+        assert_eq!(editor.into_content(), "list:\n- a\n- b\nnext: true\n");
+    }
+}
+
+mod mappings {
+    use super::*;
+
+    #[test]
+    fn replaces_and_appends_entries() {
+        let mut editor =
+            YamlEditor::new("map:\n  # Comment\n  a: 1\n  b:\n    - x\n\nnext: true\n");
+
+        editor
+            .set_entry(Some(0), "b", YamlValue::String("value".into()))
+            .unwrap();
+        editor
+            .set_entry(Some(0), "c", YamlValue::Bool(true))
+            .unwrap();
+
+        assert_eq!(
+            editor.into_content(),
+            "map:\n  # Comment\n  a: 1\n  b: value\n  c: true\n\nnext: true\n"
+        );
+    }
+
+    #[test]
+    fn inserts_keys_into_root() {
+        let mut editor = YamlEditor::new("# Settings\na: 1\n\n");
+        let index = editor.insert_key(None, "map");
+
+        editor.append_to_block(index, vec!["b: 2".into()]);
+
+        assert_eq!(editor.into_content(), "# Settings\na: 1\n\nmap:\n  b: 2\n");
+    }
+
+    #[test]
+    fn replaces_empty_documents() {
+        for content in ["{}\n", "# Settings\n~\n"] {
+            let mut editor = YamlEditor::new(content);
+            let index = editor.insert_key(None, "map");
+
+            editor.append_to_block(index, vec!["b: 2".into()]);
+
+            assert!(editor.into_content().ends_with("map:\n  b: 2\n"));
+        }
+    }
+
+    #[test]
+    fn nests_entries_under_a_key() {
+        let mut editor = YamlEditor::new("map:\n  a: 1\n  b: 2\nnext: true\n");
+        let index = editor.nest_block(0, "inner");
+
+        editor.insert_key(Some(0), "other");
+
+        assert_eq!(index, 1);
+        assert_eq!(
+            editor.into_content(),
+            "map:\n  inner:\n    a: 1\n    b: 2\n  other:\nnext: true\n"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use moon_lang::LockfileDependencyVersions;
+use moon_lang::{LockfileDependencyGraph, LockfileDependencyVersions};
 use rustc_hash::FxHashMap;
 use serde::Deserialize;
 use starbase_utils::json;
@@ -75,3 +75,76 @@ pub fn load_text_lockfile_dependencies(
 
     Ok(deps)
 }
+
+/// Load the resolved version of each package, instead of the integrity
+/// hash, for use in reporting. Workspace packages are excluded.
+pub fn load_text_lockfile_package_versions(
+    lockfile_text: Arc<String>,
+) -> miette::Result<LockfileDependencyVersions> {
+    let mut deps: LockfileDependencyVersions = FxHashMap::default();
+    let lockfile: BunLock = json::parse(lockfile_text.as_str())?;
+
+    for (_name, package) in lockfile.packages {
+        let id = match package {
+            BunLockPackage::Workspace(_) => continue,
+            BunLockPackage::DependencyAlt(id, _) | BunLockPackage::Dependency(id, _, _, _) => id,
+        };
+
+        let Some((name, version)) = id.rsplit_once('@') else {
+            continue;
+        };
+
+        if name.is_empty() || version.starts_with("workspace:") {
+            continue;
+        }
+
+        let versions = deps.entry(name.to_owned()).or_default();
+
+        if !versions.iter().any(|existing| existing == version) {
+            versions.push(version.to_owned());
+        }
+    }
+
+    for versions in deps.values_mut() {
+        versions.sort();
+    }
+
+    Ok(deps)
+}
+
+pub fn load_text_lockfile_dependency_graph(
+    lockfile_text: Arc<String>,
+) -> miette::Result<LockfileDependencyGraph> {
+    let mut graph: LockfileDependencyGraph = FxHashMap::default();
+    let lockfile: BunLock = json::parse(lockfile_text.as_str())?;
+
+    let mut add_deps = |name: &str, data: BunLockPackageJson| {
+        graph.entry(name.to_owned()).or_default().extend(
+            data.dependencies
+                .into_keys()
+                .chain(data.optional_dependencies.into_keys()),
+        );
+    };
+
+    for data in lockfile.workspaces.unwrap_or_default().into_values() {
+        if !data.name.is_empty() {
+            add_deps(&data.name.clone(), data);
+        }
+    }
+
+    for (_name, package) in lockfile.packages {
+        let (id, data) = match package {
+            BunLockPackage::Workspace(_) => continue,
+            BunLockPackage::DependencyAlt(id, data)
+            | BunLockPackage::Dependency(id, _, data, _) => (id, data),
+        };
+
+        if let Some((name, _)) = id.rsplit_once('@') {
+            if !name.is_empty() {
+                add_deps(name, data);
+            }
+        }
+    }
+
+    Ok(graph)
+}
@@ -3,7 +3,7 @@ mod bun_lockb;
 
 pub use bun_lock::*;
 pub use bun_lockb::*;
-pub use moon_lang::LockfileDependencyVersions;
+pub use moon_lang::{LockfileDependencyGraph, LockfileDependencyVersions};
 
 use cached::proc_macro::cached;
 use std::path::PathBuf;
@@ -20,3 +20,38 @@ pub fn load_lockfile_dependencies(
         load_binary_lockfile_dependencies(lockfile_text, path)
     }
 }
+
+/// Load the resolved version of each package. Only the text-based `bun.lock`
+/// is supported, as the binary `bun.lockb` does not record versions as text.
+#[cached(result)]
+pub fn load_lockfile_package_versions(
+    lockfile_text: Arc<String>,
+    path: PathBuf,
+) -> miette::Result<LockfileDependencyVersions> {
+    if path.ends_with("bun.lock") {
+        load_text_lockfile_package_versions(lockfile_text)
+    } else {
+        Err(unsupported_binary_lockfile(path))
+    }
+}
+
+/// Load the dependency graph of each package. Only the text-based `bun.lock`
+/// is supported, as the binary `bun.lockb` does not record dependencies as text.
+#[cached(result)]
+pub fn load_lockfile_dependency_graph(
+    lockfile_text: Arc<String>,
+    path: PathBuf,
+) -> miette::Result<LockfileDependencyGraph> {
+    if path.ends_with("bun.lock") {
+        load_text_lockfile_dependency_graph(lockfile_text)
+    } else {
+        Err(unsupported_binary_lockfile(path))
+    }
+}
+
+fn unsupported_binary_lockfile(path: PathBuf) -> miette::Report {
+    miette::miette!(
+        "Unable to load packages from {}, as binary lockfiles are not supported. Migrate to the text-based bun.lock and try again.",
+        path.display()
+    )
+}
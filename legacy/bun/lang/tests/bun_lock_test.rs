@@ -1,5 +1,8 @@
-use moon_bun_lang::load_lockfile_dependencies;
+use moon_bun_lang::{
+    load_lockfile_dependencies, load_lockfile_dependency_graph, load_lockfile_package_versions,
+};
 use starbase_sandbox::locate_fixture;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 #[test]
@@ -9,3 +12,39 @@ fn parses_lockfile() {
 
     let _ = load_lockfile_dependencies(Arc::new(contents), path).unwrap();
 }
+
+#[test]
+fn loads_package_versions() {
+    let path = locate_fixture("bun.lock");
+    let contents = std::fs::read_to_string(&path).unwrap();
+
+    let versions = load_lockfile_package_versions(Arc::new(contents), path).unwrap();
+
+    assert_eq!(versions.get("@actions/core").unwrap(), &["1.11.1"]);
+    assert!(!versions.contains_key("data-model-duty-handover"));
+}
+
+#[test]
+fn loads_dependency_graph() {
+    let path = locate_fixture("bun.lock");
+    let contents = std::fs::read_to_string(&path).unwrap();
+
+    let graph = load_lockfile_dependency_graph(Arc::new(contents), path).unwrap();
+
+    assert!(graph
+        .get("@actions/core")
+        .unwrap()
+        .contains("@actions/exec"));
+    assert!(graph
+        .get("data-model-duty-handover")
+        .unwrap()
+        .contains("drizzle-orm"));
+}
+
+#[test]
+fn errors_for_binary_lockfile() {
+    assert!(
+        load_lockfile_package_versions(Arc::new(String::new()), PathBuf::from("bun.lockb"))
+            .is_err()
+    );
+}
@@ -378,7 +378,7 @@ impl Platform for BunPlatform {
     #[instrument(skip_all)]
     async fn sync_project(
         &self,
-        _context: &ActionContext,
+        context: &ActionContext,
         project: &Project,
         dependencies: &FxHashMap<Id, Arc<Project>>,
     ) -> miette::Result<bool> {
@@ -388,6 +388,7 @@ impl Platform for BunPlatform {
             &self.workspace_root,
             &self.config,
             &self.typescript_config,
+            context.sync_check.as_deref(),
         )
         .await?;
 
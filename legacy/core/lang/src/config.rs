@@ -62,18 +62,28 @@ macro_rules! config_cache {
             }
 
             /// If the file exists, load it from the file system, mutate it,
-            /// write it back to the file system and to the cache.
+            /// write it back to the file system and to the cache. When checking,
+            /// it's written to a temporary copy instead of the file system.
             #[track_caller]
-            pub fn sync<P, F>(path: P, func: F) -> miette::Result<bool>
+            pub fn sync<P, F>(
+                path: P,
+                check: Option<&$crate::SyncCheck>,
+                func: F,
+            ) -> miette::Result<bool>
             where
                 P: AsRef<Path>,
                 F: FnOnce(&mut $struct) -> miette::Result<bool>
             {
-                $struct::sync_with_name(path, $file, func)
+                $struct::sync_with_name(path, $file, check, func)
             }
 
             #[track_caller]
-            pub fn sync_with_name<P, N, F>(path: P, name: N, func: F) -> miette::Result<bool>
+            pub fn sync_with_name<P, N, F>(
+                path: P,
+                name: N,
+                check: Option<&$crate::SyncCheck>,
+                func: F,
+            ) -> miette::Result<bool>
             where
                 P: AsRef<Path>,
                 N: AsRef<str>,
@@ -112,7 +122,7 @@ macro_rules! config_cache {
                     );
 
                     // Write to the file system
-                    $writer(&path, &cfg)?;
+                    $crate::write_synced_file(check, &path, |path| $writer(path, &cfg))?;
 
                     // And store in the cache
                     cache.cache_set(path, cfg);
@@ -125,7 +135,10 @@ macro_rules! config_cache {
 
             /// Write (or overwrite) the value directly into the cache.
             #[track_caller]
-            pub fn write(value: $struct) -> miette::Result<()> {
+            pub fn write(
+                value: $struct,
+                check: Option<&$crate::SyncCheck>,
+            ) -> miette::Result<()> {
                 use cached::Cached;
                 use moon_logger::trace;
                 use starbase_styles::color;
@@ -139,7 +152,7 @@ macro_rules! config_cache {
                 );
 
                 // Write to the file system
-                $writer(&value.path, &value)?;
+                $crate::write_synced_file(check, &value.path, |path| $writer(path, &value))?;
 
                 // And store in the cache
                 cache.cache_set(value.path.clone(), value);
@@ -218,16 +231,26 @@ macro_rules! config_cache_container {
             }
 
             /// If the file exists, load it from the file system, mutate it,
-            /// write it back to the file system and to the cache.
-            pub fn sync<P, F>(path: P, func: F) -> miette::Result<bool>
+            /// write it back to the file system and to the cache. When checking,
+            /// it's written to a temporary copy instead of the file system.
+            pub fn sync<P, F>(
+                path: P,
+                check: Option<&$crate::SyncCheck>,
+                func: F,
+            ) -> miette::Result<bool>
             where
                 P: AsRef<Path>,
                 F: FnOnce(&mut $struct) -> miette::Result<bool>
             {
-                $container::sync_with_name(path, $file, func)
+                $container::sync_with_name(path, $file, check, func)
             }
 
-            pub fn sync_with_name<P, N, F>(path: P, name: N, func: F) -> miette::Result<bool>
+            pub fn sync_with_name<P, N, F>(
+                path: P,
+                name: N,
+                check: Option<&$crate::SyncCheck>,
+                func: F,
+            ) -> miette::Result<bool>
             where
                 P: AsRef<Path>,
                 N: AsRef<str>,
@@ -266,7 +289,7 @@ macro_rules! config_cache_container {
                     );
 
                     // Write to the file system
-                    $writer(&path, &cfg)?;
+                    $crate::write_synced_file(check, &path, |path| $writer(path, &cfg))?;
 
                     // And store in the cache
                     cache.cache_set(path, cfg);
@@ -278,7 +301,11 @@ macro_rules! config_cache_container {
             }
 
             /// Write (or overwrite) the value directly into the cache.
-            pub fn write<P: AsRef<Path>>(path: P, value: $struct) -> miette::Result<()> {
+            pub fn write<P: AsRef<Path>>(
+                path: P,
+                value: $struct,
+                check: Option<&$crate::SyncCheck>,
+            ) -> miette::Result<()> {
                 use cached::Cached;
                 use moon_logger::trace;
                 use starbase_styles::color;
@@ -293,7 +320,7 @@ macro_rules! config_cache_container {
                 );
 
                 // Write to the file system
-                $writer(path, &value)?;
+                $crate::write_synced_file(check, path, |path| $writer(path, &value))?;
 
                 // And store in the cache
                 cache.cache_set(path.to_path_buf(), value);
@@ -383,16 +410,26 @@ macro_rules! config_cache_model {
             }
 
             /// If the file exists, load it from the file system, mutate it,
-            /// write it back to the file system and to the cache.
-            pub fn sync<P, F>(path: P, func: F) -> miette::Result<bool>
+            /// write it back to the file system and to the cache. When checking,
+            /// it's written to a temporary copy instead of the file system.
+            pub fn sync<P, F>(
+                path: P,
+                check: Option<&$crate::SyncCheck>,
+                func: F,
+            ) -> miette::Result<bool>
             where
                 P: AsRef<Path>,
                 F: FnOnce(&mut $container) -> miette::Result<bool>
             {
-                $container::sync_with_name(path, $file, func)
+                $container::sync_with_name(path, $file, check, func)
             }
 
-            pub fn sync_with_name<P, N, F>(path: P, name: N, func: F) -> miette::Result<bool>
+            pub fn sync_with_name<P, N, F>(
+                path: P,
+                name: N,
+                check: Option<&$crate::SyncCheck>,
+                func: F,
+            ) -> miette::Result<bool>
             where
                 P: AsRef<Path>,
                 N: AsRef<str>,
@@ -437,7 +474,7 @@ macro_rules! config_cache_model {
                     );
 
                     // Write to the file system
-                    $writer(&model.path, &model)?;
+                    $crate::write_synced_file(check, &model.path, |path| $writer(path, &model))?;
 
                     // And store in the cache
                     cache.cache_set(model.path, model.data);
@@ -1,11 +1,100 @@
 mod config;
 
-use rustc_hash::FxHashMap;
+#[doc(hidden)]
+pub use moon_common::{write_synced_file, SyncCheck};
+
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::fs;
 use std::path::Path;
 
 pub type LockfileDependencyVersions = FxHashMap<String, Vec<String>>;
 
+/// A mapping of package names to the names of packages they depend on.
+pub type LockfileDependencyGraph = FxHashMap<String, FxHashSet<String>>;
+
+/// Return the names of packages that were added, removed, or resolved
+/// to a different version between the base and head lockfiles.
+pub fn diff_lockfile_dependencies(
+    base: &LockfileDependencyVersions,
+    head: &LockfileDependencyVersions,
+) -> FxHashSet<String> {
+    let mut changed = FxHashSet::default();
+
+    for (name, head_versions) in head {
+        let is_same = base.get(name).is_some_and(|base_versions| {
+            let mut base_versions = base_versions.clone();
+            let mut head_versions = head_versions.clone();
+
+            base_versions.sort();
+            head_versions.sort();
+            base_versions == head_versions
+        });
+
+        if !is_same {
+            changed.insert(name.to_owned());
+        }
+    }
+
+    for name in base.keys() {
+        if !head.contains_key(name) {
+            changed.insert(name.to_owned());
+        }
+    }
+
+    changed
+}
+
+/// Return the provided packages, and all packages within the graph
+/// that depend on them, either directly or transitively.
+pub fn collect_lockfile_dependents(
+    graph: &LockfileDependencyGraph,
+    packages: FxHashSet<String>,
+) -> FxHashSet<String> {
+    let mut dependents: FxHashMap<&str, Vec<&str>> = FxHashMap::default();
+
+    for (name, deps) in graph {
+        for dep in deps {
+            dependents
+                .entry(dep.as_str())
+                .or_default()
+                .push(name.as_str());
+        }
+    }
+
+    let mut queue = packages.iter().cloned().collect::<Vec<_>>();
+    let mut collected = packages;
+
+    while let Some(name) = queue.pop() {
+        for dependent in dependents.get(name.as_str()).into_iter().flatten() {
+            if collected.insert(dependent.to_string()) {
+                queue.push(dependent.to_string());
+            }
+        }
+    }
+
+    collected
+}
+
+/// Return the provided packages, and all packages within the graph
+/// that they depend on, either directly or transitively.
+pub fn collect_lockfile_dependencies(
+    graph: &LockfileDependencyGraph,
+    packages: FxHashSet<String>,
+) -> FxHashSet<String> {
+    let mut queue = packages.iter().cloned().collect::<Vec<_>>();
+    let mut collected = packages;
+
+    while let Some(name) = queue.pop() {
+        for dep in graph.get(&name).into_iter().flatten() {
+            if collected.insert(dep.to_owned()) {
+                queue.push(dep.to_owned());
+            }
+        }
+    }
+
+    collected
+}
+
 #[inline]
 pub fn has_vendor_installed_dependencies<T: AsRef<Path>>(dir: T, vendor_dir: &str) -> bool {
     let vendor_path = dir.as_ref().join(vendor_dir);
@@ -0,0 +1,123 @@
+use moon_lang::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+fn create_versions<const N: usize>(list: [(&str, &[&str]); N]) -> LockfileDependencyVersions {
+    FxHashMap::from_iter(list.into_iter().map(|(name, versions)| {
+        (
+            name.to_owned(),
+            versions.iter().map(|v| v.to_string()).collect(),
+        )
+    }))
+}
+
+fn create_graph<const N: usize>(list: [(&str, &[&str]); N]) -> LockfileDependencyGraph {
+    FxHashMap::from_iter(list.into_iter().map(|(name, deps)| {
+        (
+            name.to_owned(),
+            deps.iter().map(|dep| dep.to_string()).collect(),
+        )
+    }))
+}
+
+fn create_set<const N: usize>(list: [&str; N]) -> FxHashSet<String> {
+    FxHashSet::from_iter(list.into_iter().map(|name| name.to_owned()))
+}
+
+mod diff_lockfile_dependencies {
+    use super::*;
+
+    #[test]
+    fn returns_nothing_if_same() {
+        let base = create_versions([("a", &["1.0.0"]), ("b", &["2.0.0", "1.0.0"])]);
+        let head = create_versions([("a", &["1.0.0"]), ("b", &["1.0.0", "2.0.0"])]);
+
+        assert_eq!(diff_lockfile_dependencies(&base, &head), create_set([]));
+    }
+
+    #[test]
+    fn returns_changed_versions() {
+        let base = create_versions([("a", &["1.0.0"]), ("b", &["1.0.0"])]);
+        let head = create_versions([("a", &["1.0.0"]), ("b", &["1.1.0"])]);
+
+        assert_eq!(diff_lockfile_dependencies(&base, &head), create_set(["b"]));
+    }
+
+    #[test]
+    fn returns_added_and_removed() {
+        let base = create_versions([("a", &["1.0.0"]), ("b", &["1.0.0"])]);
+        let head = create_versions([("a", &["1.0.0"]), ("c", &["1.0.0"])]);
+
+        assert_eq!(
+            diff_lockfile_dependencies(&base, &head),
+            create_set(["b", "c"])
+        );
+    }
+}
+
+mod collect_lockfile_dependents {
+    use super::*;
+
+    #[test]
+    fn includes_transitive_dependents() {
+        let graph = create_graph([
+            ("app", &["framework"]),
+            ("framework", &["utils"]),
+            ("utils", &[]),
+            ("other", &["lodash"]),
+        ]);
+
+        assert_eq!(
+            collect_lockfile_dependents(&graph, create_set(["utils"])),
+            create_set(["utils", "framework", "app"])
+        );
+    }
+
+    #[test]
+    fn handles_cycles() {
+        let graph = create_graph([("a", &["b"]), ("b", &["a"]), ("c", &[])]);
+
+        assert_eq!(
+            collect_lockfile_dependents(&graph, create_set(["a"])),
+            create_set(["a", "b"])
+        );
+    }
+
+    #[test]
+    fn includes_packages_not_in_graph() {
+        let graph = create_graph([("a", &[])]);
+
+        assert_eq!(
+            collect_lockfile_dependents(&graph, create_set(["removed"])),
+            create_set(["removed"])
+        );
+    }
+}
+
+mod collect_lockfile_dependencies {
+    use super::*;
+
+    #[test]
+    fn includes_transitive_dependencies() {
+        let graph = create_graph([
+            ("app", &["framework"]),
+            ("framework", &["utils"]),
+            ("utils", &[]),
+            ("other", &["lodash"]),
+        ]);
+
+        assert_eq!(
+            collect_lockfile_dependencies(&graph, create_set(["app"])),
+            create_set(["app", "framework", "utils"])
+        );
+    }
+
+    #[test]
+    fn handles_cycles() {
+        let graph = create_graph([("a", &["b"]), ("b", &["a"]), ("c", &[])]);
+
+        assert_eq!(
+            collect_lockfile_dependencies(&graph, create_set(["a"])),
+            create_set(["a", "b"])
+        );
+    }
+}
@@ -30,6 +30,9 @@ pub struct DenoJson {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scopes: Option<BTreeMap<String, BTreeMap<String, String>>>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vendor: Option<bool>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub workspace: Option<DenoJsonWorkspace>,
 
@@ -75,7 +78,7 @@ impl DenoJson {
             write_preserved_json(&self.path, self)?;
             self.dirty.clear();
 
-            DenoJson::write(self.clone())?;
+            DenoJson::write(self.clone(), None)?;
         }
 
         Ok(())
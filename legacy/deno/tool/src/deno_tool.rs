@@ -34,6 +34,27 @@ pub fn get_deno_env_paths(proto_env: &ProtoEnvironment) -> Vec<PathBuf> {
     paths
 }
 
+/// Return the arguments for installing only the dependencies
+/// imported by the provided entry files.
+pub fn get_install_entry_args(
+    v2: bool,
+    install_args: &[String],
+    entry_files: &[String],
+) -> Vec<String> {
+    let mut args = vec![];
+
+    if v2 {
+        args.extend(["install".into(), "--no-check".into()]);
+        args.extend(install_args.iter().cloned());
+        args.push("--entrypoint".into());
+    } else {
+        args.push("cache".into());
+    }
+
+    args.extend(entry_files.iter().cloned());
+    args
+}
+
 pub struct DenoTool {
     pub config: DenoConfig,
 
@@ -80,6 +101,35 @@ impl DenoTool {
             _ => false,
         })
     }
+
+    /// Deno doesn't have a concept of packages to focus on, so only install
+    /// the dependencies imported by the provided entry files.
+    #[instrument(skip_all)]
+    pub async fn install_entry_dependencies(&self, entry_files: &[String]) -> miette::Result<()> {
+        let workspace_root = get_workspace_root();
+
+        if entry_files.is_empty() {
+            return self.install_dependencies(&(), &workspace_root, true).await;
+        }
+
+        let mut cmd = self.create_command(&())?;
+
+        cmd.args(get_install_entry_args(
+            self.is_v2(),
+            &self.config.install_args,
+            entry_files,
+        ))
+        .cwd(&workspace_root)
+        .set_print_command(true);
+
+        if env::var("MOON_TEST_HIDE_INSTALL_OUTPUT").is_ok() {
+            cmd.exec_capture_output().await?;
+        } else {
+            cmd.exec_stream_output().await?;
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -237,7 +287,7 @@ impl DependencyManager<()> for DenoTool {
     async fn install_focused_dependencies(
         &self,
         _parent: &(),
-        _package_names: &[String], // Not supporetd
+        _package_names: &[String], // Not supported
         _production_only: bool,
     ) -> miette::Result<()> {
         Ok(())
@@ -0,0 +1,36 @@
+use moon_deno_tool::get_install_entry_args;
+
+mod install_entry_args {
+    use super::*;
+
+    fn strings(list: &[&str]) -> Vec<String> {
+        list.iter().map(|item| item.to_string()).collect()
+    }
+
+    #[test]
+    fn uses_install_with_entrypoints_for_v2() {
+        assert_eq!(
+            get_install_entry_args(
+                true,
+                &strings(&["--frozen"]),
+                &strings(&["apps/a/deps.ts", "apps/b/deps.ts"])
+            ),
+            strings(&[
+                "install",
+                "--no-check",
+                "--frozen",
+                "--entrypoint",
+                "apps/a/deps.ts",
+                "apps/b/deps.ts"
+            ])
+        );
+    }
+
+    #[test]
+    fn uses_cache_for_v1() {
+        assert_eq!(
+            get_install_entry_args(false, &strings(&["--frozen"]), &strings(&["deps.ts"])),
+            strings(&["cache", "deps.ts"])
+        );
+    }
+}
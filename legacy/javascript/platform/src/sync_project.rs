@@ -1,4 +1,4 @@
-use moon_common::{color, Id};
+use moon_common::{color, Id, SyncCheck};
 use moon_config::{BunConfig, DependencyScope, NodeConfig, NodeVersionFormat};
 use moon_node_lang::PackageJsonCache;
 use moon_project::Project;
@@ -10,6 +10,7 @@ use tracing::debug;
 
 pub struct JavaScriptSyncer<'app> {
     bun: bool,
+    check: Option<&'app SyncCheck>,
     project: &'app Project,
 
     // Settings
@@ -21,6 +22,7 @@ impl<'app> JavaScriptSyncer<'app> {
     pub fn for_bun(project: &'app Project, bun_config: &'app BunConfig) -> Self {
         Self {
             bun: true,
+            check: None,
             dependency_version_format: bun_config.dependency_version_format,
             sync_project_workspace_dependencies: bun_config.sync_project_workspace_dependencies,
             project,
@@ -30,12 +32,19 @@ impl<'app> JavaScriptSyncer<'app> {
     pub fn for_node(project: &'app Project, node_config: &'app NodeConfig) -> Self {
         Self {
             bun: false,
+            check: None,
             dependency_version_format: node_config.dependency_version_format,
             sync_project_workspace_dependencies: node_config.sync_project_workspace_dependencies,
             project,
         }
     }
 
+    /// Write files to the provided check, instead of the file system.
+    pub fn with_check(mut self, check: Option<&'app SyncCheck>) -> Self {
+        self.check = check;
+        self
+    }
+
     pub fn sync(&self, dependencies: &FxHashMap<Id, Arc<Project>>) -> miette::Result<bool> {
         let mut mutated = false;
 
@@ -128,7 +137,7 @@ impl<'app> JavaScriptSyncer<'app> {
             || !package_dev_deps.is_empty()
             || !package_peer_deps.is_empty()
         {
-            PackageJsonCache::sync(&self.project.root, |package_json| {
+            PackageJsonCache::sync(&self.project.root, self.check, |package_json| {
                 let mut mutated_package = false;
 
                 for (name, version) in package_prod_deps {
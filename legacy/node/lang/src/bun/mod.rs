@@ -1 +1,3 @@
-pub use moon_bun_lang::load_lockfile_dependencies;
+pub use moon_bun_lang::{
+    load_lockfile_dependencies, load_lockfile_dependency_graph, load_lockfile_package_versions,
+};
@@ -1,9 +1,10 @@
 use cached::proc_macro::cached;
 use miette::IntoDiagnostic;
-use moon_lang::{config_cache_container, LockfileDependencyVersions};
+use moon_lang::{config_cache_container, LockfileDependencyGraph, LockfileDependencyVersions};
 use package_lock_json_parser::{parse, PackageLockJson};
 use rustc_hash::FxHashMap;
-use starbase_utils::fs;
+use serde::Deserialize;
+use starbase_utils::{fs, json};
 use std::path::{Path, PathBuf};
 
 fn read_file(path: &Path) -> miette::Result<PackageLockJson> {
@@ -45,25 +46,11 @@ pub fn load_lockfile_dependencies(path: PathBuf) -> miette::Result<LockfileDepen
 
         // v2, v3
         for (name, dep) in lockfile.packages.unwrap_or_default() {
-            // node_modules/cacache
-            // node_modules/node-gyp/node_modules/cacache
-            if name.starts_with("node_modules") {
-                let name_parts = name.split("node_modules/");
-                let resolved_name = name_parts.last().unwrap_or_default();
-
-                add_dep(resolved_name, &dep.version, dep.integrity.as_ref());
-
-                // workspaces/libnpmdiff
-            } else if name.starts_with("workspaces") {
-                let name_parts = name.split("workspaces/");
-                let resolved_name = name_parts.last().unwrap_or_default();
-
-                add_dep(resolved_name, &dep.version, dep.integrity.as_ref());
-
-                // other
-            } else if !name.is_empty() {
-                add_dep(&name, &dep.version, dep.integrity.as_ref());
-            }
+            add_dep(
+                get_package_name(&name),
+                &dep.version,
+                dep.integrity.as_ref(),
+            );
         }
 
         // v1, v2
@@ -80,6 +67,127 @@ pub fn load_lockfile_dependencies(path: PathBuf) -> miette::Result<LockfileDepen
     Ok(deps)
 }
 
+/// Load the resolved version of each package, instead of the integrity
+/// hash, for use in reporting.
+#[cached(result)]
+pub fn load_lockfile_package_versions(path: PathBuf) -> miette::Result<LockfileDependencyVersions> {
+    let mut deps: LockfileDependencyVersions = FxHashMap::default();
+
+    let mut add_dep = |name: &str, version: &str| {
+        if name.is_empty() || version.is_empty() {
+            return;
+        }
+
+        let versions = deps.entry(name.to_owned()).or_default();
+
+        if !versions.iter().any(|existing| existing == version) {
+            versions.push(version.to_owned());
+        }
+    };
+
+    // v2, v3
+    if let Some(packages) = read_packages(&path)? {
+        for (key, package) in packages {
+            // Workspace members are only reported as third-party when installed
+            if key.contains("node_modules/") && !package.link {
+                add_dep(get_package_name(&key), &package.version);
+            }
+        }
+    }
+    // v1
+    else if let Some(lockfile) = PackageLockJsonCache::read(path)? {
+        for (name, dep) in lockfile.dependencies.unwrap_or_default() {
+            add_dep(&name, &dep.version);
+        }
+    }
+
+    for versions in deps.values_mut() {
+        versions.sort();
+    }
+
+    Ok(deps)
+}
+
+#[cached(result)]
+pub fn load_lockfile_dependency_graph(path: PathBuf) -> miette::Result<LockfileDependencyGraph> {
+    let mut graph: LockfileDependencyGraph = FxHashMap::default();
+
+    // v2, v3
+    if let Some(packages) = read_packages(&path)? {
+        for (key, package) in packages {
+            // Workspace members are keyed by their path, so use their name instead
+            let name = if key.contains("node_modules/") {
+                get_package_name(&key)
+            } else {
+                package.name.as_deref().unwrap_or_default()
+            };
+
+            if !name.is_empty() {
+                graph.entry(name.to_owned()).or_default().extend(
+                    package
+                        .dependencies
+                        .into_keys()
+                        .chain(package.optional_dependencies.into_keys()),
+                );
+            }
+        }
+    }
+    // v1
+    else if let Some(lockfile) = PackageLockJsonCache::read(path)? {
+        for (name, dep) in lockfile.dependencies.unwrap_or_default() {
+            graph
+                .entry(name)
+                .or_default()
+                .extend(dep.requires.unwrap_or_default().into_keys());
+        }
+    }
+
+    Ok(graph)
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct PackageLockPackages {
+    packages: Option<FxHashMap<String, PackageLockPackage>>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct PackageLockPackage {
+    dependencies: FxHashMap<String, String>,
+    link: bool,
+    name: Option<String>,
+    optional_dependencies: FxHashMap<String, String>,
+    version: String,
+}
+
+// The parser skips nested packages (`node_modules/a/node_modules/b`), which are
+// required to resolve every version and dependency, so read the packages as-is
+fn read_packages(path: &Path) -> miette::Result<Option<FxHashMap<String, PackageLockPackage>>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let lockfile: PackageLockPackages = json::read_file(path)?;
+
+    Ok(lockfile.packages)
+}
+
+fn get_package_name(name: &str) -> &str {
+    // node_modules/cacache
+    // node_modules/node-gyp/node_modules/cacache
+    if name.starts_with("node_modules") {
+        return name.split("node_modules/").last().unwrap_or_default();
+    }
+
+    // workspaces/libnpmdiff
+    if name.starts_with("workspaces") {
+        return name.split("workspaces/").last().unwrap_or_default();
+    }
+
+    name
+}
+
 #[cfg(test)]
 #[allow(clippy::disallowed_types)]
 mod tests {
@@ -87,6 +195,7 @@ mod tests {
     use moon_test_utils::{assert_fs::prelude::*, create_temp_dir, pretty_assertions::assert_eq};
     use moon_utils::string_vec;
     use package_lock_json_parser::V1Dependency;
+    use rustc_hash::FxHashSet;
     use std::collections::HashMap;
 
     #[test]
@@ -256,6 +365,96 @@ mod tests {
         temp.close().unwrap();
     }
 
+    #[test]
+    fn loads_package_versions() {
+        let temp = create_temp_dir();
+
+        temp.child("package-lock.json")
+            .write_str(
+                r#"
+{
+    "name": "moon-examples",
+    "lockfileVersion": 3,
+    "requires": true,
+    "packages": {
+        "node_modules/yaml": {
+            "version": "2.2.2",
+            "integrity": "sha512-CBKFWExMn46Foo4cldiChEzn7S7SRV+wqiluAb6xmueD/fGyRHIhX8m14vVGgeFWjN540nKCNVj6P21eQjgTuA=="
+        },
+        "node_modules/tap/node_modules/yaml": {
+            "version": "1.10.2",
+            "integrity": "sha512-r3vXyErRCYJ7wg28yvBY5VSoAF8ZvlcW9/BwUzEtUsjvX/DKs24dIkuwjtuprwJJHsbyUbLApepYTR1BN4uHrg=="
+        },
+        "node_modules/other/node_modules/yaml": {
+            "version": "2.2.2",
+            "integrity": "sha512-CBKFWExMn46Foo4cldiChEzn7S7SRV+wqiluAb6xmueD/fGyRHIhX8m14vVGgeFWjN540nKCNVj6P21eQjgTuA=="
+        }
+    }
+}"#,
+            )
+            .unwrap();
+
+        assert_eq!(
+            load_lockfile_package_versions(temp.path().join("package-lock.json")).unwrap(),
+            FxHashMap::from_iter([("yaml".to_owned(), string_vec!["1.10.2", "2.2.2"])])
+        );
+
+        temp.close().unwrap();
+    }
+
+    #[test]
+    fn loads_dependency_graph() {
+        let temp = create_temp_dir();
+
+        temp.child("package-lock.json")
+            .write_str(
+                r#"
+{
+    "name": "moon-examples",
+    "lockfileVersion": 3,
+    "requires": true,
+    "packages": {
+        "node_modules/react-dom": {
+            "version": "18.2.0",
+            "dependencies": {
+                "loose-envify": "^1.1.0",
+                "scheduler": "^0.23.0"
+            }
+        },
+        "node_modules/react-dom/node_modules/scheduler": {
+            "version": "0.23.0",
+            "dependencies": {
+                "loose-envify": "^1.1.0"
+            }
+        },
+        "node_modules/loose-envify": {
+            "version": "1.4.0"
+        }
+    }
+}"#,
+            )
+            .unwrap();
+
+        let graph = load_lockfile_dependency_graph(temp.path().join("package-lock.json")).unwrap();
+
+        assert_eq!(
+            graph,
+            FxHashMap::from_iter([
+                (
+                    "react-dom".to_owned(),
+                    FxHashSet::from_iter(["loose-envify".to_owned(), "scheduler".to_owned()])
+                ),
+                (
+                    "scheduler".to_owned(),
+                    FxHashSet::from_iter(["loose-envify".to_owned()])
+                ),
+                ("loose-envify".to_owned(), FxHashSet::default()),
+            ])
+        );
+
+        temp.close().unwrap();
+    }
+
     #[test]
     fn parses_complex_lockfile() {
         let content = reqwest::blocking::get(
@@ -5,7 +5,7 @@ pub mod workspace;
 
 use cached::proc_macro::cached;
 use dependency_path::PnpmDependencyPath;
-use moon_lang::{config_cache, LockfileDependencyVersions};
+use moon_lang::{config_cache, LockfileDependencyGraph, LockfileDependencyVersions};
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use serde_yml::Value;
@@ -34,6 +34,7 @@ pub struct PnpmLockPackage {
     pub prepare: Option<bool>,
     pub requires_build: Option<bool>,
     pub transitive_peer_dependencies: Option<Vec<String>>,
+    #[serde(default)]
     pub resolution: PnpmLockResolution,
 }
 
@@ -51,6 +52,8 @@ pub struct PnpmLock {
     pub lockfile_version: Value,
     pub importers: Option<FxHashMap<String, Value>>,
     pub packages: Option<FxHashMap<String, PnpmLockPackage>>,
+    // v9 moved dependencies of each package here
+    pub snapshots: Option<FxHashMap<String, PnpmLockPackage>>,
 
     #[serde(skip)]
     pub path: PathBuf,
@@ -86,11 +89,103 @@ pub fn load_lockfile_dependencies(path: PathBuf) -> miette::Result<LockfileDepen
     Ok(deps)
 }
 
+/// Load the resolved version of each package, instead of the integrity
+/// hash, for use in reporting.
+#[cached(result)]
+pub fn load_lockfile_package_versions(path: PathBuf) -> miette::Result<LockfileDependencyVersions> {
+    let mut deps: LockfileDependencyVersions = FxHashMap::default();
+
+    if let Some(lockfile) = PnpmLock::read(path)? {
+        let is_legacy = is_legacy_lockfile(&lockfile);
+
+        for package_key in lockfile.packages.unwrap_or_default().into_keys() {
+            let Some((name, version)) = parse_package_key(&package_key, is_legacy) else {
+                continue;
+            };
+
+            let versions = deps.entry(name).or_default();
+
+            if !versions.contains(&version) {
+                versions.push(version);
+            }
+        }
+    }
+
+    for versions in deps.values_mut() {
+        versions.sort();
+    }
+
+    Ok(deps)
+}
+
+#[cached(result)]
+pub fn load_lockfile_dependency_graph(path: PathBuf) -> miette::Result<LockfileDependencyGraph> {
+    let mut graph: LockfileDependencyGraph = FxHashMap::default();
+
+    if let Some(lockfile) = PnpmLock::read(path)? {
+        let is_legacy = is_legacy_lockfile(&lockfile);
+
+        for (package_key, details) in lockfile
+            .packages
+            .unwrap_or_default()
+            .into_iter()
+            .chain(lockfile.snapshots.unwrap_or_default())
+        {
+            let Some((name, _)) = parse_package_key(&package_key, is_legacy) else {
+                continue;
+            };
+
+            graph.entry(name).or_default().extend(
+                [details.dependencies, details.optional_dependencies]
+                    .into_iter()
+                    .flatten()
+                    .flat_map(|deps| deps.into_keys()),
+            );
+        }
+    }
+
+    Ok(graph)
+}
+
+// Lockfiles before v6 use `/name/version_peers` package keys,
+// while later versions use `/name@version(peers)` or `name@version(peers)`
+fn is_legacy_lockfile(lockfile: &PnpmLock) -> bool {
+    let version = match &lockfile.lockfile_version {
+        Value::Number(number) => number.as_f64(),
+        Value::String(string) => string.parse::<f64>().ok(),
+        _ => None,
+    };
+
+    version.is_some_and(|version| version < 6.0)
+}
+
+fn parse_package_key(key: &str, is_legacy: bool) -> Option<(String, String)> {
+    if is_legacy {
+        let path = PnpmDependencyPath::parse(key);
+
+        return path.name.zip(path.version);
+    }
+
+    let key = key.trim_start_matches('/');
+    let key = key.split_once('(').map(|(key, _)| key).unwrap_or(key);
+
+    // Skip the scope when finding the version separator
+    let index = key.get(1..)?.rfind('@')? + 1;
+    let (name, version) = (&key[..index], &key[index + 1..]);
+
+    if version.is_empty() {
+        return None;
+    }
+
+    Some((name.to_owned(), version.to_owned()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use moon_test_utils::{assert_fs::prelude::*, create_temp_dir, pretty_assertions::assert_eq};
     use moon_utils::string_vec;
+    use rustc_hash::FxHashSet;
     use serde_yml::{Mapping, Number};
 
     #[test]
@@ -234,6 +329,86 @@ packages:
         temp.close().unwrap();
     }
 
+    #[test]
+    fn loads_versions_and_graph() {
+        let temp = create_temp_dir();
+
+        temp.child("pnpm-lock.yaml")
+            .write_str(
+                r#"
+lockfileVersion: '9.0'
+
+importers:
+
+  .:
+    dependencies:
+      '@scope/a':
+        specifier: ^1.0.0
+        version: 1.0.0(b@2.0.0)
+
+packages:
+
+  '@scope/a@1.0.0':
+    resolution: {integrity: sha512-a}
+
+  b@2.0.0:
+    resolution: {integrity: sha512-b}
+
+  c@3.0.0:
+    resolution: {integrity: sha512-c}
+
+snapshots:
+
+  '@scope/a@1.0.0(b@2.0.0)':
+    dependencies:
+      c: 3.0.0
+    optionalDependencies:
+      b: 2.0.0
+
+  b@2.0.0: {}
+
+  c@3.0.0: {}
+"#,
+            )
+            .unwrap();
+
+        assert_eq!(
+            load_lockfile_package_versions(temp.path().join("pnpm-lock.yaml")).unwrap(),
+            FxHashMap::from_iter([
+                ("@scope/a".to_owned(), string_vec!["1.0.0"]),
+                ("b".to_owned(), string_vec!["2.0.0"]),
+                ("c".to_owned(), string_vec!["3.0.0"]),
+            ])
+        );
+
+        assert_eq!(
+            load_lockfile_dependency_graph(temp.path().join("pnpm-lock.yaml")).unwrap(),
+            FxHashMap::from_iter([
+                (
+                    "@scope/a".to_owned(),
+                    FxHashSet::from_iter(string_vec!["b", "c"])
+                ),
+                ("b".to_owned(), FxHashSet::default()),
+                ("c".to_owned(), FxHashSet::default()),
+            ])
+        );
+
+        temp.close().unwrap();
+    }
+
+    #[test]
+    fn parses_package_keys() {
+        assert_eq!(
+            parse_package_key("/@babel/core/7.18.9_supports-color@5.5.0", true),
+            Some(("@babel/core".to_owned(), "7.18.9".to_owned()))
+        );
+        assert_eq!(
+            parse_package_key("/@babel/core@7.18.9(supports-color@5.5.0)", false),
+            Some(("@babel/core".to_owned(), "7.18.9".to_owned()))
+        );
+        assert_eq!(parse_package_key("file:project", false), None);
+    }
+
     #[test]
     fn parses_complex_lockfile() {
         let content = reqwest::blocking::get(
@@ -1,5 +1,6 @@
 use cached::proc_macro::cached;
-use moon_lang::LockfileDependencyVersions;
+use miette::IntoDiagnostic;
+use moon_lang::{LockfileDependencyGraph, LockfileDependencyVersions};
 use rustc_hash::FxHashMap;
 use starbase_utils::fs;
 use std::path::PathBuf;
@@ -36,11 +37,58 @@ pub fn load_lockfile_dependencies(path: PathBuf) -> miette::Result<LockfileDepen
     Ok(deps)
 }
 
+/// Load the resolved version of each package, instead of the integrity
+/// hash, for use in reporting. Workspace packages are excluded.
+#[cached(result)]
+pub fn load_lockfile_package_versions(path: PathBuf) -> miette::Result<LockfileDependencyVersions> {
+    let mut deps: LockfileDependencyVersions = FxHashMap::default();
+    let yarn_lock_text = fs::read_file(&path)?;
+
+    for entry in parse_str(&yarn_lock_text).into_diagnostic()?.entries {
+        let is_workspace = entry
+            .descriptors
+            .iter()
+            .any(|(_, range)| range.starts_with("workspace:"));
+
+        if is_workspace || entry.version.is_empty() {
+            continue;
+        }
+
+        let versions = deps.entry(entry.name.to_owned()).or_default();
+
+        if !versions.iter().any(|existing| existing == entry.version) {
+            versions.push(entry.version.to_owned());
+        }
+    }
+
+    for versions in deps.values_mut() {
+        versions.sort();
+    }
+
+    Ok(deps)
+}
+
+#[cached(result)]
+pub fn load_lockfile_dependency_graph(path: PathBuf) -> miette::Result<LockfileDependencyGraph> {
+    let mut graph: LockfileDependencyGraph = FxHashMap::default();
+    let yarn_lock_text = fs::read_file(&path)?;
+
+    for entry in parse_str(&yarn_lock_text).into_diagnostic()?.entries {
+        graph
+            .entry(entry.name.to_owned())
+            .or_default()
+            .extend(entry.dependencies.iter().map(|(name, _)| name.to_string()));
+    }
+
+    Ok(graph)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use moon_test_utils::{assert_fs::prelude::*, create_temp_dir, pretty_assertions::assert_eq};
     use moon_utils::string_vec;
+    use rustc_hash::FxHashSet;
 
     #[test]
     fn parses_lockfile() {
@@ -126,6 +174,74 @@ __metadata:
         temp.close().unwrap();
     }
 
+    #[test]
+    fn loads_versions_and_graph() {
+        let temp = create_temp_dir();
+
+        temp.child("yarn.lock")
+            .write_str(
+                r#"
+# This file is generated by running "yarn install" inside your project.
+# Manual changes might be lost - proceed with caution!
+
+__metadata:
+  version: 6
+  cacheKey: 8
+
+"is-even@npm:^1.0.0":
+  version: 1.0.0
+  resolution: "is-even@npm:1.0.0"
+  dependencies:
+    is-odd: ^0.1.2
+  checksum: 0267
+  languageName: node
+  linkType: hard
+
+"is-odd@npm:^0.1.2":
+  version: 0.1.2
+  resolution: "is-odd@npm:0.1.2"
+  checksum: 1460
+  languageName: node
+  linkType: hard
+
+"test@workspace:.":
+  version: 0.0.0-use.local
+  resolution: "test@workspace:."
+  dependencies:
+    is-even: ^1.0.0
+  languageName: unknown
+  linkType: soft
+"#
+                .trim(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            load_lockfile_package_versions(temp.path().join("yarn.lock")).unwrap(),
+            FxHashMap::from_iter([
+                ("is-even".to_owned(), string_vec!["1.0.0"]),
+                ("is-odd".to_owned(), string_vec!["0.1.2"]),
+            ])
+        );
+
+        assert_eq!(
+            load_lockfile_dependency_graph(temp.path().join("yarn.lock")).unwrap(),
+            FxHashMap::from_iter([
+                (
+                    "is-even".to_owned(),
+                    FxHashSet::from_iter(string_vec!["is-odd"])
+                ),
+                ("is-odd".to_owned(), FxHashSet::default()),
+                (
+                    "test".to_owned(),
+                    FxHashSet::from_iter(string_vec!["is-even"])
+                ),
+            ])
+        );
+
+        temp.close().unwrap();
+    }
+
     #[test]
     fn parses_complex_classic_lockfile() {
         let content = reqwest::blocking::get(
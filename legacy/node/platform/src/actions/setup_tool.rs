@@ -94,7 +94,7 @@ pub async fn setup_tool(node: &NodeTool, workspace_root: &Path) -> miette::Resul
         .unwrap_or(packages_root);
 
     // Sync values to root `package.json`
-    PackageJsonCache::sync(&packages_root, |package_json| {
+    PackageJsonCache::sync(&packages_root, None, |package_json| {
         let added_manager = add_package_manager(&node.config, package_json);
         let added_constraint = add_engines_constraint(&node.config, package_json);
 
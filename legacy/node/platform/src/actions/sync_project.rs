@@ -1,4 +1,4 @@
-use moon_common::Id;
+use moon_common::{Id, SyncCheck};
 use moon_config::{NodeConfig, TypeScriptConfig};
 use moon_javascript_platform::JavaScriptSyncer;
 use moon_project::Project;
@@ -13,15 +13,22 @@ pub async fn sync_project(
     workspace_root: &Path,
     node_config: &NodeConfig,
     typescript_config: &Option<TypeScriptConfig>,
+    check: Option<&SyncCheck>,
 ) -> miette::Result<bool> {
     let mut mutated = false;
 
-    if JavaScriptSyncer::for_node(project, node_config).sync(dependencies)? {
+    if JavaScriptSyncer::for_node(project, node_config)
+        .with_check(check)
+        .sync(dependencies)?
+    {
         mutated = true;
     }
 
     if let Some(config) = &typescript_config {
-        if TypeScriptSyncer::new(project, config, workspace_root).sync(dependencies)? {
+        if TypeScriptSyncer::new(project, config, workspace_root)
+            .with_check(check)
+            .sync(dependencies)?
+        {
             mutated = true;
         }
     }
@@ -391,7 +391,7 @@ impl Platform for NodePlatform {
     #[instrument(skip_all)]
     async fn sync_project(
         &self,
-        _context: &ActionContext,
+        context: &ActionContext,
         project: &Project,
         dependencies: &FxHashMap<Id, Arc<Project>>,
     ) -> miette::Result<bool> {
@@ -401,6 +401,7 @@ impl Platform for NodePlatform {
             &self.workspace_root,
             &self.config,
             &self.typescript_config,
+            context.sync_check.as_deref(),
         )
         .await?;
 
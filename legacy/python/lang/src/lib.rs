@@ -1,4 +1,5 @@
 pub mod pip;
+pub mod pyproject;
 pub mod uv;
 
 pub use moon_lang::LockfileDependencyVersions;
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use starbase_utils::toml;
+use std::path::Path;
+
+// This isn't everything, just what we care about
+// https://packaging.python.org/en/latest/specifications/pyproject-toml/
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct PyProjectProject {
+    pub name: Option<String>,
+    pub requires_python: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct UvToolWorkspace {
+    pub exclude: Vec<String>,
+    pub members: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct UvTool {
+    pub required_version: Option<String>,
+    pub workspace: Option<UvToolWorkspace>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+pub struct PyProjectTool {
+    pub uv: Option<UvTool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default)]
+pub struct PyProject {
+    pub project: Option<PyProjectProject>,
+    pub tool: Option<PyProjectTool>,
+}
+
+impl PyProject {
+    pub fn read<P: AsRef<Path>>(dir: P) -> miette::Result<Option<PyProject>> {
+        let path = dir.as_ref().join("pyproject.toml");
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        Ok(Some(toml::read_file(path)?))
+    }
+
+    pub fn get_uv_tool(&self) -> Option<&UvTool> {
+        self.tool.as_ref().and_then(|tool| tool.uv.as_ref())
+    }
+
+    pub fn get_uv_workspace_members(&self) -> Vec<String> {
+        self.get_uv_tool()
+            .and_then(|uv| uv.workspace.as_ref())
+            .map(|workspace| workspace.members.clone())
+            .unwrap_or_default()
+    }
+}
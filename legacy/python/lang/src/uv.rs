@@ -1,9 +1,10 @@
 use cached::proc_macro::cached;
-use moon_lang::{config_cache_container, LockfileDependencyVersions};
+use moon_lang::{config_cache_container, LockfileDependencyGraph, LockfileDependencyVersions};
 use pyproject_toml::PyProjectToml;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use starbase_utils::{fs, toml};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 fn read_file(path: &Path) -> miette::Result<PyProjectToml> {
@@ -24,12 +25,21 @@ pub struct UvLockPackageSdist {
     pub hash: String,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct UvLockDependency {
+    pub name: String,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(default, rename_all = "kebab-case")]
 pub struct UvLockPackage {
     pub name: String,
     pub version: String,
     pub sdist: UvLockPackageSdist,
+    pub dependencies: Vec<UvLockDependency>,
+    pub dev_dependencies: BTreeMap<String, Vec<UvLockDependency>>,
+    pub optional_dependencies: BTreeMap<String, Vec<UvLockDependency>>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
@@ -51,3 +61,66 @@ pub fn load_lockfile_dependencies(path: PathBuf) -> miette::Result<LockfileDepen
 
     Ok(deps)
 }
+
+/// Load the resolved version of each package, without the source
+/// distribution hash, for use in reporting.
+#[cached(result)]
+pub fn load_lockfile_package_versions(path: PathBuf) -> miette::Result<LockfileDependencyVersions> {
+    let mut deps: LockfileDependencyVersions = FxHashMap::default();
+    let lockfile: UvLock = toml::read_file(&path)?;
+
+    for package in lockfile.package {
+        let versions = deps.entry(package.name).or_default();
+
+        if !package.version.is_empty() && !versions.contains(&package.version) {
+            versions.push(package.version);
+        }
+    }
+
+    Ok(deps)
+}
+
+#[cached(result)]
+pub fn load_lockfile_dependency_graph(path: PathBuf) -> miette::Result<LockfileDependencyGraph> {
+    create_dependency_graph(path, true)
+}
+
+/// Load a dependency graph that excludes development dependencies
+/// and optional dependencies (extras).
+#[cached(result)]
+pub fn load_lockfile_production_dependency_graph(
+    path: PathBuf,
+) -> miette::Result<LockfileDependencyGraph> {
+    create_dependency_graph(path, false)
+}
+
+fn create_dependency_graph(
+    path: PathBuf,
+    include_dev_and_optional: bool,
+) -> miette::Result<LockfileDependencyGraph> {
+    let mut graph: LockfileDependencyGraph = FxHashMap::default();
+    let lockfile: UvLock = toml::read_file(&path)?;
+
+    for package in lockfile.package {
+        let mut deps = package
+            .dependencies
+            .into_iter()
+            .map(|dep| dep.name)
+            .collect::<Vec<_>>();
+
+        if include_dev_and_optional {
+            deps.extend(
+                package
+                    .dev_dependencies
+                    .into_values()
+                    .chain(package.optional_dependencies.into_values())
+                    .flatten()
+                    .map(|dep| dep.name),
+            );
+        }
+
+        graph.entry(package.name).or_default().extend(deps);
+    }
+
+    Ok(graph)
+}
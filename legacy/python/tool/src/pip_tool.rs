@@ -58,6 +58,42 @@ impl PipTool {
             );
         }
     }
+
+    /// Requirements files don't name the packages they belong to, so install
+    /// the dependencies of each provided `requirements.txt` instead.
+    #[instrument(skip_all)]
+    pub async fn install_requirements_files(
+        &self,
+        python: &PythonTool,
+        requirements_files: &[String],
+    ) -> miette::Result<()> {
+        if requirements_files.is_empty() {
+            return Ok(());
+        }
+
+        let workspace_root = get_workspace_root();
+        let mut cmd = self.create_command(python)?;
+
+        self.inject_command_paths(&mut cmd, python, &workspace_root);
+
+        cmd.arg("install");
+
+        for file in requirements_files {
+            cmd.args(["-r", file]);
+        }
+
+        cmd.args(&self.config.install_args)
+            .cwd(&workspace_root)
+            .set_print_command(true);
+
+        if env::var("MOON_TEST_HIDE_INSTALL_OUTPUT").is_ok() {
+            cmd.exec_capture_output().await?;
+        } else {
+            cmd.exec_stream_output().await?;
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -221,10 +221,54 @@ impl DependencyManager<PythonTool> for UvTool {
     #[instrument(skip_all)]
     async fn install_focused_dependencies(
         &self,
-        _python: &PythonTool,
-        _packages: &[String],
-        _production_only: bool,
+        python: &PythonTool,
+        packages: &[String],
+        production_only: bool,
     ) -> miette::Result<()> {
+        let workspace_root = get_workspace_root();
+
+        // Only a single package can be synced at a time, so sync the first
+        // exactly (removing extraneous packages), and the rest inexactly
+        let mut package_args = packages
+            .iter()
+            .enumerate()
+            .map(|(index, package)| {
+                let mut args = vec!["--package", package.as_str()];
+
+                if index > 0 {
+                    args.push("--inexact");
+                }
+
+                args
+            })
+            .collect::<Vec<_>>();
+
+        if package_args.is_empty() {
+            package_args.push(vec![]);
+        }
+
+        for args in package_args {
+            let mut cmd = self.create_command(python)?;
+
+            self.inject_command_paths(&mut cmd, python, &workspace_root);
+
+            cmd.arg("sync").args(args);
+
+            if production_only {
+                cmd.arg("--no-dev");
+            }
+
+            cmd.args(&self.config.sync_args)
+                .cwd(&workspace_root)
+                .set_print_command(true);
+
+            if env::var("MOON_TEST_HIDE_INSTALL_OUTPUT").is_ok() {
+                cmd.exec_capture_output().await?;
+            } else {
+                cmd.exec_stream_output().await?;
+            }
+        }
+
         Ok(())
     }
 }
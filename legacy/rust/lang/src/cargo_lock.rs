@@ -1,7 +1,7 @@
 use cached::proc_macro::cached;
 use cargo_lock::Lockfile as CargoLock;
 use miette::IntoDiagnostic;
-use moon_lang::{config_cache_container, LockfileDependencyVersions};
+use moon_lang::{config_cache_container, LockfileDependencyGraph, LockfileDependencyVersions};
 use rustc_hash::FxHashMap;
 use std::path::{Path, PathBuf};
 
@@ -33,3 +33,44 @@ pub fn load_lockfile_dependencies(path: PathBuf) -> miette::Result<LockfileDepen
 
     Ok(deps)
 }
+
+/// Load the resolved version of each package, instead of the checksum,
+/// for use in reporting.
+#[cached(result)]
+pub fn load_lockfile_package_versions(path: PathBuf) -> miette::Result<LockfileDependencyVersions> {
+    let mut deps: LockfileDependencyVersions = FxHashMap::default();
+
+    if let Some(lockfile) = CargoLockCache::read(path)? {
+        for package in lockfile.packages {
+            let version = package.version.to_string();
+            let versions = deps.entry(package.name.as_str().to_string()).or_default();
+
+            if !versions.contains(&version) {
+                versions.push(version);
+            }
+        }
+    }
+
+    Ok(deps)
+}
+
+#[cached(result)]
+pub fn load_lockfile_dependency_graph(path: PathBuf) -> miette::Result<LockfileDependencyGraph> {
+    let mut graph: LockfileDependencyGraph = FxHashMap::default();
+
+    if let Some(lockfile) = CargoLockCache::read(path)? {
+        for package in lockfile.packages {
+            graph
+                .entry(package.name.as_str().to_string())
+                .or_default()
+                .extend(
+                    package
+                        .dependencies
+                        .iter()
+                        .map(|dep| dep.name.as_str().to_string()),
+                );
+        }
+    }
+
+    Ok(graph)
+}
@@ -1,5 +1,6 @@
 use moon_rust_lang::cargo_lock::*;
 use moon_test_utils::{assert_debug_snapshot, create_sandbox};
+use rustc_hash::FxHashSet;
 
 #[test]
 fn resolves_lockfile_dep_checksums() {
@@ -16,3 +17,31 @@ fn resolves_lockfile_dep_checksums_for_nonworkspace() {
 
     assert_debug_snapshot!(deps);
 }
+
+#[test]
+fn resolves_lockfile_package_versions() {
+    let sandbox = create_sandbox("rust/workspaces");
+    let versions = load_lockfile_package_versions(sandbox.path().join("Cargo.lock")).unwrap();
+
+    assert_eq!(versions.get("ci_env").unwrap(), &vec!["0.2.0".to_owned()]);
+    assert_eq!(
+        versions.get("path-deps").unwrap(),
+        &vec!["0.0.1".to_owned()]
+    );
+}
+
+#[test]
+fn resolves_lockfile_dependency_graph() {
+    let sandbox = create_sandbox("rust/workspaces");
+    let graph = load_lockfile_dependency_graph(sandbox.path().join("Cargo.lock")).unwrap();
+
+    assert_eq!(
+        graph.get("path-deps").unwrap(),
+        &FxHashSet::from_iter(["inherited-dep".to_owned(), "normal-dep".to_owned()])
+    );
+    assert_eq!(
+        graph.get("normal-dep").unwrap(),
+        &FxHashSet::from_iter(["ci_env".to_owned()])
+    );
+    assert!(graph.get("bin-crate").unwrap().is_empty());
+}
@@ -7,7 +7,7 @@ use moon_action_context::ActionContext;
 use moon_common::{
     is_ci,
     path::{exe_name, is_root_level_source, WorkspaceRelativePath, WorkspaceRelativePathBuf},
-    Id,
+    remove_synced_file, write_synced_file, Id,
 };
 use moon_config::{
     BinEntry, DependencyConfig, DependencyScope, DependencySource, HasherConfig, PlatformType,
@@ -472,10 +472,11 @@ impl Platform for RustPlatform {
     #[instrument(skip_all)]
     async fn sync_project(
         &self,
-        _context: &ActionContext,
+        context: &ActionContext,
         project: &Project,
         _dependencies: &FxHashMap<Id, Arc<Project>>,
     ) -> miette::Result<bool> {
+        let check = context.sync_check.as_deref();
         let mut mutated_files = false;
 
         let lockfile_path = find_cargo_lock(&project.root, &self.workspace_root);
@@ -499,13 +500,17 @@ impl Platform for RustPlatform {
             let legacy_contents = fs::read_file(&legacy_toolchain_path)?;
 
             if legacy_contents.contains("[toolchain]") {
-                fs::rename(&legacy_toolchain_path, &toolchain_path)?;
+                write_synced_file(check, &toolchain_path, |path| {
+                    Ok(fs::write_file(path, &legacy_contents)?)
+                })?;
+                remove_synced_file(check, &legacy_toolchain_path)?;
             } else {
-                fs::remove_file(&legacy_toolchain_path)?;
+                remove_synced_file(check, &legacy_toolchain_path)?;
 
                 ToolchainTomlCache::write(
                     &toolchain_path,
                     ToolchainToml::new_with_channel(&legacy_contents),
+                    check,
                 )?;
             }
 
@@ -517,7 +522,7 @@ impl Platform for RustPlatform {
             let version = self.config.version.as_ref().map(|v| v.to_string()).unwrap();
 
             if toolchain_path.exists() {
-                ToolchainTomlCache::sync(toolchain_path, |cfg| {
+                ToolchainTomlCache::sync(toolchain_path, check, |cfg| {
                     if cfg.toolchain.channel.as_ref() != Some(&version) {
                         debug!(
                             target: LOG_TARGET,
@@ -544,6 +549,7 @@ impl Platform for RustPlatform {
                 ToolchainTomlCache::write(
                     toolchain_path,
                     ToolchainToml::new_with_channel(&version),
+                    check,
                 )?;
 
                 mutated_files = true;
@@ -1,4 +1,4 @@
-use moon_common::Id;
+use moon_common::{write_synced_file, Id, SyncCheck};
 use moon_config::TypeScriptConfig;
 use moon_node_lang::PackageJsonCache;
 use moon_project::Project;
@@ -20,6 +20,7 @@ use std::{
 use tracing::{debug, instrument};
 
 pub struct TypeScriptSyncer<'app> {
+    check: Option<&'app SyncCheck>,
     project: &'app Project,
     typescript_config: &'app TypeScriptConfig,
     types_root: PathBuf,
@@ -32,12 +33,19 @@ impl<'app> TypeScriptSyncer<'app> {
         workspace_root: &'app Path,
     ) -> Self {
         Self {
+            check: None,
             types_root: path::normalize(workspace_root.join(&typescript_config.root)),
             project,
             typescript_config,
         }
     }
 
+    /// Write files to the provided check, instead of the file system.
+    pub fn with_check(mut self, check: Option<&'app SyncCheck>) -> Self {
+        self.check = check;
+        self
+    }
+
     pub fn should_include_project_reference_sources(&self) -> bool {
         self.project
             .config
@@ -111,7 +119,9 @@ impl<'app> TypeScriptSyncer<'app> {
             ..TsConfigJson::default()
         };
 
-        json::write_file(&tsconfig_path, &json, true)?;
+        write_synced_file(self.check, &tsconfig_path, |path| {
+            Ok(json::write_file(path, &json, true)?)
+        })?;
 
         Ok(true)
     }
@@ -122,26 +132,33 @@ impl<'app> TypeScriptSyncer<'app> {
         let tsconfig_root_name = &self.typescript_config.root_config_file_name;
         let tsconfig_project_name = &self.typescript_config.project_config_file_name;
 
-        TsConfigJsonCache::sync_with_name(&self.types_root, tsconfig_root_name, |tsconfig_json| {
-            // Don't sync a root project to itself
-            if self.project.root == self.types_root && tsconfig_project_name == tsconfig_root_name {
-                return Ok(false);
-            }
+        TsConfigJsonCache::sync_with_name(
+            &self.types_root,
+            tsconfig_root_name,
+            self.check,
+            |tsconfig_json| {
+                // Don't sync a root project to itself
+                if self.project.root == self.types_root
+                    && tsconfig_project_name == tsconfig_root_name
+                {
+                    return Ok(false);
+                }
 
-            if self.project.root.join(tsconfig_project_name).exists()
-                && tsconfig_json.add_project_ref(&self.project.root, tsconfig_project_name)?
-            {
-                debug!(
-                    "Syncing {} as a project reference to the root {}",
-                    color::id(&self.project.id),
-                    tsconfig_root_name
-                );
+                if self.project.root.join(tsconfig_project_name).exists()
+                    && tsconfig_json.add_project_ref(&self.project.root, tsconfig_project_name)?
+                {
+                    debug!(
+                        "Syncing {} as a project reference to the root {}",
+                        color::id(&self.project.id),
+                        tsconfig_root_name
+                    );
 
-                return Ok(true);
-            }
+                    return Ok(true);
+                }
 
-            Ok(false)
-        })
+                Ok(false)
+            },
+        )
     }
 
     // Sync a project's `tsconfig.json`.
@@ -153,6 +170,7 @@ impl<'app> TypeScriptSyncer<'app> {
         TsConfigJsonCache::sync_with_name(
             &self.project.root,
             &self.typescript_config.project_config_file_name,
+            self.check,
             |tsconfig_json| {
                 let mut mutated_tsconfig = false;
                 let should_include_sources = self.should_include_project_reference_sources();
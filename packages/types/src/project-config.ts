@@ -47,6 +47,11 @@ export interface ProjectDockerFileConfig {
 	buildTask: string | null;
 	/** The base Docker image. */
 	image: string | null;
+	/**
+	 * The base Docker image for a runtime-only final stage. When defined,
+	 * only the build outputs and production dependencies are copied.
+	 */
+	runtimeImage: string | null;
 	/** A task within the current project for starting the project. */
 	startTask: string | null;
 }
@@ -313,6 +318,11 @@ export interface PartialProjectDockerFileConfig {
 	buildTask?: string | null;
 	/** The base Docker image. */
 	image?: string | null;
+	/**
+	 * The base Docker image for a runtime-only final stage. When defined,
+	 * only the build outputs and production dependencies are copied.
+	 */
+	runtimeImage?: string | null;
 	/** A task within the current project for starting the project. */
 	startTask?: string | null;
 }
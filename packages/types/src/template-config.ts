@@ -2,11 +2,15 @@
 
 /* eslint-disable */
 
+/** The format in which to merge a template file with an existing file. */
+export type TemplateMergeType = 'ini' | 'json' | 'toml' | 'yaml';
+
 /** Docs: https://moonrepo.dev/docs/config/template#frontmatter */
 export interface TemplateFrontmatterConfig {
 	/** @default 'https://moonrepo.dev/schemas/template-frontmatter.json' */
 	$schema?: string;
 	force: boolean;
+	merge: TemplateMergeType | null;
 	skip: boolean;
 	to: string | null;
 }
@@ -16,6 +20,7 @@ export interface PartialTemplateFrontmatterConfig {
 	/** @default 'https://moonrepo.dev/schemas/template-frontmatter.json' */
 	$schema?: string | null;
 	force?: boolean | null;
+	merge?: TemplateMergeType | null;
 	skip?: boolean | null;
 	to?: string | null;
 }
@@ -98,6 +103,25 @@ export type TemplateVariable =
 	| TemplateVariableNumberSetting
 	| TemplateVariableStringSetting;
 
+/** Configures actions to run after code has been generated. */
+export interface TemplatePostGenerateConfig {
+	/**
+	 * Add the destination as a project to the `projects` setting
+	 * in `.moon/workspace.yml`, if not already configured.
+	 */
+	addToWorkspace: boolean;
+	/**
+	 * A list of shell commands to execute in order, from the destination.
+	 * Supports variable interpolation.
+	 */
+	commands: string[];
+	/**
+	 * A list of targets to run through the action pipeline,
+	 * after all commands have executed. Supports variable interpolation.
+	 */
+	targets: string[];
+}
+
 /**
  * Configures a template and its files to be scaffolded.
  * Docs: https://moonrepo.dev/docs/config/template
@@ -116,6 +140,8 @@ export interface TemplateConfig {
 	extends: string | string[];
 	/** Overrides the ID of the template, instead of using the folder name. */
 	id: string | null;
+	/** Actions to run after code has been generated. */
+	postGenerate: TemplatePostGenerateConfig;
 	/** A human-readable title for the template. */
 	title: string;
 	/**
@@ -123,6 +149,11 @@ export interface TemplateConfig {
 	 * Variables can also be populated by passing command line arguments.
 	 */
 	variables: Record<string, TemplateVariable>;
+	/**
+	 * The version of the template, which is recorded in the lock file
+	 * of generated code, and compared against when updating.
+	 */
+	version: string | null;
 }
 
 /** Configuration for a template variable. */
@@ -203,6 +234,25 @@ export type PartialTemplateVariable =
 	| PartialTemplateVariableNumberSetting
 	| PartialTemplateVariableStringSetting;
 
+/** Configures actions to run after code has been generated. */
+export interface PartialTemplatePostGenerateConfig {
+	/**
+	 * Add the destination as a project to the `projects` setting
+	 * in `.moon/workspace.yml`, if not already configured.
+	 */
+	addToWorkspace?: boolean | null;
+	/**
+	 * A list of shell commands to execute in order, from the destination.
+	 * Supports variable interpolation.
+	 */
+	commands?: string[] | null;
+	/**
+	 * A list of targets to run through the action pipeline,
+	 * after all commands have executed. Supports variable interpolation.
+	 */
+	targets?: string[] | null;
+}
+
 /**
  * Configures a template and its files to be scaffolded.
  * Docs: https://moonrepo.dev/docs/config/template
@@ -221,6 +271,8 @@ export interface PartialTemplateConfig {
 	extends?: string | string[] | null;
 	/** Overrides the ID of the template, instead of using the folder name. */
 	id?: string | null;
+	/** Actions to run after code has been generated. */
+	postGenerate?: PartialTemplatePostGenerateConfig | null;
 	/** A human-readable title for the template. */
 	title?: string | null;
 	/**
@@ -228,4 +280,9 @@ export interface PartialTemplateConfig {
 	 * Variables can also be populated by passing command line arguments.
 	 */
 	variables?: Record<string, PartialTemplateVariable> | null;
+	/**
+	 * The version of the template, which is recorded in the lock file
+	 * of generated code, and compared against when updating.
+	 */
+	version?: string | null;
 }
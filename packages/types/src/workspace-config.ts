@@ -34,8 +34,46 @@ export interface CodeownersConfig {
 	syncOnRun: boolean;
 }
 
+/** Denies relationships between projects that match the provided globs. */
+export interface ConstraintsDenyConfig {
+	/** List of project ID globs that the rule applies to. */
+	from: string[];
+	/** An optional reason for the rule, displayed in violations. */
+	reason: string | null;
+	/** List of project ID globs that cannot be depended on. */
+	to: string[];
+}
+
+/**
+ * Allows a relationship between projects that would otherwise be
+ * denied by a layer or deny rule.
+ */
+export interface ConstraintsExceptionConfig {
+	/** The date (YYYY-MM-DD) after which the exception no longer applies. */
+	expires: string | null;
+	/** A project ID glob of the dependent project. */
+	from: string;
+	/** An optional reason for the exception. */
+	reason: string | null;
+	/** A project ID glob of the dependency project. */
+	to: string;
+}
+
+/** A layer within a layered architecture. */
+export interface ConstraintsLayerConfig {
+	/** Unique ID of the layer. */
+	id: string;
+	/** List of project ID globs that belong to the layer. */
+	projects: string[];
+}
+
 /** Configures boundaries and constraints between projects. */
 export interface ConstraintsConfig {
+	/**
+	 * List of rules that deny relationships between projects,
+	 * based on project ID globs.
+	 */
+	denyRelationships: ConstraintsDenyConfig[];
 	/**
 	 * Enforces relationships between projects based on each project's
 	 * `type` setting.
@@ -43,6 +81,26 @@ export interface ConstraintsConfig {
 	 * @default true
 	 */
 	enforceProjectTypeRelationships?: boolean;
+	/**
+	 * List of relationships that are allowed, even when denied by
+	 * `layers` or `denyRelationships`. Can be configured to expire.
+	 */
+	exceptions: ConstraintsExceptionConfig[];
+	/**
+	 * List of layers, ordered from highest to lowest. A project within a
+	 * layer may only depend on projects within the same or a lower layer.
+	 */
+	layers: ConstraintsLayerConfig[];
+	/**
+	 * The maximum number of projects, including transitive projects,
+	 * that a project may depend on.
+	 */
+	maxDependencies: number | null;
+	/**
+	 * The maximum number of projects, including transitive projects,
+	 * that may depend on a project.
+	 */
+	maxDependents: number | null;
 	/**
 	 * Enforces relationships between projects based on each project's
 	 * `tags` setting. Requires a mapping of tags, to acceptable tags.
@@ -192,10 +250,55 @@ export interface HasherConfig {
 	warnOnMissingInputs?: boolean;
 }
 
+/** Configures a single webhook endpoint, and which events are sent to it. */
+export interface NotifierWebhookConfig {
+	/**
+	 * Group high-volume action and task events into batches of this size,
+	 * and send each batch as a single request. When `0`, batching is disabled.
+	 */
+	batchSize: number;
+	/**
+	 * List of event types to send, with support for a trailing
+	 * wildcard (`action.*`). When empty, all events are sent.
+	 */
+	events: string[];
+	/**
+	 * Only send events that represent a failure, such as a failed
+	 * action, or a pipeline that did not complete successfully.
+	 */
+	failuresOnly: boolean;
+	/** HTTP headers to inject into every request. */
+	headers: Record<string, string>;
+	/**
+	 * The maximum amount of times to retry a failed request,
+	 * with an exponential backoff between each attempt. Cannot exceed 10.
+	 *
+	 * @default 3
+	 */
+	retries?: number;
+	/**
+	 * The name of an environment variable that contains a secret, used to
+	 * sign payloads with HMAC-SHA256 via the `X-Moon-Signature` header.
+	 */
+	secret: string | null;
+	/**
+	 * A Tera template that is rendered to create the request body,
+	 * instead of using the default JSON payload.
+	 */
+	template: string | null;
+	/** A secure URL in which to send webhooks to. */
+	url: string;
+}
+
 /** Configures how and where notifications are sent. */
 export interface NotifierConfig {
 	/** A secure URL in which to send webhooks to. */
 	webhookUrl: string | null;
+	/**
+	 * A list of webhook endpoints, each with their own event
+	 * filtering, payload template, and signing secret.
+	 */
+	webhooks: NotifierWebhookConfig[];
 }
 
 /** Configures projects in the workspace, using both globs and explicit source paths. */
@@ -473,8 +576,46 @@ export interface PartialCodeownersConfig {
 	syncOnRun?: boolean | null;
 }
 
+/** Denies relationships between projects that match the provided globs. */
+export interface PartialConstraintsDenyConfig {
+	/** List of project ID globs that the rule applies to. */
+	from?: string[] | null;
+	/** An optional reason for the rule, displayed in violations. */
+	reason?: string | null;
+	/** List of project ID globs that cannot be depended on. */
+	to?: string[] | null;
+}
+
+/**
+ * Allows a relationship between projects that would otherwise be
+ * denied by a layer or deny rule.
+ */
+export interface PartialConstraintsExceptionConfig {
+	/** The date (YYYY-MM-DD) after which the exception no longer applies. */
+	expires?: string | null;
+	/** A project ID glob of the dependent project. */
+	from?: string | null;
+	/** An optional reason for the exception. */
+	reason?: string | null;
+	/** A project ID glob of the dependency project. */
+	to?: string | null;
+}
+
+/** A layer within a layered architecture. */
+export interface PartialConstraintsLayerConfig {
+	/** Unique ID of the layer. */
+	id?: string | null;
+	/** List of project ID globs that belong to the layer. */
+	projects?: string[] | null;
+}
+
 /** Configures boundaries and constraints between projects. */
 export interface PartialConstraintsConfig {
+	/**
+	 * List of rules that deny relationships between projects,
+	 * based on project ID globs.
+	 */
+	denyRelationships?: PartialConstraintsDenyConfig[] | null;
 	/**
 	 * Enforces relationships between projects based on each project's
 	 * `type` setting.
@@ -482,6 +623,26 @@ export interface PartialConstraintsConfig {
 	 * @default true
 	 */
 	enforceProjectTypeRelationships?: boolean | null;
+	/**
+	 * List of relationships that are allowed, even when denied by
+	 * `layers` or `denyRelationships`. Can be configured to expire.
+	 */
+	exceptions?: PartialConstraintsExceptionConfig[] | null;
+	/**
+	 * List of layers, ordered from highest to lowest. A project within a
+	 * layer may only depend on projects within the same or a lower layer.
+	 */
+	layers?: PartialConstraintsLayerConfig[] | null;
+	/**
+	 * The maximum number of projects, including transitive projects,
+	 * that a project may depend on.
+	 */
+	maxDependencies?: number | null;
+	/**
+	 * The maximum number of projects, including transitive projects,
+	 * that may depend on a project.
+	 */
+	maxDependents?: number | null;
 	/**
 	 * Enforces relationships between projects based on each project's
 	 * `tags` setting. Requires a mapping of tags, to acceptable tags.
@@ -623,10 +784,55 @@ export interface PartialHasherConfig {
 	warnOnMissingInputs?: boolean | null;
 }
 
+/** Configures a single webhook endpoint, and which events are sent to it. */
+export interface PartialNotifierWebhookConfig {
+	/**
+	 * Group high-volume action and task events into batches of this size,
+	 * and send each batch as a single request. When `0`, batching is disabled.
+	 */
+	batchSize?: number | null;
+	/**
+	 * List of event types to send, with support for a trailing
+	 * wildcard (`action.*`). When empty, all events are sent.
+	 */
+	events?: string[] | null;
+	/**
+	 * Only send events that represent a failure, such as a failed
+	 * action, or a pipeline that did not complete successfully.
+	 */
+	failuresOnly?: boolean | null;
+	/** HTTP headers to inject into every request. */
+	headers?: Record<string, string> | null;
+	/**
+	 * The maximum amount of times to retry a failed request,
+	 * with an exponential backoff between each attempt. Cannot exceed 10.
+	 *
+	 * @default 3
+	 */
+	retries?: number | null;
+	/**
+	 * The name of an environment variable that contains a secret, used to
+	 * sign payloads with HMAC-SHA256 via the `X-Moon-Signature` header.
+	 */
+	secret?: string | null;
+	/**
+	 * A Tera template that is rendered to create the request body,
+	 * instead of using the default JSON payload.
+	 */
+	template?: string | null;
+	/** A secure URL in which to send webhooks to. */
+	url?: string | null;
+}
+
 /** Configures how and where notifications are sent. */
 export interface PartialNotifierConfig {
 	/** A secure URL in which to send webhooks to. */
 	webhookUrl?: string | null;
+	/**
+	 * A list of webhook endpoints, each with their own event
+	 * filtering, payload template, and signing secret.
+	 */
+	webhooks?: PartialNotifierWebhookConfig[] | null;
 }
 
 /** Configures projects in the workspace, using both globs and explicit source paths. */
@@ -1,11 +1,19 @@
 /* eslint-disable node/no-unsupported-features/node-builtins */
 
 import './app.css';
-import { useState } from 'preact/hooks';
+import type { Core } from 'cytoscape';
+import { useEffect, useMemo, useRef, useState } from 'preact/hooks';
+import { Details } from './components/Details';
 import { Graph } from './components/Graph';
+import { findPaths, loadGraph, queryNodes } from './helpers/api';
+import type { CollapseBy, GraphEdge, GraphInfo, GraphNode, GraphPaths } from './helpers/types';
 
 const SUPPORTED_LAYOUTS = ['dagre', 'klay', 'breadthfirst', 'grid'];
 
+const COLLAPSE_OPTIONS: CollapseBy[] = ['none', 'stack', 'tag'];
+
+const INPUT_CLASS = 'border border-slate-400 rounded bg-slate-600 text-slate-50 p-1';
+
 function getLayoutFromQuery(): string {
 	let layout = new URLSearchParams(window.location.search).get('layout');
 
@@ -25,37 +33,250 @@ function setLayoutIntoQuery(layout: string) {
 }
 
 export function App() {
+	const initialData = useMemo(() => JSON.parse(window.GRAPH_DATA) as GraphInfo, []);
+	const cyRef = useRef<Core | null>(null);
 	const [layout, setLayout] = useState(getLayoutFromQuery());
+	const [collapse, setCollapse] = useState<CollapseBy>('none');
+	const [data, setData] = useState<GraphInfo>(initialData);
+	const [search, setSearch] = useState('');
+	const [query, setQuery] = useState('');
+	const [matched, setMatched] = useState<Set<number> | null>(null);
+	const [error, setError] = useState('');
+	const [selectedNode, setSelectedNode] = useState<GraphNode | null>(null);
+	const [selectedEdge, setSelectedEdge] = useState<GraphEdge | null>(null);
+	const [pathStart, setPathStart] = useState<GraphNode | null>(null);
+	const [pathEnd, setPathEnd] = useState<GraphNode | null>(null);
+	const [allPaths, setAllPaths] = useState(false);
+	const [paths, setPaths] = useState<GraphPaths | null>(null);
+	const [renderCount, setRenderCount] = useState(0);
 
-	function handleChange(event: Event) {
-		const target = event.target as HTMLSelectElement;
-		const newLayout = target.value;
+	function handleLayoutChange(event: Event) {
+		const newLayout = (event.target as HTMLSelectElement).value;
 
 		setLayout(newLayout);
 		setLayoutIntoQuery(newLayout);
 	}
 
+	function handleCollapseChange(event: Event) {
+		const newCollapse = (event.target as HTMLSelectElement).value as CollapseBy;
+
+		setCollapse(newCollapse);
+		setPathStart(null);
+		setPathEnd(null);
+		setSelectedNode(null);
+		setSelectedEdge(null);
+
+		loadGraph(newCollapse)
+			.then(setData)
+			.catch((error_: Error) => {
+				setError(error_.message);
+			});
+	}
+
+	function handleQuerySubmit(event: Event) {
+		event.preventDefault();
+		setError('');
+
+		if (!query.trim()) {
+			setMatched(null);
+
+			return;
+		}
+
+		queryNodes(query)
+			.then((result) => {
+				setMatched(new Set(result.nodes));
+			})
+			.catch((error_: Error) => {
+				setError(error_.message);
+			});
+	}
+
+	// Find paths between the selected nodes
+	useEffect(() => {
+		if (!pathStart || !pathEnd) {
+			setPaths(null);
+
+			return;
+		}
+
+		findPaths(pathStart.id, pathEnd.id, allPaths, collapse)
+			.then(setPaths)
+			.catch((error_: Error) => {
+				setError(error_.message);
+			});
+	}, [pathStart, pathEnd, allPaths, collapse]);
+
+	// Highlight nodes that match the search and query, and edges along paths
+	useEffect(() => {
+		const cy = cyRef.current;
+
+		if (!cy) {
+			return;
+		}
+
+		const term = search.trim().toLowerCase();
+		const pathNodes = new Set(paths?.paths.flat() ?? []);
+		const pathEdges = new Set(
+			paths?.paths.flatMap((path) =>
+				path.slice(1).map((target, index) => `${path[index]} -> ${target}`),
+			) ?? [],
+		);
+		const hasFilter = term !== '' || matched !== null;
+		const filtering = hasFilter || pathNodes.size > 0;
+
+		cy.batch(() => {
+			cy.elements().removeClass('dimmed matched endpoint path');
+
+			cy.nodes().forEach((element) => {
+				const node = element.data('node') as GraphNode;
+				const isMatch =
+					(term === '' || node.label.toLowerCase().includes(term)) &&
+					(matched === null ||
+						matched.has(node.id) ||
+						(node.members ?? []).some((id) => matched.has(id)));
+
+				if (pathNodes.has(node.id)) {
+					element.addClass(
+						node.id === pathStart?.id || node.id === pathEnd?.id ? 'endpoint' : 'matched',
+					);
+				} else if (hasFilter && isMatch) {
+					element.addClass('matched');
+				} else if (filtering) {
+					element.addClass('dimmed');
+				}
+			});
+
+			cy.edges().forEach((element) => {
+				if (pathEdges.has(element.id())) {
+					element.addClass('path');
+				} else if (filtering) {
+					element.addClass('dimmed');
+				}
+			});
+		});
+	}, [search, matched, paths, pathStart, pathEnd, renderCount]);
+
 	return (
 		<main>
-			<div className="p-4 flex items-center float-right">
-				<span className="inline-block mr-1">Layout:</span>
-
-				<select
-					className="border border-slate-400 rounded bg-slate-600 text-slate-50 p-1"
-					value={layout}
-					onChange={handleChange}
-				>
-					{SUPPORTED_LAYOUTS.map((value) => (
-						<option key={value} value={value}>
-							{value}
-						</option>
-					))}
-				</select>
+			<div className="p-4 flex items-center gap-4 float-right">
+				<label className="flex items-center gap-1">
+					Collapse:
+					<select className={INPUT_CLASS} value={collapse} onChange={handleCollapseChange}>
+						{COLLAPSE_OPTIONS.map((value) => (
+							<option key={value} value={value}>
+								{value}
+							</option>
+						))}
+					</select>
+				</label>
+
+				<label className="flex items-center gap-1">
+					Layout:
+					<select className={INPUT_CLASS} value={layout} onChange={handleLayoutChange}>
+						{SUPPORTED_LAYOUTS.map((value) => (
+							<option key={value} value={value}>
+								{value}
+							</option>
+						))}
+					</select>
+				</label>
 			</div>
 
 			<h2 className="m-0 p-4 text-3xl font-extrabold sm:text-4xl">{window.PAGE_TITLE}</h2>
 
-			<Graph layout={layout} />
+			<div className="px-4 flex flex-wrap items-center gap-4">
+				<input
+					className={INPUT_CLASS}
+					placeholder="Search..."
+					type="search"
+					value={search}
+					onInput={(event) => {
+						setSearch((event.target as HTMLInputElement).value);
+					}}
+				/>
+
+				<form className="flex items-center gap-1" onSubmit={handleQuerySubmit}>
+					<input
+						className={`${INPUT_CLASS} w-80`}
+						placeholder="MQL filter, e.g. tag=react && projectOwner=@org/team"
+						type="text"
+						value={query}
+						onInput={(event) => {
+							setQuery((event.target as HTMLInputElement).value);
+						}}
+					/>
+					<button className={INPUT_CLASS} type="submit">
+						Filter
+					</button>
+				</form>
+
+				<div className="flex items-center gap-2 text-sm">
+					<span>
+						Path: {pathStart?.label ?? '?'} → {pathEnd?.label ?? '?'}
+					</span>
+					<label className="flex items-center gap-1">
+						<input
+							checked={allPaths}
+							type="checkbox"
+							onChange={(event) => {
+								setAllPaths((event.target as HTMLInputElement).checked);
+							}}
+						/>
+						All paths
+					</label>
+					<button
+						className={INPUT_CLASS}
+						type="button"
+						onClick={() => {
+							setPathStart(null);
+							setPathEnd(null);
+						}}
+					>
+						Clear
+					</button>
+					{paths && (
+						<span className="text-slate-400">
+							{paths.paths.length === 0
+								? 'No path found'
+								: `${paths.paths.length} path(s)${paths.truncated ? ', truncated' : ''}`}
+						</span>
+					)}
+				</div>
+
+				{error && <span className="text-red-400 text-sm">{error}</span>}
+			</div>
+
+			<div className="flex">
+				<div className="grow">
+					<Graph
+						data={data}
+						layout={layout}
+						onEdgeSelect={(edge) => {
+							setSelectedNode(null);
+							setSelectedEdge(edge);
+						}}
+						onNodeSelect={(node) => {
+							setSelectedEdge(null);
+							setSelectedNode(node);
+						}}
+						onRender={(cy) => {
+							cyRef.current = cy;
+							setRenderCount((count) => count + 1);
+						}}
+					/>
+				</div>
+
+				<aside className="w-72 p-4">
+					<Details
+						edge={selectedEdge}
+						node={selectedNode}
+						nodes={[...data.nodes, ...initialData.nodes]}
+						onPathEnd={setPathEnd}
+						onPathStart={setPathStart}
+					/>
+				</aside>
+			</div>
 		</main>
 	);
 }
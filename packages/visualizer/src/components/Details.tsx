@@ -0,0 +1,85 @@
+import type { GraphEdge, GraphNode } from '../helpers/types';
+
+export interface DetailsProps {
+	edge: GraphEdge | null;
+	node: GraphNode | null;
+	// All known nodes, including collapsed members, for resolving labels
+	nodes: GraphNode[];
+	onPathEnd: (node: GraphNode) => void;
+	onPathStart: (node: GraphNode) => void;
+}
+
+function Row({ label, value }: { label: string; value?: string }) {
+	if (!value) {
+		return null;
+	}
+
+	return (
+		<div className="flex gap-2">
+			<dt className="text-slate-400">{label}</dt>
+			<dd className="m-0">{value}</dd>
+		</div>
+	);
+}
+
+function getLabel(nodes: GraphNode[], id: number) {
+	return nodes.find((node) => node.id === id)?.label ?? String(id);
+}
+
+export function Details({ edge, node, nodes, onPathEnd, onPathStart }: DetailsProps) {
+	if (edge) {
+		return (
+			<dl className="m-0 text-sm">
+				<Row label="From" value={getLabel(nodes, edge.source)} />
+				<Row label="To" value={getLabel(nodes, edge.target)} />
+				<Row label="Scope" value={edge.reason?.scope ?? edge.label} />
+				<Row label="Source" value={edge.reason?.source} />
+				<Row label="Via" value={edge.reason?.via} />
+			</dl>
+		);
+	}
+
+	if (node) {
+		return (
+			<div className="text-sm">
+				<dl className="m-0">
+					<Row label="Name" value={node.label} />
+					<Row label="Project" value={node.project} />
+					<Row label="Target" value={node.target} />
+					<Row label="Language" value={node.language} />
+					<Row label="Layer" value={node.layer} />
+					<Row label="Stack" value={node.stack} />
+					<Row label="Owner" value={node.owner} />
+					<Row label="Tags" value={node.tags?.join(', ')} />
+					<Row
+						label="Members"
+						value={node.members?.map((id) => getLabel(nodes, id)).join(', ')}
+					/>
+				</dl>
+
+				<div className="mt-2 flex gap-2">
+					<button
+						className="border border-slate-400 rounded bg-slate-600 px-2 py-1"
+						type="button"
+						onClick={() => {
+							onPathStart(node);
+						}}
+					>
+						Path from
+					</button>
+					<button
+						className="border border-slate-400 rounded bg-slate-600 px-2 py-1"
+						type="button"
+						onClick={() => {
+							onPathEnd(node);
+						}}
+					>
+						Path to
+					</button>
+				</div>
+			</div>
+		);
+	}
+
+	return <p className="m-0 text-sm text-slate-400">Select a node or edge to view details.</p>;
+}
@@ -1,19 +1,41 @@
+import type { Core } from 'cytoscape';
 import { useEffect, useRef } from 'preact/hooks';
 import { render } from '../helpers/render';
-import type { GraphInfo } from '../helpers/types';
+import type { GraphEdge, GraphInfo, GraphNode } from '../helpers/types';
 
 export interface GraphProps {
+	data: GraphInfo;
 	layout: string;
+	onEdgeSelect: (edge: GraphEdge) => void;
+	onNodeSelect: (node: GraphNode) => void;
+	onRender: (cy: Core) => void;
 }
 
-export function Graph({ layout }: GraphProps) {
+export function Graph({ data, layout, onEdgeSelect, onNodeSelect, onRender }: GraphProps) {
 	const graphRef = useRef<HTMLDivElement>(null);
 
 	useEffect(() => {
-		if (graphRef.current) {
-			render(graphRef.current, JSON.parse(window.GRAPH_DATA) as GraphInfo, layout);
+		if (!graphRef.current) {
+			return undefined;
 		}
-	}, [layout]);
+
+		const cy = render(graphRef.current, data, layout);
+
+		cy.on('tap', 'node', (event) => {
+			onNodeSelect(event.target.data('node') as GraphNode);
+		});
+
+		cy.on('tap', 'edge', (event) => {
+			onEdgeSelect(event.target.data('edge') as GraphEdge);
+		});
+
+		onRender(cy);
+
+		return () => {
+			cy.destroy();
+		};
+		// eslint-disable-next-line react-hooks/exhaustive-deps
+	}, [data, layout]);
 
 	return <div id="graph" ref={graphRef} style={{ height: '80vh', width: '100%' }} />;
 }
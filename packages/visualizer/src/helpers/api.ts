@@ -0,0 +1,40 @@
+/* eslint-disable node/no-unsupported-features/node-builtins */
+
+import type { CollapseBy, GraphInfo, GraphPaths, GraphQuery } from './types';
+
+async function request<T>(path: string, params: Record<string, string>): Promise<T> {
+	const response = await fetch(`${path}?${new URLSearchParams(params)}`);
+	const data = (await response.json()) as T & { error?: string };
+
+	if (!response.ok) {
+		throw new Error(data.error ?? `Request to ${path} failed.`);
+	}
+
+	return data;
+}
+
+export async function loadGraph(collapse: CollapseBy): Promise<GraphInfo> {
+	if (collapse === 'none') {
+		return JSON.parse(window.GRAPH_DATA) as GraphInfo;
+	}
+
+	return request('/api/graph', { collapse });
+}
+
+export async function queryNodes(query: string): Promise<GraphQuery> {
+	return request('/api/query', { q: query });
+}
+
+export async function findPaths(
+	from: number,
+	to: number,
+	all: boolean,
+	collapse: CollapseBy,
+): Promise<GraphPaths> {
+	return request('/api/paths', {
+		all: String(all),
+		collapse,
+		from: String(from),
+		to: String(to),
+	});
+}
@@ -44,11 +44,17 @@ function getShortDepLabel(label: string) {
 
 export function render(element: HTMLElement, data: GraphInfo, layout: string) {
 	const nodes = data.nodes.map((n) => ({
-		data: { id: n.id.toString(), label: n.label, type: getActionType(n.label) },
+		data: {
+			id: n.id.toString(),
+			label: n.label,
+			node: n,
+			type: n.members && n.members.length > 0 ? 'group' : getActionType(n.label),
+		},
 	}));
 
 	const edges = data.edges.map((e) => ({
 		data: {
+			edge: e,
 			id: e.id.toString(),
 			label: getShortDepLabel(e.label),
 			source: e.source.toString(),
@@ -149,6 +155,53 @@ export function render(element: HTMLElement, data: GraphInfo, layout: string) {
 					width: 120,
 				},
 			},
+			{
+				selector: 'node[type="group"]',
+				style: {
+					// @ts-expect-error Types incorrect
+					'background-gradient-stop-colors': '#ffe8a3 #ffd35c #cca94a',
+					height: 100,
+					shape: 'round-rectangle',
+					width: 100,
+				},
+			},
+			{
+				selector: '.dimmed',
+				style: {
+					opacity: 0.15,
+				},
+			},
+			{
+				selector: 'node.matched',
+				style: {
+					'border-color': '#ffd35c',
+					'border-width': 4,
+				},
+			},
+			{
+				selector: 'node.endpoint',
+				style: {
+					'border-color': '#79d5e9',
+					'border-width': 6,
+				},
+			},
+			{
+				selector: 'edge.path',
+				style: {
+					'line-color': '#79d5e9',
+					'line-opacity': 1,
+					'target-arrow-color': '#79d5e9',
+					'z-index': 10,
+				},
+			},
+			{
+				selector: 'edge:selected',
+				style: {
+					'line-color': '#ff79ff',
+					'line-opacity': 1,
+					'target-arrow-color': '#ff79ff',
+				},
+			},
 		],
 	});
 }
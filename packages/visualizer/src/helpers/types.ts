@@ -1,6 +1,20 @@
 export interface GraphNode {
 	id: number;
 	label: string;
+	project?: string;
+	target?: string;
+	language?: string;
+	layer?: string;
+	stack?: string;
+	owner?: string;
+	tags?: string[];
+	members?: number[];
+}
+
+export interface GraphEdgeReason {
+	scope: string;
+	source?: string;
+	via?: string;
 }
 
 export interface GraphEdge {
@@ -8,9 +22,21 @@ export interface GraphEdge {
 	source: number;
 	target: number;
 	label: string;
+	reason?: GraphEdgeReason;
 }
 
 export interface GraphInfo {
 	nodes: GraphNode[];
 	edges: GraphEdge[];
 }
+
+export interface GraphPaths {
+	paths: number[][];
+	truncated: boolean;
+}
+
+export interface GraphQuery {
+	nodes: number[];
+}
+
+export type CollapseBy = 'none' | 'stack' | 'tag';
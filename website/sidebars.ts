@@ -128,11 +128,16 @@ const sidebars: SidebarsConfig = {
 				},
 				'commands/ext',
 				'commands/generate',
+				'commands/imports',
 				'commands/init',
 				{
 					type: 'category',
 					label: 'migrate',
-					items: ['commands/migrate/from-package-json', 'commands/migrate/from-turborepo'],
+					items: [
+						'commands/migrate/from-nx',
+						'commands/migrate/from-package-json',
+						'commands/migrate/from-turborepo',
+					],
 					link: {
 						type: 'generated-index',
 						title: 'migrate',
@@ -141,6 +146,7 @@ const sidebars: SidebarsConfig = {
 						keywords: ['cli', 'commands', 'migrate'],
 					},
 				},
+				'commands/owners',
 				'commands/project',
 				'commands/project-graph',
 				{
@@ -164,6 +170,7 @@ const sidebars: SidebarsConfig = {
 				},
 				'commands/run',
 				'commands/setup',
+				'commands/stats',
 				{
 					type: 'category',
 					label: 'sync',
@@ -179,7 +186,15 @@ const sidebars: SidebarsConfig = {
 				'commands/task',
 				'commands/task-graph',
 				'commands/teardown',
-				'commands/templates',
+				{
+					type: 'category',
+					label: 'templates',
+					items: ['commands/templates/test'],
+					link: {
+						type: 'doc',
+						id: 'commands/templates',
+					},
+				},
 				'commands/upgrade',
 			],
 			link: {